@@ -0,0 +1,53 @@
+// Compare breaking with a value through `twist! -box` and through `twist! -enum`
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use tear::twist;
+use tear::Looping;
+
+// Pseudo-random values
+fn data () -> Vec<u32> {
+	let mut x = 1u32;
+	(0..1 << 16).map(|_| { x = x.wrapping_mul(1_103_515_245).wrapping_add(12_345); x >> 8 }).collect()
+}
+
+// The first value of each chunk that is a multiple of 16, or 0
+fn boxed (data :&[u32]) -> u32 {
+	let mut sum = 0u32;
+	for chunk in data.chunks(64) {
+		let found = 'a: loop {
+			for &v in chunk {
+				twist! { -box -label 'a :u32 |
+					if black_box(v) & 0xf == 0 { Looping::breakval_boxed(Some(0), v) } else { Looping::Resume(()) }
+				}
+			}
+			break 0;
+		};
+		sum = sum.wrapping_add(found);
+	}
+	sum
+}
+
+fn enumed (data :&[u32]) -> u32 {
+	let mut sum = 0u32;
+	for chunk in data.chunks(64) {
+		let found = 'a: loop {
+			for &v in chunk {
+				twist! { -enum Sig -label 'a as A: u32 |
+					if black_box(v) & 0xf == 0 { Looping::BreakVal { label: None, value: Sig::A(v) } } else { Looping::Resume(()) }
+				}
+			}
+			break 0;
+		};
+		sum = sum.wrapping_add(found);
+	}
+	sum
+}
+
+fn bench (c :&mut Criterion) {
+	let data = data();
+	assert_eq![ boxed(&data), enumed(&data) ];
+	c.bench_function("box", |b| b.iter(|| boxed(black_box(&data))));
+	c.bench_function("enum", |b| b.iter(|| enumed(black_box(&data))));
+}
+
+criterion_group!(benches, bench);
+criterion_main!(benches);
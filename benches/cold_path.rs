@@ -0,0 +1,90 @@
+// Compare `terror!` with a hand-written `match` in a branchy parser that rarely returns early.
+// Compare the "cold-path" feature with a baseline:
+//   cargo bench --bench cold_path -- --save-baseline plain
+//   cargo bench --bench cold_path --features cold-path -- --baseline plain
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use tear::terror;
+
+#[derive(Debug, PartialEq)]
+enum Error {
+	Empty,
+	Digit(u8),
+	Overflow,
+}
+
+// Comma-separated numbers, with a bad byte in one record out of 256
+fn data () -> Vec<Vec<u8>> {
+	let mut x = 1u32;
+	(0..1 << 12).map(|_| {
+		let mut record = Vec::new();
+		for _ in 0..8 {
+			x = x.wrapping_mul(1_103_515_245).wrapping_add(12_345);
+			record.extend((x >> 12).to_string().bytes());
+			record.push(b',');
+		}
+		if x & 0xff00 == 0 { record[3] = b'x'; }
+		record
+	}).collect()
+}
+
+fn digit (b :u8) -> Result<u32, Error> {
+	if b.is_ascii_digit() { Ok(u32::from(b - b'0')) } else { Err(Error::Digit(b)) }
+}
+
+fn with_terror (record :&[u8]) -> Result<u32, Error> {
+	let mut sum = 0u32;
+	for field in record.split(|&b| b == b',').filter(|f| !f.is_empty()) {
+		let mut n = 0u32;
+		for &b in field {
+			let d = terror! { digit(b) };
+			n = terror! { n.checked_mul(10).and_then(|n| n.checked_add(d)) =>> Error::Overflow };
+		}
+		sum = sum.wrapping_add(n);
+	}
+	terror! { if sum == 0 { None } else { Some(()) } =>> Error::Empty };
+	Ok(sum)
+}
+
+// Spelled out instead of `?`, which expands to the same kind of `match`
+#[allow(clippy::question_mark)]
+fn with_match (record :&[u8]) -> Result<u32, Error> {
+	let mut sum = 0u32;
+	for field in record.split(|&b| b == b',').filter(|f| !f.is_empty()) {
+		let mut n = 0u32;
+		for &b in field {
+			let d = match digit(b) {
+				Ok(d) => d,
+				Err(e) => return Err(e),
+			};
+			n = match n.checked_mul(10).and_then(|n| n.checked_add(d)) {
+				Some(n) => n,
+				None => return Err(Error::Overflow),
+			};
+		}
+		sum = sum.wrapping_add(n);
+	}
+	if sum == 0 { return Err(Error::Empty); }
+	Ok(sum)
+}
+
+fn parse_all (data :&[Vec<u8>], f :fn(&[u8]) -> Result<u32, Error>) -> (u32, usize) {
+	let mut sum = 0u32;
+	let mut errors = 0;
+	for record in data {
+		match f(black_box(record)) {
+			Ok(v) => sum = sum.wrapping_add(v),
+			Err(_) => errors += 1,
+		}
+	}
+	(sum, errors)
+}
+
+fn bench (c :&mut Criterion) {
+	let data = data();
+	assert_eq![ parse_all(&data, with_terror), parse_all(&data, with_match) ];
+	c.bench_function("parser terror", |b| b.iter(|| parse_all(black_box(&data), with_terror)));
+	c.bench_function("parser match", |b| b.iter(|| parse_all(black_box(&data), with_match)));
+}
+
+criterion_group!(benches, bench);
+criterion_main!(benches);
@@ -0,0 +1,47 @@
+// Compare `terror!` with `map_err(..)?` when parsing numbers
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use tear::terror;
+
+#[derive(Debug, PartialEq)]
+struct Error(usize);
+
+// Pseudo-random numbers, one in eight of them invalid
+fn data () -> Vec<String> {
+	let mut x = 1u32;
+	(0..1 << 12).map(|_| {
+		x = x.wrapping_mul(1_103_515_245).wrapping_add(12_345);
+		if x & 0x70000 == 0 { format!("{}x", x >> 20) } else { (x >> 8).to_string() }
+	}).collect()
+}
+
+fn parse_terror (s :&str) -> Result<u32, Error> {
+	let v = terror! { s.parse::<u32>() => |_| Error(s.len()) };
+	Ok(v)
+}
+
+fn parse_map_err (s :&str) -> Result<u32, Error> {
+	let v = s.parse::<u32>().map_err(|_| Error(s.len()))?;
+	Ok(v)
+}
+
+fn sum (data :&[String], f :fn(&str) -> Result<u32, Error>) -> (u32, usize) {
+	let mut sum = 0u32;
+	let mut errors = 0;
+	for s in data {
+		match f(black_box(s)) {
+			Ok(v) => sum = sum.wrapping_add(v),
+			Err(Error(n)) => errors += n,
+		}
+	}
+	(sum, errors)
+}
+
+fn bench (c :&mut Criterion) {
+	let data = data();
+	assert_eq![ sum(&data, parse_terror), sum(&data, parse_map_err) ];
+	c.bench_function("terror", |b| b.iter(|| sum(black_box(&data), parse_terror)));
+	c.bench_function("map_err", |b| b.iter(|| sum(black_box(&data), parse_map_err)));
+}
+
+criterion_group!(benches, bench);
+criterion_main!(benches);
@@ -0,0 +1,64 @@
+// Compare `twist!` with a hand-written match in a tight parse loop
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use tear::twist;
+use tear::{Looping, BreakValError};
+
+// Lines of digits and spaces, some of them with a `#` comment
+fn data () -> Vec<u8> {
+	let mut x = 1u32;
+	(0..1 << 16).map(|_| {
+		x = x.wrapping_mul(1_103_515_245).wrapping_add(12_345);
+		match (x >> 8) % 64 {
+			0 => b'\n',
+			1 => b'#',
+			2..=9 => b' ',
+			n => b'0' + (n % 10) as u8,
+		}
+	}).collect()
+}
+
+// Skip spaces, skip the rest of the line at a comment, and resume with the digit value
+fn signal (b :u8) -> Looping<u32, BreakValError> {
+	match b {
+		b'0'..=b'9' => Looping::Resume(u32::from(b - b'0')),
+		b'#' => Looping::Continue { label: Some(0) },
+		_ => Looping::Continue { label: None },
+	}
+}
+
+fn with_twist (data :&[u8]) -> u32 {
+	let mut sum = 0u32;
+	'line: for line in data.split(|&b| b == b'\n') {
+		for &b in line {
+			let d = twist! { -label 'line | signal(black_box(b)) };
+			sum = sum.wrapping_mul(10).wrapping_add(d);
+		}
+	}
+	sum
+}
+
+fn with_match (data :&[u8]) -> u32 {
+	let mut sum = 0u32;
+	'line: for line in data.split(|&b| b == b'\n') {
+		for &b in line {
+			let d = match signal(black_box(b)) {
+				Looping::Resume(v) => v,
+				Looping::Continue { label: Some(_) } => continue 'line,
+				Looping::Continue { label: None } => continue,
+				_ => unreachable!(),
+			};
+			sum = sum.wrapping_mul(10).wrapping_add(d);
+		}
+	}
+	sum
+}
+
+fn bench (c :&mut Criterion) {
+	let data = data();
+	assert_eq![ with_twist(&data), with_match(&data) ];
+	c.bench_function("twist", |b| b.iter(|| with_twist(black_box(&data))));
+	c.bench_function("match", |b| b.iter(|| with_match(black_box(&data))));
+}
+
+criterion_group!(benches, bench);
+criterion_main!(benches);
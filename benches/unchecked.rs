@@ -0,0 +1,59 @@
+// Compare `twist! -label` with `twist! -unchecked -label` on a hot loop
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use tear::twist;
+use tear::{Looping, BreakValError};
+
+// Skip the rest of the chunk for some values, the value for others, or resume with it
+fn signal (v :u32) -> Looping<u32, BreakValError> {
+	if v & 0x3ff == 0 {
+		Looping::Continue { label: Some(1) }
+	} else if v & 0xf == 0 {
+		Looping::Continue { label: Some(2) }
+	} else {
+		Looping::Resume(v)
+	}
+}
+
+// Pseudo-random values
+fn data () -> Vec<u32> {
+	let mut x = 1u32;
+	(0..1 << 16).map(|_| { x = x.wrapping_mul(1_103_515_245).wrapping_add(12_345); x >> 8 }).collect()
+}
+
+// The signals are hidden behind `black_box`, so that the optimizer can't see that the label
+// indices are valid
+fn checked (data :&[u32]) -> u32 {
+	let mut sum = 0u32;
+	'a: for _ in 0..2 {
+		'b: for chunk in data.chunks(64) {
+			'c: for &v in chunk {
+				let v = twist! { -label 'a, 'b, 'c | black_box(signal(v)) };
+				sum = sum.wrapping_add(v);
+			}
+		}
+	}
+	sum
+}
+
+fn unchecked (data :&[u32]) -> u32 {
+	let mut sum = 0u32;
+	'a: for _ in 0..2 {
+		'b: for chunk in data.chunks(64) {
+			'c: for &v in chunk {
+				let v = twist! { -unchecked -label 'a, 'b, 'c | black_box(signal(v)) };
+				sum = sum.wrapping_add(v);
+			}
+		}
+	}
+	sum
+}
+
+fn bench (c :&mut Criterion) {
+	let data = data();
+	assert_eq![ checked(&data), unchecked(&data) ];
+	c.bench_function("label", |b| b.iter(|| checked(black_box(&data))));
+	c.bench_function("unchecked label", |b| b.iter(|| unchecked(black_box(&data))));
+}
+
+criterion_group!(benches, bench);
+criterion_main!(benches);
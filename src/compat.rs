@@ -0,0 +1,101 @@
+/*! Migration helpers from other crates (f=compat)
+
+# Description
+
+[`guard!`] has the same syntax as the macro of the [guard](https://docs.rs/crate/guard) crate, so
+that you can switch by changing the import:
+```
+use tear::compat::guard;
+
+fn first_word (s :&str) -> Result<&str, String> {
+    guard!(let Some(w) = s.split_whitespace().next() else { return Err("empty".to_string()) });
+    Ok(w)
+}
+
+assert_eq![ first_word("hello world"), Ok("hello") ];
+assert_eq![ first_word(" "), Err("empty".to_string()) ];
+```
+
+Then move to the native forms of this crate when the pattern binds a single value from a type
+that implements `Judge`:
+
+| `guard!`                                                  | Native form                                  |
+|-----------------------------------------------------------|----------------------------------------------|
+| `guard!(let Some(x) = $e else { return $v });`            | `let x = tear! { $e =>> $v };`               |
+| `guard!(let Ok(x) = $e else { return Err($err) });`       | `let x = terror! { $e =>> $err };`           |
+| `guard!(let Some(x) = $e else { continue });`             | `let x = twist! { $e =>> next!() };`         |
+| `guard!(let Some(x) = $e else { break });`                | `let x = twist! { $e =>> last!() };`         |
+
+`tear_if!` is the opposite: it returns early when the pattern *matches*. Patterns that bind
+several values, or that aren't `Good` or `Val` variants, stay with `guard!`.
+
+It needs Rust 1.65, as it expands to `let … else`.
+*/
+
+pub use crate::guard;
+
+/** Bind a pattern or run the diverging `else` block, with the syntax of the `guard` crate (f=compat)
+
+```text
+guard!(let $pattern = $expr else { $diverging });
+guard!({ $diverging } unless let $pattern = $expr);
+```
+
+The bindings of `$pattern` are available after the macro call. The `else` block must return,
+break, continue or panic. It's the same as `let $pattern = $expr else { $diverging };`.
+
+# Examples
+
+```
+use tear::compat::guard;
+
+let mut sum = 0;
+for s in &["1", "x", "2", "end", "3"] {
+    guard!(let Ok(v) = s.parse::<i32>() else {
+        if *s == "end" { break } else { continue }
+    });
+    sum += v;
+}
+assert_eq![ sum, 3 ];
+```
+
+The old syntax of the `guard` crate, with the block first
+```
+# use tear::compat::guard;
+fn head (v :&[i32]) -> i32 {
+    guard!({ return -1 } unless let [first, ..] = v);
+    *first
+}
+
+assert_eq![ head(&[4, 5]), 4 ];
+assert_eq![ head(&[]), -1 ];
+```
+*/
+#[macro_export]
+macro_rules! guard {
+	// Find the `else` that ends the expression
+	( @expr $p:tt [$($e:tt)+] [ else $body:block ] ) => {
+		let $p = $($e)+ else $body;
+	};
+	( @expr $p:tt [$($e:tt)*] [ $token:tt $($rest:tt)* ] ) => {
+		$crate::guard! { @expr $p [$($e)* $token] [$($rest)*] }
+	};
+	( @expr $p:tt [$($e:tt)*] [] ) => {
+		compile_error!("Missing `else` block after the expression in `guard!`")
+	};
+	// `let $pattern = $expr else $body`
+	( let $p:pat = $($rest:tt)+ ) => {
+		$crate::guard! { @expr $p [] [$($rest)+] }
+	};
+	// Top-level or-patterns need parentheses in `let`
+	( let $p0:pat $(| $p:pat)+ = $($rest:tt)+ ) => {
+		$crate::guard! { @expr ($p0 $(| $p)+) [] [$($rest)+] }
+	};
+	// `$body unless let $pattern = $expr`
+	( $body:block unless let $p:pat = $e:expr ) => {
+		let $p = $e else $body;
+	};
+	( $body:block unless let $p0:pat $(| $p:pat)+ = $e:expr ) => {
+		let ($p0 $(| $p)+) = $e else $body;
+	};
+}
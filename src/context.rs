@@ -0,0 +1,142 @@
+/*! Error context for `anyhow` and `eyre` (f=anyhow, f=eyre)
+
+Macros are accessible from the crate root:
+- (f=anyhow) `ctx!`
+- (f=eyre) `wrap_err!`
+
+Both macros expand to a closure that wraps the bad value into an error report with a context message.
+They're meant to be used on the right-hand side of `terror!`'s mapping syntax:
+
+```text
+terror! { $e => ctx!("reading {}", path) }
+```
+
+The message is only formatted when the expression is Bad, like `with_context`. The bad value
+can be anything that implements `std::error::Error`, a `String` or a `&'static str`.
+*/
+use alloc::string::String;
+
+/** (dev) Marker for bad values that implement `std::error::Error` */
+pub enum ViaError {}
+/** (dev) Marker for bad values that are messages, like `String` */
+pub enum ViaMessage {}
+/** (dev) Marker for bad values that already are reports */
+pub enum ViaReport {}
+
+/** (dev) Convert a bad value into an `anyhow::Error` (f=anyhow)
+
+The `M` parameter is a marker that lets us implement it for both `std::error::Error` and `String`.
+Otherwise, the implementations would conflict.
+*/
+#[cfg(feature = "anyhow")]
+pub trait IntoAnyhow<M> {
+	/// Convert into `anyhow::Error`, keeping the error source chain if there is one
+	fn into_anyhow (self) -> anyhow::Error;
+}
+
+#[cfg(feature = "anyhow")]
+impl<E> IntoAnyhow<ViaError> for E where E :std::error::Error + Send + Sync + 'static {
+	fn into_anyhow (self) -> anyhow::Error { anyhow::Error::new(self) }
+}
+
+#[cfg(feature = "anyhow")]
+impl IntoAnyhow<ViaMessage> for String {
+	fn into_anyhow (self) -> anyhow::Error { anyhow::Error::msg(self) }
+}
+
+#[cfg(feature = "anyhow")]
+impl IntoAnyhow<ViaMessage> for &'static str {
+	fn into_anyhow (self) -> anyhow::Error { anyhow::Error::msg(self) }
+}
+
+#[cfg(feature = "anyhow")]
+impl IntoAnyhow<ViaReport> for anyhow::Error {
+	fn into_anyhow (self) -> anyhow::Error { self }
+}
+
+/** (dev) Convert a bad value into an `eyre::Report` (f=eyre)
+
+See [`IntoAnyhow`] for the role of `M`.
+*/
+#[cfg(feature = "eyre")]
+pub trait IntoEyre<M> {
+	/// Convert into `eyre::Report`, keeping the error source chain if there is one
+	fn into_eyre (self) -> eyre::Report;
+}
+
+#[cfg(feature = "eyre")]
+impl<E> IntoEyre<ViaError> for E where E :std::error::Error + Send + Sync + 'static {
+	fn into_eyre (self) -> eyre::Report { eyre::Report::new(self) }
+}
+
+#[cfg(feature = "eyre")]
+impl IntoEyre<ViaMessage> for String {
+	fn into_eyre (self) -> eyre::Report { eyre::Report::msg(self) }
+}
+
+#[cfg(feature = "eyre")]
+impl IntoEyre<ViaMessage> for &'static str {
+	fn into_eyre (self) -> eyre::Report { eyre::Report::msg(self) }
+}
+
+#[cfg(feature = "eyre")]
+impl IntoEyre<ViaReport> for eyre::Report {
+	fn into_eyre (self) -> eyre::Report { self }
+}
+
+/** Wrap the bad value into an `anyhow::Error` with a context message (f=anyhow)
+
+Takes the same arguments as `format!`.
+
+# Example
+
+```
+# use tear::prelude::*;
+use tear::ctx;
+
+fn parse (s :&str) -> anyhow::Result<i32> {
+    let v = terror! { s.parse::<i32>() => ctx!("parsing {:?}", s) };
+    Ok(v)
+}
+
+let e = parse("a").unwrap_err();
+assert_eq![ format!("{:#}", e), r#"parsing "a": invalid digit found in string"# ];
+```
+*/
+#[cfg(feature = "anyhow")]
+#[macro_export]
+macro_rules! ctx {
+	( $($fmt:tt)+ ) => {
+		|e| $crate::context::IntoAnyhow::into_anyhow(e).context($crate::context::__format!($($fmt)+))
+	};
+}
+
+/** Wrap the bad value into an `eyre::Report` with a context message (f=eyre)
+
+Takes the same arguments as `format!`.
+
+# Example
+
+```
+# use tear::prelude::*;
+use tear::wrap_err;
+
+fn parse (s :&str) -> eyre::Result<i32> {
+    let v = terror! { s.parse::<i32>() => wrap_err!("parsing {:?}", s) };
+    Ok(v)
+}
+
+let e = parse("a").unwrap_err();
+assert_eq![ e.to_string(), r#"parsing "a""# ];
+```
+*/
+#[cfg(feature = "eyre")]
+#[macro_export]
+macro_rules! wrap_err {
+	( $($fmt:tt)+ ) => {
+		|e| $crate::context::IntoEyre::into_eyre(e).wrap_err($crate::context::__format!($($fmt)+))
+	};
+}
+
+// Reexport for the macros
+#[doc(hidden)] pub use alloc::format as __format;
@@ -12,19 +12,31 @@ In addition to all the symbols in `prelude`, it exports the following:
 
 - Moral and its variants Good and Bad
 - Looping
-- Judge and Return traits
-- Utility macros `last!`, `next!` and `resume!`
+- Judge, Return and IntoLooping traits
+- Utility macros `last!`, `next!`, `resume!` and `breakval!`
+- Their variants with an inferred break value type `stop!`, `skip!` and `go!`
+- `redo!` and `tredo_loop!`, the loop that handles it
+- `maybe_match!` for enum accessors
 - `gut` function, and `Maru` type
+- `Folded`, the result of `looping_fold`
+
+The iterator extension traits are in scope through `prelude`, but without their names.
 */
 
 pub use crate::prelude::*;
 
 // Extra types that might name conflict
 pub use crate::Moral::{self, *};
-pub use crate::{Judge, Return};
+pub use crate::{Judge, Return, IntoLooping};
 
 // Extra macros
-pub use crate::{last, next, resume};
+pub use crate::{last, next, resume, breakval};
+pub use crate::{stop, skip, go};
+pub use crate::{redo, tredo_loop};
+pub use crate::maybe_match;
+
+// Iterator extension
+pub use crate::Folded;
 
 // Gutting
 pub use crate::gut;
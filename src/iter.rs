@@ -0,0 +1,609 @@
+/*! Iterator adapters driven by `Looping` signals
+
+# Description
+
+[`LoopingIteratorExt`] iterates with closures that return `Looping` signals, like the body of a
+loop that uses `twist!`.
+
+[`MapLooping`] is the iterator returned by [`LoopingIteratorExt::map_looping`]. It brings the
+`twist! { $e => $f }` pattern to iterator pipelines, where `twist!` can't be used: `$e` is the
+first closure, and `$f` is the second one.
+
+```
+use tear::extra::*;
+
+let mut numbers = vec!["1", "a", "2", "-3", "4"].into_iter().map_looping(
+    |s| s.parse::<i32>(),
+    |_| next!(),
+).map_looping(
+    |v| if v < 0 { Bad(v) } else { Good(v) },
+    |v| breakval!(v),
+);
+
+assert_eq![ numbers.by_ref().collect::<Vec<_>>(), vec![1, 2] ];
+assert_eq![ numbers.broke(), Some(&-3) ];
+```
+
+[`Goods`] and [`Bads`] are the iterators returned by [`JudgeIteratorExt::goods`] and
+[`JudgeIteratorExt::bads`]. They keep the Good or the Bad values of an iterator of `Judge` items,
+like `twist! { $e => |_| next!() }` in a loop.
+
+[`UntilBad`], returned by [`JudgeIteratorExt::until_bad`], yields the Good values until the first
+Bad one, and keeps it, like `twist! { $e => |_| last!() }` in a loop.
+
+[`TearMap`], returned by [`ValRetIteratorExt::tear_map`], maps the items to `ValRet` values, and
+stops at the first `Ret`. Its [`finish`](TearMap::finish) method collects the rest into a `ValRet`
+that `tear!` can return early with.
+
+```
+use tear::prelude::*;
+use tear::ValRetIteratorExt;
+
+fn parse (s :&str) -> ValRet<i32, String> {
+    match s.parse() {
+        Ok(v) => Val(v),
+        Err(_) => Ret(format!("not a number: {}", s)),
+    }
+}
+
+fn parse_all (values :&[&str]) -> String {
+    let v :Vec<i32> = tear! { values.iter().tear_map(|s| parse(s)).finish() };
+    format!("{:?}", v)
+}
+
+assert_eq![ parse_all(&["1", "2"]), "[1, 2]" ];
+assert_eq![ parse_all(&["1", "x", "y"]), "not a number: x" ];
+```
+*/
+use crate::{Judge, Looping, ValRet};
+use crate::Moral::{self, Good, Bad};
+use core::iter::FusedIterator;
+#[cfg(feature = "alloc")] use alloc::vec::Vec;
+
+/** Iterate with a closure that returns `Looping` signals, like the body of a `for` loop
+
+# Description
+
+`tear_for_each` calls the closure on each item, and acts on the `Looping` value it returns:
+- `Resume(())` and `Continue` go on to the next item
+- `Break` stops iterating, and it returns `None`
+- `BreakVal` stops iterating, and it returns `Some(value)`
+
+It returns `None` when the iterator is exhausted. There are no enclosing loops, so it panics
+if the signal has a label, like `twist!` with an invalid label index.
+
+# Example
+
+```
+use tear::extra::*;
+use tear::LoopingIteratorExt;
+
+let first_even = (1..10).tear_for_each(|v| {
+    if v % 2 == 0 { breakval!(v) } else { go!(()) }
+});
+assert_eq![ first_even, Some(2) ];
+
+let none = (1..10).tear_for_each(|v| if v > 20 { stop!() } else { skip!() });
+assert_eq![ none, None::<()> ];
+```
+*/
+pub trait LoopingIteratorExt :Iterator {
+	/** Calls `f` on each item, until it breaks or the iterator is exhausted
+
+	Returns the break value of `BreakVal`, or `None`. See the [trait documentation](LoopingIteratorExt).
+	*/
+	fn tear_for_each<B, F> (self, mut f :F) -> Option<B>
+	where Self :Sized, F :FnMut(Self::Item) -> Looping<(), B> {
+		for v in self {
+			match f(v) {
+				Looping::Resume(()) => {},
+				Looping::Continue { label: None } => {},
+				Looping::Break { label: None } => return None,
+				Looping::BreakVal { label: None, value } => return Some(value),
+				signal => crate::twist_impl::no_enclosing_loop(&signal),
+			}
+		}
+		None
+	}
+
+	/** Folds the items with `f`, until it breaks or the iterator is exhausted
+
+	`f` takes the accumulator and the item, and returns a `Looping`:
+	- `Resume(acc)` goes on with the new accumulator
+	- `Continue` goes on with the previous accumulator, so we clone it before each call
+	- `Break` stops, and it returns `Folded::Done` with the previous accumulator
+	- `BreakVal` stops, and it returns `Folded::Broke` with the break value
+
+	It returns `Folded::Done` with the last accumulator when the iterator is exhausted.
+	It panics if the signal has a label, like [`tear_for_each`](LoopingIteratorExt::tear_for_each).
+
+	The closure can then be shared with code that uses `twist!` in a loop:
+
+	```
+	use tear::extra::*;
+	use tear::{Folded, LoopingIteratorExt};
+
+	// Skip the strings that aren't numbers, and stop at the first negative number
+	fn step (acc :i32, s :&str) -> Looping<i32, i32> {
+	    match s.parse::<i32>() {
+	        Err(_) => skip!(),
+	        Ok(v) if v < 0 => breakval!(v),
+	        Ok(v) => go!(acc + v),
+	    }
+	}
+
+	assert_eq![ vec!["1", "a", "2"].into_iter().looping_fold(0, step), Folded::Done(3) ];
+	assert_eq![ vec!["1", "-4", "2"].into_iter().looping_fold(0, step), Folded::Broke(-4) ];
+
+	let mut acc = 0;
+	let mut strings = vec!["1", "a", "-2"].into_iter();
+	let r = loop {
+	    let s = twist! { -val strings.next() => |_| breakval!(0) };
+	    acc = twist! { -val step(acc, s) };
+	};
+	assert_eq![ (acc, r), (1, -2) ];
+	```
+	*/
+	fn looping_fold<Acc, B, F> (self, init :Acc, mut f :F) -> Folded<Acc, B>
+	where Self :Sized, Acc :Clone, F :FnMut(Acc, Self::Item) -> Looping<Acc, B> {
+		let mut acc = init;
+		for v in self {
+			match f(acc.clone(), v) {
+				Looping::Resume(next) => acc = next,
+				Looping::Continue { label: None } => {},
+				Looping::Break { label: None } => break,
+				Looping::BreakVal { label: None, value } => return Folded::Broke(value),
+				signal => crate::twist_impl::no_enclosing_loop(&signal),
+			}
+		}
+		Folded::Done(acc)
+	}
+
+	/** Maps each item with `f`, and the Bad values with `g`, yielding the Good values
+
+	It's `twist! { f(item) => g }` for each item, but as an iterator adapter: `Continue` skips the
+	item, and `Break` and `BreakVal` stop the iterator. See [`MapLooping`] for
+	the details, and for getting the break value with `broke` afterwards.
+
+	```
+	use tear::extra::*;
+
+	let v :Vec<i32> = vec!["1", "a", "2"].into_iter().map_looping(|s| s.parse::<i32>(), |_| next!()).collect();
+	assert_eq![ v, vec![1, 2] ];
+	```
+	*/
+	fn map_looping<F, G, J, B> (self, f :F, g :G) -> MapLooping<Self, F, G, B>
+	where Self :Sized, F :FnMut(Self::Item) -> J, J :crate::Judge, G :FnMut(J::Negative) -> Looping<J::Positive, B> {
+		MapLooping::new(self, f, g)
+	}
+}
+
+impl<I :Iterator> LoopingIteratorExt for I {}
+
+/** Result of [`LoopingIteratorExt::looping_fold`] */
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Folded<Acc, B> {
+	/// The iterator was exhausted, or the fold was stopped with `Break`
+	Done(Acc),
+	/// The fold was stopped with `BreakVal`
+	Broke(B),
+}
+
+/** Iterator that yields the Good values, and maps the Bad ones to `Looping` signals
+
+Created with [`LoopingIteratorExt::map_looping`]. For each item, it calls the first closure on it,
+which returns a value implementing `Judge`:
+- A Good value is yielded
+- A Bad value is passed to the second closure, and the iterator acts on the `Looping` it returns:
+  - `Resume(v)` yields `v` instead
+  - `Continue` skips the item
+  - `Break` stops the iterator
+  - `BreakVal` stops the iterator, and [`broke`](MapLooping::broke) then returns the value
+
+Once stopped, it always returns `None`, even if the inner iterator has more items. There are no
+enclosing loops, so it panics if the signal has a label.
+*/
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct MapLooping<I, F, G, B> {
+	iter :I,
+	f :F,
+	g :G,
+	stopped :bool,
+	broke :Option<B>,
+}
+
+impl<I, F, G, B> MapLooping<I, F, G, B> {
+	/// (dev) Use [`LoopingIteratorExt::map_looping`] instead
+	pub(crate) fn new (iter :I, f :F, g :G) -> Self {
+		MapLooping { iter, f, g, stopped: false, broke: None }
+	}
+
+	/// The break value, if the iterator was stopped with `BreakVal`
+	pub fn broke (&self) -> Option<&B> {
+		self.broke.as_ref()
+	}
+
+	/// Whether the iterator was stopped with `Break` or `BreakVal`, or is exhausted
+	pub fn is_stopped (&self) -> bool {
+		self.stopped
+	}
+}
+
+impl<I, F, G, J, B> Iterator for MapLooping<I, F, G, B>
+where I :Iterator, F :FnMut(I::Item) -> J, J :Judge, G :FnMut(J::Negative) -> Looping<J::Positive, B> {
+	type Item = J::Positive;
+
+	fn next (&mut self) -> Option<Self::Item> {
+		if self.stopped {
+			return None;
+		}
+		for v in self.iter.by_ref() {
+			let e = match (self.f)(v).into_moral() {
+				Good(v) => return Some(v),
+				Bad(e) => e,
+			};
+			match (self.g)(e) {
+				Looping::Resume(v) => return Some(v),
+				Looping::Continue { label: None } => {},
+				Looping::Break { label: None } => break,
+				Looping::BreakVal { label: None, value } => {
+					self.broke = Some(value);
+					break;
+				},
+				signal => crate::twist_impl::no_enclosing_loop(&signal),
+			}
+		}
+		self.stopped = true;
+		None
+	}
+
+	fn size_hint (&self) -> (usize, Option<usize>) {
+		if self.stopped {
+			(0, Some(0))
+		} else {
+			(0, self.iter.size_hint().1)
+		}
+	}
+}
+
+impl<I, F, G, J, B> FusedIterator for MapLooping<I, F, G, B>
+where I :Iterator, F :FnMut(I::Item) -> J, J :Judge, G :FnMut(J::Negative) -> Looping<J::Positive, B> {}
+
+/** Iterator adapters for iterators of `Judge` values
+
+# Example
+
+```
+use tear::JudgeIteratorExt;
+
+let values = vec![Ok(1), Err("a"), Ok(2)];
+assert_eq![ values.clone().into_iter().goods().collect::<Vec<_>>(), vec![1, 2] ];
+assert_eq![ values.into_iter().bads().collect::<Vec<_>>(), vec!["a"] ];
+```
+*/
+pub trait JudgeIteratorExt :Iterator where Self::Item :Judge {
+	/// Yields the Good values, and drops the Bad ones
+	fn goods (self) -> Goods<Self> where Self :Sized {
+		Goods { iter: self }
+	}
+
+	/// Yields the Bad values, and drops the Good ones
+	fn bads (self) -> Bads<Self> where Self :Sized {
+		Bads { iter: self }
+	}
+
+	/** Yields the Good values, and stops at the first Bad one
+
+	The Bad value is then available with [`UntilBad::bad`] or [`UntilBad::into_bad`].
+
+	```
+	use tear::JudgeIteratorExt;
+
+	let mut lines = vec!["1", "2", "x", "3"].into_iter().map(|s| s.parse::<i32>()).until_bad();
+	assert_eq![ lines.by_ref().sum::<i32>(), 3 ];
+	assert_eq![ lines.into_bad().map(|e| e.to_string()), Some("invalid digit found in string".to_string()) ];
+	```
+	*/
+	fn until_bad (self) -> UntilBad<Self, <Self::Item as Judge>::Negative> where Self :Sized {
+		UntilBad { iter: self, stopped: false, bad: None }
+	}
+
+	/** Folds the Good and the Bad values separately, and returns both accumulators
+
+	```
+	use tear::JudgeIteratorExt;
+
+	let (sum, errors) = vec![Ok(1), Err("a"), Ok(2)].into_iter()
+	    .fold_moral(0, 0, |sum, v| sum + v, |count, _| count + 1);
+	assert_eq![ (sum, errors), (3, 1) ];
+	```
+	*/
+	fn fold_moral<G, B, FG, FB> (self, init_good :G, init_bad :B, mut fg :FG, mut fb :FB) -> (G, B)
+	where Self :Sized, FG :FnMut(G, <Self::Item as Judge>::Positive) -> G, FB :FnMut(B, <Self::Item as Judge>::Negative) -> B {
+		self.fold((init_good, init_bad), |(g, b), v| match v.into_moral() {
+			Good(v) => (fg(g, v), b),
+			Bad(v) => (g, fb(b, v)),
+		})
+	}
+
+	/** Folds the Good values, and stops at the first Bad one
+
+	Returns the accumulator in `Good`, or the first Bad value in `Bad`, so that `terror!` can
+	return early with it. Like collecting into a `Result`, but without a collection.
+
+	```
+	use tear::prelude::*;
+	use tear::JudgeIteratorExt;
+
+	fn sum (values :&[&str]) -> Result<i32, std::num::ParseIntError> {
+	    Ok(terror! { values.iter().map(|s| s.parse::<i32>()).fold_good(0, |sum, v| sum + v) })
+	}
+	assert_eq![ sum(&["1", "2"]), Ok(3) ];
+	assert![ sum(&["1", "x"]).is_err() ];
+	```
+	*/
+	fn fold_good<Acc, F> (self, init :Acc, mut f :F) -> Moral<Acc, <Self::Item as Judge>::Negative>
+	where Self :Sized, F :FnMut(Acc, <Self::Item as Judge>::Positive) -> Acc {
+		let mut acc = init;
+		for v in self {
+			match v.into_moral() {
+				Good(v) => acc = f(acc, v),
+				Bad(e) => return Bad(e),
+			}
+		}
+		Good(acc)
+	}
+
+	/** Collects the Good and the Bad values separately, in order (f=alloc)
+
+	Like `Iterator::partition`, but the values are unwrapped.
+
+	```
+	use tear::JudgeIteratorExt;
+
+	let (goods, bads) = vec![Ok(1), Err("a"), Ok(2)].into_iter().partition_moral();
+	assert_eq![ goods, vec![1, 2] ];
+	assert_eq![ bads, vec!["a"] ];
+	```
+	*/
+	#[cfg(feature = "alloc")]
+	#[allow(clippy::type_complexity)] // A pair of `Vec`, like `Iterator::partition`
+	fn partition_moral (self) -> (Vec<<Self::Item as Judge>::Positive>, Vec<<Self::Item as Judge>::Negative>)
+	where Self :Sized {
+		self.fold_moral(Vec::new(), Vec::new(),
+			|mut g, v| { g.push(v); g },
+			|mut b, v| { b.push(v); b })
+	}
+}
+
+impl<I :Iterator> JudgeIteratorExt for I where I::Item :Judge {}
+
+/** Iterator that yields the Good values of an iterator of `Judge` values
+
+Created with [`JudgeIteratorExt::goods`].
+*/
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+#[derive(Debug, Clone)]
+pub struct Goods<I> {
+	iter :I,
+}
+
+impl<I :Iterator> Iterator for Goods<I> where I::Item :Judge {
+	type Item = <I::Item as Judge>::Positive;
+
+	fn next (&mut self) -> Option<Self::Item> {
+		self.iter.by_ref().find_map(|v| v.into_moral().good())
+	}
+
+	fn size_hint (&self) -> (usize, Option<usize>) {
+		(0, self.iter.size_hint().1)
+	}
+}
+
+impl<I :FusedIterator> FusedIterator for Goods<I> where I::Item :Judge {}
+
+/** Iterator that yields the Bad values of an iterator of `Judge` values
+
+Created with [`JudgeIteratorExt::bads`].
+*/
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+#[derive(Debug, Clone)]
+pub struct Bads<I> {
+	iter :I,
+}
+
+impl<I :Iterator> Iterator for Bads<I> where I::Item :Judge {
+	type Item = <I::Item as Judge>::Negative;
+
+	fn next (&mut self) -> Option<Self::Item> {
+		self.iter.by_ref().find_map(|v| v.into_moral().bad())
+	}
+
+	fn size_hint (&self) -> (usize, Option<usize>) {
+		(0, self.iter.size_hint().1)
+	}
+}
+
+impl<I :FusedIterator> FusedIterator for Bads<I> where I::Item :Judge {}
+
+/** Iterator that yields the Good values of an iterator of `Judge` values, until the first Bad one
+
+Created with [`JudgeIteratorExt::until_bad`]. Once it gets a Bad value, it keeps it and always
+returns `None`, without advancing the inner iterator any further.
+*/
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+#[derive(Debug, Clone)]
+pub struct UntilBad<I, N> {
+	iter :I,
+	stopped :bool,
+	bad :Option<N>,
+}
+
+impl<I, N> UntilBad<I, N> {
+	/// The Bad value that stopped the iterator, if any
+	pub fn bad (&self) -> Option<&N> {
+		self.bad.as_ref()
+	}
+
+	/// Consumes the iterator, and returns the Bad value that stopped it, if any
+	pub fn into_bad (self) -> Option<N> {
+		self.bad
+	}
+
+	/// Whether the iterator was stopped by a Bad value, or is exhausted
+	pub fn is_stopped (&self) -> bool {
+		self.stopped
+	}
+}
+
+impl<I :Iterator> Iterator for UntilBad<I, <I::Item as Judge>::Negative> where I::Item :Judge {
+	type Item = <I::Item as Judge>::Positive;
+
+	fn next (&mut self) -> Option<Self::Item> {
+		if self.stopped {
+			return None;
+		}
+		match self.iter.next().map(Judge::into_moral) {
+			Some(Good(v)) => return Some(v),
+			Some(Bad(e)) => self.bad = Some(e),
+			None => {},
+		}
+		self.stopped = true;
+		None
+	}
+
+	fn size_hint (&self) -> (usize, Option<usize>) {
+		if self.stopped {
+			(0, Some(0))
+		} else {
+			(0, self.iter.size_hint().1)
+		}
+	}
+}
+
+impl<I :Iterator> FusedIterator for UntilBad<I, <I::Item as Judge>::Negative> where I::Item :Judge {}
+
+/** Iterator adapters that return early through `ValRet` */
+pub trait ValRetIteratorExt :Iterator {
+	/** Maps the items with `f`, and stops at the first `Ret`
+
+	It yields the `Val` values. Use [`TearMap::finish`] to collect them, or to get the `Ret`.
+	*/
+	fn tear_map<U, R, F> (self, f :F) -> TearMap<Self, F, R>
+	where Self :Sized, F :FnMut(Self::Item) -> ValRet<U, R> {
+		TearMap { iter: self, f, stopped: false, ret: None }
+	}
+
+	/** Folds the items with `f`, and stops at the first `Ret`
+
+	Returns the accumulator in `Val`, or the first `Ret` value. It uses `Iterator::try_fold`,
+	so iterators with a specialized `try_fold` like `Chain` take their fast path.
+
+	```
+	use tear::prelude::*;
+	use tear::ValRetIteratorExt;
+
+	let sum = (1..10).chain(20..30).val_ret_fold(0, |sum, v| if sum > 50 { Ret(v) } else { Val(sum + v) });
+	assert_eq![ sum, Ret(21) ];
+	```
+	*/
+	fn val_ret_fold<Acc, R, F> (mut self, init :Acc, mut f :F) -> ValRet<Acc, R>
+	where Self :Sized, F :FnMut(Acc, Self::Item) -> ValRet<Acc, R> {
+		// `Result` rather than `ControlFlow`, which needs Rust 1.55
+		let r = self.try_fold(init, |acc, v| match f(acc, v) {
+			ValRet::Val(acc) => Ok(acc),
+			ValRet::Ret(r) => Err(r),
+		});
+		match r {
+			Ok(acc) => ValRet::Val(acc),
+			Err(r) => ValRet::Ret(r),
+		}
+	}
+}
+
+impl<I :Iterator> ValRetIteratorExt for I {}
+
+/** Iterator that maps items to `ValRet` values, and yields the `Val` values until the first `Ret`
+
+Created with [`ValRetIteratorExt::tear_map`]. Once it gets a `Ret` value, it keeps it and always
+returns `None`, without advancing the inner iterator any further.
+*/
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct TearMap<I, F, R> {
+	iter :I,
+	f :F,
+	stopped :bool,
+	ret :Option<R>,
+}
+
+impl<I, F, R> TearMap<I, F, R> {
+	/// The `Ret` value that stopped the iterator, if any
+	pub fn ret (&self) -> Option<&R> {
+		self.ret.as_ref()
+	}
+
+	/// Consumes the iterator, and returns the `Ret` value that stopped it, if any
+	pub fn into_ret (self) -> Option<R> {
+		self.ret
+	}
+
+	/// Whether the iterator was stopped by a `Ret` value, or is exhausted
+	pub fn is_stopped (&self) -> bool {
+		self.stopped
+	}
+}
+
+impl<I, F, U, R> TearMap<I, F, R>
+where I :Iterator, F :FnMut(I::Item) -> ValRet<U, R> {
+	/** Collects the remaining values, or returns the `Ret` value
+
+	```
+	use tear::prelude::*;
+	use tear::ValRetIteratorExt;
+
+	let v :ValRet<Vec<i32>, &str> = vec![1, 2].into_iter().tear_map(Val).finish();
+	assert_eq![ v, Val(vec![1, 2]) ];
+
+	let v :ValRet<Vec<i32>, &str> = vec![1, -1, 2].into_iter()
+	    .tear_map(|v| if v < 0 { Ret("negative") } else { Val(v) })
+	    .finish();
+	assert_eq![ v, Ret("negative") ];
+	```
+	*/
+	pub fn finish<C :core::iter::FromIterator<U>> (mut self) -> ValRet<C, R> {
+		let c = self.by_ref().collect();
+		match self.ret {
+			Some(r) => ValRet::Ret(r),
+			None => ValRet::Val(c),
+		}
+	}
+}
+
+impl<I, F, U, R> Iterator for TearMap<I, F, R>
+where I :Iterator, F :FnMut(I::Item) -> ValRet<U, R> {
+	type Item = U;
+
+	fn next (&mut self) -> Option<Self::Item> {
+		if self.stopped {
+			return None;
+		}
+		match self.iter.next().map(&mut self.f) {
+			Some(ValRet::Val(v)) => return Some(v),
+			Some(ValRet::Ret(r)) => self.ret = Some(r),
+			None => {},
+		}
+		self.stopped = true;
+		None
+	}
+
+	fn size_hint (&self) -> (usize, Option<usize>) {
+		if self.stopped {
+			(0, Some(0))
+		} else {
+			(0, self.iter.size_hint().1)
+		}
+	}
+}
+
+impl<I, F, U, R> FusedIterator for TearMap<I, F, R>
+where I :Iterator, F :FnMut(I::Item) -> ValRet<U, R> {}
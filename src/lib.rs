@@ -16,11 +16,67 @@ Otherwise, read the `overview` module documentation that mentions *all* the thin
 
 ## Feature flags
 
-- The "experimental" crate feature enables support for the experimental `Try` trait.
+- The "experimental" crate feature implements the experimental `Try` trait (`try_trait_v2`) for `ValRet`,
+  `Moral` and `Looping`, so that `?` works on them. It needs a nightly compiler.
 
 - The "combinators" crate feature adds the `side` method to the `Judge` trait. It lets you convert
   to `Either` any type that implements `Judge`. You can then use `Either`'s combinators to do
-  what you want.
+  what you want. `side_left` and `side_right` choose the side of the Good value, and `Moral` and
+  `ValRet` convert back from `Either`.
+
+- The "alloc" crate feature enables things that need an allocator, like `tear_all!`, `anybox!`
+  and `twist! -box`. They work in `no_std` crates that have `alloc`.
+
+- The "anyhow" and "eyre" crate features add the `ctx!` and `wrap_err!` macros respectively.
+  They add a context message to the bad value when used with `terror!`'s mapping syntax.
+  They need the "std" feature too, as the bad values implement `std::error::Error`.
+
+- The "std" crate feature implements `std::error::Error` for `Traced`, and adds the `std_support`
+  module with the other integrations with the standard library. The crate is `no_std` without it.
+  It enables "alloc".
+
+- The "testing" crate feature adds `testing::Flaky`, a scripted source of good and bad values to
+  test loop control and retry code. It enables "alloc".
+
+- The "auto" crate feature adds the `#[tear::auto]` attribute that rewrites `?` into `terror!`.
+
+- The "tloop" crate feature adds `tloop!`, that lets you list the `twist!` labels once for a loop nest.
+
+- The "control-flow" crate feature converts between `core::ops::ControlFlow` and `Looping`, and adds
+  `twist! { -cf $e }`. It needs Rust 1.55.
+
+- The "block-labels" crate feature adds `twist! { -block -with 'b | $e }` and `twist! { -block -label … }`,
+  that break labeled blocks. They need Rust 1.65.
+
+- The "poll" crate feature implements Judge for `Poll<Option<Result<T, E>>>`, the return type of
+  `Stream::poll_next`, with `StreamBad` as the Bad type. It needs Rust 1.36.
+
+- The "const-labels" crate feature adds `LabelIndex<N>`, a label index as a type, the `labels!` macro
+  that names them, and `twist! -typed` that checks them against the label list at compile time.
+  It needs Rust 1.57.
+
+- The "compat" crate feature adds the `compat` module, with a `guard!` macro that has the syntax of
+  the `guard` crate to ease migrating from it. It needs Rust 1.65.
+
+- The "cold-path" crate feature marks the early returns of `tear!` and `terror!` as unlikely, by
+  returning through a `#[cold]` function. It can help hot loops that rarely return early, but adds
+  a function call to the early return, which shows in debug builds.
+
+- The "tiny-panic" crate feature replaces the panic messages of `twist!` and `tredo_loop!` with
+  short codes like `"tear:E1"`, to keep the strings out of small `no_std` binaries. The codes are
+  listed in the `twist_impl` module documentation.
+
+- The "track-caller" crate feature marks the functions that `twist!` and `tredo_loop!` panic
+  through, and `Looping::unwrap_resume` and the like, with `#[track_caller]`, so that the panic
+  location is the macro call or the method call in your code. It also adds `Traced`, an error
+  wrapper that remembers where `terror!` returned it. It needs Rust 1.46.
+
+- The "arbitrary" crate feature implements `arbitrary::Arbitrary` for `ValRet`, `Moral`, `Looping`
+  and `Maru`, for fuzzing and property testing. The label indices of `Looping` are less than
+  `ARBITRARY_LABEL_COUNT`. It needs the "std" feature too, as `arbitrary` needs the standard library.
+
+- The "defmt" crate feature implements `defmt::Format` for `ValRet`, `Moral`, `Looping` and `Maru`,
+  with the same shapes as their `Debug` output, to log them on embedded targets.
 
 - (dev) "ignore-ui" lets you ignore error message tests because all of them are wrong as soon
   as you have any warnings.
@@ -103,7 +159,7 @@ fn status_code() -> i32 {
 
 - [Error Handling in Rust §The real `try!` macro / `?` operator](https://blog.burntsushi.net/rust-error-handling/#the-real-try-macro-operator)
 - [guard](https://docs.rs/crate/guard), a crate implementing "guard" expressions,
-  the opposite of `tear_if!`.
+  the opposite of `tear_if!`. `compat::guard!` has the same syntax (f=compat).
 
 Finally, please star the [GitHub repo](https://github.com/tqdv/tear) if you found this crate useful.
 It helps developer ego !
@@ -117,30 +173,63 @@ in public API. Nonetheless, they will be documented in the changelog
 In this module, we define in order
 - ValRet, its implementation, and its associated trait Return
 - Moral, its implementation, and its associated trait Judge
-- tear!, tear_if! and terror! macros
+- tear!, tear_if! (and its (dev) helper macro) and terror! macros
+- (dev) helper macro for the annotated form of terror!
+- terror_retry! and its (dev) helper macro
+- terror_all!
+- (f=alloc) tear_all! and its (dev) helper macro
 */
-#![no_std] // But we use std for tests
+#![cfg_attr(not(feature = "std"), no_std)] // But we use std for tests
 #![warn(missing_docs)] // Documentation lints
 #![allow(clippy::tabs_in_doc_comments)] // Clippy ignore
 
 // Optional features
-#![cfg_attr(feature = "experimental", feature(try_trait))]
+#![cfg_attr(feature = "experimental", feature(try_trait_v2, try_trait_v2_residual))]
+#[cfg(feature = "alloc")] extern crate alloc;
+#[cfg(all(any(feature = "anyhow", feature = "eyre", feature = "arbitrary"), not(feature = "std")))]
+compile_error!("The \"anyhow\", \"eyre\" and \"arbitrary\" features need the \"std\" feature too");
 
 // Modules
 pub mod overview; // For documentation
 pub mod prelude;
 pub mod extra;
+pub mod prefixed; // Prelude with prefixed macro names
 pub mod trait_impl; // Move the trait implementations as they are quite noisy
 pub mod twist_impl; // Currently only for `twist!`
+pub mod iter; // Iterator adapters driven by `Looping` signals
 #[macro_use] pub mod util; // Utility macros that aren't the main focus. To reduce file size.
+pub mod testing; // Assertion macros for tests
+#[cfg(any(feature = "anyhow", feature = "eyre"))] pub mod context; // Error context macros
+#[cfg(feature = "track-caller")] pub mod traced; // Errors with their location
+#[cfg(feature = "std")] pub mod std_support; // Integrations with the standard library
+#[cfg(feature = "const-labels")] pub mod typed_label; // Label indices as types
+#[cfg(feature = "compat")] pub mod compat; // Migration helpers from other crates
 
 // Reexports for macros and convenience
 pub use twist_impl::BreakValError;
+#[allow(deprecated)]
 pub use twist_impl::{BREAKVAL_IN_NOT_LOOP, BREAK_WITHOUT_VAL, BAD_BREAKVAL_TYPE};
-pub use twist_impl::Looping;
-pub use util::gut;
+pub use twist_impl::{Looping, IntoLooping};
+pub use twist_impl::{TwistError, TearErrorKind};
+pub use iter::{LoopingIteratorExt, JudgeIteratorExt, ValRetIteratorExt, Folded};
+pub use util::{gut, __apply, __cold_ret};
 pub use trait_impl::Maru;
+#[cfg(feature = "poll")] pub use trait_impl::StreamBad;
+#[cfg(feature = "experimental")] pub use trait_impl::LoopingResidual;
+#[cfg(feature = "arbitrary")] pub use trait_impl::ARBITRARY_LABEL_COUNT;
+#[cfg(feature = "const-labels")] pub use typed_label::{LabelIndex, TypedLabel};
 pub use core::convert::From;
+#[cfg(feature = "auto")] pub use tear_macros::auto;
+#[cfg(feature = "tloop")] pub use tear_macros::tloop;
+#[cfg(feature = "track-caller")] pub use traced::{Traced, traced, retraced};
+
+/// (dev) Reexport of `alloc` for macros (f=alloc)
+#[cfg(feature = "alloc")]
+#[doc(hidden)]
+pub mod __alloc {
+	pub use alloc::vec;
+	pub use alloc::boxed;
+}
 
 // For convenience, also used in prelude
 use ValRet::*;
@@ -156,6 +245,8 @@ returns early (Ret).
 */
 #[must_use = "Suggestion: use tear! to handle it"]
 #[derive(PartialEq, Debug, Clone)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub enum ValRet<V, R> {
 	/// The usable value
 	Val(V),
@@ -178,9 +269,52 @@ impl<V, R> ValRet<V, R> {
 	/* Accessors */
 
 	/// Gets the `Val(V)` variant as `Option<V>`
+	#[inline]
 	pub fn val (self) -> Option<V> { maybe_match! { self, Val(v) => v } }
 	/// Gets the `Ret(R)` variant as `Option<R>`
+	#[inline]
 	pub fn ret (self) -> Option<R> { maybe_match! { self, Ret(r) => r } }
+
+	/* Conversions */
+
+	/** Convert to Either (f=combinators)
+
+	Maps Val to Right and Ret to Left, like [`Judge::side`].
+
+	```
+	# use tear::prelude::*;
+	# use either::Either::{Left, Right};
+	# let ok:    ValRet<&str, &str> = Val("ok");
+	# let error: ValRet<&str, &str> = Ret("error");
+	assert_eq![ ok.into_either(), Right("ok") ];
+	assert_eq![ error.into_either(), Left("error") ];
+	```
+	*/
+	#[cfg(feature = "combinators")]
+	pub fn into_either (self) -> Either<R, V> {
+		match self {
+			Val(v) => Right(v),
+			Ret(r) => Left(r),
+		}
+	}
+
+	/** Convert from Either, with Right as Val and Left as Ret (f=combinators)
+
+	The inverse of [`ValRet::into_either`]. It's also available as `From<Either<R, V>>`.
+	*/
+	#[cfg(feature = "combinators")]
+	pub fn from_either (e :Either<R, V>) -> Self {
+		match e {
+			Right(v) => Val(v),
+			Left(r) => Ret(r),
+		}
+	}
+}
+
+/// Right is Val and Left is Ret (f=combinators)
+#[cfg(feature = "combinators")]
+impl<V, R> From<Either<R, V>> for ValRet<V, R> {
+	fn from (e :Either<R, V>) -> Self { ValRet::from_either(e) }
 }
 
 /// Convert into [`ValRet`]
@@ -196,6 +330,8 @@ pub trait Return where Self :Sized {
 
 /// A notion of good and bad for the [`terror!`] macro
 #[derive(PartialEq, Debug, Clone)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub enum Moral<Y, N> {
 	/// The good
 	Good(Y),
@@ -207,8 +343,10 @@ impl<Y, N> Moral<Y, N> {
 	/* Accessors */
 
 	/// Gets the `Good(Y)` variant as `Option<Y>`
+	#[inline]
 	pub fn good (self) -> Option<Y> { maybe_match! { self, Good(v) => v } }
 	/// Gets the `Bad(N)` variant as `Option<N>`
+	#[inline]
 	pub fn bad (self) -> Option<N> { maybe_match! { self, Bad(v) => v } }
 
 	/* Conversions */
@@ -217,6 +355,7 @@ impl<Y, N> Moral<Y, N> {
 
 	Maps Good to Val and Bad to Ret.
 	*/
+	#[inline]
 	pub fn into_valret (self) -> ValRet<Y, N> {
 		match self {
 			Good(v) => Val(v),
@@ -228,6 +367,7 @@ impl<Y, N> Moral<Y, N> {
 
 	Maps Good to Ok and Bad to Err.
 	*/
+	#[inline]
 	pub fn into_result (self) -> Result<Y, N> {
 		match self {
 			Good(v) => Ok(v),
@@ -246,6 +386,18 @@ impl<Y, N> Moral<Y, N> {
 			Bad(v) => Left(v),
 		}
 	}
+
+	/** Convert from Either, with Right as Good and Left as Bad (f=combinators)
+
+	The inverse of [`Moral::into_either`]. It's also available as `From<Either<N, Y>>`.
+	*/
+	#[cfg(feature = "combinators")]
+	pub fn from_either (e :Either<N, Y>) -> Self {
+		match e {
+			Right(v) => Good(v),
+			Left(v) => Bad(v),
+		}
+	}
 	
 	/* Special conversions */
 
@@ -253,8 +405,10 @@ impl<Y, N> Moral<Y, N> {
 
 	The function `f` takes the bad value and maps it to a `Looping` value.
 
-	Used in the `twist!` macro with the mapping (`=>`) syntax. See [`twist!`] documentation.
+	Used by [`Looping::from_judge`], that the `twist!` macro calls with the mapping (`=>`) syntax.
+	See [`twist!`] documentation.
 	*/
+	#[inline]
 	pub fn resume_or_else<B> (self, f :impl FnOnce(N) -> Looping<Y, B>) -> Looping<Y, B> {
 		match self {
 			Good(v) => Looping::Resume(v),
@@ -298,11 +452,42 @@ pub trait Judge :Sized {
 		self.into_moral().into_result()
 	}
 
-	/** Convert to Either */
+	/** Convert to Either, with the Good value on the Right (f=combinators) */
 	#[cfg(feature = "combinators")]
 	fn side (self) -> Either<Self::Negative, Self::Positive> {
 		self.into_moral().into_either()
 	}
+
+	/** Convert to Either, with the Good value on the Left (f=combinators)
+
+	```
+	# use tear::prelude::*;
+	use tear::Judge;
+	use either::Either::{Left, Right};
+
+	assert_eq![ Ok::<_, ()>(1).side_left(), Left(1) ];
+	assert_eq![ Err::<i32, _>("e").side_left(), Right("e") ];
+	```
+	*/
+	#[cfg(feature = "combinators")]
+	fn side_left (self) -> Either<Self::Positive, Self::Negative> {
+		self.side().flip()
+	}
+
+	/** Convert to Either, with the Good value on the Right (f=combinators)
+
+	Same as [`Judge::side`], but explicit about the orientation.
+	*/
+	#[cfg(feature = "combinators")]
+	fn side_right (self) -> Either<Self::Negative, Self::Positive> {
+		self.side()
+	}
+}
+
+/// Right is Good and Left is Bad (f=combinators)
+#[cfg(feature = "combinators")]
+impl<Y, N> From<Either<N, Y>> for Moral<Y, N> {
+	fn from (e :Either<N, Y>) -> Self { Moral::from_either(e) }
 }
 
 /** Turns a [`ValRet`] into a value or an early return
@@ -327,6 +512,13 @@ let x = tear! { $e => $f }
 Same as the previous form, but the return value `r` is first mapped through $f before returning.
 In short, we return `$f(r)`.
 
+```text
+let x = tear! { $e =>> $r }
+```
+
+Same as the previous form, but the return value is dropped, and we return `$r` instead.
+It is the same as `$e => |_| $r`, except that `$r` is an expression that is only evaluated when returning.
+
 Additionally, both forms make use of the [`convert::From`](`core::convert::From`) trait to automatically convert
 the value when returning it. This behaviour is the same as the try operator `?`.
 You may need to be more specific with type annotations so that the compiler can infer the right types.
@@ -412,7 +604,14 @@ macro_rules! tear {
 	( $e:expr ) => {
 		match $crate::Return::into_valret($e) {
 			$crate::ValRet::Val(v) => v,
-			$crate::ValRet::Ret(r) => return $crate::From::from(r),
+			$crate::ValRet::Ret(r) => return $crate::__cold_ret($crate::From::from(r)),
+		}
+	};
+	// Ignoring the bad value eg. `tear! { $e =>> -1 }`
+	( $e:expr =>> $r:expr ) => {
+		match $crate::Judge::into_moral($e) {
+			$crate::Moral::Good(v) => v,
+			$crate::Moral::Bad(_) => return $crate::__cold_ret($crate::From::from($r)),
 		}
 	};
 	// With a mapping function eg. `tear! { $e => |v| v }` or `tear! { $e => func }`
@@ -421,7 +620,7 @@ macro_rules! tear {
 			#[allow(clippy::redundant_closure_call)]
 			match $crate::Judge::into_moral($e) {
 				$crate::Moral::Good(v) => v,
-				$crate::Moral::Bad(v) => return $crate::From::from($f(v)),
+				$crate::Moral::Bad(v) => return $crate::__cold_ret($crate::From::from($f(v))),
 			}
 		}
 	}
@@ -450,6 +649,29 @@ tear_if! { let pat = expr,
 
 You can also use the pattern matching `if let`.
 
+```text
+tear_if! { let pat = expr, guard1, guard2,
+    do_things();
+    v
+}
+```
+
+The pattern can be followed by boolean guards that must all be true for the early return to happen,
+like let-chains. The pattern bindings are usable in the guards and in the body. Each expression
+followed by a comma is a guard, and the body is the rest, so it can contain commas
+(eg. `HashMap::<K, V>::new()` or a closure `|a, b|`).
+
+```text
+tear_if! { cond1, v1 ;
+    else if cond2, v2 ;
+    else if let pat = expr, v3
+}
+```
+
+Branches can be chained with `; else if`, and each branch can be any of the forms above.
+The first matching branch returns early with its value. If none match, execution continues
+after the macro, which acts as the `else` branch: a final `else` without condition is an error.
+
 # Examples
 
 Early return a value: recursively computing the length of a slice.
@@ -492,11 +714,114 @@ fn add_five(x: Option<i32>) -> i32 {
 assert_eq![ add_five(Some(2)), 7 ];
 assert_eq![ add_five(None), 0 ];
 ```
+
+Add guards to the pattern
+```rust
+# #[macro_use] extern crate tear;
+fn clamp(x: Option<i32>) -> i32 {
+    tear_if! { let Some(v) = x, v > 10, 10 }
+
+    x.unwrap_or(0)
+}
+
+assert_eq![ clamp(Some(20)), 10 ];
+assert_eq![ clamp(Some(2)), 2 ];
+```
+
+Chain related conditions with `else if`
+```rust
+# #[macro_use] extern crate tear;
+fn sign(x: Option<i32>) -> &'static str {
+    tear_if! { x.is_none(), "none" ;
+        else if let Some(0) = x, "zero" ;
+        else if x < Some(0), "negative"
+    }
+
+    "positive"
+}
+
+assert_eq![ sign(None), "none" ];
+assert_eq![ sign(Some(0)), "zero" ];
+assert_eq![ sign(Some(-3)), "negative" ];
+assert_eq![ sign(Some(3)), "positive" ];
+```
 */
 #[macro_export]
 macro_rules! tear_if {
+	// Normal tear_if! { $cond, $expr }
+	( $c:expr $( , $b:expr )? ) => {
+		$crate::__impl_tear_if! { @branch $c $(, $b)? }
+	};
+	// Handle tear_if! { let …, $guards, $expr }
+	( let $p:pat = $e:expr $( , $x:expr )* ) => {
+		$crate::__impl_tear_if! { @branch let $p = $e $(, $x)* }
+	};
+	// Bodies with statements, and `; else if` chains
+	( $($t:tt)* ) => {
+		$crate::__impl_tear_if! { @chain [] [] [$($t)*] }
+	};
+}
+
+/** (dev) Implementation of `tear_if!`
+
+`tear_if!` sends the forms whose body is a single expression straight to `@branch`.
+The others go through `@chain`, which separates the branches of an `else if` chain. As each branch
+returns early, the chain expands to one `@branch` after the other.
+
+`@branch` handles a single `$cond, $body` or `let $p = $e, $guards, $body`. Its last arms catch
+the malformed branches (no condition, `let` without `=`, missing comma) with a readable error.
+
+`@guards` takes the guards one expression at a time, so commas inside an expression stay in it.
+`@build` then expands to the nested `if let` and `if`.
+*/
+#[macro_export]
+macro_rules! __impl_tear_if {
+	// Split on top-level `; else if`
+	// ≪ [ <(<$branch-token>*)>* ] [ <$current-token>* ] [ <$token>* ] ≫
+	( @chain [$($br:tt)*] [$($cur:tt)*] [ ; else if $($rest:tt)* ] ) => {
+		$crate::__impl_tear_if! { @chain [$($br)* ($($cur)*)] [] [$($rest)*] }
+	};
+	( @chain [$($br:tt)*] [$($cur:tt)*] [ ; else $($rest:tt)* ] ) => {
+		compile_error!(concat!(
+			"`tear_if!` chains only accept `else if` branches, as the code after the macro is the `else` branch. Got: else ",
+			stringify!($($rest)*)))
+	};
+	// Otherwise, take up to four tokens at a time, stopping before `;`
+	( @chain [$($br:tt)*] [$($cur:tt)*] [ $t0:tt ; $($rest:tt)* ] ) => {
+		$crate::__impl_tear_if! { @chain [$($br)*] [$($cur)* $t0] [; $($rest)*] }
+	};
+	( @chain [$($br:tt)*] [$($cur:tt)*] [ $t0:tt $t1:tt ; $($rest:tt)* ] ) => {
+		$crate::__impl_tear_if! { @chain [$($br)*] [$($cur)* $t0 $t1] [; $($rest)*] }
+	};
+	( @chain [$($br:tt)*] [$($cur:tt)*] [ $t0:tt $t1:tt $t2:tt ; $($rest:tt)* ] ) => {
+		$crate::__impl_tear_if! { @chain [$($br)*] [$($cur)* $t0 $t1 $t2] [; $($rest)*] }
+	};
+	( @chain [$($br:tt)*] [$($cur:tt)*] [ $t0:tt $t1:tt $t2:tt $t3:tt $($rest:tt)* ] ) => {
+		$crate::__impl_tear_if! { @chain [$($br)*] [$($cur)* $t0 $t1 $t2 $t3] [$($rest)*] }
+	};
+	( @chain [$($br:tt)*] [$($cur:tt)*] [ $($token:tt)+ ] ) => {
+		$crate::__impl_tear_if! { @chain [$($br)*] [$($cur)* $($token)+] [] }
+	};
+	// Single branch
+	( @chain [] [$($cur:tt)*] [] ) => {
+		$crate::__impl_tear_if! { @branch $($cur)* }
+	};
+	// Else-if chain
+	( @chain [$( ($($br:tt)*) )+] [$($cur:tt)*] [] ) => {
+		{
+			$( $crate::__impl_tear_if! { @branch $($br)* }; )+
+			$crate::__impl_tear_if! { @branch $($cur)* }
+		}
+	};
+
+	// Guards can't be placed before the pattern
+	( @branch $c:expr , let $p:pat = $e:expr , $($rest:tt)* ) => {
+		compile_error!(concat!(
+			"The `let` pattern must come before the guards in `tear_if!`, got guard: ",
+			stringify!($c)))
+	};
 	// Normal tear_if! { $cond, $block }
-	( $c:expr $( , $($b:tt)* )? ) => {
+	( @branch $c:expr $( , $($b:tt)* )? ) => {
 		$crate::tear! {
 			if $c {
 				$crate::ValRet::Ret({ $($($b)*)? })
@@ -505,11 +830,66 @@ macro_rules! tear_if {
 			}
 		}
 	};
-	// Handle tear_if! { let … }
-	( let $p:pat = $e:expr $( , $($b:tt)* )? ) => {
+	// Handle tear_if! { let … } without a body
+	( @branch let $p:pat = $e:expr ) => {
+		$crate::__impl_tear_if! { @build ($p) ($e) [] () }
+	};
+	// Handle tear_if! { let …, $guards, $block }
+	( @branch let $p:pat = $e:expr , $($rest:tt)* ) => {
+		$crate::__impl_tear_if! { @guards ($p) ($e) [] [$($rest)*] }
+	};
+	// Malformed branches, instead of the error cascade from the arms above
+	( @branch ) => {
+		compile_error!("tear_if! expects a condition: tear_if! { cond, value }")
+	};
+	( @branch , $($rest:tt)* ) => {
+		compile_error!(concat!(
+			"tear_if! expects a condition before the comma: tear_if! { cond, value }. Got: , ",
+			stringify!($($rest)*)))
+	};
+	( @branch let $($rest:tt)* ) => {
+		compile_error!(concat!(
+			"tear_if! expects `=` after the `let` pattern: tear_if! { let pattern = expr, value }. Got: let ",
+			stringify!($($rest)*)))
+	};
+	( @branch $($rest:tt)* ) => {
+		compile_error!(concat!(
+			"tear_if! expects a comma between the condition and the body: tear_if! { cond, value }. Got: ",
+			stringify!($($rest)*)))
+	};
+
+	// Each expression followed by a comma is a guard, the rest is the body
+	// ≪ ($p) ($e) [ <($guard)>* ] [ <$token>* ] ≫
+	// A `let` statement starts the body, without trying to parse it as a guard
+	( @guards ($p:pat) ($e:expr) [$($g:tt)*] [ let $($b:tt)* ] ) => {
+		$crate::__impl_tear_if! { @build ($p) ($e) [$($g)*] (let $($b)*) }
+	};
+	( @guards ($p:pat) ($e:expr) [$($g:tt)*] [ $x:expr , $($rest:tt)+ ] ) => {
+		$crate::__impl_tear_if! { @guards ($p) ($e) [$($g)* ($x)] [$($rest)+] }
+	};
+	( @guards ($p:pat) ($e:expr) [$($g:tt)*] [ $($b:tt)* ] ) => {
+		$crate::__impl_tear_if! { @build ($p) ($e) [$($g)*] ($($b)*) }
+	};
+
+	// Without guards
+	( @build ($p:pat) ($e:expr) [] ($($b:tt)*) ) => {
 		$crate::tear! {
 			if let $p = $e {
-				$crate::ValRet::Ret({ $($($b)*)? })
+				$crate::ValRet::Ret({ $($b)* })
+			} else {
+				$crate::ValRet::Val(())
+			}
+		}
+	};
+	// With guards
+	( @build ($p:pat) ($e:expr) [ $( ($g:expr) )+ ] ($($b:tt)*) ) => {
+		$crate::tear! {
+			if let $p = $e {
+				if $($g)&&+ {
+					$crate::ValRet::Ret({ $($b)* })
+				} else {
+					$crate::ValRet::Val(())
+				}
 			} else {
 				$crate::ValRet::Val(())
 			}
@@ -538,9 +918,37 @@ let x = terror! { $e => $f };
 Same as the previous form, but the bad `value` is first mapped through $f before returning.
 In short, we return `from_bad($f(value))`.
 
+```text
+let x = terror! { $e =>> $r };
+```
+
+Same as the previous form, but the bad value is dropped and we return `from_bad($r)`.
+It is the same as `$e => |_| $r`, except that `$r` is an expression that is only evaluated when returning.
+
+```text
+let x = terror! { $e => $f : $type };
+```
+
+Same as the mapping form, but the output of `$f` is annotated with `$type`, ie. we return
+`from_bad(From::from(mapped))` where `let mapped :$type = $f(value)`. Use it when the compiler can't
+infer the output type of `$f`, for example with generic conversions like `Into::into`.
+The type is everything after the last `:` that isn't inside parentheses or braces.
+
 Both forms make use of the [`convert::From`](`core::convert::From`) trait to convert the bad value,
 making it fully compatible with `try!` and the `?` operator.
 
+Annotating the type of the mapped value when it can't be inferred:
+
+```rust
+# #[macro_use] extern crate tear;
+fn widen(v: Result<(), u8>) -> Result<(), i64> {
+    // Without `: i32`, the compiler can't infer the target of `Into::into`
+    terror! { v => Into::into : i32 };
+    Ok(())
+}
+# assert_eq![ widen(Err(3)), Err(3) ];
+```
+
 # Explanation using examples
 
 The description is especially terse on purpose: it is really hard to explain what `terror!` does without using examples.
@@ -677,7 +1085,7 @@ Since `terror!` mimics `?`, it also supports autoconversion using the `convert::
 #     ( $e:expr, $($p:pat)|+ ) => {
 #         match $e {
 #             $($p)|+ => (),
-#             ref e => panic!("assertion failed: `{:?}` does not match `{}`", e, stringify!($($p)|+)),
+#             ref e => ::core::panic!("assertion failed: `{:?}` does not match `{}`", e, stringify!($($p)|+)),
 #         }
 #     }
 # }
@@ -752,6 +1160,22 @@ fn open_file(path: PathBuf) -> Result<(), Error> {
 }
 ```
 
+Since we don't need the bad value, we can also use the `=>>` shorthand:
+
+```
+# #[macro_use] extern crate tear;
+# use std::{fs::File, path::PathBuf};
+# enum Error {
+#     OpenF(PathBuf),
+# }
+# 
+fn open_file(path: PathBuf) -> Result<(), Error> {
+    let file = terror! { File::open(&path) =>> Error::OpenF(path) };
+    # drop(path); drop(file);
+    # Ok(())
+}
+```
+
 # Naming
 
 The name terror comes from "return error" and "tear! error".
@@ -763,7 +1187,14 @@ macro_rules! terror {
 	( $e:expr ) => {
 		match $crate::Judge::into_moral($e) {
 			$crate::Moral::Good(v) => v,
-			$crate::Moral::Bad(v) => return $crate::Judge::from_bad($crate::From::from(v)),
+			$crate::Moral::Bad(v) => return $crate::__cold_ret($crate::Judge::from_bad($crate::From::from(v))),
+		}
+	};
+	// Ignoring the bad value eg. `terror! { $e =>> Error::Missing }`
+	( $e:expr =>> $r:expr ) => {
+		match $crate::Judge::into_moral($e) {
+			$crate::Moral::Good(v) => v,
+			$crate::Moral::Bad(_) => return $crate::__cold_ret($crate::Judge::from_bad($crate::From::from($r))),
 		}
 	};
 	// With a mapping function eg. `terror! { $e => |v| v }` or `terror! { $e => func }`
@@ -772,8 +1203,271 @@ macro_rules! terror {
 			#[allow(clippy::redundant_closure_call)]
 			match $crate::Judge::into_moral($e) {
 				$crate::Moral::Good(v) => v,
-				$crate::Moral::Bad(v) => return $crate::Judge::from_bad($crate::From::from($f(v))),
+				$crate::Moral::Bad(v) => return $crate::__cold_ret($crate::Judge::from_bad($crate::From::from($f(v)))),
 			}
 		}
-	}
+	};
+	// With an annotated mapping function eg. `terror! { $e => Into::into : u32 }`
+	( $e:expr => $($rest:tt)+ ) => {
+		$crate::__impl_terror_annotated! { ($e) [] () [] [$($rest)+] }
+	};
+}
+
+/** (dev) Implementation of `terror! { $e => $f : $type }`
+
+Splits the tokens after `=>` on the last top-level `:`. The tokens before are the mapping function,
+and the tokens after are the type.
+
+Syntax: `($e) [ <$function-token>* ] ( <:>? ) [ <$current-token>* ] [ <$token>* ]`
+*/
+#[macro_export]
+macro_rules! __impl_terror_annotated {
+	// Found a `:`, move the current tokens into the function tokens
+	( ($e:expr) [$($f:tt)*] ($($sep:tt)?) [$($cur:tt)*] [ : $($rest:tt)* ] ) => {
+		$crate::__impl_terror_annotated! { ($e) [$($f)* $($sep)? $($cur)*] (:) [] [$($rest)*] }
+	};
+	( ($e:expr) [$($f:tt)*] ($($sep:tt)?) [$($cur:tt)*] [ $token:tt $($rest:tt)* ] ) => {
+		$crate::__impl_terror_annotated! { ($e) [$($f)*] ($($sep)?) [$($cur)* $token] [$($rest)*] }
+	};
+	// The current tokens are the type
+	( ($e:expr) [$($f:tt)+] (:) [$($cur:tt)+] [] ) => {
+		$crate::__impl_terror_annotated! { @expand ($e) ($($f)+) ($($cur)+) }
+	};
+	( ($e:expr) [$($f:tt)*] ($($sep:tt)?) [$($cur:tt)*] [] ) => {
+		compile_error!(concat!(
+			"Expected either `$f` or `$f : $type` after `=>` in `terror!`, got: ",
+			stringify!($($f)* $($sep)? $($cur)*)))
+	};
+
+	( @expand ($e:expr) ($f:expr) ($type:ty) ) => {
+		{
+			#[allow(clippy::redundant_closure_call)]
+			match $crate::Judge::into_moral($e) {
+				$crate::Moral::Good(v) => v,
+				$crate::Moral::Bad(v) => {
+					let mapped :$type = $f(v);
+					return $crate::__cold_ret($crate::Judge::from_bad($crate::From::from(mapped)));
+				},
+			}
+		}
+	};
+}
+
+/** [`terror!`] that retries its expression a few times before giving up
+
+# Description
+
+```text
+let x = terror_retry! { $n, $e };
+let x = terror_retry! { $n, $e => $f };
+```
+
+Evaluates `$e` up to `$n` times, until it is Good. The first good value is assigned to x.
+If every attempt is Bad, the last bad value is returned like `terror!` does, and through `$f`
+in the second form. `$n` is the maximum number of attempts, and `$e` is always evaluated at
+least once.
+
+`$e` is wrapped in a closure so that it can be evaluated again for each attempt.
+This means that `return`, `break` and `continue` can't be used inside of it.
+
+```text
+let x = terror_retry! { $n, -on_retry $g, $e };
+let x = terror_retry! { $n, -on_retry $g, $e => $f };
+```
+
+Same as the previous forms, but `$g` is called before each retry with the index of the failed
+attempt (counting from 0) and its bad value. Use it for logging, or for waiting between attempts.
+
+# Examples
+
+```rust
+# use tear::prelude::*;
+# use std::cell::Cell;
+let attempts = Cell::new(0);
+let flaky = || {
+    attempts.set(attempts.get() + 1);
+    if attempts.get() < 3 { Err("not yet") } else { Ok(attempts.get()) }
+};
+
+fn get<F :FnMut() -> Result<i32, &'static str>> (mut f :F) -> Result<i32, String> {
+    let v = terror_retry! { 5, f() };
+    Ok(v)
+}
+
+assert_eq![ get(flaky), Ok(3) ];
+```
+
+Logging failed attempts with `-on_retry`:
+
+```rust
+# use tear::prelude::*;
+fn parse (s :&str) -> Result<i32, String> {
+    let mut log = Vec::new();
+    let v = terror_retry! { 2, -on_retry |i, e| log.push((i, e)), s.parse::<i32>() => |e| format!("{:?}", e) };
+    Ok(v)
+}
+
+assert![ parse("nope").is_err() ];
+```
+
+# See also
+- (f=testing) `testing::Flaky` to test your retry code with a scripted sequence of values
+*/
+#[macro_export]
+macro_rules! terror_retry {
+	// With a hook eg. `terror_retry! { 3, -on_retry |i, e| log(i, e), $e }`
+	( $n:expr, -on_retry $g:expr, $e:expr $( => $f:expr )? ) => {
+		$crate::__impl_terror_retry! { ($n) ($g) ($e) $( ($f) )? }
+	};
+	// `terror_retry! { $n, $e }` or with a mapping function `terror_retry! { $n, $e => $f }`
+	( $n:expr, $e:expr $( => $f:expr )? ) => {
+		$crate::__impl_terror_retry! { ($n) (|_, _| ()) ($e) $( ($f) )? }
+	};
+}
+
+/** (dev) Implementation of `terror_retry!`
+
+Syntax: `($n) ($hook) ($e) [($f)]`
+*/
+#[macro_export]
+macro_rules! __impl_terror_retry {
+	// Without a mapping function
+	( ($n:expr) ($g:expr) ($e:expr) ) => {
+		$crate::__impl_terror_retry! { ($n) ($g) ($e) (|v| v) }
+	};
+	( ($n:expr) ($g:expr) ($e:expr) ($f:expr) ) => {
+		{
+			let max_attempts :usize = $n;
+			let mut attempt :usize = 0;
+			#[allow(unused_mut)]
+			let mut evaluate = || $e;
+			loop {
+				match $crate::Judge::into_moral(evaluate()) {
+					$crate::Moral::Good(v) => break v,
+					$crate::Moral::Bad(v) => {
+						attempt += 1;
+						if attempt >= max_attempts {
+							#[allow(clippy::redundant_closure_call)]
+							return $crate::Judge::from_bad($crate::From::from($f(v)));
+						}
+						#[allow(clippy::redundant_closure_call)]
+						$g(attempt - 1, v);
+					},
+				}
+			}
+		}
+	};
+}
+
+/** [`terror!`] on several expressions at once
+
+# Description
+
+```text
+let (a, b, c) = terror_all! { $a, $b, $c };
+let (a, b, c) = terror_all! { $a, $b, $c => $f };
+```
+
+Evaluates each expression in order, like `terror!`. If they're all good, it evaluates to the tuple
+of the good values. Otherwise, we return early at the first bad value, and the following expressions
+aren't evaluated.
+
+In the second form, the bad value is mapped through $f like in `terror! { $e => $f }`, whichever
+expression it comes from. So all the expressions need to have the same bad type.
+
+# Example
+
+```rust
+# use tear::prelude::*;
+# use std::num::ParseIntError;
+fn parse_pair (a :&str, b :&str) -> Result<(i32, u8), ParseIntError> {
+    let (a, b) = terror_all! { a.parse(), b.parse() };
+    Ok((a, b))
+}
+
+assert_eq![ parse_pair("-1", "2"), Ok((-1, 2)) ];
+assert![ parse_pair("-1", "-2").is_err() ];
+```
+*/
+#[macro_export]
+macro_rules! terror_all {
+	// With a mapping function eg. `terror_all! { $a, $b => $f }`
+	( $( $e:expr ),+ => $f:expr ) => {
+		( $( $crate::terror! { $e => $f }, )+ )
+	};
+	// `terror_all! { $a, $b }`
+	( $( $e:expr ),+ $(,)? ) => {
+		( $( $crate::terror! { $e }, )+ )
+	};
+}
+
+/** Evaluates all the expressions and returns early with every bad value (f=alloc)
+
+# Description
+
+```text
+let (a, b, c) = tear_all! { $a, $b, $c };
+```
+
+Evaluates every expression (they implement [`Judge`]). If they're all good, it evaluates to the
+tuple of the good values. Otherwise, it returns early with a `Vec` of all the bad values in order,
+like `terror!` does: we return `from_bad(From::from(bads))`.
+
+Contrary to [`terror_all!`], it doesn't stop at the first bad value. This means that all the
+expressions must have the same bad type.
+
+# Example
+
+```rust
+# use tear::prelude::*;
+fn check_form (name :&str, age :i32) -> Result<(String, u8), Vec<String>> {
+    let form = tear_all! {
+        if name.is_empty() { Err("Empty name".to_string()) } else { Ok(name.to_string()) },
+        if age < 0 { Err("Negative age".to_string()) } else { Ok(age as u8) },
+    };
+    Ok(form)
+}
+
+assert_eq![ check_form("Sam", 20), Ok(("Sam".to_string(), 20)) ];
+assert_eq![ check_form("", -1), Err(vec!["Empty name".to_string(), "Negative age".to_string()]) ];
+```
+*/
+#[cfg(feature = "alloc")]
+#[macro_export]
+macro_rules! tear_all {
+	( $( $e:expr ),+ $(,)? ) => {
+		{
+			let mut bads = $crate::__alloc::vec::Vec::new();
+			$crate::__impl_tear_all! { @eval (bads) [] $( $e, )+ }
+		}
+	};
+}
+
+/** (dev) Implementation of `tear_all!`
+
+Each step binds the moral of an expression to `v`. Since identifiers are hygienic, each `v` is
+a different variable, so we collect them to build the tuple at the end.
+
+Syntax: `@eval ($bads) [ <$v>* ] <$e ,>*`
+*/
+#[cfg(feature = "alloc")]
+#[macro_export]
+macro_rules! __impl_tear_all {
+	( @eval ($bads:ident) [$($v:ident)*] $e:expr, $($rest:tt)* ) => {
+		{
+			let v = match $crate::Judge::into_moral($e) {
+				$crate::Moral::Good(v) => ::core::option::Option::Some(v),
+				$crate::Moral::Bad(b) => { $bads.push(b); ::core::option::Option::None },
+			};
+			$crate::__impl_tear_all! { @eval ($bads) [$($v)* v] $($rest)* }
+		}
+	};
+	( @eval ($bads:ident) [$($v:ident)*] ) => {
+		{
+			if !$bads.is_empty() {
+				return $crate::Judge::from_bad($crate::From::from($bads));
+			}
+			( $( match $v { ::core::option::Option::Some(v) => v, ::core::option::Option::None => ::core::unreachable!() }, )* )
+		}
+	};
 }
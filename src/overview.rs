@@ -11,6 +11,10 @@ The `prelude` and `extra` modules are meant to be used for bulk importing.
 You generally want to use `prelude` only and import extra symbols one-by-one. Some symbols in
 `extra` might conflict with yours.
 
+When the short macro names conflict with your own macros, use the `prefixed` module instead.
+It has the same symbols as `extra`, but the macros are prefixed with `t_`, like `t_twist!` and
+`t_next!`.
+
 All symbols are accessible directly from the crate root as we reexport them all.
 
 # Early returns
@@ -34,6 +38,11 @@ that we pass to the mapping function.
 This is why arguments must implement the `Judge` trait that knows how to convert it to either
 `Moral::Good` or `Moral::Bad`.
 
+If you don't need the unwanted value, use `=>>` followed by an expression instead of a function:
+```text
+terror! { ... =>> ... }
+```
+
 # Error handling
 
 `terror!` is the error-handling macro. It depends on [`Judge`] to decide if the value is usable
@@ -55,6 +64,15 @@ If you need to do some things before returning `None`, use a block, and return `
 end. [`Maru`] is the placeholder type used to represent the bad value of `Option<T>`, or the good
 and bad values of `bool`.
 
+With the "anyhow" or "eyre" crate features, the `ctx!` and `wrap_err!` macros add a context
+message to the bad value: `terror! { $e => ctx!("reading {}", path) }`.
+
+To handle several expressions at once and get a tuple of their good values, use [`terror_all!`].
+If you want all the bad values instead of the first one, use `tear_all!` with the "alloc" feature.
+
+If the operation can fail temporarily, [`terror_retry!`] evaluates it a few times before
+returning early like `terror!`.
+
 # Loop control
 
 The `twist!` macro has many forms (see its documentation), and it only processes `Looping` types.
@@ -71,7 +89,9 @@ assert_eq![ x, 3 ];
 
 In the complex case where you want to breakval from multiple loops with a different type, you can
 use `Box<dyn Any>` to hide those type. We provide the [`anybox!`] macro to take the concrete type,
-and wrap it into a `Box<dyn Any>` object. See [`twist!`] documentation for more information.
+and wrap it into a `Box<dyn Any>` object, and [`Looping::breakval_boxed`] to build the signal
+directly. To avoid the allocation, `-enum` breaks with the variants
+of a generated enum instead. See [`twist!`] documentation for more information.
 
 ```
 use tear::prelude::*;
@@ -79,7 +99,7 @@ use tear::prelude::*;
 let x: i32 = 'a: loop {
 	let y: String = 'b: loop {
 		let _ = twist! { -box -val String, -label 'a: i32 |
-			Looping::BreakVal { label: Some(0), value: anybox!(3) }
+			Looping::breakval_boxed(Some(0), 3)
 		};
 		if false { break "a".to_string() }
 	};
@@ -98,8 +118,24 @@ loop {
 }
 ```
 
+To break a `loop` with a value, use [`breakval!`] with the `-val` flag:
+
+```
+use tear::extra::*;
+let x = loop {
+    twist! { -val None => |_| breakval!(4) };
+};
+assert_eq![ x, 4 ];
+```
+
 There's also [`next_if!`] and [`last_if!`] macros that continue or break the loop based on a condition
-or a pattern match.
+or a pattern match, and [`resume_if!`] that evaluates to a value or skips the iteration.
+[`twist_if!`] does the same for any `Looping` expression, with the flags of `twist!`.
+With the "tloop" feature, `tloop!` lets you list the `-label`s once for a loop nest,
+instead of in every `twist!`.
+
+For the common case of skipping or breaking on None or Err, use [`some_or_next!`], [`some_or_last!`],
+[`ok_or_next!`] and [`ok_or_last!`].
 
 # Add functionality to your own types
 
@@ -117,8 +153,8 @@ fn f () -> $your-type {
 You only need to implement `Judge` trait for that type, because `Return` is automatically implemented
 for you.
 
-If using the "experimental" crate feature, then you only need to implement the `Try` trait. The
-`Judge` and `Return` trait will be automatically implemented.
+If using the "experimental" crate feature, you can implement the `Try` trait instead, and then
+call `impl_judge_from_try!` to implement `Judge` and `Return` from it.
 
 */
 #[allow(unused_imports)]
@@ -0,0 +1,166 @@
+/*! Prelude with prefixed macro names
+
+# Usage
+
+```rust
+use tear::prefixed::*;
+```
+
+# Description
+
+The short macro names of `prelude` and `extra`, like `next!` or `last_if!`, may conflict with
+your own macros. This module exports the same things as `extra`, but each macro has a `t_` prefix:
+`t_tear!`, `t_twist!`, `t_next!`, `t_last_if!`, and so on.
+
+```rust
+use tear::prefixed::*;
+
+// Our own `next!`
+macro_rules! next { ($v:expr) => { $v + 1 } }
+
+let mut sum = 0;
+for s in &["1", "a", "2"] {
+    sum += t_twist! { s.parse::<i32>() => |_| t_next!() };
+}
+assert_eq![ next!(sum), 4 ];
+```
+
+Choose one of the preludes: `prelude` (with `extra` for the loop signals) for the short names, or
+`prefixed` for the prefixed ones. Each prefixed macro forwards its input as is to the macro without
+the prefix, so their syntax and documentation are the same. The macros called by their expansion
+are always the original ones, through `$crate::`.
+
+The traits are in scope without their names, like in `prelude`. The types, their variants, and the
+`gut` function have the same names as in `extra`.
+*/
+
+pub use crate::ValRet::{self, *};
+pub use crate::Moral::{self, *};
+pub use crate::Looping;
+pub use crate::{Folded, Maru, gut};
+
+// Traits, in scope without their names
+pub use crate::{Judge as _, Return as _, IntoLooping as _};
+pub use crate::{LoopingIteratorExt as _, JudgeIteratorExt as _, ValRetIteratorExt as _};
+
+// Macros
+pub use crate::{t_tear, t_terror, t_twist};
+pub use crate::{t_terror_retry, t_terror_all};
+pub use crate::{t_tear_if, t_twist_if, t_anybox, t_unbox};
+pub use crate::{t_next_if, t_last_if, t_resume_if};
+pub use crate::{t_some_or_next, t_some_or_last, t_ok_or_next, t_ok_or_last};
+pub use crate::{t_last, t_next, t_resume, t_breakval};
+pub use crate::{t_stop, t_skip, t_go};
+pub use crate::{t_redo, t_tredo_loop};
+pub use crate::t_maybe_match;
+
+#[cfg(feature = "alloc")] pub use crate::t_tear_all;
+
+/// Same as [`tear!`](crate::tear!), with a prefixed name
+#[macro_export]
+macro_rules! t_tear { ( $($t:tt)* ) => { $crate::tear! { $($t)* } } }
+
+/// Same as [`terror!`](crate::terror!), with a prefixed name
+#[macro_export]
+macro_rules! t_terror { ( $($t:tt)* ) => { $crate::terror! { $($t)* } } }
+
+/// Same as [`twist!`](crate::twist!), with a prefixed name
+#[macro_export]
+macro_rules! t_twist { ( $($t:tt)* ) => { $crate::twist! { $($t)* } } }
+
+/// Same as [`terror_retry!`](crate::terror_retry!), with a prefixed name
+#[macro_export]
+macro_rules! t_terror_retry { ( $($t:tt)* ) => { $crate::terror_retry! { $($t)* } } }
+
+/// Same as [`terror_all!`](crate::terror_all!), with a prefixed name
+#[macro_export]
+macro_rules! t_terror_all { ( $($t:tt)* ) => { $crate::terror_all! { $($t)* } } }
+
+/// Same as [`tear_if!`](crate::tear_if!), with a prefixed name
+#[macro_export]
+macro_rules! t_tear_if { ( $($t:tt)* ) => { $crate::tear_if! { $($t)* } } }
+
+/// Same as [`twist_if!`](crate::twist_if!), with a prefixed name
+#[macro_export]
+macro_rules! t_twist_if { ( $($t:tt)* ) => { $crate::twist_if! { $($t)* } } }
+
+/// Same as [`anybox!`](crate::anybox!), with a prefixed name
+#[macro_export]
+macro_rules! t_anybox { ( $($t:tt)* ) => { $crate::anybox! { $($t)* } } }
+
+/// Same as [`unbox!`](crate::unbox!), with a prefixed name
+#[macro_export]
+macro_rules! t_unbox { ( $($t:tt)* ) => { $crate::unbox! { $($t)* } } }
+
+/// Same as [`next_if!`](crate::next_if!), with a prefixed name
+#[macro_export]
+macro_rules! t_next_if { ( $($t:tt)* ) => { $crate::next_if! { $($t)* } } }
+
+/// Same as [`last_if!`](crate::last_if!), with a prefixed name
+#[macro_export]
+macro_rules! t_last_if { ( $($t:tt)* ) => { $crate::last_if! { $($t)* } } }
+
+/// Same as [`resume_if!`](crate::resume_if!), with a prefixed name
+#[macro_export]
+macro_rules! t_resume_if { ( $($t:tt)* ) => { $crate::resume_if! { $($t)* } } }
+
+/// Same as [`some_or_next!`](crate::some_or_next!), with a prefixed name
+#[macro_export]
+macro_rules! t_some_or_next { ( $($t:tt)* ) => { $crate::some_or_next! { $($t)* } } }
+
+/// Same as [`some_or_last!`](crate::some_or_last!), with a prefixed name
+#[macro_export]
+macro_rules! t_some_or_last { ( $($t:tt)* ) => { $crate::some_or_last! { $($t)* } } }
+
+/// Same as [`ok_or_next!`](crate::ok_or_next!), with a prefixed name
+#[macro_export]
+macro_rules! t_ok_or_next { ( $($t:tt)* ) => { $crate::ok_or_next! { $($t)* } } }
+
+/// Same as [`ok_or_last!`](crate::ok_or_last!), with a prefixed name
+#[macro_export]
+macro_rules! t_ok_or_last { ( $($t:tt)* ) => { $crate::ok_or_last! { $($t)* } } }
+
+/// Same as [`tear_all!`](crate::tear_all!), with a prefixed name (f=alloc)
+#[cfg(feature = "alloc")]
+#[macro_export]
+macro_rules! t_tear_all { ( $($t:tt)* ) => { $crate::tear_all! { $($t)* } } }
+
+/// Same as [`last!`](crate::last!), with a prefixed name
+#[macro_export]
+macro_rules! t_last { ( $($t:tt)* ) => { $crate::last! { $($t)* } } }
+
+/// Same as [`next!`](crate::next!), with a prefixed name
+#[macro_export]
+macro_rules! t_next { ( $($t:tt)* ) => { $crate::next! { $($t)* } } }
+
+/// Same as [`resume!`](crate::resume!), with a prefixed name
+#[macro_export]
+macro_rules! t_resume { ( $($t:tt)* ) => { $crate::resume! { $($t)* } } }
+
+/// Same as [`breakval!`](crate::breakval!), with a prefixed name
+#[macro_export]
+macro_rules! t_breakval { ( $($t:tt)* ) => { $crate::breakval! { $($t)* } } }
+
+/// Same as [`stop!`](crate::stop!), with a prefixed name
+#[macro_export]
+macro_rules! t_stop { ( $($t:tt)* ) => { $crate::stop! { $($t)* } } }
+
+/// Same as [`skip!`](crate::skip!), with a prefixed name
+#[macro_export]
+macro_rules! t_skip { ( $($t:tt)* ) => { $crate::skip! { $($t)* } } }
+
+/// Same as [`go!`](crate::go!), with a prefixed name
+#[macro_export]
+macro_rules! t_go { ( $($t:tt)* ) => { $crate::go! { $($t)* } } }
+
+/// Same as [`redo!`](crate::redo!), with a prefixed name
+#[macro_export]
+macro_rules! t_redo { ( $($t:tt)* ) => { $crate::redo! { $($t)* } } }
+
+/// Same as [`tredo_loop!`](crate::tredo_loop!), with a prefixed name
+#[macro_export]
+macro_rules! t_tredo_loop { ( $($t:tt)* ) => { $crate::tredo_loop! { $($t)* } } }
+
+/// Same as [`maybe_match!`](crate::maybe_match!), with a prefixed name
+#[macro_export]
+macro_rules! t_maybe_match { ( $($t:tt)* ) => { $crate::maybe_match! { $($t)* } } }
@@ -15,12 +15,27 @@ It exports the following symbols:
 - ValRet and its variants Val and Ret
 - Looping
 - `tear!`, `terror!` and `twist!` macros
-- The useful `tear_if!` and `anybox!` macros
-- `next_if!` and `last_if` because they're unlikely to conflict
+- `terror_retry!` for retrying before giving up, and `terror_all!`
+- The useful `tear_if!`, `twist_if!`, `anybox!` and `unbox!` macros
+- `next_if!`, `last_if!` and `resume_if!` because they're unlikely to conflict
+- `some_or_next!`, `some_or_last!`, `ok_or_next!` and `ok_or_last!` loop shortcuts
+- (f=alloc) `tear_all!`
 - (f=experimental) `impl_judge_from_try!`
 
-It also brings the Judge and Return traits into scope as they are required for the macros to work.
-However, they are not imported as symbols.
+It also brings the following traits into scope, as they are required for the macros and the
+extension methods to work. However, they are not imported as symbols, so they don't conflict with
+your own names:
+
+- `Judge` and `Return`, that the macros use
+- `IntoLooping`, for `into_looping`
+- `LoopingIteratorExt`, for `tear_for_each`, `looping_fold` and `map_looping`
+- `JudgeIteratorExt`, for `goods`, `bads`, `until_bad`, `fold_good`, `fold_moral` and (f=alloc)
+  `partition_moral`
+- `ValRetIteratorExt`, for `tear_map` and `val_ret_fold`
+
+None of these methods have the name of a method of `Iterator`, `Option` or `Result`, so they don't
+shadow the standard ones. Import the traits from the crate root or from [`extra`](crate::extra)
+to name them, eg. in trait bounds.
 */
 
 pub use crate::ValRet::{self, *};
@@ -28,7 +43,14 @@ pub use crate::Looping;
 
 // Macros
 pub use crate::{tear, terror, twist};
-pub use crate::{tear_if, anybox};
-pub use crate::{next_if, last_if};
+pub use crate::{terror_retry, terror_all};
+pub use crate::{tear_if, twist_if, anybox, unbox};
+pub use crate::{next_if, last_if, resume_if};
+pub use crate::{some_or_next, some_or_last, ok_or_next, ok_or_last};
 
+#[cfg(feature = "alloc")] pub use crate::tear_all;
 #[cfg(feature = "experimental")] pub use crate::impl_judge_from_try;
+
+// Traits, in scope without their names
+pub use crate::{Judge as _, Return as _, IntoLooping as _};
+pub use crate::{LoopingIteratorExt as _, JudgeIteratorExt as _, ValRetIteratorExt as _};
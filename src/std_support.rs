@@ -0,0 +1,101 @@
+/*! Integrations with the standard library (f=std)
+
+# Description
+
+The crate is `no_std` unless the "std" feature is enabled. This module groups what needs the
+standard library, so that it all lands behind that single feature:
+- `std::error::Error` for [`TwistError`]
+- [`ExitVal`], the return type of a `main` that exits with a code on errors
+
+`Traced` also implements `std::error::Error` with "std", but lives in its own module as it only
+needs "track-caller" otherwise.
+*/
+use crate::{Judge, Moral, Maru, TwistError};
+use std::string::{String, ToString};
+
+impl std::error::Error for TwistError {}
+
+/** Return type of `main` that exits with a code, and prints the error to stderr (f=std)
+
+It implements `Termination` and `Judge`, so `terror!` can return early from `main` with it. Its Good
+value is the success, and its Bad value is itself. Any type that implements `Display`, like
+errors, converts into a failure with the exit code 1. `Termination` needs Rust 1.61.
+
+```no_run
+use tear::prelude::*;
+use tear::std_support::ExitVal;
+
+fn run () -> Result<(), std::io::Error> {
+    Err(std::io::Error::new(std::io::ErrorKind::Other, "oops"))
+}
+
+fn main () -> ExitVal {
+    // Prints "oops" to stderr, and exits with the code 2
+    terror! { run() => |e| ExitVal::fail(2, e) };
+    // Or exits with the code 1
+    terror! { run() };
+    ExitVal::success()
+}
+```
+*/
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExitVal {
+	code :u8,
+	message :Option<String>,
+}
+
+impl ExitVal {
+	/// Exits with the code 0, without printing anything
+	pub fn success () -> Self {
+		ExitVal { code: 0, message: None }
+	}
+
+	/// Exits with `code` after printing `e` to stderr
+	pub fn fail (code :u8, e :impl core::fmt::Display) -> Self {
+		ExitVal { code, message: Some(e.to_string()) }
+	}
+
+	/// Exits with `code` without printing anything
+	pub fn code_only (code :u8) -> Self {
+		ExitVal { code, message: None }
+	}
+
+	/// The exit code
+	pub fn code (&self) -> u8 { self.code }
+
+	/// The message printed to stderr on exit, if any
+	pub fn message (&self) -> Option<&str> { self.message.as_deref() }
+
+	/// Whether it exits with the code 0
+	pub fn is_success (&self) -> bool { self.code == 0 }
+}
+
+/// Failure with the code 1, that prints the value to stderr
+impl<E :core::fmt::Display> From<E> for ExitVal {
+	fn from (e :E) -> Self { ExitVal::fail(1, e) }
+}
+
+impl Judge for ExitVal {
+	type Positive = Maru;
+	type Negative = ExitVal;
+
+	#[inline]
+	fn into_moral (self) -> Moral<Maru, ExitVal> {
+		if self.is_success() { Moral::Good(Maru) } else { Moral::Bad(self) }
+	}
+
+	#[inline]
+	fn from_good (_ :Maru) -> Self { ExitVal::success() }
+	#[inline]
+	fn from_bad (v :ExitVal) -> Self { v }
+}
+
+/// Prints the message to stderr, and returns the exit code
+impl std::process::Termination for ExitVal {
+	fn report (self) -> std::process::ExitCode {
+		if let Some(message) = &self.message {
+			std::eprintln!("{}", message);
+		}
+		std::process::ExitCode::from(self.code)
+	}
+}
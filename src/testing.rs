@@ -0,0 +1,293 @@
+/*! Test support
+
+Assertion macros for types that implement [`Judge`](crate::Judge) and for [`Looping`](crate::Looping).
+They panic with the `Debug` representation of the actual value. They only use `core`, so they work
+in `no_std` tests.
+
+Macros are accessible from the crate root:
+- `assert_good!` and `assert_bad!`
+- `assert_looping!`
+
+(f=testing) [`Flaky`] is a value source that returns a scripted sequence of Good and Bad values.
+It is the recommended way to unit test loop control and retry code built with this crate.
+*/
+#[cfg(feature = "testing")] use alloc::vec::Vec;
+#[cfg(feature = "testing")] use core::cell::Cell;
+#[cfg(feature = "testing")] use crate::Moral;
+
+/** Scripted source of Good and Bad values (f=testing)
+
+# Description
+
+`Flaky` returns the values of its script in order each time [`next`](Flaky::next) is called,
+and panics when the script is exhausted. It counts how many times it was polled, so you can check
+how many times your code evaluated it.
+
+It only needs a shared reference, so it can be used from closures and loops without fuss.
+
+# Examples
+
+Testing `terror_retry!`
+```
+# use tear::prelude::*;
+use tear::testing::Flaky;
+use tear::Moral::*;
+
+fn fetch (source :&Flaky<i32, &'static str>) -> Result<i32, &'static str> {
+    let v = terror_retry! { 3, source.next() };
+    Ok(v)
+}
+
+let source = Flaky::new([Bad("timeout"), Bad("timeout"), Good(42)]);
+assert_eq![ fetch(&source), Ok(42) ];
+assert_eq![ source.polls(), 3 ];
+```
+
+Testing a loop with `twist!`
+```
+# use tear::prelude::*;
+use tear::testing::Flaky;
+use tear::Moral::*;
+use tear::next;
+
+let source = Flaky::new([Good(1), Bad(()), Good(3), Bad(())]);
+let mut sum = 0;
+for _ in 0..source.len() {
+    sum += twist! { source.next() => |_| next!() };
+}
+assert_eq![ sum, 4 ];
+assert_eq![ source.remaining(), 0 ];
+```
+*/
+#[cfg(feature = "testing")]
+#[derive(Debug, Clone)]
+pub struct Flaky<Y, N> {
+	script :Vec<Moral<Y, N>>,
+	polls :Cell<usize>,
+}
+
+#[cfg(feature = "testing")]
+impl<Y :Clone, N :Clone> Flaky<Y, N> {
+	/// Creates a source that returns the values of `script` in order
+	pub fn new (script :impl IntoIterator<Item = Moral<Y, N>>) -> Self {
+		Flaky { script: script.into_iter().collect(), polls: Cell::new(0) }
+	}
+
+	/** Returns a clone of the next value of the script
+
+	# Panics
+
+	Panics if all the values of the script have already been returned.
+	*/
+	pub fn next (&self) -> Moral<Y, N> {
+		let i = self.polls.get();
+		match self.script.get(i) {
+			Some(v) => {
+				self.polls.set(i + 1);
+				v.clone()
+			},
+			None => panic!("Flaky was polled {} times, but its script only has {} values", i + 1, self.script.len()),
+		}
+	}
+
+	/// Number of values returned so far
+	pub fn polls (&self) -> usize { self.polls.get() }
+
+	/// Number of values left in the script
+	pub fn remaining (&self) -> usize { self.script.len() - self.polls.get() }
+
+	/// Length of the script
+	pub fn len (&self) -> usize { self.script.len() }
+
+	/// Returns true if the script is empty
+	pub fn is_empty (&self) -> bool { self.script.is_empty() }
+}
+
+/** Asserts that a value is Good
+
+# Description
+
+```text
+let v = assert_good! { $e };
+assert_good! { $e, $expected };
+```
+
+Converts `$e` into a [`Moral`](crate::Moral) with [`Judge::into_moral`](crate::Judge::into_moral),
+and panics if it is Bad. The first form evaluates to the good value. The second form also asserts
+that the good value is equal to `$expected`.
+
+# Examples
+
+```
+use tear::assert_good;
+
+let v = assert_good! { Some(3) };
+assert_eq![ v, 3 ];
+assert_good! { "12".parse::<i32>(), 12 };
+```
+
+```should_panic
+# use tear::assert_good;
+// Panics with "assertion failed: `Err::<i32, _>(\"nope\")` is Good, got: Bad(\"nope\")"
+assert_good! { Err::<i32, _>("nope") };
+```
+*/
+#[macro_export]
+macro_rules! assert_good {
+	( $e:expr ) => {
+		match $crate::Judge::into_moral($e) {
+			$crate::Moral::Good(v) => v,
+			$crate::Moral::Bad(v) => ::core::panic!(
+				"assertion failed: `{}` is Good, got: Bad({:?})", stringify!($e), v),
+		}
+	};
+	( $e:expr , $expected:expr $(,)? ) => {
+		match ($crate::Judge::into_moral($e), &$expected) {
+			($crate::Moral::Good(ref v), expected) if *v == *expected => (),
+			(actual, expected) => ::core::panic!(
+				"assertion failed: `{}` is Good({:?}), got: {:?}", stringify!($e), expected, actual),
+		}
+	};
+}
+
+/** Asserts that a value is Bad
+
+# Description
+
+```text
+let v = assert_bad! { $e };
+assert_bad! { $e, $expected };
+```
+
+Converts `$e` into a [`Moral`](crate::Moral) with [`Judge::into_moral`](crate::Judge::into_moral),
+and panics if it is Good. The first form evaluates to the bad value. The second form also asserts
+that the bad value is equal to `$expected`.
+
+# Examples
+
+```
+use tear::assert_bad;
+
+assert_bad! { None::<i32> };
+let e = assert_bad! { "x".parse::<i32>() };
+assert_eq![ e.to_string(), "invalid digit found in string" ];
+assert_bad! { Err::<i32, _>(5), 5 };
+```
+
+```should_panic
+# use tear::assert_bad;
+// Panics with "assertion failed: `Some(1)` is Bad, got: Good(1)"
+assert_bad! { Some(1) };
+```
+*/
+#[macro_export]
+macro_rules! assert_bad {
+	( $e:expr ) => {
+		match $crate::Judge::into_moral($e) {
+			$crate::Moral::Bad(v) => v,
+			$crate::Moral::Good(v) => ::core::panic!(
+				"assertion failed: `{}` is Bad, got: Good({:?})", stringify!($e), v),
+		}
+	};
+	( $e:expr , $expected:expr $(,)? ) => {
+		match ($crate::Judge::into_moral($e), &$expected) {
+			($crate::Moral::Bad(ref v), expected) if *v == *expected => (),
+			(actual, expected) => ::core::panic!(
+				"assertion failed: `{}` is Bad({:?}), got: {:?}", stringify!($e), expected, actual),
+		}
+	};
+}
+
+/** Asserts that a [`Looping`](crate::Looping) value is the expected variant
+
+# Description
+
+```text
+assert_looping! { $e, Resume($v) };
+assert_looping! { $e, Break };
+assert_looping! { $e, Break($label) };
+assert_looping! { $e, BreakVal($label, $v) };
+assert_looping! { $e, Continue };
+assert_looping! { $e, Continue($label) };
+assert_looping! { $e, Redo };
+assert_looping! { $e, Redo($label) };
+```
+
+`Break`, `Continue` and `Redo` without a label accept any label. Otherwise, `$label` is compared to
+the `Option<usize>` label of the variant, and `$v` to its value.
+
+# Examples
+
+```
+use tear::{assert_looping, last, next, resume, Looping, BreakValError};
+
+assert_looping! { resume!(3), Resume(3) };
+let v :Looping<i32, BreakValError> = last!();
+assert_looping! { v, Break };
+let v :Looping<i32, BreakValError> = next!(1);
+assert_looping! { v, Continue(Some(1)) };
+assert_looping! { Looping::BreakVal::<(), _> { label: None, value: "v" }, BreakVal(None, "v") };
+```
+
+```should_panic
+# use tear::{assert_looping, resume};
+// Panics with "assertion failed: `resume!(1)` is Continue, got: Resume(1)"
+assert_looping! { resume!(1), Continue };
+```
+*/
+#[macro_export]
+macro_rules! assert_looping {
+	( $e:expr , Resume($v:expr) $(,)? ) => {
+		match (&$e, &$v) {
+			($crate::Looping::Resume(x), expected) if *x == *expected => (),
+			(actual, expected) => ::core::panic!(
+				"assertion failed: `{}` is Resume({:?}), got: {:?}", stringify!($e), expected, actual),
+		}
+	};
+	( $e:expr , Break $(,)? ) => {
+		match $e {
+			$crate::Looping::Break { .. } => (),
+			actual => ::core::panic!("assertion failed: `{}` is Break, got: {:?}", stringify!($e), actual),
+		}
+	};
+	( $e:expr , Break($l:expr) $(,)? ) => {
+		match (&$e, &$l) {
+			($crate::Looping::Break { label }, expected) if *label == *expected => (),
+			(actual, expected) => ::core::panic!(
+				"assertion failed: `{}` is Break({:?}), got: {:?}", stringify!($e), expected, actual),
+		}
+	};
+	( $e:expr , BreakVal($l:expr, $v:expr) $(,)? ) => {
+		match (&$e, &$l, &$v) {
+			($crate::Looping::BreakVal { label, value }, l, v) if *label == *l && *value == *v => (),
+			(actual, l, v) => ::core::panic!(
+				"assertion failed: `{}` is BreakVal({:?}, {:?}), got: {:?}", stringify!($e), l, v, actual),
+		}
+	};
+	( $e:expr , Continue $(,)? ) => {
+		match $e {
+			$crate::Looping::Continue { .. } => (),
+			actual => ::core::panic!("assertion failed: `{}` is Continue, got: {:?}", stringify!($e), actual),
+		}
+	};
+	( $e:expr , Continue($l:expr) $(,)? ) => {
+		match (&$e, &$l) {
+			($crate::Looping::Continue { label }, expected) if *label == *expected => (),
+			(actual, expected) => ::core::panic!(
+				"assertion failed: `{}` is Continue({:?}), got: {:?}", stringify!($e), expected, actual),
+		}
+	};
+	( $e:expr , Redo $(,)? ) => {
+		match $e {
+			$crate::Looping::Redo { .. } => (),
+			actual => ::core::panic!("assertion failed: `{}` is Redo, got: {:?}", stringify!($e), actual),
+		}
+	};
+	( $e:expr , Redo($l:expr) $(,)? ) => {
+		match (&$e, &$l) {
+			($crate::Looping::Redo { label }, expected) if *label == *expected => (),
+			(actual, expected) => ::core::panic!(
+				"assertion failed: `{}` is Redo({:?}), got: {:?}", stringify!($e), expected, actual),
+		}
+	};
+}
@@ -0,0 +1,119 @@
+/*! Errors that remember where they were created (f=track-caller)
+
+# Description
+
+[`Traced`] wraps an error with the source code location of the caller that created it.
+Use the [`traced`] function in the mapping position of `terror!` to know which callsite
+returned early:
+
+```
+# use tear::prelude::*;
+use tear::traced::{traced, Traced};
+
+fn parse (s :&str) -> Result<i32, Traced<core::num::ParseIntError>> {
+    let v = terror! { s.parse::<i32>() => traced };
+    Ok(v)
+}
+
+let e = parse("nope").unwrap_err();
+assert_eq![ e.location().file(), file!() ];
+assert_eq![ e.to_string(), format!("invalid digit found in string (at {})", e.location()) ];
+```
+
+The location is captured with `#[track_caller]`, so it is the location of the `terror!` call.
+
+# Converting the inner error
+
+`From<Traced<E>> for Traced<E2>` can't be implemented as it would conflict with
+`impl<T> From<T> for T`. Use [`retraced`] in the mapping position instead, which converts the inner
+error with `From` and keeps the original location:
+
+```
+# use tear::prelude::*;
+use tear::traced::{traced, retraced, Traced};
+
+#[derive(Debug)]
+struct MyError;
+impl From<core::num::ParseIntError> for MyError {
+    fn from (_ :core::num::ParseIntError) -> Self { MyError }
+}
+
+fn parse (s :&str) -> Result<i32, Traced<core::num::ParseIntError>> {
+    Ok(terror! { s.parse::<i32>() => traced })
+}
+
+fn run () -> Result<i32, Traced<MyError>> {
+    let v = terror! { parse("nope") => retraced };
+    Ok(v)
+}
+
+let e = run().unwrap_err();
+assert_eq![ e.location().file(), file!() ]; // Location of `traced`, not `retraced`
+```
+*/
+use core::fmt;
+use core::ops::{Deref, DerefMut};
+use core::panic::Location;
+
+/** An error and the location where it was created
+
+It dereferences to the inner error. See the [module documentation](self) for examples.
+*/
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Traced<E> {
+	error :E,
+	location :&'static Location<'static>,
+}
+
+impl<E> Traced<E> {
+	/// Wraps the error with the location of the caller
+	#[track_caller]
+	pub fn new (error :E) -> Self {
+		Traced { error, location: Location::caller() }
+	}
+
+	/// Location where the error was wrapped
+	pub fn location (&self) -> &'static Location<'static> { self.location }
+
+	/// Gets the inner error, discarding the location
+	pub fn into_inner (self) -> E { self.error }
+
+	/// Maps the inner error, keeping the location
+	pub fn map<F> (self, f :impl FnOnce(E) -> F) -> Traced<F> {
+		Traced { error: f(self.error), location: self.location }
+	}
+}
+
+impl<E> Deref for Traced<E> {
+	type Target = E;
+	fn deref (&self) -> &E { &self.error }
+}
+
+impl<E> DerefMut for Traced<E> {
+	fn deref_mut (&mut self) -> &mut E { &mut self.error }
+}
+
+impl<E :fmt::Display> fmt::Display for Traced<E> {
+	fn fmt (&self, f :&mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "{} (at {})", self.error, self.location)
+	}
+}
+
+/// The source is the inner error's source, as the inner error is already displayed (f=std)
+#[cfg(feature = "std")]
+impl<E :std::error::Error> std::error::Error for Traced<E> {
+	fn source (&self) -> Option<&(dyn std::error::Error + 'static)> { self.error.source() }
+}
+
+/** Wraps the error with the location of the caller
+
+Meant for the mapping position of `terror!`: `terror! { $e => traced }`.
+*/
+#[track_caller]
+pub fn traced<E> (error :E) -> Traced<E> { Traced::new(error) }
+
+/** Converts the inner error with `From`, keeping the original location
+
+Meant for the mapping position of `terror!`: `terror! { $e => retraced }`.
+*/
+pub fn retraced<E, F :From<E>> (t :Traced<E>) -> Traced<F> { t.map(F::from) }
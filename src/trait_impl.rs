@@ -2,21 +2,23 @@
 
 This module implements in order
 - Maru <-> ()
+- Judge for bool
+- (f=poll) StreamBad, and Judge for `Poll<Option<Result>>`
 - Return for impl Judge
-- Normal case:
-  - Judge for Option, Result, ValRet and Moral
+- Judge for Option, Result, ValRet and Moral
+- (f=arbitrary) Arbitrary for Looping, the others are derived
 - If using the "experimental" feature flag:
-  - Try for ValRet and Moral
+  - Try, FromResidual and Residual (`try_trait_v2`) for ValRet and Moral
+  - LoopingResidual, and Try for Looping
   - `impl_judge_from_try!`
-  - Judge for Option, Result, Moral and ValRet
-  - Maru -> NoneError
 */
 use crate::*;
 
 /** A placeholder type with a single value ◯
 
-It mirrors the [`NoneError`](`core::option::NoneError`) type. For example, it is used in conjunction with [`Moral`] to
-represent the bad types for `bool` or `Option<T>`.
+It mirrors the `NoneError` type of the old `Try` trait. For example, it is used in conjunction with [`Moral`] to
+represent the bad types for `bool` or `Option<T>`. It converts from `()`, so the mapping function of
+`terror!` can return `()` in a function that returns `Option<T>`, with or without "experimental".
 
 # Examples
 
@@ -42,6 +44,8 @@ fn f() -> () {
 - the [`gut`] function, that takes over the right-hand side
 */
 #[derive(Copy, Debug, Clone)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub struct Maru;
 
 // Equivalence to ()
@@ -59,27 +63,110 @@ impl Judge for bool {
 	type Positive = Maru;
 	type Negative = Maru;
 
+	#[inline]
 	fn into_moral (self) -> Moral<Maru, Maru> {
 		if self { Good(Maru) }
 		else { Bad(Maru) }
 	}
 
+	#[inline]
 	fn from_good (_ :Maru) -> Self { true }
+	#[inline]
 	fn from_bad (_ :Maru) -> Self { false }
 }
 
+/** The Bad value of `Poll<Option<Result<T, E>>>`, the shape of `Stream::poll_next` (f=poll)
+
+With its implementation of Judge, `terror! { inner.poll_next(cx) }` binds the item, and otherwise
+returns early with `Poll::Pending`, `Poll::Ready(None)` or the error. Use [`StreamBad::map_err`]
+when the returned error type is different:
+
+```
+use tear::prelude::*;
+use tear::StreamBad;
+use core::task::Poll;
+
+fn double (p :Poll<Option<Result<i32, u8>>>) -> Poll<Option<Result<i32, u16>>> {
+    let v = terror! { p => |b :StreamBad<u8>| b.map_err(u16::from) };
+    Poll::Ready(Some(Ok(v * 2)))
+}
+
+assert_eq![ double(Poll::Ready(Some(Ok(2)))), Poll::Ready(Some(Ok(4))) ];
+assert_eq![ double(Poll::Ready(Some(Err(3)))), Poll::Ready(Some(Err(3))) ];
+assert_eq![ double(Poll::Ready(None)), Poll::Ready(None) ];
+assert_eq![ double(Poll::Pending), Poll::Pending ];
+```
+*/
+#[cfg(feature = "poll")]
+#[derive(PartialEq, Debug, Clone)]
+pub enum StreamBad<E> {
+	/// `Poll::Pending`
+	Pending,
+	/// `Poll::Ready(None)`, the stream is exhausted
+	Done,
+	/// `Poll::Ready(Some(Err(e)))`
+	Err(E),
+}
+
+#[cfg(feature = "poll")]
+impl<E> StreamBad<E> {
+	/// Maps the error with `f`, and keeps `Pending` and `Done`
+	pub fn map_err<E2, F :FnOnce(E) -> E2> (self, f :F) -> StreamBad<E2> {
+		match self {
+			StreamBad::Pending => StreamBad::Pending,
+			StreamBad::Done => StreamBad::Done,
+			StreamBad::Err(e) => StreamBad::Err(f(e)),
+		}
+	}
+}
+
+#[cfg(feature = "poll")]
+impl<T, E> Judge for core::task::Poll<Option<Result<T, E>>> {
+	type Positive = T;
+	type Negative = StreamBad<E>;
+
+	#[inline]
+	fn into_moral (self) -> Moral<T, StreamBad<E>> {
+		use core::task::Poll;
+		match self {
+			Poll::Ready(Some(Ok(v))) => Good(v),
+			Poll::Ready(Some(Err(e))) => Bad(StreamBad::Err(e)),
+			Poll::Ready(None) => Bad(StreamBad::Done),
+			Poll::Pending => Bad(StreamBad::Pending),
+		}
+	}
+
+	#[inline]
+	fn from_good (v :T) -> Self { core::task::Poll::Ready(Some(Ok(v))) }
+	#[inline]
+	fn from_bad (b :StreamBad<E>) -> Self { b.into() }
+}
+
+/// Converts back to `Poll`, and the error with `From` (f=poll)
+#[cfg(feature = "poll")]
+impl<U, E, E2 :From<E>> From<StreamBad<E>> for core::task::Poll<Option<Result<U, E2>>> {
+	fn from (b :StreamBad<E>) -> Self {
+		use core::task::Poll;
+		match b {
+			StreamBad::Pending => Poll::Pending,
+			StreamBad::Done => Poll::Ready(None),
+			StreamBad::Err(e) => Poll::Ready(Some(Err(E2::from(e)))),
+		}
+	}
+}
+
 /// Blanket implementation of Return for types that implement Judge
 impl<T, E, Me> Return for Me where Me: Judge<Positive=T, Negative=E> {
 	type Value = T;
 	type Returned = E;
 
+	#[inline]
 	fn into_valret(self) -> ValRet<T, E> {
 		self.into_moral().into_valret()
 	}
 }
 
 /// Normal Implementations
-#[cfg(not(feature = "experimental"))]
 mod independent {
 	use crate::*;
 
@@ -90,6 +177,7 @@ mod independent {
 		type Positive = T;
 		type Negative = Maru;
 
+		#[inline]
 		fn into_moral(self) -> Moral<T, Maru> {
 			match self {
 				Some(v) => Good(v),
@@ -97,7 +185,9 @@ mod independent {
 			}
 		}
 
+		#[inline]
 		fn from_good(v: T) -> Self { Some(v) }
+		#[inline]
 		fn from_bad(_: Maru) -> Self { None }
 	}
 
@@ -106,6 +196,7 @@ mod independent {
 		type Positive = T;
 		type Negative = E;
 
+		#[inline]
 		fn into_moral(self) -> Moral<T, E> {
 			match self {
 				Ok(v) => Good(v),
@@ -113,7 +204,9 @@ mod independent {
 			}
 		}
 
+		#[inline]
 		fn from_good(v: T) -> Self { Ok(v) }
+		#[inline]
 		fn from_bad(v: E) -> Self { Err(v) }
 	}
 
@@ -121,6 +214,7 @@ mod independent {
 		type Positive = T;
 		type Negative = R;
 
+		#[inline]
 		fn into_moral(self) -> Moral<T, R> {
 			match self {
 				Val(v) => Good(v),
@@ -128,7 +222,9 @@ mod independent {
 			}
 		}
 
+		#[inline]
 		fn from_good(v: T) -> Self { Val(v) }
+		#[inline]
 		fn from_bad(r: R) -> Self { Ret(r) }
 	}
 
@@ -136,84 +232,259 @@ mod independent {
 		type Positive = Y;
 		type Negative = N;
 
+		#[inline]
 		fn into_moral(self) -> Moral<Y, N> { self }
 
+		#[inline]
 		fn from_good(v: Y) -> Self { Good(v) }
+		#[inline]
 		fn from_bad(v: N) -> Self { Bad(v) }
 	}
 }
 
-/// Implementations based on experimental features (`try_trait`)
+#[cfg(feature = "arbitrary")] pub use fuzzing::ARBITRARY_LABEL_COUNT;
+
+/// Implementation of `Arbitrary` for Looping, with small label indices (f=arbitrary)
+#[cfg(feature = "arbitrary")]
+mod fuzzing {
+	use crate::Looping;
+	use arbitrary::{Arbitrary, Unstructured, Result};
+	use arbitrary::size_hint;
+
+	/** The label indices of arbitrary `Looping` values are less than this (f=arbitrary)
+
+	Most `twist! -label` calls list a few loops. A label index past them hits the panic arm, so
+	we keep them small to exercise the other arms too.
+	*/
+	pub const ARBITRARY_LABEL_COUNT :usize = 4;
+
+	// None, or an index less than ARBITRARY_LABEL_COUNT. It takes up to 2 bytes
+	fn label (u :&mut Unstructured) -> Result<Option<usize>> {
+		if u.arbitrary()? {
+			Ok(Some(u.int_in_range(0..=ARBITRARY_LABEL_COUNT - 1)?))
+		} else {
+			Ok(None)
+		}
+	}
+
+	impl<'a, T :Arbitrary<'a>, B :Arbitrary<'a>> Arbitrary<'a> for Looping<T, B> {
+		fn arbitrary (u :&mut Unstructured<'a>) -> Result<Self> {
+			Ok(match u.int_in_range(0..=4u8)? {
+				0 => Looping::Resume(u.arbitrary()?),
+				1 => Looping::Break { label: label(u)? },
+				2 => Looping::Continue { label: label(u)? },
+				3 => Looping::BreakVal { label: label(u)?, value: u.arbitrary()? },
+				_ => Looping::Redo { label: label(u)? },
+			})
+		}
+
+		fn size_hint (depth :usize) -> (usize, Option<usize>) {
+			let label = (1, Some(2));
+			size_hint::and((1, Some(1)), size_hint::or_all(&[
+				T::size_hint(depth),
+				label,
+				size_hint::and(label, B::size_hint(depth)),
+			]))
+		}
+	}
+}
+
+#[cfg(feature = "experimental")] pub use nightly::LoopingResidual;
+
+/// Implementations based on experimental features (`try_trait_v2` and `try_trait_v2_residual`)
 #[cfg(feature = "experimental")]
 mod nightly {
-	use core::ops::Try;
-	use core::option::NoneError;
+	use core::ops::{Try, FromResidual, Residual, ControlFlow};
+	use core::convert::Infallible;
 	use crate::*;
 
 	/* Implementations of Try for ValRet and Moral */
 
 	impl<T, R> Try for ValRet<T, R> {
-		type Ok = T;
-		type Error = R;
+		type Output = T;
+		type Residual = ValRet<Infallible, R>;
+
+		fn from_output(v: T) -> Self { Val(v) }
 
-		fn into_result(self) -> Result<T, R> {
+		fn branch(self) -> ControlFlow<ValRet<Infallible, R>, T> {
 			match self {
-				Val(v) => Ok(v),
-				Ret(r) => Err(r),
+				Val(v) => ControlFlow::Continue(v),
+				Ret(r) => ControlFlow::Break(Ret(r)),
 			}
 		}
+	}
 
-		fn from_ok(v: T) -> Self { Val(v) }
-		fn from_error(v: R) -> Self { Ret(v) }
+	impl<T, R> Residual<T> for ValRet<Infallible, R> {
+		type TryType = ValRet<T, R>;
+	}
+
+	impl<T, R, R2: From<R>> FromResidual<ValRet<Infallible, R>> for ValRet<T, R2> {
+		fn from_residual(r: ValRet<Infallible, R>) -> Self {
+			match r {
+				Val(never) => match never {},
+				Ret(r) => Ret(From::from(r)),
+			}
+		}
+	}
+
+	/// `?` on a `Result` in a function returning `ValRet`
+	impl<T, R, E> FromResidual<Result<Infallible, E>> for ValRet<T, R> where R: From<E> {
+		fn from_residual(r: Result<Infallible, E>) -> Self {
+			match r {
+				Ok(never) => match never {},
+				Err(e) => Ret(From::from(e)),
+			}
+		}
 	}
 
 	impl<Y, N> Try for Moral<Y, N> {
-		type Ok = Y;
-		type Error = N;
+		type Output = Y;
+		type Residual = Moral<Infallible, N>;
+
+		fn from_output(v: Y) -> Self { Good(v) }
 
-		fn into_result(self) -> Result<Y, N> {
-			Self::into_result(self)
+		fn branch(self) -> ControlFlow<Moral<Infallible, N>, Y> {
+			match self {
+				Good(v) => ControlFlow::Continue(v),
+				Bad(v) => ControlFlow::Break(Bad(v)),
+			}
 		}
+	}
+
+	impl<Y, N> Residual<Y> for Moral<Infallible, N> {
+		type TryType = Moral<Y, N>;
+	}
 
-		fn from_ok(v: Y) -> Self { Good(v) }
-		fn from_error(v: N) -> Self { Bad(v) }
+	impl<Y, N, N2: From<N>> FromResidual<Moral<Infallible, N>> for Moral<Y, N2> {
+		fn from_residual(r: Moral<Infallible, N>) -> Self {
+			match r {
+				Good(never) => match never {},
+				Bad(v) => Bad(From::from(v)),
+			}
+		}
+	}
+
+	/// `?` on a `Result` in a function returning `Moral`
+	impl<Y, N, E> FromResidual<Result<Infallible, E>> for Moral<Y, N> where N: From<E> {
+		fn from_residual(r: Result<Infallible, E>) -> Self {
+			match r {
+				Ok(never) => match never {},
+				Err(e) => Bad(From::from(e)),
+			}
+		}
+	}
+
+	/* Implementation of Try for Looping */
+
+	/** The residual of `Looping`: any signal but `Resume` (f=experimental)
+
+	It's what `?` returns early with in a function returning `Looping`. A `Result` whose error
+	converts into it also works with `?`, eg. `v.ok_or(last!())?`, and `?` on a `None` continues the
+	innermost loop.
+
+	```
+	use tear::extra::*;
+
+	fn parse (s :&str) -> Looping<i32, tear::BreakValError> {
+	    let v = s.parse::<i32>().ok()?; // Skips the values that aren't numbers
+	    let v = if v < 0 { Err(last!()) } else { Ok(v) }?; // Stops at the first negative value
+	    resume!(v)
+	}
+
+	let mut sum = 0;
+	for s in &["1", "x", "2", "-1", "3"] {
+	    sum += twist! { parse(s) };
+	}
+	assert_eq![ sum, 3 ];
+	```
+	*/
+	#[derive(PartialEq, Debug, Clone)]
+	pub struct LoopingResidual<B>(pub Looping<Infallible, B>);
+
+	impl<B> From<Looping<Infallible, B>> for LoopingResidual<B> {
+		fn from(l: Looping<Infallible, B>) -> Self { LoopingResidual(l) }
+	}
+
+	impl<T, B> Residual<T> for LoopingResidual<B> {
+		type TryType = Looping<T, B>;
+	}
+
+	impl<T, B> Try for Looping<T, B> {
+		type Output = T;
+		type Residual = LoopingResidual<B>;
+
+		fn from_output(v: T) -> Self { Looping::Resume(v) }
+
+		fn branch(self) -> ControlFlow<LoopingResidual<B>, T> {
+			let l = match self {
+				Looping::Resume(v) => return ControlFlow::Continue(v),
+				Looping::Break { label } => Looping::Break { label },
+				Looping::BreakVal { label, value } => Looping::BreakVal { label, value },
+				Looping::Continue { label } => Looping::Continue { label },
+				Looping::Redo { label } => Looping::Redo { label },
+			};
+			ControlFlow::Break(LoopingResidual(l))
+		}
+	}
+
+	impl<T, B> FromResidual<LoopingResidual<B>> for Looping<T, B> {
+		fn from_residual(r: LoopingResidual<B>) -> Self {
+			match r.0 {
+				Looping::Resume(never) => match never {},
+				Looping::Break { label } => Looping::Break { label },
+				Looping::BreakVal { label, value } => Looping::BreakVal { label, value },
+				Looping::Continue { label } => Looping::Continue { label },
+				Looping::Redo { label } => Looping::Redo { label },
+			}
+		}
+	}
+
+	/// `?` on a `Result` in a function returning `Looping`, when the error converts to a signal
+	impl<T, B, E> FromResidual<Result<Infallible, E>> for Looping<T, B> where E: Into<LoopingResidual<B>> {
+		fn from_residual(r: Result<Infallible, E>) -> Self {
+			match r {
+				Ok(never) => match never {},
+				Err(e) => Self::from_residual(e.into()),
+			}
+		}
+	}
+
+	/// `?` on a `None` in a function returning `Looping` continues the innermost loop
+	impl<T, B> FromResidual<Option<Infallible>> for Looping<T, B> {
+		fn from_residual(_: Option<Infallible>) -> Self {
+			Looping::Continue { label: None }
+		}
 	}
 
 	/** Implement Judge for a type that implements Try
 
-	Give it the type (`Option<T>`), and the generic type parameters (`T`).
+	Give it the type (`Option<T>`), and the generic type parameters (`T`). The Bad type is the
+	residual of `Try`.
 
 	```text
-	impl_judge_from_try!(Result<T, U>, T, U);
+	impl_judge_from_try!(MyResult<T, U>, T, U);
 	```
 	*/
 	#[macro_export]
 	macro_rules! impl_judge_from_try {
 		( $t:ty $(, $i:ident)* $(,)? ) => {
-			impl<__Y, __N $(, $i)* > $crate::Judge for $t where $t :core::ops::Try<Ok=__Y, Error=__N> {
-				type Positive = __Y;
-				type Negative = __N;
-
-				fn into_moral(self) -> $crate::Moral<__Y, __N> {
-					match core::ops::Try::into_result(self) {
-						Ok(v) => $crate::Moral::Good(v),
-						Err(e) => $crate::Moral::Bad(e),
+			impl<$($i),*> $crate::Judge for $t where $t :core::ops::Try {
+				type Positive = <$t as core::ops::Try>::Output;
+				type Negative = <$t as core::ops::Try>::Residual;
+
+				#[inline]
+				fn into_moral(self) -> $crate::Moral<Self::Positive, Self::Negative> {
+					match core::ops::Try::branch(self) {
+						core::ops::ControlFlow::Continue(v) => $crate::Moral::Good(v),
+						core::ops::ControlFlow::Break(e) => $crate::Moral::Bad(e),
 					}
 				}
 
-				fn from_good(v: __Y) -> Self { core::ops::Try::from_ok(v) }
-				fn from_bad(v: __N) -> Self { core::ops::Try::from_error(v) }
+				#[inline]
+				fn from_good(v: Self::Positive) -> Self { core::ops::Try::from_output(v) }
+				#[inline]
+				fn from_bad(v: Self::Negative) -> Self { core::ops::FromResidual::from_residual(v) }
 			}
 		}
 	}
-
-	impl_judge_from_try!(Option<T>, T);
-	impl_judge_from_try!(Result<T, U>, T, U);
-	impl_judge_from_try!(Moral<T, U>, T, U);
-	impl_judge_from_try!(ValRet<T, U>, T, U);
-
-	/// Conversion for creating None with `terror!`
-	impl From<Maru> for NoneError {
-		fn from (_ :Maru) -> Self { NoneError }
-	}
 }
@@ -4,27 +4,243 @@ We also define some macros in this module, but since they're macros, they're acc
 - (dev) `__impl_twist`
 - `twist!`
 - `next_if!` and `last_if!`
+- `some_or_next!`, `some_or_last!`, `ok_or_next!` and `ok_or_last!`
+- `tredo_loop!`
 
 We also reexport all the types in this module for convenience.
+
+# Panic codes
+
+With the "tiny-panic" feature, the panics of `twist!` and `tredo_loop!` have a short code instead
+of the message, to keep the strings out of small binaries:
+
+| Code      | Message                                                                         |
+|-----------|---------------------------------------------------------------------------------|
+| `tear:E1` | [`TearErrorKind::BreakValInNotLoop`]: breaking a `for` or `while` loop with a value |
+| `tear:E2` | [`TearErrorKind::BreakWithoutVal`]: breaking without a value a loop that expects one |
+| `tear:E3` | [`TearErrorKind::BadBreakValType`]: the boxed break value isn't of the type of the loop |
+| `tear:E4` | [`TearErrorKind::ContinueInBlock`]: continuing a labeled block                  |
+| `tear:E5` | [`TearErrorKind::UnlabeledInBlock`]: breaking without a label with `twist! -block -label` |
+| `tear:E6` | [`TearErrorKind::RedoWithoutTredo`]: `Redo` outside of `tredo_loop!`            |
+| `tear:E7` | [`TearErrorKind::InvalidLabel`]: invalid label index in a `Break`, `Continue` or `BreakVal` signal |
+| `tear:E8` | Label index given to a form of `twist!` that only handles a single loop         |
+| `tear:E9` | Label index given to `tredo_loop!`, that only handles its own loop              |
+*/
+
+// Picks the short code with the "tiny-panic" feature, and the message otherwise
+#[cfg(not(feature = "tiny-panic"))]
+macro_rules! message { ( $code:literal, $message:literal ) => { $message } }
+#[cfg(feature = "tiny-panic")]
+macro_rules! message { ( $code:literal, $message:literal ) => { $code } }
+
+/** The kinds of panics of `twist!` and `tredo_loop!`
+
+[`message`](TearErrorKind::message) gives the panic message, or its short code with the
+"tiny-panic" feature. [`TwistError::kind`] gives the kind of the errors of `twist! -try`.
+
+```
+use tear::{TearErrorKind, TwistError};
+
+let kind = TwistError::MissingValue.kind();
+assert_eq![ kind, TearErrorKind::BreakWithoutVal ];
+assert_eq![ kind.to_string(), kind.message() ];
+```
 */
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TearErrorKind {
+	/// Breaking with a value a `for` or `while` loop, or a loop without `-val`
+	BreakValInNotLoop,
+	/// Breaking without a value a loop that expects one with `twist -val`
+	BreakWithoutVal,
+	/// The break value isn't of the type of the loop it's breaking from
+	BadBreakValType,
+	/// Continuing a labeled block with `twist! -block`
+	ContinueInBlock,
+	/// Breaking without a label with `twist! -block -label`, that only breaks the labeled blocks
+	UnlabeledInBlock,
+	/// A `Redo` signal outside of `tredo_loop!`
+	RedoWithoutTredo,
+	/// The label index doesn't correspond to any loop handled by `twist!`
+	InvalidLabel,
+}
+
+impl TearErrorKind {
+	/// The panic message, or its short code like `"tear:E1"` with the "tiny-panic" feature
+	#[allow(deprecated)] // The constants hold the messages until they're removed
+	pub fn message (&self) -> &'static str {
+		match self {
+			TearErrorKind::BreakValInNotLoop => BREAKVAL_IN_NOT_LOOP,
+			TearErrorKind::BreakWithoutVal => BREAK_WITHOUT_VAL,
+			TearErrorKind::BadBreakValType => BAD_BREAKVAL_TYPE,
+			TearErrorKind::ContinueInBlock => message!("tear:E4", "\
+				error[E0696]: `continue` pointing to a labeled block. \
+				Labeled blocks can't be continued. \
+				Use Break or BreakVal instead of Continue in `twist! -block` expression."),
+			TearErrorKind::UnlabeledInBlock => message!("tear:E5", "\
+				error[E0268]: `break` outside of a loop. \
+				`twist! -block -label` only breaks the labeled blocks. \
+				Add the label index to the Break or BreakVal object."),
+			TearErrorKind::RedoWithoutTredo => message!("tear:E6", "\
+				Looping::Redo runs the loop body again, which only `tredo_loop!` can do. \
+				Use `tredo_loop!` for the loop, or Continue instead of Redo."),
+			// The panics add the label index and the number of labels after it
+			TearErrorKind::InvalidLabel => message!("tear:E7", "invalid label index"),
+		}
+	}
+}
+
+impl core::fmt::Display for TearErrorKind {
+	fn fmt (&self, f :&mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		f.write_str(self.message())
+	}
+}
 
-/** (dev) Error message when trying to break with a value in a non-`loop` loop */
-pub const BREAKVAL_IN_NOT_LOOP :&str = "\
+/** (dev) Message of [`TearErrorKind::BreakValInNotLoop`] */
+#[deprecated(note = "use `TearErrorKind::BreakValInNotLoop.message()`")]
+pub const BREAKVAL_IN_NOT_LOOP :&str = message!("tear:E1", "\
 	error[E0571]: `break` with value is invalid in a `for` or `while` loop. \
 	Use Break instead of BreakVal in `twist!` expression \
-	or use `twist!` with the `-val` flag.";
+	or use `twist!` with the `-val` flag.");
 
-/** (dev) Error message when trying to break without a value in a `twist -val` statement */
-pub const BREAK_WITHOUT_VAL :&str = "\
+/** (dev) Message of [`TearErrorKind::BreakWithoutVal`] */
+#[deprecated(note = "use `TearErrorKind::BreakWithoutVal.message()`")]
+pub const BREAK_WITHOUT_VAL :&str = message!("tear:E2", "\
 	error[E0308]: mismatched types. \
 	Breaking without a value when using `twist -val`. \
-	Use BreakVal instead of Break, or use `twist!` without `-val`";
+	Use BreakVal instead of Break, or use `twist!` without `-val`");
 
-/** (dev) Error message when trying to break with the wrong type in a `twist -val` statement */
-pub const BAD_BREAKVAL_TYPE :&str = "\
+/** (dev) Message of [`TearErrorKind::BadBreakValType`] */
+#[deprecated(note = "use `TearErrorKind::BadBreakValType.message()`")]
+pub const BAD_BREAKVAL_TYPE :&str = message!("tear:E3", "\
 	error[E0308]: mismatched types. \
 	Looping::BreakVal has a value type different from the loop it's breaking from. \
-	Check you're breaking from the right loop, or use Break instead of BreakVal.";
+	Check you're breaking from the right loop, or use Break instead of BreakVal.");
+
+/** (dev) Panics with the message of `kind`
+
+The panics of the `twist!` expansions go through these cold functions, so that each `twist!`
+doesn't generate its own formatting code. With the "track-caller" feature, they have
+`#[track_caller]`, so that the panic location is the `twist!` call instead of this module.
+*/
+#[doc(hidden)]
+#[cold]
+#[inline(never)]
+#[cfg_attr(feature = "track-caller", track_caller)]
+pub fn __fail (kind :TearErrorKind) -> ! {
+	panic!("{}", kind.message())
+}
+
+/** (dev) Panics on a label `index` that `twist!` doesn't handle, as it declared `labels` labels
+
+It only uses the formatting of `core`, so the message doesn't need `alloc`.
+*/
+#[doc(hidden)]
+#[cold]
+#[inline(never)]
+#[cfg_attr(feature = "track-caller", track_caller)]
+pub fn __invalid_label (index :usize, labels :usize) -> ! {
+	#[cfg(feature = "tiny-panic")]
+	{ let _ = (index, labels); __fail(TearErrorKind::InvalidLabel) }
+	#[cfg(not(feature = "tiny-panic"))]
+	panic!("{} {} (twist! declared {} labels)", TearErrorKind::InvalidLabel, index, labels)
+}
+
+/** Panics on a `signal` that the iterator methods can't handle, as there are no enclosing loops
+
+A label index is invalid, like in a `twist!` that declared no labels, and `Redo` needs `tredo_loop!`.
+*/
+#[cold]
+#[inline(never)]
+pub(crate) fn no_enclosing_loop<T, B> (signal :&Looping<T, B>) -> ! {
+	match *signal {
+		Looping::Redo { .. } => __fail(TearErrorKind::RedoWithoutTredo),
+		_ => __invalid_label(signal.label().unwrap_or(0), 0),
+	}
+}
+
+/** (dev) Checks the label index of the `signal` that `twist!` is about to match, in debug builds
+
+`@boxed` calls it in a match guard that only exists with `debug_assertions`, so that release
+builds don't have the check. It returns `false`, or panics if the label index isn't less than the
+number of `labels`, with the `producer` expression of the signal.
+*/
+#[doc(hidden)]
+#[inline]
+#[cfg_attr(feature = "track-caller", track_caller)]
+pub fn __check_label<T, B> (signal :&Looping<T, B>, labels :usize, producer :&'static str) -> bool {
+	match *signal {
+		Looping::Break { label: Some(l) }
+		| Looping::Continue { label: Some(l) }
+		| Looping::BreakVal { label: Some(l), .. } if l >= labels => __bad_signal(l, labels, producer),
+		_ => false,
+	}
+}
+
+/** (dev) Like [`__invalid_label`], with the `producer` expression of the signal */
+#[doc(hidden)]
+#[cold]
+#[inline(never)]
+#[cfg_attr(feature = "track-caller", track_caller)]
+pub fn __bad_signal (index :usize, labels :usize, producer :&'static str) -> ! {
+	#[cfg(feature = "tiny-panic")]
+	{ let _ = (index, labels, producer); __fail(TearErrorKind::InvalidLabel) }
+	#[cfg(not(feature = "tiny-panic"))]
+	panic!("{} {} (twist! declared {} labels) in the signal from `{}`", TearErrorKind::InvalidLabel, index, labels, producer)
+}
+
+/** (dev) Panics when the break value for `label` isn't of type `type_name`, but of type `got` */
+#[doc(hidden)]
+#[cold]
+#[inline(never)]
+#[cfg_attr(feature = "track-caller", track_caller)]
+pub fn __bad_type (label :&'static str, type_name :&'static str, got :core::any::TypeId) -> ! {
+	#[cfg(feature = "tiny-panic")]
+	{ let _ = (label, type_name, got); __fail(TearErrorKind::BadBreakValType) }
+	#[cfg(not(feature = "tiny-panic"))]
+	panic!("At label {} with type {} (got {:?}): {}", label, type_name, got, TearErrorKind::BadBreakValType)
+}
+
+/** (dev) Like [`__bad_type`], for the single-loop forms of `twist!` */
+#[doc(hidden)]
+#[cold]
+#[inline(never)]
+#[cfg_attr(feature = "track-caller", track_caller)]
+pub fn __bad_single_type (type_name :&'static str, got :core::any::TypeId) -> ! {
+	#[cfg(feature = "tiny-panic")]
+	{ let _ = (type_name, got); __fail(TearErrorKind::BadBreakValType) }
+	#[cfg(not(feature = "tiny-panic"))]
+	panic!("With type {} (got {:?}): {}", type_name, got, TearErrorKind::BadBreakValType)
+}
+
+/** (dev) Panics on a label index in the forms of `twist!` that only handle the loop `label`
+
+`label` is `None` for the innermost loop.
+*/
+#[doc(hidden)]
+#[cold]
+#[inline(never)]
+#[cfg_attr(feature = "track-caller", track_caller)]
+pub fn __single_label (label :Option<&'static str>, index :usize) -> ! {
+	#[cfg(feature = "tiny-panic")]
+	{ let _ = (label, index); panic!("tear:E8") }
+	#[cfg(not(feature = "tiny-panic"))]
+	match label {
+		None => panic!("`twist!` only handles the innermost loop, got label index {}. Use `twist! -label` for the outer loops.", index),
+		Some(label) => panic!("`twist!` only handles the {} loop, got label index {}. Use `twist! -label` for the other loops.", label, index),
+	}
+}
+
+/** (dev) Panics on a label index in `tredo_loop!` */
+#[doc(hidden)]
+#[cold]
+#[inline(never)]
+#[cfg_attr(feature = "track-caller", track_caller)]
+pub fn __redo_label (index :usize) -> ! {
+	#[cfg(feature = "tiny-panic")]
+	{ let _ = index; panic!("tear:E9") }
+	#[cfg(not(feature = "tiny-panic"))]
+	panic!("`tredo_loop!` only handles its own loop, got label index {}.", index)
+}
 
 /** (dev) Type to provide a nicer error message when trying to breakval from a non-`loop` loop
 
@@ -37,6 +253,7 @@ In other cases, the compiler will emit a generic type mismatch or "cannot break
 See `rustc --explain E0571` for what the compiler is warning against.
 */
 #[allow(non_camel_case_types)]
+#[derive(Debug)]
 pub enum Error0571__Tried_to_break_with_value_using_twist_without_val_flag__Use_Break_instead_of_BreakVal_or_add_the_dash_val_flag_to_twist{}
 
 /** (dev) Short name for `Error0571__Tr...twist`
@@ -46,11 +263,62 @@ because the type name is too long.
 */
 pub type BreakValError = Error0571__Tried_to_break_with_value_using_twist_without_val_flag__Use_Break_instead_of_BreakVal_or_add_the_dash_val_flag_to_twist;
 
+/** Error returned by `twist! -try` instead of panicking, and by [`Looping::validate`]
+
+See [`twist!`] documentation.
+*/
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TwistError {
+	/// The label index doesn't correspond to any loop handled by `twist!`
+	InvalidLabel {
+		/// The label index of the signal
+		index: usize,
+		/// The number of labels, so the valid indices are below it
+		max: usize,
+	},
+	/// The signal breaks without a value a loop that expects one
+	MissingValue,
+	/// The boxed break value couldn't be downcast to the type of the loop
+	BadType {
+		/// The type of the loop, as written in `twist!`
+		expected: &'static str,
+	},
+}
+
+impl TwistError {
+	/** The kind of panic that `twist!` would have had without `-try`
+
+	```
+	use tear::{TwistError, TearErrorKind};
+
+	assert_eq![ TwistError::MissingValue.kind(), TearErrorKind::BreakWithoutVal ];
+	```
+	*/
+	pub fn kind (&self) -> TearErrorKind {
+		match self {
+			TwistError::InvalidLabel { .. } => TearErrorKind::InvalidLabel,
+			TwistError::MissingValue => TearErrorKind::BreakWithoutVal,
+			TwistError::BadType { .. } => TearErrorKind::BadBreakValType,
+		}
+	}
+}
+
+impl core::fmt::Display for TwistError {
+	fn fmt (&self, f :&mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		match self {
+			TwistError::InvalidLabel { index, max } => write!(f, "Invalid label index {} in Looping object, expected less than {}", index, max),
+			TwistError::MissingValue => f.write_str("The Looping object breaks without a value a loop that expects one"),
+			TwistError::BadType { expected } => write!(f, "The boxed break value isn't a {}, the type of the loop it's breaking from", expected),
+		}
+	}
+}
+
 /** Different loop control signals that [`twist!`] understands
 
-We map `break`, `break $value` and `continue` to types.
+We map `break`, `break $value` and `continue` to types, and `Redo` to running the loop body again.
 */
 #[derive(PartialEq, Debug, Clone)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub enum Looping<T, B> {
 	/// Resume loop execution with value of type T
 	Resume(T),
@@ -70,9 +338,570 @@ pub enum Looping<T, B> {
 	Continue {
 		/// The index of the label of the loop to continue from. `None` means innermost loop
 		label: Option<usize>
+	},
+	/** Run the current iteration of the loop selected by `label` again
+
+	Only [`tredo_loop!`] handles it, as `twist!` can't run the body of the loop again.
+	*/
+	Redo {
+		/// The index of the label of the loop to redo. `None` means innermost loop
+		label: Option<usize>
+	},
+}
+
+#[allow(clippy::match_like_matches_macro)] // `matches!` needs Rust 1.42
+impl<T, B> Looping<T, B> {
+	/* Predicates */
+
+	/// Whether it is `Resume`
+	pub fn is_resume (&self) -> bool { match self { Looping::Resume(_) => true, _ => false } }
+	/// Whether it is `Break`, with or without a label
+	pub fn is_break (&self) -> bool { match self { Looping::Break { .. } => true, _ => false } }
+	/// Whether it is `Continue`, with or without a label
+	pub fn is_continue (&self) -> bool { match self { Looping::Continue { .. } => true, _ => false } }
+	/// Whether it is `BreakVal`, with or without a label
+	pub fn is_break_val (&self) -> bool { match self { Looping::BreakVal { .. } => true, _ => false } }
+	/// Whether it is `Redo`, with or without a label
+	pub fn is_redo (&self) -> bool { match self { Looping::Redo { .. } => true, _ => false } }
+
+	/* Accessors */
+
+	/// Gets the label index of `Break`, `Continue`, `BreakVal` or `Redo`. `None` for `Resume` and the unlabeled signals
+	pub fn label (&self) -> Option<usize> {
+		match self {
+			Looping::Resume(_) => None,
+			Looping::Break { label } | Looping::Continue { label } | Looping::BreakVal { label, .. }
+			| Looping::Redo { label } => *label,
+		}
+	}
+
+	/// Gets the `Resume(T)` value as `Option<T>`
+	#[inline]
+	pub fn resume (self) -> Option<T> { match self { Looping::Resume(v) => Some(v), _ => None } }
+	/** Gets the `Resume` value, or `default` for the other signals
+
+	```
+	# use tear::{Looping, last, resume};
+	// Says Break at the end of the input
+	fn next_word<'a> (words :&mut core::slice::Iter<'a, &'a str>) -> Looping<&'a str, tear::BreakValError> {
+	    match words.next() { Some(w) => resume!(*w), None => last!() }
+	}
+
+	let mut words = ["hello"].iter();
+	assert_eq![ next_word(&mut words).resume_or("<end>"), "hello" ];
+	assert_eq![ next_word(&mut words).resume_or("<end>"), "<end>" ];
+	```
+	*/
+	#[inline]
+	pub fn resume_or (self, default :T) -> T { self.resume_or_else(|| default) }
+	/// Gets the `Resume` value, or calls `f` for the other signals
+	#[inline]
+	pub fn resume_or_else (self, f :impl FnOnce() -> T) -> T {
+		match self { Looping::Resume(v) => v, _ => f() }
+	}
+	/// Gets the `BreakVal` value as `Option<B>`
+	pub fn break_value (self) -> Option<B> { match self { Looping::BreakVal { value, .. } => Some(value), _ => None } }
+
+	/** Checks that the label index is below `label_count`, the number of labels of `twist! -label`
+
+	Use it to check signals built far from the `twist!` that handles them.
+
+	```
+	# use tear::{Looping, TwistError};
+	let l :Looping<(), ()> = Looping::Break { label: Some(2) };
+	assert_eq![ l.validate(3), Ok(()) ];
+	assert_eq![ l.validate(2), Err(TwistError::InvalidLabel { index: 2, max: 2 }) ];
+	```
+	*/
+	pub fn validate (&self, label_count :usize) -> Result<(), TwistError> {
+		match self.label() {
+			Some(index) if index >= label_count => Err(TwistError::InvalidLabel { index, max: label_count }),
+			_ => Ok(()),
+		}
+	}
+
+	/* Constructors */
+
+	/** Resume with the Good value of `j`, or map its Bad value to a signal with `on_bad`
+
+	The function form of `twist! { $e => $f }`, that `twist!` expands to. It lets functions return
+	signals without matching on the `Judge` value.
+
+	```
+	# use tear::{Looping, skip};
+	fn parse (s :&str) -> Looping<i32, ()> {
+	    Looping::from_judge(s.parse::<i32>(), |_| skip!())
+	}
+	assert_eq![ parse("3"), Looping::Resume(3) ];
+	assert_eq![ parse("a"), Looping::Continue { label: None } ];
+	```
+	*/
+	#[inline]
+	pub fn from_judge<J :crate::Judge<Positive = T>> (j :J, on_bad :impl FnOnce(J::Negative) -> Looping<T, B>) -> Self {
+		j.into_moral().resume_or_else(on_bad)
+	}
+
+	/* Combinators */
+
+	/** Maps the `Resume` value with `f`, and leaves the other variants untouched
+
+	Useful to share a signal producer between loops with different item types.
+
+	```
+	# use tear::Looping;
+	let l :Looping<i32, ()> = Looping::Resume(2);
+	assert_eq![ l.map_resume(|v| v.to_string()), Looping::Resume("2".to_string()) ];
+	```
+	*/
+	pub fn map_resume<U> (self, f :impl FnOnce(T) -> U) -> Looping<U, B> {
+		self.map(f, |v| v)
+	}
+
+	/** Maps the `BreakVal` value with `f`, and leaves the other variants untouched
+
+	Useful to share a signal producer between loops with different break value types,
+	without using `-box`.
+
+	```
+	# use tear::{twist, breakval, go, Looping};
+	// Breaks with the first value above 100
+	fn above_100 (v :u8) -> Looping<(), u8> {
+	    if v > 100 { breakval!(v) } else { go!(()) }
+	}
+
+	let mut values = vec![3, 150, 7].into_iter();
+	let x :i64 = loop {
+	    let v = values.next().unwrap();
+	    twist! { -val above_100(v).map_break_value(i64::from) }
+	};
+	assert_eq![ x, 150 ];
+	```
+	*/
+	pub fn map_break_value<C> (self, f :impl FnOnce(B) -> C) -> Looping<T, C> {
+		self.map(|v| v, f)
+	}
+
+	/** Calls `f` with the `Resume` value, and leaves the other variants untouched
+
+	Chains checks that each return a signal, like `Option::and_then`. The first signal that isn't
+	`Resume` is returned with its label, and the next checks don't run.
+
+	```
+	# use tear::{Looping, skip};
+	fn parse (s :&str) -> Looping<i32, ()> {
+	    Looping::from_judge(s.parse::<i32>(), |_| skip!())
+	}
+	fn positive (v :i32) -> Looping<u32, ()> {
+	    if v > 0 { Looping::Resume(v as u32) } else { skip!() }
+	}
+
+	assert_eq![ parse("3").and_then(positive), Looping::Resume(3) ];
+	assert_eq![ parse("-3").and_then(positive), Looping::Continue { label: None } ];
+	assert_eq![ parse("a").and_then(positive), Looping::Continue { label: None } ];
+	```
+	*/
+	pub fn and_then<U> (self, f :impl FnOnce(T) -> Looping<U, B>) -> Looping<U, B> {
+		match self {
+			Looping::Resume(v) => f(v),
+			Looping::Break { label } => Looping::Break { label },
+			Looping::BreakVal { label, value } => Looping::BreakVal { label, value },
+			Looping::Continue { label } => Looping::Continue { label },
+			Looping::Redo { label } => Looping::Redo { label },
+		}
+	}
+
+	/** Maps the label index of `Break`, `BreakVal`, `Continue` and `Redo` with `f`
+
+	Signals without a label target the innermost loop, so they are untouched. Useful when a
+	function returns signals for its own labels, and the `twist!` that handles them has more.
+	*/
+	pub fn map_label (self, f :impl FnOnce(usize) -> usize) -> Self {
+		match self {
+			Looping::Break { label } => Looping::Break { label: label.map(f) },
+			Looping::BreakVal { label, value } => Looping::BreakVal { label: label.map(f), value },
+			Looping::Continue { label } => Looping::Continue { label: label.map(f) },
+			Looping::Redo { label } => Looping::Redo { label: label.map(f) },
+			v @ Looping::Resume(_) => v,
+		}
+	}
+
+	/** Adds `by` to the label index, for a `twist!` with `by` more labels before those of the signal
+
+	```
+	# use tear::{twist, Looping, last};
+	// Breaks 'b when its label index is 0
+	fn stop_inner () -> Looping<(), tear::BreakValError> { last!(0) }
+
+	let mut outer = 0;
+	'a: for _ in 0..3 {
+	    outer += 1;
+	    'b: loop {
+	        loop {
+	            twist! { -label 'a, 'b | stop_inner().offset_labels(1) }
+	        }
+	    }
+	}
+	assert_eq![ outer, 3 ];
+	```
+	*/
+	pub fn offset_labels (self, by :usize) -> Self {
+		self.map_label(|l| l + by)
+	}
+
+	/** Maps the `Resume` value with `f`, and the `BreakVal` value with `g`
+
+	`Break`, `Continue` and `Redo` are untouched, and the labels are kept.
+	*/
+	pub fn map<U, C> (self, f :impl FnOnce(T) -> U, g :impl FnOnce(B) -> C) -> Looping<U, C> {
+		match self {
+			Looping::Resume(v) => Looping::Resume(f(v)),
+			Looping::Break { label } => Looping::Break { label },
+			Looping::BreakVal { label, value } => Looping::BreakVal { label, value: g(value) },
+			Looping::Continue { label } => Looping::Continue { label },
+			Looping::Redo { label } => Looping::Redo { label },
+		}
+	}
+}
+
+impl<T, B :core::fmt::Debug> Looping<T, B> {
+	/** Gets the `Resume` value, or panics with the signal
+
+	```should_panic
+	# use tear::Looping;
+	let l :Looping<i32, &str> = Looping::BreakVal { label: None, value: "stop" };
+	l.unwrap_resume(); // Panics with "called `Looping::unwrap_resume()` on BreakVal { label: None, value: "stop" }"
+	```
+	*/
+	#[cfg_attr(feature = "track-caller", track_caller)]
+	pub fn unwrap_resume (self) -> T {
+		self.expect_resume("called `Looping::unwrap_resume()`")
+	}
+
+	/// Gets the `Resume` value, or panics with `msg` and the signal
+	#[cfg_attr(feature = "track-caller", track_caller)]
+	pub fn expect_resume (self, msg :&str) -> T {
+		match self {
+			Looping::Resume(v) => v,
+			// Not Resume, so there is no `T` to print
+			l => panic!("{} on {:?}", msg, l.map_resume(|_| ())),
+		}
+	}
+}
+
+impl<T :core::fmt::Debug, B> Looping<T, B> {
+	/** Gets the `BreakVal` value, or panics with the signal
+
+	```should_panic
+	# use tear::Looping;
+	let l :Looping<i32, &str> = Looping::Continue { label: Some(1) };
+	l.unwrap_break_val(); // Panics with "called `Looping::unwrap_break_val()` on Continue { label: Some(1) }"
+	```
+	*/
+	#[cfg_attr(feature = "track-caller", track_caller)]
+	pub fn unwrap_break_val (self) -> B {
+		self.expect_break_val("called `Looping::unwrap_break_val()`")
+	}
+
+	/// Gets the `BreakVal` value, or panics with `msg` and the signal
+	#[cfg_attr(feature = "track-caller", track_caller)]
+	pub fn expect_break_val (self, msg :&str) -> B {
+		match self {
+			Looping::BreakVal { value, .. } => value,
+			// Not BreakVal, so there is no `B` to print
+			l => panic!("{} on {:?}", msg, l.map_break_value(|_| ())),
+		}
+	}
+}
+
+/** Signals from conditions
+
+Function forms of [`last_if!`] and [`next_if!`], when you need a `Looping` value, eg. to chain it
+with [`and_then`](Looping::and_then). With `twist! -label` and untyped labels, nothing sets the
+break value type, so annotate it, eg. `Looping::<_, ()>::break_label_if(0, cond)`.
+
+```
+# use tear::{twist, Looping};
+let lines = ["a", "", "b", "end", "c"];
+let mut seen = Vec::new();
+for line in lines.iter() {
+    twist! { Looping::continue_if(line.is_empty())
+        .and_then(|_| Looping::break_if(*line == "end")) }
+    seen.push(*line);
+}
+assert_eq![ seen, ["a", "b"] ];
+```
+*/
+impl<B> Looping<(), B> {
+	/** Break the innermost loop if `cond` is true, or resume
+
+	```
+	# use tear::{twist, Looping};
+	let mut count = 0;
+	loop {
+	    count += 1;
+	    twist! { Looping::break_if(count == 3) }
+	}
+	assert_eq![ count, 3 ];
+	```
+	*/
+	pub fn break_if (cond :bool) -> Self {
+		if cond { Looping::Break { label: None } } else { Looping::Resume(()) }
+	}
+
+	/** Continue the innermost loop if `cond` is true, or resume
+
+	```
+	# use tear::{twist, Looping};
+	let mut odds = Vec::new();
+	for i in 0..6 {
+	    twist! { Looping::continue_if(i % 2 == 0) }
+	    odds.push(i);
+	}
+	assert_eq![ odds, [1, 3, 5] ];
+	```
+	*/
+	pub fn continue_if (cond :bool) -> Self {
+		if cond { Looping::Continue { label: None } } else { Looping::Resume(()) }
+	}
+
+	/** Break the loop with the label index `label` if `cond` is true, or resume
+
+	```
+	# use tear::{twist, Looping};
+	let mut last = (0, 0);
+	'a: for i in 0..5 {
+	    for j in 0..5 {
+	        last = (i, j);
+	        twist! { -label 'a | Looping::<_, ()>::break_label_if(0, i * j == 6) }
+	    }
+	}
+	assert_eq![ last, (2, 3) ];
+	```
+	*/
+	pub fn break_label_if (label :usize, cond :bool) -> Self {
+		if cond { Looping::Break { label: Some(label) } } else { Looping::Resume(()) }
+	}
+
+	/** Continue the loop with the label index `label` if `cond` is true, or resume
+
+	```
+	# use tear::{twist, Looping};
+	let mut pairs = 0;
+	'a: for i in 0..4 {
+	    for j in 0..4 {
+	        twist! { -label 'a | Looping::<_, ()>::continue_label_if(0, j > i) }
+	        pairs += 1;
+	    }
+	}
+	assert_eq![ pairs, 10 ];
+	```
+	*/
+	pub fn continue_label_if (label :usize, cond :bool) -> Self {
+		if cond { Looping::Continue { label: Some(label) } } else { Looping::Resume(()) }
+	}
+}
+
+/** Resume with the default value
+
+`Resume` is the neutral signal: `twist!` evaluates to the value and the loop goes on as if
+nothing happened. `Continue` would skip the rest of the iteration, so a signal that was never set
+would change the control flow. This lets you `core::mem::take` a stored signal.
+
+```
+# use tear::Looping;
+let mut l :Looping<i32, ()> = Looping::Break { label: None };
+assert_eq![ core::mem::take(&mut l), Looping::Break { label: None } ];
+assert_eq![ l, Looping::Resume(0) ];
+```
+*/
+impl<T :Default, B> Default for Looping<T, B> {
+	fn default () -> Self {
+		Looping::Resume(T::default())
+	}
+}
+
+/** Describes the signal, without its values
+
+For logging the loop control decisions: `resume`, `break innermost`, `continue loop #1`,
+`redo innermost`, `break loop #2 with value`, etc. The label index is the one of `twist! -label`.
+*/
+impl<T, B> core::fmt::Display for Looping<T, B> {
+	fn fmt (&self, f :&mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		let (signal, label) = match self {
+			Looping::Resume(_) => return f.write_str("resume"),
+			Looping::Break { label } | Looping::BreakVal { label, .. } => ("break", label),
+			Looping::Continue { label } => ("continue", label),
+			Looping::Redo { label } => ("redo", label),
+		};
+		match label {
+			Some(l) => write!(f, "{} loop #{}", signal, l)?,
+			None => write!(f, "{} innermost", signal)?,
+		}
+		if let Looping::BreakVal { .. } = self {
+			f.write_str(" with value")?;
+		}
+		Ok(())
+	}
+}
+
+#[cfg(feature = "alloc")]
+impl<T> Looping<T, alloc::boxed::Box<dyn core::any::Any>> {
+	/** BreakVal with a boxed value, for `twist! -box` (f=alloc)
+
+	Shortcut for `Looping::BreakVal { label, value: anybox!(value) }`.
+
+	```
+	# use tear::{twist, Looping};
+	let x = 'a: loop {
+	    loop {
+	        twist! { -box -label 'a: String | Looping::breakval_boxed(Some(0), "a".to_string()) }
+	    }
+	};
+	assert_eq![ x, "a".to_string() ];
+	```
+	*/
+	pub fn breakval_boxed (label :Option<usize>, value :impl core::any::Any) -> Self {
+		Looping::BreakVal { label, value: alloc::boxed::Box::new(value) }
+	}
+
+	/// BreakVal of the innermost loop with a boxed value, for `twist! -box -val $type,` (f=alloc)
+	pub fn breakval_boxed_innermost (value :impl core::any::Any) -> Self {
+		Self::breakval_boxed(None, value)
+	}
+}
+
+/** Break the innermost loop with a value, or resume (f=control-flow)
+
+Use `twist! { -val -cf $e }` to convert the `ControlFlow` in `twist!`.
+*/
+#[cfg(feature = "control-flow")]
+impl<C, B> From<core::ops::ControlFlow<B, C>> for Looping<C, B> {
+	fn from (cf :core::ops::ControlFlow<B, C>) -> Self {
+		match cf {
+			core::ops::ControlFlow::Continue(v) => Looping::Resume(v),
+			core::ops::ControlFlow::Break(v) => Looping::BreakVal { label: None, value: v },
+		}
+	}
+}
+
+#[cfg(feature = "control-flow")]
+impl<C> Looping<C, BreakValError> {
+	/** Break the innermost loop without a value, or resume (f=control-flow)
+
+	Like `From<ControlFlow>`, but for `ControlFlow<(), C>` in loops that don't break with a value.
+	Use `twist! { -cf $e }` to convert the `ControlFlow` in `twist!`.
+	*/
+	pub fn from_control_flow (cf :core::ops::ControlFlow<(), C>) -> Self {
+		match cf {
+			core::ops::ControlFlow::Continue(v) => Looping::Resume(v),
+			core::ops::ControlFlow::Break(()) => Looping::Break { label: None },
+		}
+	}
+}
+
+#[cfg(feature = "control-flow")]
+impl<C, B> Looping<C, B> {
+	/** Convert back into `ControlFlow`, if it can represent the signal (f=control-flow)
+
+	The inverse of `From<ControlFlow>`: `Resume(v)` is `Continue(v)`, and an unlabeled `BreakVal`
+	is `Break(value)`. `ControlFlow` has no equivalent for `Break`, `Continue`, `Redo` and the labeled
+	signals, so they return `None`. Use [`to_control_flow`](Looping::to_control_flow) to convert
+	them anyway.
+
+	```
+	# use tear::Looping;
+	use core::ops::ControlFlow;
+
+	let l :Looping<i32, &str> = Looping::BreakVal { label: None, value: "done" };
+	assert_eq![ l.into_control_flow(), Some(ControlFlow::Break("done")) ];
+
+	let l :Looping<i32, &str> = Looping::BreakVal { label: Some(1), value: "done" };
+	assert_eq![ l.into_control_flow(), None ];
+	```
+	*/
+	pub fn into_control_flow (self) -> Option<core::ops::ControlFlow<B, C>> {
+		match self {
+			Looping::Resume(v) => Some(core::ops::ControlFlow::Continue(v)),
+			Looping::BreakVal { label: None, value } => Some(core::ops::ControlFlow::Break(value)),
+			_ => None,
+		}
+	}
+
+	/** Convert into `ControlFlow`, dropping the labels (f=control-flow)
+
+	`Break` and `BreakVal` are `Break`, and `Continue` and `Resume` are `Continue`. They hold
+	`None` for the signals without a value. The label indices are dropped, so a signal meant for
+	an outer loop acts on the innermost one, eg. in `Iterator::try_fold`. `Redo` is also
+	`Continue(None)`, as `ControlFlow` can't run the body again.
+
+	```
+	# use tear::Looping;
+	use core::ops::ControlFlow;
+
+	let l :Looping<i32, &str> = Looping::Continue { label: Some(1) };
+	assert_eq![ l.to_control_flow(), ControlFlow::Continue(None) ];
+
+	// Sums the values until the first negative one
+	let sum = [1, 2, -3, 4].iter().try_fold(0, |sum, &v| {
+	    let l :Looping<i32, ()> = if v < 0 { Looping::Break { label: None } } else { Looping::Resume(v) };
+	    match l.to_control_flow() {
+	        ControlFlow::Continue(v) => ControlFlow::Continue(sum + v.unwrap_or(0)),
+	        ControlFlow::Break(_) => ControlFlow::Break(sum),
+	    }
+	});
+	assert_eq![ sum, ControlFlow::Break(3) ];
+	```
+	*/
+	pub fn to_control_flow (self) -> core::ops::ControlFlow<Option<B>, Option<C>> {
+		match self {
+			Looping::Resume(v) => core::ops::ControlFlow::Continue(Some(v)),
+			Looping::Continue { .. } | Looping::Redo { .. } => core::ops::ControlFlow::Continue(None),
+			Looping::Break { .. } => core::ops::ControlFlow::Break(None),
+			Looping::BreakVal { value, .. } => core::ops::ControlFlow::Break(Some(value)),
+		}
 	}
 }
 
+/** Convert into a [`Looping`] signal, for `twist!`
+
+`twist!` calls `into_looping` on the right-hand side, or on what the mapping function returns,
+before acting on the signal. Implement it for your own signal types to use them in `twist!`
+directly.
+
+```
+use tear::extra::*;
+
+enum Action { Keep(i32), SkipOne, Abort(&'static str) }
+
+impl IntoLooping<i32, &'static str> for Action {
+    fn into_looping (self) -> Looping<i32, &'static str> {
+        match self {
+            Action::Keep(v) => Looping::Resume(v),
+            Action::SkipOne => skip!(),
+            Action::Abort(e) => breakval!(e),
+        }
+    }
+}
+
+let step = |v| if v < 0 { Action::Abort("negative") } else if v == 0 { Action::SkipOne } else { Action::Keep(v) };
+let mut values = [1, 0, 2, -1, 3].iter();
+let mut sum = 0;
+let e = loop {
+    let v = match values.next() { Some(&v) => v, None => break "done" };
+    sum += twist! { -val step(v) };
+};
+assert_eq![ (sum, e), (3, "negative") ];
+```
+*/
+pub trait IntoLooping<T, B> {
+	/// Convert itself to a `Looping` signal
+	fn into_looping (self) -> Looping<T, B>;
+}
+
+impl<T, B> IntoLooping<T, B> for Looping<T, B> {
+	fn into_looping (self) -> Looping<T, B> { self }
+}
+
 /** (dev) Macro required by `twist!`
 
 Mostly contains step by step (@prefix) parsing for all the entrypoints in `twist!`. When it's done,
@@ -80,30 +909,69 @@ it calls `twist!` with the final processed values.
 
 # Overview of the steps
 
-When breaking from a single loop, `@parse-map` parse the right-hand part as either an expression, or
-an expression `=>` another expression (which is the mapping function).
+When breaking from a single loop, `@parse-map` parse the right-hand part as either an expression,
+an expression `=>` another expression (which is the mapping function), or an expression `=>>`
+a `Looping` expression. A struct literal after `=>` is treated like `=>>`. With `-box -val $type, -or $fallback |`, `@single-or` first collects
+the fallback, and `@single-or-apply` calls it when the downcast fails.
+
+Before that, `@innermost-scan` fails to compile when the expression visibly breaks the innermost
+loop with a value, but `twist!` doesn't know its type. `@innermost-check` does the same for the
+`-label` forms, after `@label-parse`.
+
+`twist! { -finally $block … }` moves the block after the right-hand side, as `; -finally $block`.
+Every step that parses the right-hand side (`@parse-map`, `@label-expr`, `@with-expr` and
+`@enum-labels`) accepts it, and wraps the parsed expression with `@finally`.
+
+`twist! { -track $var … }` is moved the same way, as `; -track $var`, after the `-finally` block.
+It's added to the mode, as `("panic" $var)`, or to the last argument of `@single`, and `@track`
+sets the variable right before each `break`.
+
+`twist! { -ret $type, … }` is moved last, as `; -ret $type`. The steps that accept `-finally` pass
+it to `@finally`, which unwraps the `ValRet` or returns, and to `@judge`, which builds the `ValRet`
+of `$e => $f`. `@finally` and `@judge` also convert the signal with `IntoLooping::into_looping`.
+
+`twist! { -loop $kind … }` calls `@loop-hint`, which scans the leading flags for `-val` when the
+kind is `for` or `while`, then calls the macro again without the hint.
 
 When breaking from multiple loop labels, there are multiple steps:
-- `@label-parse` separates the labels from the right-hand expressions
-- `@label-expr` parses the right-hand expressions as either a single expression, or
-  an expression `=>` the mapping function
-- `@label-labels` parses each comma-separated label of the format `$label` or `$label : $type`
+- `@label-parse` separates the labels from the right-hand expressions. A well-formed list followed
+  by `|` is matched in one step, otherwise it takes up to four tokens at a time. A type can't be
+  followed by `-` in a macro pattern, so the labels before `-or` always take the slow path
+- `@label-expr` parses the right-hand expressions as either a single expression,
+  an expression `=>` the mapping function, or an expression `=>>` a `Looping` expression
+- `@label-labels` parses the comma-separated labels of the format `$label` or `$label : $type`,
+  two at a time. `$label : into $type` adds `into` to the breakval tuple, and `@into` converts
+  the value when breaking
 - `@label-box` moves the collected data for breakvals into the right slot, to indicate if
   we need to unbox the values or not
 
+With `-named`, `@named-flags`, `@named-split` and `@named-labels` remove the names from the labels
+and define the `Label` type, then call `twist!` again without `-named`. Like `@label-parse`,
+`@named-split`, `@typed-split` and `@enum-split` match a well-formed list followed by `|` in one
+step, and only take one token at a time otherwise.
+
+With `-typed`, `@typed-flags`, `@typed-split` and `@typed-count` count the labels, and `@typed-scan`
+collects the typed labels of the `Looping` constructors in the expression. It asserts in a constant
+that each index is less than the label count, then calls `twist!` again without `-typed`.
+
+With `-enum`, `@enum-split` and `@enum-labels` collect the labels with their variant names and parse
+the right-hand expressions, then `@enum-build` defines the enum and matches on its variants.
+
 # Input and Output
 
 The syntax for calling `@label-parse` is the following:
 ```text
-(("pass") -> ("break") () ()) [$($tokens)*] ->
-  │          │         │  │      └ The tokens that make up the label list and the expression
-  │          ├─────────┴──┘        eg. `'a 'b | 1 + 1`
-  │          └ Only one the three flags should be filled. In order:
-  │            - "break" if the innermost loop can be broken normally
-  │            - the type of the innermost loop break value if we break with a value
-  │            - the type of the boxed innermost loop break value, if we break
-  │              with Box<dyn Any>
-  └ "unbox" if we unbox the breakvals, otherwise "pass"
+(("panic") () ("pass") -> ("break") () ()) [$($tokens)*] ->
+  │         │          │         │  │      └ The tokens that make up the label list and the expression
+  │         │          ├─────────┴──┘        eg. `'a 'b | 1 + 1`
+  │         │          └ Only one the three flags should be filled. In order:
+  │         │            - "break" if the innermost loop can be broken normally
+  │         │            - the type of the innermost loop break value if we break with a value
+  │         │            - the type of the boxed innermost loop break value, if we break
+  │         │              with Box<dyn Any>
+  │         └ "unbox" if we unbox the breakvals, otherwise "pass"
+  └ "try" if we evaluate to a Result instead of panicking, "unchecked" if an invalid label index
+    is undefined behavior, otherwise "panic"
 ```
 
 We use "flags" to simulate booleans with empty parenthese or non-empty parentheses with
@@ -113,16 +981,33 @@ we need to have a slot for each case.
 We call `twist! @boxed` with the following syntax:
 
 ```text
-($($flag)*) ($($bk)*) [ ($($bv)*) () ] $e
-    │           │       │         │    └ The expression to match on
-    │           │       ├─────────┘
-    │           │       └ Only one of these two slots should be filled.
-    │           │         The left one is filled if we breakval normally
-    │           │         The right one is filled if we unbox the value before breakval'ing
-    │           └ The normal breaks
-    └ The same three flags from the input
+$mode [<x>*] $or ($producer) ($($flag)*) ($($bk)*) [ ($($bv)*) () ] $e
+  │      │     │      │           │           │       │         │    └ The expression to match on
+  │      │     │      │           │           │       ├─────────┘
+  │      │     │      │           │           │       └ Only one of these two slots should be filled.
+  │      │     │      │           │           │         The left one is filled if we breakval normally
+  │      │     │      │           │           │         The right one is filled if we unbox the value before breakval'ing
+  │      │     │      │           │           └ The normal breaks
+  │      │     │      │           └ The same three flags from the input
+  │      │     │      └ The user's expression, stringified
+  │      │     └ `()`, or the `-or` fallback function followed by `;` and the rest of the arguments
+  │      └ The number of labels, counted like the label indices
+  └ ("try"), ("unchecked") or ("panic") from the input
 ```
 
+In `@boxed`, `@label-dups` fails to compile if a label appears twice, with a single macro that has
+two arms per label. `@ok` and `@err` wrap the value or the error depending on the mode.
+When a downcast fails, `@or` calls `@fallback` with the `-or` function, or fails like `@err`. `@fallback` is like `@boxed`, but its `Looping`
+value can't break with a value. Both continue the loops with `@continue`.
+
+In debug builds, `@boxed` first calls [`__check_label`] in a match guard, that panics with the
+user's expression if the label index is too large. `@check` skips it with `-try`.
+
+`@err` takes the error for `-try` and the panic expression for the other modes. The panics call
+the cold functions [`__fail`], [`__invalid_label`] and [`__bad_type`], so that the expansions stay
+small. The single-loop forms call [`__single_label`] and [`__bad_single_type`], and `tredo_loop!`
+calls [`__redo_label`]. With the "tiny-panic" feature, they all panic with a short code.
+
 See inline documentation for brief explanations of what each `@step` does.
 */
 #[macro_export]
@@ -130,84 +1015,764 @@ macro_rules! __impl_twist {
 	/* For @single */
 
 	// Parse the right-hand side
+	// ...as an expression =>> looping-expression
+	( @parse-map [$($bk:tt)*] [$($bv:tt)*] ($e:expr =>> $f:expr $(; - finally $fin:block)? $(; - track $tr:ident)? $(; - ret $rt:ty)?) ) => {
+		$crate::twist! { @single [$($bk)*] [$($bv)*] ($crate::__impl_twist! { @finally ($crate::__impl_twist! { @judge ($e) (|_| $f) $(-ret $rt)? }) $($fin)? $(; -ret $rt)? }) ("single" $($tr)?) }
+	};
+	// ...as an expression => struct literal, which can't be a mapping function, so it's the same as `=>>`
+	( @parse-map [$($bk:tt)*] [$($bv:tt)*] ($e:expr => $($p:ident)::+ $(::<$($g:ty),*> $(:: $v:ident)?)? { $($fields:tt)* } $(; $($rest:tt)*)?) ) => {
+		$crate::__impl_twist! { @parse-map [$($bk)*] [$($bv)*] ($e =>> $($p)::+ $(::<$($g),*> $(:: $v)?)? { $($fields)* } $(; $($rest)*)?) }
+	};
 	// ...as an expression => mapping-function
-	( @parse-map [$($bk:tt)*] [$($bv:tt)*] ($e:expr => $f:expr) ) => {
-		$crate::twist! { @single [$($bk)*] [$($bv)*] ($crate::Judge::into_moral($e).resume_or_else($f)) }
+	( @parse-map [$($bk:tt)*] [$($bv:tt)*] ($e:expr => $f:expr $(; - finally $fin:block)? $(; - track $tr:ident)? $(; - ret $rt:ty)?) ) => {
+		$crate::twist! { @single [$($bk)*] [$($bv)*] ($crate::__impl_twist! { @finally ($crate::__impl_twist! { @judge ($e) ($f) $(-ret $rt)? }) $($fin)? $(; -ret $rt)? }) ("single" $($tr)?) }
 	};
 	// ...as an expression
-	( @parse-map [$($bk:tt)*] [$($bv:tt)*] ($e:expr) ) => {
-		$crate::twist! { @single [$($bk)*] [$($bv)*] ($e) }
+	( @parse-map [$($bk:tt)*] [$($bv:tt)*] ($e:expr $(; - finally $fin:block)? $(; - track $tr:ident)? $(; - ret $rt:ty)?) ) => {
+		$crate::twist! { @single [$($bk)*] [$($bv)*] ($crate::__impl_twist! { @finally ($e) $($fin)? $(; -ret $rt)? }) ("single" $($tr)?) }
 	};
 	// ...or fail
 	( @parse-map [$($bk:tt)*] [$($bv:tt)*] ($($tokens:tt)*) ) => {
 		compile_error!(concat!(
-			"Expected either `$e` or `$e => $f` on the right-hand side, got: ",
+			"Expected either `$e`, `$e => $f` or `$e =>> $f` on the right-hand side, got: ",
 			stringify!($($tokens)*)))
 	};
 
-	/* For @boxed */
-
-	// Separate the labels from the expression by getting everything before `|`
-	// ≪ (<$flag>*) [ $input ] -> ≫
-	// → ≪ (<$flag>*) [ <$expr-token>* ] -> <$label-token>* ≫
-	( @label-parse ($($flag:tt)*) [ | $($rest:tt)* ] -> $($l:tt)* ) => {
-		$crate::__impl_twist! { @label-expr ($($flag)*) [$($rest)*] -> $($l)* }
+	// Convert to `Looping`, and run the `-finally` block before `twist!` handles any signal but `Resume`
+	// ≪ ($expr) $block? ≫
+	( @finally ($e:expr) ) => { $crate::IntoLooping::into_looping($e) };
+	( @finally ($e:expr) $fin:block ) => {
+		match $crate::IntoLooping::into_looping($e) {
+			v @ $crate::Looping::Resume(_) => v,
+			v => { $fin; v },
+		}
 	};
-	( @label-parse ($($flag:tt)*) [ $token:tt $($rest:tt)* ] -> $($l:tt)* ) => {
-		$crate::__impl_twist! { @label-parse ($($flag)*) [$($rest)*] -> $($l)* $token }
+	// With `-ret`, $e is a `ValRet`, and `Ret` returns from the function like `terror!`
+	( @finally ($e:expr) $($fin:block)? ; -ret $rt:ty ) => {
+		match $e {
+			$crate::ValRet::Val(v) => $crate::__impl_twist! { @finally (v) $($fin)? },
+			$crate::ValRet::Ret(r) => {
+				$($fin;)?
+				let r :$rt = r;
+				return $crate::Judge::from_bad($crate::From::from(r))
+			},
+		}
 	};
-	// There is no `|`: There's only an expression
-	( @label-parse ($($flag:tt)*) [ ] -> $($rest:tt)* ) => {
-		compile_error!("Missing `|` separator after labels in `twist! -label` macro invocation. Add labels, or use `twist!` without `-label`.")
+
+	// Build the signal of `$e => $f`, which is a `ValRet` with `-ret`
+	// ≪ ($expr) ($fn) [-ret $type]? ≫
+	( @judge ($e:expr) ($f:expr) ) => {
+		$crate::Looping::from_judge($e, |v| $crate::IntoLooping::into_looping($crate::__apply(v, $f)))
 	};
-	
-	// Parse the expression, or fail
-	// ≪ (<$flag>*) [ <$expr-token>* ] -> <$label-token>* ≫
-	// → ≪ (<$flag>*) 0, [ <$label-token>* , ] -> [() ()] <$expr> ≫
-	// ...as `$e
-	( @label-expr ($($flag:tt)*) [ $e:expr ] -> $($l:tt)* ) => {
-		// We add an extra comma, so that every label ends with a comma, simplifies parsing
-		$crate::__impl_twist! { @label-labels ($($flag)*) 0, [$($l)* ,] -> [() ()] $e }
+	( @judge ($e:expr) ($f:expr) -ret $rt:ty ) => {
+		match $crate::Judge::into_moral($e) {
+			$crate::Moral::Good(v) => $crate::ValRet::Val($crate::Looping::Resume(v)),
+			$crate::Moral::Bad(v) => $crate::__apply(v, $f),
+		}
 	};
-	// ...as `$e => $f`
-	( @label-expr ($($flag:tt)*) [ $e:expr => $f:expr ] -> $($l:tt)* ) => {
-		// We add an extra comma, so that every label ends with a comma, simplifies parsing
-		$crate::__impl_twist! { @label-labels ($($flag)*) 0, [$($l)* ,] -> [() ()] $crate::Judge::into_moral($e).resume_or_else($f) }
+
+	/* For -with [labels] */
+
+	// Parse the right-hand side like `@parse-map`
+	// ≪ $mode [ <$label>* ] ( <$expr-token>* ) ≫
+	// → ≪ $mode [] [] [ <$label>* ] ($expr) ≫
+	( @with-expr ($mode:tt) [$($l:tt)*] ($e:expr =>> $f:expr $(; - finally $fin:block)? $(; - track $tr:ident)? $(; - ret $rt:ty)?) ) => {
+		$crate::__impl_twist! { @with-labels ($mode $($tr)?) [] [] [$($l)*] ($crate::__impl_twist! { @finally ($crate::__impl_twist! { @judge ($e) (|_| $f) $(-ret $rt)? }) $($fin)? $(; -ret $rt)? }) }
 	};
-	// ...or fail
-	( @label-expr ($($flag:tt)*) [ $($rest:tt)* ] $($whatever:tt)* ) => {
-		compile_error!(concat!("This failed to parse as an expression: ", stringify!($($rest)*)))
+	( @with-expr ($mode:tt) [$($l:tt)*] ($e:expr => $($p:ident)::+ $(::<$($g:ty),*> $(:: $v:ident)?)? { $($fields:tt)* } $(; $($rest:tt)*)?) ) => {
+		$crate::__impl_twist! { @with-expr ($mode) [$($l)*] ($e =>> $($p)::+ $(::<$($g),*> $(:: $v)?)? { $($fields)* } $(; $($rest)*)?) }
 	};
-	
-	// Parse labels (eg. `'a` or `'a: i32`) separated with commas and separate those that
-	//   break with values and those that don't. Break = $bk and BreakVal = $bv
-	// ≪ (<$flag>*) 0, [ <$label-token>* , ] -> [() ()] <$expr> ≫
-	// → ≪ (<$flag>*) (<$bk>*) (<$bv>*) $expr ≫
-	// Nothing left to parse
-	( @label-labels ($($flag:tt)*) $count:expr, [] -> [($($bk:tt)*) ($($bv:tt)*)] $e:expr ) => {
-		$crate::__impl_twist! { @label-box ($($flag)*) ($($bk)*) ($($bv)*) $e }
+	( @with-expr ($mode:tt) [$($l:tt)*] ($e:expr => $f:expr $(; - finally $fin:block)? $(; - track $tr:ident)? $(; - ret $rt:ty)?) ) => {
+		$crate::__impl_twist! { @with-labels ($mode $($tr)?) [] [] [$($l)*] ($crate::__impl_twist! { @finally ($crate::__impl_twist! { @judge ($e) ($f) $(-ret $rt)? }) $($fin)? $(; -ret $rt)? }) }
 	};
-	// Parse `'a: i32,`
-	( @label-labels ($($flag:tt)*) $count:expr, [ $label:lifetime : $type:ty , $($rest:tt)* ] -> [($($bk:tt)*) ($($bv:tt)*)] $e:expr ) => {
-		$crate::__impl_twist! { @label-labels ($($flag)*) $count + 1, [$($rest)*] -> [($($bk)*) ( $($bv)* ($count, $label, $type) )] $e }
+	( @with-expr ($mode:tt) [$($l:tt)*] ($e:expr $(; - finally $fin:block)? $(; - track $tr:ident)? $(; - ret $rt:ty)?) ) => {
+		$crate::__impl_twist! { @with-labels ($mode $($tr)?) [] [] [$($l)*] ($crate::__impl_twist! { @finally ($e) $($fin)? $(; -ret $rt)? }) }
 	};
-	// Parse `'a,`
-	( @label-labels ($($flag:tt)*) $count:expr, [ $label:lifetime , $($rest:tt)* ] -> [($($bk:tt)*) ($($bv:tt)*)] $e:expr ) => {
-		$crate::__impl_twist! { @label-labels ($($flag)*) $count + 1, [$($rest)*] -> [( $($bk)* ($count, $label) ) ($($bv)*)] $e }
+	( @with-expr $mode:tt [$($l:tt)*] ($($tokens:tt)*) ) => {
+		compile_error!(concat!(
+			"Expected either `$e`, `$e => $f` or `$e =>> $f` on the right-hand side, got: ",
+			stringify!($($tokens)*)))
 	};
-	// Bad label syntax
-	( @label-labels ($($flag:tt)*) $count:expr, [ $($rest:tt)* ] -> [($($bk:tt)*) ($($bv:tt)*)] $e:expr ) => {
-		compile_error!(concat!("Bad label syntax: ", stringify!($($rest)*)))
+	// Count the labels, then handle it like `@fallback`, as nothing breaks with a value
+	// ≪ $mode [<x>*] [ <($count, $label)>* ] [ <$label>* ] ($expr) ≫
+	( @with-labels $mode:tt [$($c:tt)*] [$($done:tt)*] [$l:lifetime $($rest:tt)*] $e:tt ) => {
+		$crate::__impl_twist! { @with-labels $mode [$($c)* x] [$($done)* ([$($c)*], $l)] [$($rest)*] $e }
+	};
+	( @with-labels $mode:tt $c:tt [$($done:tt)*] [] ($e:expr) ) => {
+		$crate::__impl_twist! { @fallback $mode $c (("break") () ()) ($($done)*) [() ()] $e }
+	};
+
+	// Fail to compile if the expression breaks the innermost loop with a value, but `twist!` can't.
+	//   We only see the `breakval!` calls and `BreakVal { … }` literals that aren't nested
+	// ≪ ("single"|"label") [ <$expr-token>* ] -> <$next-step-token>* ≫
+	// → ≪ <$next-step-token>* ≫ or a compile error
+	( @innermost-scan $m:tt [] -> $($next:tt)* ) => {
+		$crate::__impl_twist! { $($next)* }
+	};
+	( @innermost-scan ("single") [ breakval ! $args:tt $($rest:tt)* ] -> $($next:tt)* ) => {
+		compile_error!("`twist!` breaks with a value, but it doesn't have the `-val` flag. Add `-val`, or use Break instead of BreakVal.")
+	};
+	( @innermost-scan ("single") [ BreakVal $($rest:tt)* ] -> $($next:tt)* ) => {
+		compile_error!("`twist!` breaks with a value, but it doesn't have the `-val` flag. Add `-val`, or use Break instead of BreakVal.")
+	};
+	( @innermost-scan ("label") [ breakval ! ( None , $($args:tt)* ) $($rest:tt)* ] -> $($next:tt)* ) => {
+		compile_error!("`twist!` breaks the innermost loop with a value, but it doesn't know its type. Add `-val $type,` before `-label`, or use Break instead of BreakVal.")
+	};
+	( @innermost-scan ("label") [ breakval ! ( $v:expr ) $($rest:tt)* ] -> $($next:tt)* ) => {
+		compile_error!("`twist!` breaks the innermost loop with a value, but it doesn't know its type. Add `-val $type,` before `-label`, or use Break instead of BreakVal.")
+	};
+	( @innermost-scan ("label") [ BreakVal $(:: < $a:ty , $b:ty >)? { $($f:tt)* } $($rest:tt)* ] -> $($next:tt)* ) => {
+		$crate::__impl_twist! { @innermost-fields [$($f)*] [$($rest)*] -> $($next)* }
+	};
+	( @innermost-scan $m:tt [ $token:tt $($rest:tt)* ] -> $($next:tt)* ) => {
+		$crate::__impl_twist! { @innermost-scan $m [$($rest)*] -> $($next)* }
+	};
+	( @innermost-fields [ label : None $($f:tt)* ] $rest:tt -> $($next:tt)* ) => {
+		compile_error!("`twist!` breaks the innermost loop with a value, but it doesn't know its type. Add `-val $type,` before `-label`, or use Break instead of BreakVal.")
+	};
+	( @innermost-fields [ $token:tt $($f:tt)* ] $rest:tt -> $($next:tt)* ) => {
+		$crate::__impl_twist! { @innermost-fields [$($f)*] $rest -> $($next)* }
+	};
+	( @innermost-fields [] [$($rest:tt)*] -> $($next:tt)* ) => {
+		$crate::__impl_twist! { @innermost-scan ("label") [$($rest)*] -> $($next)* }
+	};
+	// Only scan when the innermost loop breaks without a value
+	// ≪ (<$flag>*) [ <$expr-token>* ] -> <$next-step-token>* ≫
+	( @innermost-check ($mode:tt $or:tt $box:tt -> ("break") () ()) [$($e:tt)*] -> $($next:tt)* ) => {
+		$crate::__impl_twist! { @innermost-scan ("label") [$($e)*] -> $($next)* }
+	};
+	( @innermost-check ($($flag:tt)*) [$($e:tt)*] -> $($next:tt)* ) => {
+		$crate::__impl_twist! { $($next)* }
+	};
+
+	// Collect the `-or` fallback of `-box -val $type,` before `|`, like `@label-or`
+	// ≪ ($type) [ <$token>* ] ≫
+	// → `@parse-map` with the ("unbox") ($type) ($fallback) flags
+	( @single-or $type:tt [ | $($rest:tt)* ] ) => {
+		$crate::__impl_twist! { @single-or-args $type [|] [$($rest)*] }
+	};
+	( @single-or $type:tt [ $($rest:tt)* ] ) => {
+		$crate::__impl_twist! { @single-or-body $type [] [$($rest)*] }
+	};
+	( @single-or-args $type:tt [$($f:tt)*] [ | $($rest:tt)* ] ) => {
+		$crate::__impl_twist! { @single-or-body $type [$($f)* |] [$($rest)*] }
+	};
+	( @single-or-args $type:tt [$($f:tt)*] [ $token:tt $($rest:tt)* ] ) => {
+		$crate::__impl_twist! { @single-or-args $type [$($f)* $token] [$($rest)*] }
+	};
+	( @single-or-body $type:tt [$($f:tt)+] [ | $($rest:tt)* ] ) => {
+		$crate::__impl_twist! { @parse-map [] [("unbox") $type (($($f)+))] ($($rest)*) }
+	};
+	( @single-or-body $type:tt [$($f:tt)*] [ $token:tt $($rest:tt)* ] ) => {
+		$crate::__impl_twist! { @single-or-body $type [$($f)* $token] [$($rest)*] }
+	};
+	( @single-or-args $type:tt [$($f:tt)*] [] ) => {
+		compile_error!("Missing `|` separator after the `-or` fallback in `twist!` macro invocation.")
+	};
+	( @single-or-body $type:tt [$($f:tt)*] [] ) => {
+		compile_error!("Missing `|` separator after the `-or` fallback in `twist!` macro invocation.")
+	};
+
+	// Replace the box that failed to downcast with the `-or` fallback, or panic.
+	// The fallback can't break with a value, as it would need to be boxed again
+	( @single-or-apply () ($b:ident) ($panic:expr) ) => {
+		$panic
+	};
+	( @single-or-apply ($($f:tt)+) ($b:ident) ($panic:expr) ) => {
+		match $crate::__apply($b, $($f)+) {
+			$crate::Looping::Resume(v) => v,
+			$crate::Looping::Break { .. } => $crate::twist_impl::__fail($crate::TearErrorKind::BreakWithoutVal),
+			$crate::Looping::Redo { .. } => $crate::twist_impl::__fail($crate::TearErrorKind::RedoWithoutTredo),
+			$crate::Looping::Continue { label: ::core::option::Option::None } => continue,
+			$crate::Looping::Continue { label: ::core::option::Option::Some(l) } => $crate::__impl_twist! { @single-label () l },
+			$crate::Looping::BreakVal::<_, $crate::BreakValError> { value, .. } => match value {},
+		}
+	};
+
+	/* For @boxed */
+
+	// Separate the labels from the expression by getting everything before `|`
+	// ≪ (<$flag>*) [ $input ] -> ≫
+	// → ≪ (<$flag>*) [ <$expr-token>* ] -> <$label-token>* ≫
+	// A well-formed label list followed by `|` is parsed in a single step
+	( @label-parse ($($flag:tt)*) [ $($label:lifetime $(: $type:ty)?),+ $(,)? | $($rest:tt)* ] -> ) => {
+		$crate::__impl_twist! { @innermost-check ($($flag)*) [$($rest)*] ->
+			@label-expr ($($flag)*) [$($rest)*] -> $($label $(: $type)?),* }
+	};
+	// ...or everything before `-or`, which is followed by the fallback function
+	( @label-parse ($mode:tt () ("unbox") $($flag:tt)*) [ - or $($rest:tt)* ] -> $($l:tt)* ) => {
+		$crate::__impl_twist! { @label-or ($mode () ("unbox") $($flag)*) [$($rest)*] -> $($l)* }
+	};
+	( @label-parse ($mode:tt () ("pass") $($flag:tt)*) [ - or $($rest:tt)* ] -> $($l:tt)* ) => {
+		compile_error!("The `-or` fallback of `twist!` is only used to replace failed downcasts. Add `-box` or remove `-or`.")
+	};
+	( @label-parse ($($flag:tt)*) [ | $($rest:tt)* ] -> $($l:tt)* ) => {
+		$crate::__impl_twist! { @innermost-check ($($flag)*) [$($rest)*] ->
+			@label-expr ($($flag)*) [$($rest)*] -> $($l)* }
+	};
+	// Otherwise, take up to four tokens at a time, stopping before `|` or `-or`
+	( @label-parse ($($flag:tt)*) [ $t0:tt | $($rest:tt)* ] -> $($l:tt)* ) => {
+		$crate::__impl_twist! { @label-parse ($($flag)*) [| $($rest)*] -> $($l)* $t0 }
+	};
+	( @label-parse ($($flag:tt)*) [ $t0:tt - or $($rest:tt)* ] -> $($l:tt)* ) => {
+		$crate::__impl_twist! { @label-parse ($($flag)*) [- or $($rest)*] -> $($l)* $t0 }
+	};
+	( @label-parse ($($flag:tt)*) [ $t0:tt $t1:tt | $($rest:tt)* ] -> $($l:tt)* ) => {
+		$crate::__impl_twist! { @label-parse ($($flag)*) [| $($rest)*] -> $($l)* $t0 $t1 }
+	};
+	( @label-parse ($($flag:tt)*) [ $t0:tt $t1:tt - or $($rest:tt)* ] -> $($l:tt)* ) => {
+		$crate::__impl_twist! { @label-parse ($($flag)*) [- or $($rest)*] -> $($l)* $t0 $t1 }
+	};
+	( @label-parse ($($flag:tt)*) [ $t0:tt $t1:tt $t2:tt | $($rest:tt)* ] -> $($l:tt)* ) => {
+		$crate::__impl_twist! { @label-parse ($($flag)*) [| $($rest)*] -> $($l)* $t0 $t1 $t2 }
+	};
+	( @label-parse ($($flag:tt)*) [ $t0:tt $t1:tt $t2:tt - or $($rest:tt)* ] -> $($l:tt)* ) => {
+		$crate::__impl_twist! { @label-parse ($($flag)*) [- or $($rest)*] -> $($l)* $t0 $t1 $t2 }
+	};
+	( @label-parse ($($flag:tt)*) [ $t0:tt $t1:tt $t2:tt $t3:tt $($rest:tt)* ] -> $($l:tt)* ) => {
+		$crate::__impl_twist! { @label-parse ($($flag)*) [$($rest)*] -> $($l)* $t0 $t1 $t2 $t3 }
+	};
+	( @label-parse ($($flag:tt)*) [ $($token:tt)+ ] -> $($l:tt)* ) => {
+		$crate::__impl_twist! { @label-parse ($($flag)*) [] -> $($l)* $($token)+ }
+	};
+	// There is no `|`: There's only an expression
+	( @label-parse ($($flag:tt)*) [ ] -> $($rest:tt)* ) => {
+		compile_error!("Missing `|` separator after labels in `twist! -label` macro invocation. Add labels, or use `twist!` without `-label`.")
+	};
+	
+	// Separate the fallback function from the expression. If it's a closure, its body ends at
+	//   the first `|` after its arguments
+	// ≪ ($mode () ("unbox") <$flag>*) [ $input ] -> <$label-token>* ≫
+	// → ≪ ($mode (<$fallback-token>*) ("unbox") <$flag>*) [ <$expr-token>* ] -> <$label-token>* ≫
+	( @label-or ($($flag:tt)*) [ | $($rest:tt)* ] -> $($l:tt)* ) => {
+		$crate::__impl_twist! { @label-or-args ($($flag)*) [|] [$($rest)*] -> $($l)* }
+	};
+	( @label-or ($($flag:tt)*) [ $($rest:tt)* ] -> $($l:tt)* ) => {
+		$crate::__impl_twist! { @label-or-body ($($flag)*) [] [$($rest)*] -> $($l)* }
+	};
+	( @label-or-args ($($flag:tt)*) [$($f:tt)*] [ | $($rest:tt)* ] -> $($l:tt)* ) => {
+		$crate::__impl_twist! { @label-or-body ($($flag)*) [$($f)* |] [$($rest)*] -> $($l)* }
+	};
+	( @label-or-args ($($flag:tt)*) [$($f:tt)*] [ $token:tt $($rest:tt)* ] -> $($l:tt)* ) => {
+		$crate::__impl_twist! { @label-or-args ($($flag)*) [$($f)* $token] [$($rest)*] -> $($l)* }
+	};
+	( @label-or-body ($mode:tt () $($flag:tt)*) [$($f:tt)+] [ | $($rest:tt)* ] -> $($l:tt)* ) => {
+		$crate::__impl_twist! { @innermost-check ($mode ($($f)+) $($flag)*) [$($rest)*] ->
+			@label-expr ($mode ($($f)+) $($flag)*) [$($rest)*] -> $($l)* }
+	};
+	( @label-or-body ($($flag:tt)*) [$($f:tt)*] [ $token:tt $($rest:tt)* ] -> $($l:tt)* ) => {
+		$crate::__impl_twist! { @label-or-body ($($flag)*) [$($f)* $token] [$($rest)*] -> $($l)* }
+	};
+	( @label-or-args ($($flag:tt)*) [$($f:tt)*] [] -> $($l:tt)* ) => {
+		compile_error!("Missing `|` separator after the `-or` fallback in `twist!` macro invocation.")
+	};
+	( @label-or-body ($($flag:tt)*) [$($f:tt)*] [] -> $($l:tt)* ) => {
+		compile_error!("Missing `|` separator after the `-or` fallback in `twist!` macro invocation.")
+	};
+
+	// Parse the expression, or fail
+	// ≪ (<$flag>*) [ <$expr-token>* ] -> <$label-token>* ≫
+	// → ≪ (<$flag>* ($producer)) [<x>*], [ <$label-token>* , ] -> [() ()] <$expr> ≫
+	// The producer is the stringified expression, for the debug check of `@boxed`
+	// ...as `$e
+	( @label-expr (($mode:tt) $($flag:tt)*) [ $e:expr $(; - finally $fin:block)? $(; - track $tr:ident)? $(; - ret $rt:ty)? ] -> $($l:tt)* ) => {
+		// We add an extra comma, so that every label ends with a comma, simplifies parsing
+		$crate::__impl_twist! { @label-labels (($mode $($tr)?) $($flag)* (stringify!($e))) [], [$($l)* ,] -> [() ()] $crate::__impl_twist! { @finally ($e) $($fin)? $(; -ret $rt)? } }
+	};
+	// ...as `$e =>> $f`
+	( @label-expr (($mode:tt) $($flag:tt)*) [ $e:expr =>> $f:expr $(; - finally $fin:block)? $(; - track $tr:ident)? $(; - ret $rt:ty)? ] -> $($l:tt)* ) => {
+		$crate::__impl_twist! { @label-labels (($mode $($tr)?) $($flag)* (stringify!($e))) [], [$($l)* ,] -> [() ()] $crate::__impl_twist! { @finally ($crate::__impl_twist! { @judge ($e) (|_| $f) $(-ret $rt)? }) $($fin)? $(; -ret $rt)? } }
+	};
+	// ...as `$e => $p { … }`, a struct literal, which is the same as `$e =>> $p { … }`
+	( @label-expr $flag:tt [ $e:expr => $($p:ident)::+ $(::<$($g:ty),*> $(:: $v:ident)?)? { $($fields:tt)* } $(; $($rest:tt)*)? ] -> $($l:tt)* ) => {
+		$crate::__impl_twist! { @label-expr $flag [ $e =>> $($p)::+ $(::<$($g),*> $(:: $v)?)? { $($fields)* } $(; $($rest)*)? ] -> $($l)* }
+	};
+	// ...as `$e => $f`
+	( @label-expr (($mode:tt) $($flag:tt)*) [ $e:expr => $f:expr $(; - finally $fin:block)? $(; - track $tr:ident)? $(; - ret $rt:ty)? ] -> $($l:tt)* ) => {
+		// We add an extra comma, so that every label ends with a comma, simplifies parsing
+		$crate::__impl_twist! { @label-labels (($mode $($tr)?) $($flag)* (stringify!($e))) [], [$($l)* ,] -> [() ()] $crate::__impl_twist! { @finally ($crate::__impl_twist! { @judge ($e) ($f) $(-ret $rt)? }) $($fin)? $(; -ret $rt)? } }
+	};
+	// ...or fail
+	( @label-expr ($($flag:tt)*) [ $($rest:tt)* ] $($whatever:tt)* ) => {
+		compile_error!(concat!("This failed to parse as an expression: ", stringify!($($rest)*)))
+	};
+	
+	// Parse labels (eg. `'a` or `'a: i32`) separated with commas and separate those that
+	//   break with values and those that don't. Break = $bk and BreakVal = $bv
+	// The label index $count is counted with one `x` per label, eg. `[x x]` for 2, so that
+	//   `__label_index!` can turn it into a literal
+	// ≪ (<$flag>*) [<x>*], [ <$label-token>* , ] -> [() ()] <$expr> ≫
+	// → ≪ (<$flag>*) [<x>*] (<$bk>*) (<$bv>*) $expr ≫
+	// Nothing left to parse
+	( @label-labels ($($flag:tt)*) [$($count:tt)*], [] -> [($($bk:tt)*) ($($bv:tt)*)] $e:expr ) => {
+		$crate::__impl_twist! { @label-box ($($flag)*) [$($count)*] ($($bk)*) ($($bv)*) $e }
+	};
+	// The user's trailing comma, followed by the one we added
+	( @label-labels ($($flag:tt)*) [$($count:tt)*], [ , ] -> [$($l:tt)*] $e:expr ) => {
+		$crate::__impl_twist! { @label-labels ($($flag)*) [$($count)*], [] -> [$($l)*] $e }
+	};
+	// Parse two labels at a time, eg. `'a: i32, 'b,`
+	( @label-labels ($($flag:tt)*) [$($count:tt)*], [ $l0:lifetime : $t0:ty , $l1:lifetime : $t1:ty , $($rest:tt)* ] -> [($($bk:tt)*) ($($bv:tt)*)] $e:expr ) => {
+		$crate::__impl_twist! { @label-labels ($($flag)*) [$($count)* x x], [$($rest)*] -> [($($bk)*) ( $($bv)* ([$($count)*], $l0, $t0) ([$($count)* x], $l1, $t1) )] $e }
+	};
+	( @label-labels ($($flag:tt)*) [$($count:tt)*], [ $l0:lifetime : $t0:ty , $l1:lifetime , $($rest:tt)* ] -> [($($bk:tt)*) ($($bv:tt)*)] $e:expr ) => {
+		$crate::__impl_twist! { @label-labels ($($flag)*) [$($count)* x x], [$($rest)*] -> [( $($bk)* ([$($count)* x], $l1) ) ( $($bv)* ([$($count)*], $l0, $t0) )] $e }
+	};
+	( @label-labels ($($flag:tt)*) [$($count:tt)*], [ $l0:lifetime , $l1:lifetime : $t1:ty , $($rest:tt)* ] -> [($($bk:tt)*) ($($bv:tt)*)] $e:expr ) => {
+		$crate::__impl_twist! { @label-labels ($($flag)*) [$($count)* x x], [$($rest)*] -> [( $($bk)* ([$($count)*], $l0) ) ( $($bv)* ([$($count)* x], $l1, $t1) )] $e }
+	};
+	( @label-labels ($($flag:tt)*) [$($count:tt)*], [ $l0:lifetime , $l1:lifetime , $($rest:tt)* ] -> [($($bk:tt)*) ($($bv:tt)*)] $e:expr ) => {
+		$crate::__impl_twist! { @label-labels ($($flag)*) [$($count)* x x], [$($rest)*] -> [( $($bk)* ([$($count)*], $l0) ([$($count)* x], $l1) ) ($($bv)*)] $e }
+	};
+	// Parse `'a: into i64,`, whose break value is converted with `Into`. Boxed values are downcast to
+	//   the label type instead
+	( @label-labels ($mode:tt $or:tt ("unbox") $($flag:tt)*) [$($count:tt)*], [ $label:lifetime : into $type:ty , $($rest:tt)* ] -> $l:tt $e:expr ) => {
+		compile_error!(concat!("`into` can't be used with `twist! -box`, as the value is downcast to the label type: ",
+			stringify!($label), ": into ", stringify!($type)))
+	};
+	( @label-labels ($($flag:tt)*) [$($count:tt)*], [ $label:lifetime : into $type:ty , $($rest:tt)* ] -> [($($bk:tt)*) ($($bv:tt)*)] $e:expr ) => {
+		$crate::__impl_twist! { @label-labels ($($flag)*) [$($count)* x], [$($rest)*] -> [($($bk)*) ( $($bv)* ([$($count)*], $label, $type, into) )] $e }
+	};
+	// Parse `'a: i32,`
+	( @label-labels ($($flag:tt)*) [$($count:tt)*], [ $label:lifetime : $type:ty , $($rest:tt)* ] -> [($($bk:tt)*) ($($bv:tt)*)] $e:expr ) => {
+		$crate::__impl_twist! { @label-labels ($($flag)*) [$($count)* x], [$($rest)*] -> [($($bk)*) ( $($bv)* ([$($count)*], $label, $type) )] $e }
+	};
+	// Parse `'a,`
+	( @label-labels ($($flag:tt)*) [$($count:tt)*], [ $label:lifetime , $($rest:tt)* ] -> [($($bk:tt)*) ($($bv:tt)*)] $e:expr ) => {
+		$crate::__impl_twist! { @label-labels ($($flag)*) [$($count)* x], [$($rest)*] -> [( $($bk)* ([$($count)*], $label) ) ($($bv)*)] $e }
+	};
+	// Bad label syntax
+	( @label-labels ($($flag:tt)*) [$($count:tt)*], [ $($rest:tt)* ] -> [($($bk:tt)*) ($($bv:tt)*)] $e:expr ) => {
+		compile_error!(concat!("Bad label syntax: ", stringify!($($rest)*)))
+	};
+
+	// Fail to compile if a label appears twice. We can't compare lifetimes directly, so we
+	// define a single macro with two arms per label: the first matches the label with its own
+	// index, the second matches it with any other index and fails. Calling the macro on each label
+	// then reaches the arms of the first label with the same name.
+	// `$d` is a `$` token, so that we can write the metavariables of the inner macro
+	// ≪ ($) <($count, $label)>* ≫
+	( @label-dups ($d:tt) ) => {};
+	( @label-dups ($d:tt) $( ($c:tt, $l:lifetime) )+ ) => {
+		#[allow(unused_macros)]
+		macro_rules! __tear_duplicate_label {
+			$(
+				( $l $c ) => {};
+				( $l $d other:tt ) => {
+					compile_error!(concat!("duplicate label ", stringify!($l), " in twist! -label list"))
+				};
+			)+
+		}
+		$( __tear_duplicate_label! { $l $c } )+
+	};
+
+	/* For -named */
+
+	// Collect the flags before `-label`
+	// ≪ [ <$flag-token>* ] [ <$token>* ] ≫
+	( @named-flags [$($f:tt)*] [ -label $($rest:tt)* ] ) => {
+		$crate::__impl_twist! { @named-split [$($f)*] [] [$($rest)*] }
+	};
+	( @named-flags [$($f:tt)*] [ $token:tt $($rest:tt)* ] ) => {
+		$crate::__impl_twist! { @named-flags [$($f)* $token] [$($rest)*] }
+	};
+	( @named-flags [$($f:tt)*] [] ) => {
+		compile_error!("Missing `-label` in `twist! -named` macro invocation")
+	};
+
+	// Separate the labels from the expression by getting everything before `|`
+	// ≪ [ <$flag-token>* ] [ <$label-token>* ] [ <$token>* ] ≫
+	// A well-formed label list is taken in a single step, like in `@label-parse`
+	( @named-split [$($f:tt)*] [] [ $($label:lifetime $(as $name:ident)? $(: $type:ty)?),+ $(,)? | $($rest:tt)* ] ) => {
+		$crate::__impl_twist! { @named-split [$($f)*] [$($label $(as $name)? $(: $type)?),*] [| $($rest)*] }
+	};
+	( @named-split [$($f:tt)*] [$($l:tt)*] [ | $($rest:tt)* ] ) => {
+		// We add an extra comma, so that every label ends with a comma, simplifies parsing
+		$crate::__impl_twist! { @named-labels [$($f)*] [], [$($l)* ,] -> [] [] | $($rest)* }
+	};
+	( @named-split [$($f:tt)*] [$($l:tt)*] [ - or $($rest:tt)* ] ) => {
+		$crate::__impl_twist! { @named-labels [$($f)*] [], [$($l)* ,] -> [] [] - or $($rest)* }
+	};
+	( @named-split [$($f:tt)*] [$($l:tt)*] [ $token:tt $($rest:tt)* ] ) => {
+		$crate::__impl_twist! { @named-split [$($f)*] [$($l)* $token] [$($rest)*] }
+	};
+	( @named-split [$($f:tt)*] [$($l:tt)*] [] ) => {
+		compile_error!("Missing `|` separator after labels in `twist! -named` macro invocation.")
+	};
+
+	// Remove the `as $name` from each label, and collect the names with their index
+	// ≪ [ <$flag-token>* ] [<x>*], [ <$label-token>* , ] -> [ <(<$label-token>*)>* ] [ <($name, $count)>* ] <$expr-token>* ≫
+	( @named-labels [$($f:tt)*] [$($count:tt)*], [ $label:lifetime as $name:ident : into $type:ty , $($rest:tt)* ] -> [$($l:tt)*] [$($n:tt)*] $($e:tt)* ) => {
+		$crate::__impl_twist! { @named-labels [$($f)*] [$($count)* x], [$($rest)*] -> [$($l)* ($label : into $type)] [$($n)* ($name, [$($count)*])] $($e)* }
+	};
+	( @named-labels [$($f:tt)*] [$($count:tt)*], [ $label:lifetime as $name:ident : $type:ty , $($rest:tt)* ] -> [$($l:tt)*] [$($n:tt)*] $($e:tt)* ) => {
+		$crate::__impl_twist! { @named-labels [$($f)*] [$($count)* x], [$($rest)*] -> [$($l)* ($label : $type)] [$($n)* ($name, [$($count)*])] $($e)* }
+	};
+	( @named-labels [$($f:tt)*] [$($count:tt)*], [ $label:lifetime as $name:ident , $($rest:tt)* ] -> [$($l:tt)*] [$($n:tt)*] $($e:tt)* ) => {
+		$crate::__impl_twist! { @named-labels [$($f)*] [$($count)* x], [$($rest)*] -> [$($l)* ($label)] [$($n)* ($name, [$($count)*])] $($e)* }
+	};
+	( @named-labels [$($f:tt)*] [$($count:tt)*], [ $label:lifetime : into $type:ty , $($rest:tt)* ] -> [$($l:tt)*] [$($n:tt)*] $($e:tt)* ) => {
+		$crate::__impl_twist! { @named-labels [$($f)*] [$($count)* x], [$($rest)*] -> [$($l)* ($label : into $type)] [$($n)*] $($e)* }
+	};
+	( @named-labels [$($f:tt)*] [$($count:tt)*], [ $label:lifetime : $type:ty , $($rest:tt)* ] -> [$($l:tt)*] [$($n:tt)*] $($e:tt)* ) => {
+		$crate::__impl_twist! { @named-labels [$($f)*] [$($count)* x], [$($rest)*] -> [$($l)* ($label : $type)] [$($n)*] $($e)* }
+	};
+	( @named-labels [$($f:tt)*] [$($count:tt)*], [ $label:lifetime , $($rest:tt)* ] -> [$($l:tt)*] [$($n:tt)*] $($e:tt)* ) => {
+		$crate::__impl_twist! { @named-labels [$($f)*] [$($count)* x], [$($rest)*] -> [$($l)* ($label)] [$($n)*] $($e)* }
+	};
+	( @named-labels [$($f:tt)*] [$($count:tt)*], [ , ] -> [$($l:tt)*] [$($n:tt)*] $($e:tt)* ) => {
+		$crate::__impl_twist! { @named-labels [$($f)*] [$($count)*], [] -> [$($l)*] [$($n)*] $($e)* }
+	};
+	// Define `Label` so that it's in scope of the expression
+	( @named-labels [$($f:tt)*] [$($count:tt)*], [] -> [$( ($($l:tt)*) )*] [$( ($name:ident, $c:tt) )*] $($e:tt)* ) => {
+		{
+			#[allow(dead_code)]
+			struct Label;
+			#[allow(dead_code)]
+			impl Label { $( const $name :usize = $crate::__label_index!$c; )* }
+			// Parentheses so that it's parsed as the tail expression of the block
+			$crate::twist!( $($f)* -label $($($l)*),* $($e)* )
+		}
+	};
+	( @named-labels [$($f:tt)*] [$($count:tt)*], [ $($rest:tt)* ] -> [$($l:tt)*] [$($n:tt)*] $($e:tt)* ) => {
+		compile_error!(concat!("Bad label syntax: ", stringify!($($rest)*)))
+	};
+
+	/* For -typed */
+
+	// Collect the flags before `-label`
+	// ≪ [ <$flag-token>* ] [ <$token>* ] ≫
+	( @typed-flags [$($f:tt)*] [ -label $($rest:tt)* ] ) => {
+		$crate::__impl_twist! { @typed-split [$($f)*] [] [$($rest)*] }
+	};
+	( @typed-flags [$($f:tt)*] [ $token:tt $($rest:tt)* ] ) => {
+		$crate::__impl_twist! { @typed-flags [$($f)* $token] [$($rest)*] }
+	};
+	( @typed-flags [$($f:tt)*] [] ) => {
+		compile_error!("Missing `-label` in `twist! -typed` macro invocation")
+	};
+
+	// Separate the labels from the expression by getting everything before `|`
+	// ≪ [ <$flag-token>* ] [ <$label-token>* ] [ <$token>* ] ≫
+	// A well-formed label list is taken in a single step, like in `@label-parse`
+	( @typed-split [$($f:tt)*] [] [ $($label:lifetime $(as $name:ident)? $(: $type:ty)?),+ $(,)? | $($rest:tt)* ] ) => {
+		$crate::__impl_twist! { @typed-split [$($f)*] [$($label $(as $name)? $(: $type)?),*] [| $($rest)*] }
+	};
+	( @typed-split [$($f:tt)*] [$($l:tt)*] [ | $($rest:tt)* ] ) => {
+		// We add an extra comma, so that every label ends with a comma, simplifies parsing
+		$crate::__impl_twist! { @typed-count [$($f)*] [$($l)*] [], [$($l)* ,] -> | $($rest)* }
+	};
+	( @typed-split [$($f:tt)*] [$($l:tt)*] [ - or $($rest:tt)* ] ) => {
+		$crate::__impl_twist! { @typed-count [$($f)*] [$($l)*] [], [$($l)* ,] -> - or $($rest)* }
+	};
+	( @typed-split [$($f:tt)*] [$($l:tt)*] [ $token:tt $($rest:tt)* ] ) => {
+		$crate::__impl_twist! { @typed-split [$($f)*] [$($l)* $token] [$($rest)*] }
+	};
+	( @typed-split [$($f:tt)*] [$($l:tt)*] [] ) => {
+		compile_error!("Missing `|` separator after labels in `twist! -typed` macro invocation.")
+	};
+
+	// Count the labels, like `@named-labels`. The labels are kept as they are for `twist!`
+	// ≪ [ <$flag-token>* ] [ <$label-token>* ] [<x>*], [ <$label-token>* , ] -> <$expr-token>* ≫
+	( @typed-count $f:tt $l:tt [$($count:tt)*], [ $label:lifetime $(as $name:ident)? : into $type:ty , $($rest:tt)* ] -> $($e:tt)* ) => {
+		$crate::__impl_twist! { @typed-count $f $l [$($count)* x], [$($rest)*] -> $($e)* }
+	};
+	( @typed-count $f:tt $l:tt [$($count:tt)*], [ $label:lifetime $(as $name:ident)? : $type:ty , $($rest:tt)* ] -> $($e:tt)* ) => {
+		$crate::__impl_twist! { @typed-count $f $l [$($count)* x], [$($rest)*] -> $($e)* }
+	};
+	( @typed-count $f:tt $l:tt [$($count:tt)*], [ $label:lifetime $(as $name:ident)? , $($rest:tt)* ] -> $($e:tt)* ) => {
+		$crate::__impl_twist! { @typed-count $f $l [$($count)* x], [$($rest)*] -> $($e)* }
+	};
+	( @typed-count $f:tt $l:tt $count:tt, [ , ] -> $($e:tt)* ) => {
+		$crate::__impl_twist! { @typed-count $f $l $count, [] -> $($e)* }
+	};
+	( @typed-count $f:tt $l:tt $count:tt, [] -> $($e:tt)* ) => {
+		$crate::__impl_twist! { @typed-scan ($f $l $count [$($e)*]) [] [$($e)*] }
+	};
+	( @typed-count $f:tt $l:tt $count:tt, [ $($rest:tt)* ] -> $($e:tt)* ) => {
+		compile_error!(concat!("Bad label syntax: ", stringify!($($rest)*)))
+	};
+
+	// Collect the typed labels of the `Looping` constructors, looking inside groups
+	// ≪ ([ <$flag-token>* ] [ <$label-token>* ] [<x>*] [ <$expr-token>* ]) [ <$type>* ] [ <$token>* ] ≫
+	( @typed-scan $args:tt [$($t:tt)*] [ break_at :: < $L:ty > $($rest:tt)* ] ) => {
+		$crate::__impl_twist! { @typed-scan $args [$($t)* ($L)] [$($rest)*] }
+	};
+	( @typed-scan $args:tt [$($t:tt)*] [ break_val_at :: < $L:ty > $($rest:tt)* ] ) => {
+		$crate::__impl_twist! { @typed-scan $args [$($t)* ($L)] [$($rest)*] }
+	};
+	( @typed-scan $args:tt [$($t:tt)*] [ continue_at :: < $L:ty > $($rest:tt)* ] ) => {
+		$crate::__impl_twist! { @typed-scan $args [$($t)* ($L)] [$($rest)*] }
+	};
+	( @typed-scan $args:tt [$($t:tt)*] [ redo_at :: < $L:ty > $($rest:tt)* ] ) => {
+		$crate::__impl_twist! { @typed-scan $args [$($t)* ($L)] [$($rest)*] }
+	};
+	( @typed-scan $args:tt $t:tt [ ( $($inner:tt)* ) $($rest:tt)* ] ) => {
+		$crate::__impl_twist! { @typed-scan $args $t [$($inner)* $($rest)*] }
+	};
+	( @typed-scan $args:tt $t:tt [ [ $($inner:tt)* ] $($rest:tt)* ] ) => {
+		$crate::__impl_twist! { @typed-scan $args $t [$($inner)* $($rest)*] }
+	};
+	( @typed-scan $args:tt $t:tt [ { $($inner:tt)* } $($rest:tt)* ] ) => {
+		$crate::__impl_twist! { @typed-scan $args $t [$($inner)* $($rest)*] }
+	};
+	( @typed-scan $args:tt $t:tt [ $token:tt $($rest:tt)* ] ) => {
+		$crate::__impl_twist! { @typed-scan $args $t [$($rest)*] }
+	};
+	// Check each typed label against the label count, then call `twist!` again without `-typed`
+	( @typed-scan ([$($f:tt)*] [$($l:tt)*] $count:tt [$($e:tt)*]) [$( ($L:ty) )*] [] ) => {
+		{
+			// Not `assert!`, which calls the `panic!` in scope on edition 2018
+			$( const _ :() = if <$L as $crate::TypedLabel>::INDEX >= $crate::__label_index!$count {
+				::core::panic!("A typed label of the `twist! -typed` expression is out of range of its label list")
+			}; )*
+			// Parentheses so that it's parsed as the tail expression of the block
+			$crate::twist!( $($f)* -label $($l)* $($e)* )
+		}
+	};
+
+	/* For -enum */
+
+	// Separate the labels from the expression by getting everything before `|`
+	// ≪ ($mode $name ($bk?) ($bv?)) [ <$label-token>* ] [ <$token>* ] ≫
+	// A well-formed label list is taken in a single step, like in `@label-parse`
+	( @enum-split $flag:tt [] [ $($label:lifetime $(as $var:ident)? $(: $type:ty)?),+ $(,)? | $($rest:tt)* ] ) => {
+		$crate::__impl_twist! { @enum-split $flag [$($label $(as $var)? $(: $type)?),*] [| $($rest)*] }
+	};
+	( @enum-split $flag:tt [$($l:tt)*] [ | $($rest:tt)* ] ) => {
+		// We add an extra comma, so that every label ends with a comma, simplifies parsing
+		$crate::__impl_twist! { @enum-labels $flag [], [$($l)* ,] -> () () $($rest)* }
+	};
+	( @enum-split $flag:tt [$($l:tt)*] [ $token:tt $($rest:tt)* ] ) => {
+		$crate::__impl_twist! { @enum-split $flag [$($l)* $token] [$($rest)*] }
+	};
+	( @enum-split $flag:tt [$($l:tt)*] [] ) => {
+		compile_error!("Missing `|` separator after labels in `twist! -enum` macro invocation.")
+	};
+
+	// Collect the untyped labels, and the typed labels with their variant name
+	// ≪ $flag [<x>*], [ <$label-token>* , ] -> ( <($count, $label)>* ) ( <($count, $variant, $label, $type)>* ) <$expr-token>* ≫
+	( @enum-labels $flag:tt [$($count:tt)*], [ $label:lifetime as $var:ident : into $type:ty , $($rest:tt)* ] -> $bk:tt $bv:tt $($e:tt)* ) => {
+		compile_error!(concat!("`into` can't be used with `twist! -enum`, as the variant already has the label type: ",
+			stringify!($label), " as ", stringify!($var), ": into ", stringify!($type)))
+	};
+	( @enum-labels $flag:tt [$($count:tt)*], [ $label:lifetime as $var:ident : $type:ty , $($rest:tt)* ] -> ($($bk:tt)*) ($($bv:tt)*) $($e:tt)* ) => {
+		$crate::__impl_twist! { @enum-labels $flag [$($count)* x], [$($rest)*] -> ($($bk)*) ($($bv)* ([$($count)*], $var, $label, $type)) $($e)* }
+	};
+	( @enum-labels $flag:tt [$($count:tt)*], [ $label:lifetime , $($rest:tt)* ] -> ($($bk:tt)*) ($($bv:tt)*) $($e:tt)* ) => {
+		$crate::__impl_twist! { @enum-labels $flag [$($count)* x], [$($rest)*] -> ($($bk)* ([$($count)*], $label)) ($($bv)*) $($e)* }
+	};
+	( @enum-labels $flag:tt [$($count:tt)*], [ $label:lifetime : $type:ty , $($rest:tt)* ] -> ($($bk:tt)*) ($($bv:tt)*) $($e:tt)* ) => {
+		compile_error!(concat!("Typed labels of `twist! -enum` need a variant name, eg. `",
+			stringify!($label), " as Name: ", stringify!($type), "`"))
+	};
+	( @enum-labels $flag:tt [$($count:tt)*], [ , ] -> $bk:tt $bv:tt $($e:tt)* ) => {
+		$crate::__impl_twist! { @enum-labels $flag [$($count)*], [] -> $bk $bv $($e)* }
+	};
+	// Parse the right-hand expressions like `@label-expr`
+	( @enum-labels (($mode:tt) $($flag:tt)*) [$($count:tt)*], [] -> $bk:tt $bv:tt $e:expr $(; - finally $fin:block)? $(; - track $tr:ident)? $(; - ret $rt:ty)? ) => {
+		$crate::__impl_twist! { @enum-build (($mode $($tr)?) $($flag)*) [$($count)*] $bk $bv $crate::__impl_twist! { @finally ($e) $($fin)? $(; -ret $rt)? } }
+	};
+	( @enum-labels (($mode:tt) $($flag:tt)*) [$($count:tt)*], [] -> $bk:tt $bv:tt $e:expr =>> $f:expr $(; - finally $fin:block)? $(; - track $tr:ident)? $(; - ret $rt:ty)? ) => {
+		$crate::__impl_twist! { @enum-build (($mode $($tr)?) $($flag)*) [$($count)*] $bk $bv $crate::__impl_twist! { @finally ($crate::__impl_twist! { @judge ($e) (|_| $f) $(-ret $rt)? }) $($fin)? $(; -ret $rt)? } }
+	};
+	( @enum-labels $flag:tt [$($count:tt)*], [] -> $bk:tt $bv:tt $e:expr => $($p:ident)::+ $(::<$($g:ty),*> $(:: $v:ident)?)? { $($fields:tt)* } $(; $($rest:tt)*)? ) => {
+		$crate::__impl_twist! { @enum-labels $flag [$($count)*], [] -> $bk $bv $e =>> $($p)::+ $(::<$($g),*> $(:: $v)?)? { $($fields)* } $(; $($rest)*)? }
+	};
+	( @enum-labels (($mode:tt) $($flag:tt)*) [$($count:tt)*], [] -> $bk:tt $bv:tt $e:expr => $f:expr $(; - finally $fin:block)? $(; - track $tr:ident)? $(; - ret $rt:ty)? ) => {
+		$crate::__impl_twist! { @enum-build (($mode $($tr)?) $($flag)*) [$($count)*] $bk $bv $crate::__impl_twist! { @finally ($crate::__impl_twist! { @judge ($e) ($f) $(-ret $rt)? }) $($fin)? $(; -ret $rt)? } }
+	};
+	( @enum-labels $flag:tt [$($count:tt)*], [] -> $bk:tt $bv:tt $($e:tt)* ) => {
+		compile_error!(concat!("Bad right-hand expression syntax: ", stringify!($($e)*)))
+	};
+	( @enum-labels $flag:tt [$($count:tt)*], [ $($rest:tt)* ] -> $bk:tt $bv:tt $($e:tt)* ) => {
+		compile_error!(concat!("Bad label syntax: ", stringify!($($rest)*)))
+	};
+
+	// Define the enum so that it's in scope of the expression, then break on its variants. `$max`
+	//   is the number of labels
+	( @enum-build ($mode:tt $name:ident ($($ibk:tt)?) ($($ibv:ty)?)) $max:tt
+		( $( ($c:tt, $l:lifetime) )* )
+		( $( ($count:tt, $var:ident, $label:lifetime, $type:ty) )* )
+		$e:expr
+	) => {
+		{
+			$crate::__impl_twist! { @label-dups ($) $(($c, $l))* $(($count, $label))* }
+			#[allow(dead_code)]
+			enum $name { $( Innermost($ibv), )? $( $var($type), )* }
+			match $e {
+				$crate::Looping::Resume(v) => $crate::__impl_twist! { @ok $mode v },
+				$( $crate::Looping::Break { label: ::core::option::Option::None } => { $crate::__unit!($ibk); $crate::__impl_twist! { @track $mode } break; }, )?
+				$( $crate::Looping::Break { label: ::core::option::Option::None } => { $crate::__unit!($ibv); $crate::__impl_twist! { @fail $mode ($crate::TwistError::MissingValue) ($crate::twist_impl::__fail($crate::TearErrorKind::BreakWithoutVal)) } }, )?
+				$crate::Looping::Break { label: ::core::option::Option::Some(l) } => {
+					match l {
+						$( $crate::__label_index!$c => { $crate::__impl_twist! { @track $mode } break $l; }, )*
+						_ => $crate::__impl_twist! { @err $mode ($crate::TwistError::InvalidLabel { index: l, max: $crate::__label_index!$max }) ($crate::twist_impl::__invalid_label(l, $crate::__label_index!$max)) },
+					}
+				},
+				$crate::Looping::Redo { .. } => $crate::twist_impl::__fail($crate::TearErrorKind::RedoWithoutTredo),
+				$crate::Looping::Continue { label } => $crate::__impl_twist! { @continue $mode label $max ( $(($c, $l))* $(($count, $label))* ) },
+				// The variant chooses the loop, so the label is ignored
+				$crate::Looping::BreakVal::<_, $name> { value, .. } => {
+					match value {
+						$( $name::Innermost(v) => { let v :$ibv = v; $crate::__impl_twist! { @track $mode } break v; }, )?
+						$( $name::$var(v) => { $crate::__impl_twist! { @track $mode } break $label v; }, )*
+					}
+				},
+			}
+		}
 	};
 
 	// Apply the box flag onto $bv so we can differentiate when consuming it
-	// ≪ ( ($box) -> <$flag>*) (<$bk>*) (<$bv>*) $expr ≫
-	// → ≪ (<$flag>*)  (<$bk>*) [ (<$bv>*) (<$bx>*) ] $expr ≫
-	( @label-box ( ("unbox") -> $($flag:tt)* ) ($($bk:tt)*) ($($bv:tt)*) $e:expr ) => {
-		twist! { @boxed ($($flag)*) ($($bk)*) [ () ($($bv)*) ] $e }
+	// ≪ ( ($box) -> <$flag>* ($producer)) [<x>*] (<$bk>*) (<$bv>*) $expr ≫
+	// → ≪ [<x>*] ($producer) (<$flag>*)  (<$bk>*) [ (<$bv>*) (<$bx>*) ] $expr ≫
+	// With `-block`, there's no innermost loop
+	( @label-box ( $mode:tt () ("pass") -> () () () $src:tt ) $max:tt ($($bk:tt)*) ($($bv:tt)*) $e:expr ) => {
+		$crate::twist! { @block $mode $max ($($bk)*) ($($bv)*) $e }
+	};
+	( @label-box ( $mode:tt () ("unbox") -> $f0:tt $f1:tt $f2:tt $src:tt ) $max:tt ($($bk:tt)*) ($($bv:tt)*) $e:expr ) => {
+		$crate::twist! { @boxed $mode $max () $src ($f0 $f1 $f2) ($($bk)*) [ () ($($bv)*) ] $e }
+	};
+	// Keep a copy of the arguments for `@fallback`
+	( @label-box ( $mode:tt ($($f:tt)+) ("unbox") -> $f0:tt $f1:tt $f2:tt $src:tt ) $max:tt ($($bk:tt)*) ($($bv:tt)*) $e:expr ) => {
+		$crate::twist! { @boxed $mode $max
+			( ($($f)+) ; $max ($f0 $f1 $f2) ($($bk)*) [ () ($($bv)*) ] )
+			$src ($f0 $f1 $f2) ($($bk)*) [ () ($($bv)*) ] $e }
+	};
+	( @label-box ( $mode:tt () ("pass") -> $f0:tt $f1:tt $f2:tt $src:tt ) $max:tt ($($bk:tt)*) ($($bv:tt)*) $e:expr ) => {
+		$crate::twist! { @boxed $mode $max () $src ($f0 $f1 $f2) ($($bk)*) [ ($($bv)*) () ] $e }
+	};
+
+	// Wrap the resumed value, or fail, depending on the mode
+	// The mode may be followed by the `-track` variable
+	( @ok ("panic" $($tr:ident)?) $v:expr ) => { $v };
+	( @ok ("try" $($tr:ident)?) $v:expr ) => { ::core::result::Result::Ok($v) };
+	( @err ("panic" $($tr:ident)?) ($e:expr) ($panic:expr) ) => { $panic };
+	( @err ("try" $($tr:ident)?) ($e:expr) ($panic:expr) ) => { ::core::result::Result::Err($e) };
+	// Only used for invalid label indices, which the user promised can't happen
+	( @ok ("unchecked" $($tr:ident)?) $v:expr ) => { $v };
+	( @err ("unchecked" $($tr:ident)?) ($e:expr) ($panic:expr) ) => { unsafe { ::core::hint::unreachable_unchecked() } };
+	// The debug check of `@boxed`, that `-try` leaves to `TwistError::InvalidLabel`
+	( @check ("try" $($tr:ident)?) ($s:ident) ($labels:expr) ($producer:expr) ) => { false };
+	( @check $mode:tt ($s:ident) ($labels:expr) ($producer:expr) ) => { $crate::twist_impl::__check_label($s, $labels, $producer) };
+	// For the other errors, that `-unchecked` still checks
+	( @fail ("unchecked" $($tr:ident)?) ($e:expr) ($panic:expr) ) => { $panic };
+	( @fail $mode:tt ($e:expr) ($panic:expr) ) => { $crate::__impl_twist! { @err $mode ($e) ($panic) } };
+
+	// Continue the innermost loop, or the loop of the label index, or fail. All the labels are given,
+	//   after their number
+	// ≪ $mode $label [<x>*] ( <($count, $label)>* ) ≫
+	( @continue $mode:tt $i:ident $max:tt ( $( ($c:tt, $l:lifetime) )* ) ) => {
+		match $i {
+			::core::option::Option::None => continue,
+			$( ::core::option::Option::Some($crate::__label_index!$c) => continue $l, )*
+			::core::option::Option::Some(l) => $crate::__impl_twist! { @err $mode ($crate::TwistError::InvalidLabel { index: l, max: $crate::__label_index!$max })
+				($crate::twist_impl::__invalid_label(l, $crate::__label_index!$max)) },
+		}
+	};
+
+	// Set the `-track` variable, right before breaking. Also used with ("single" $tr?)
+	( @track ($m:tt) ) => {};
+	( @track ($m:tt $tr:ident) ) => { $tr = true; };
+
+	// Fail to compile if the flags break a `for` or `while` loop with a value, or continue with
+	//   the macro call without the hint. Only the leading flags are scanned
+	// ≪ ($kind) [ <$token>* ] -> $macro { <$token>* } ≫
+	( @loop-hint (loop) $scan:tt -> $m:ident { $($t:tt)* } ) => {
+		$crate::$m! { $($t)* }
+	};
+	( @loop-hint (for) [ - val $($rest:tt)* ] -> $($next:tt)* ) => {
+		compile_error!("error[E0571]: `break` with value is invalid in a `for` loop. Remove `-val`, and use Break instead of BreakVal in the `twist!` expression, or change the `-loop for` hint.")
+	};
+	( @loop-hint (while) [ - val $($rest:tt)* ] -> $($next:tt)* ) => {
+		compile_error!("error[E0571]: `break` with value is invalid in a `while` loop. Remove `-val`, and use Break instead of BreakVal in the `twist!` expression, or change the `-loop while` hint.")
+	};
+	( @loop-hint $kind:tt [ - box $($rest:tt)* ] -> $($next:tt)* ) => {
+		$crate::__impl_twist! { @loop-hint $kind [$($rest)*] -> $($next)* }
 	};
-	( @label-box ( ("pass") -> $($flag:tt)* ) ($($bk:tt)*) ($($bv:tt)*) $e:expr ) => {
-		twist! { @boxed ($($flag)*) ($($bk)*) [ ($($bv)*) () ] $e }
+	( @loop-hint $kind:tt [ - try $($rest:tt)* ] -> $($next:tt)* ) => {
+		$crate::__impl_twist! { @loop-hint $kind [$($rest)*] -> $($next)* }
+	};
+	( @loop-hint $kind:tt [ - named $($rest:tt)* ] -> $($next:tt)* ) => {
+		$crate::__impl_twist! { @loop-hint $kind [$($rest)*] -> $($next)* }
+	};
+	( @loop-hint $kind:tt [ - typed $($rest:tt)* ] -> $($next:tt)* ) => {
+		$crate::__impl_twist! { @loop-hint $kind [$($rest)*] -> $($next)* }
+	};
+	( @loop-hint $kind:tt [ - enum $name:tt $($rest:tt)* ] -> $($next:tt)* ) => {
+		$crate::__impl_twist! { @loop-hint $kind [$($rest)*] -> $($next)* }
+	};
+	( @loop-hint $kind:tt [ - finally $fin:tt $($rest:tt)* ] -> $($next:tt)* ) => {
+		$crate::__impl_twist! { @loop-hint $kind [$($rest)*] -> $($next)* }
+	};
+	( @loop-hint $kind:tt [ - track $tr:tt $($rest:tt)* ] -> $($next:tt)* ) => {
+		$crate::__impl_twist! { @loop-hint $kind [$($rest)*] -> $($next)* }
+	};
+	( @loop-hint (for) $scan:tt -> $m:ident { $($t:tt)* } ) => {
+		$crate::$m! { $($t)* }
+	};
+	( @loop-hint (while) $scan:tt -> $m:ident { $($t:tt)* } ) => {
+		$crate::$m! { $($t)* }
+	};
+	( @loop-hint ($kind:tt) $($whatever:tt)* ) => {
+		compile_error!(concat!("Expected `for`, `while` or `loop` after `-loop`, got: ", stringify!($kind)))
+	};
+
+	// Fail on a label index in the forms that only handle a single loop
+	( @single-label () $l:ident ) => {
+		$crate::twist_impl::__single_label(::core::option::Option::None, $l)
+	};
+	( @single-label ($label:lifetime) $l:ident ) => {
+		$crate::twist_impl::__single_label(::core::option::Option::Some(stringify!($label)), $l)
+	};
+
+	// Convert the break value of a label marked with `into`
+	( @into () ($type:ty) $v:ident ) => { $v };
+	( @into (into) ($type:ty) $v:ident ) => { ::core::convert::Into::<$type>::into($v) };
+
+	// Replace the box that failed to downcast with the `-or` fallback, or fail
+	// A failed downcast still panics with `-unchecked`
+	( @or ("unchecked" $($tr:ident)?) () ($b:ident) ($e:expr) ($panic:expr) ) => {
+		$crate::__impl_twist! { @or ("panic") () ($b) ($e) ($panic) }
+	};
+	( @or $mode:tt () ($b:ident) ($e:expr) ($panic:expr) ) => {
+		{
+			let _ = $b;
+			$crate::__impl_twist! { @err $mode ($e) ($panic) }
+		}
+	};
+	( @or $mode:tt ( ($($f:tt)+) ; $($args:tt)* ) ($b:ident) ($e:expr) ($panic:expr) ) => {
+		$crate::__impl_twist! { @fallback $mode $($args)* ($crate::__apply($b, $($f)+)) }
+	};
+
+	// Same as `twist! @boxed`, but without breakvals. The fallback can't break with a value,
+	// as it would need to be boxed again
+	( @fallback $mode:tt $max:tt
+		( ($($bk:tt)?) ($($bv:ty)?) ($($bx:ty)?) )
+		( $( ($c:tt, $l:lifetime) )* )
+		[ ($( ($count:tt,  $label:lifetime,  $type:ty)  )*)
+		  ($( ($bcount:tt, $blabel:lifetime, $btype:ty) )*) ]
+		$e:expr
+	) => {
+		match $e {
+			// The Resume arm is always there, so we check the labels in it
+			$crate::Looping::Resume(v) => {
+				$crate::__impl_twist! { @label-dups ($) $(($c, $l))* $(($count, $label))* $(($bcount, $blabel))* }
+				$crate::__impl_twist! { @ok $mode v }
+			},
+			$( $crate::Looping::Break { label: ::core::option::Option::None } => { $crate::__unit!($bk); $crate::__impl_twist! { @track $mode } break; }, )?
+			$( $crate::Looping::Break { label: ::core::option::Option::None } => { $crate::__unit!($bv); $crate::__impl_twist! { @fail $mode ($crate::TwistError::MissingValue) ($crate::twist_impl::__fail($crate::TearErrorKind::BreakWithoutVal)) } }, )?
+			$( $crate::Looping::Break { label: ::core::option::Option::None } => { $crate::__unit!($bx); $crate::__impl_twist! { @fail $mode ($crate::TwistError::MissingValue) ($crate::twist_impl::__fail($crate::TearErrorKind::BreakWithoutVal)) } }, )?
+			$crate::Looping::Break { label: ::core::option::Option::Some(l) } => {
+				match l {
+					$( $crate::__label_index!$c => { $crate::__impl_twist! { @track $mode } break $l; }, )*
+					_ => $crate::__impl_twist! { @err $mode ($crate::TwistError::InvalidLabel { index: l, max: $crate::__label_index!$max }) ($crate::twist_impl::__invalid_label(l, $crate::__label_index!$max)) },
+				}
+			},
+			$crate::Looping::Redo { .. } => $crate::twist_impl::__fail($crate::TearErrorKind::RedoWithoutTredo),
+			$crate::Looping::Continue { label } => $crate::__impl_twist! { @continue $mode label $max ( $(($c, $l))* $(($count, $label))* $(($bcount, $blabel))* ) },
+			$crate::Looping::BreakVal::<_, $crate::BreakValError> { value, .. } => match value {},
+		}
 	};
 }
 
@@ -218,7 +1783,7 @@ macro_rules! __impl_twist {
 The general syntax is the following:
 
 ```text
-// With $e an expression of type `Looping`
+// With $e an expression of type `Looping`, or that implements `IntoLooping`
 twist! { [-val] $e }
 twist! { [-val] -with $label | $e }
 twist! { [-box] [-val $type,] -label <$label [: $type]>,* | $e }
@@ -227,6 +1792,64 @@ twist! { [-box] [-val $type,] -label <$label [: $type]>,* | $e }
 twist! { [-val] $e => $f }
 twist! { [-val] -with $label | $e => $f }
 twist! { [-box] [-val $type,] -label <$label [: $type]>,* | $e => $f }
+
+// Same as `$e => |_| $l`: $l is a Looping expression only evaluated if $e is Bad
+twist! { [-val] $e =>> $l }
+twist! { [-val] -with $label | $e =>> $l }
+twist! { [-box] [-val $type,] -label <$label [: $type]>,* | $e =>> $l }
+// `=>` also works if $l is a struct literal (eg. `Looping::Break { label: None }`)
+
+// Same as -label with untyped labels and without -val: nothing can break with a value
+twist! { [-try] -with [<$label>,*] | $e }
+
+// Same as -val -with and -label, but the break value is converted with `Into` for the loops
+// marked with `into`. Not with -box or -enum
+twist! { -val -with $label into | $e }
+twist! { [-val $type,] -label <$label [: [into] $type]>,* | $e }
+
+// Same as -label, but with named label indices
+twist! { -named [-box] [-val $type,] -label <$label [as $name] [: $type]>,* | $e }
+
+// Same as -label, but checks the typed labels of $e at compile time (f=const-labels)
+twist! { -typed [<$flag>*] -label <$label [: $type]>,* | $e }
+
+// Same as -label, but evaluates to a Result instead of panicking
+twist! { -try [-box] [-val $type,] -label <$label [: $type]>,* | $e }
+
+// Same as -label, -with [labels] and -enum, but an invalid label index is undefined behavior
+twist! { -unchecked [-box] [-val $type,] -label <$label [: $type]>,* | $e }
+
+// Same as -box -label, but breaks with the variants of the generated enum $name. The -val type
+// is the `Innermost` variant, and the label of `BreakVal` is ignored
+twist! { [-try] -enum $name [-val $type,] -label <$label [as $variant: $type]>,* | $e }
+
+// Same as -box -label, but $fallback replaces the box when it fails to downcast
+twist! { -box [-val $type,] -label <$label [: $type]>,* -or $fallback | $e }
+
+// With $e breaking the innermost loop with a `Box<dyn Any>`, that we unbox to $type
+twist! { -box -val $type, $e }
+twist! { -box -val $type, $e => $f }
+twist! { -box -val $type, -or $fallback | $e }
+
+// (f=control-flow) Same as `twist! { [-val] $e }`, but $e is a ControlFlow
+twist! { [-val] -cf $e }
+
+// (f=block-labels) Same as -with and -label, but the labels are labeled blocks
+twist! { -block [-val $type,] -with $label | $e }
+twist! { [-try] -block -label <$label [: $type]>,* | $e }
+
+// Any of the previous ones, but $block runs before breaking or continuing
+twist! { -finally $block … }
+
+// Any of the previous ones, but sets the bool variable $var to true when breaking
+twist! { -track $var [|] … }
+
+// Any of the previous ones, but $e (or $f) gives a `ValRet<Looping, $type>`, and `Ret(v)` returns
+// `From::from(v)` from the function, like `terror!`
+twist! { -ret $type, … }
+
+// Any of the previous ones, but fails to compile if it breaks a `for` or `while` loop with a value
+twist! { -loop <for|while|loop> … }
 ```
 
 ## Use cases
@@ -238,6 +1861,25 @@ twist! { $e }      // Usual case
 twist! { -val $e } // If you're breaking with a value (`loop` loop)
 ```
 
+If you have a `ControlFlow`, eg. from `Iterator::try_for_each`, with the "control-flow" feature:
+
+```text
+twist! { -cf $e }      // ControlFlow<(), T> breaks the current loop
+twist! { -val -cf $e } // ControlFlow<B, T> breaks the current loop with a B value
+```
+
+`break` with a value is invalid in `for` and `while` loops (E0571). `twist!` can't see which loop
+it's in, so this error points inside its expansion. Give the kind of loop that `twist!` breaks with
+`-loop`, so that `-val` on a `for` or `while` loop fails with a clearer message:
+
+```text
+twist! { -loop for $e }       // Fine
+twist! { -loop for -val $e }  // Compile error: `for` loops can't break with a value
+```
+
+It's the innermost loop, or the `-with` loop. `next_if!` never breaks with a value, and
+`last_if! { -loop for, … }` passes the hint to `twist!`.
+
 If you're breaking a labeled loop:
 
 ```text
@@ -245,6 +1887,30 @@ twist! { -with 'label | $e }      // Normal break from the labeled loop
 twist! { -val -with 'label | $e } // If you're breaking the labeled loop with a value
 ```
 
+If you're leaving a labeled block (`'b: { … }`), with the "block-labels" feature:
+
+```text
+twist! { -block -with 'b | $e }              // Break the block
+twist! { -block -val i32, -with 'b | $e }    // Break the block with a value
+twist! { -block -label 'b: i32, 'c | $e }    // Like -label, for labeled blocks
+```
+
+Blocks can't be continued, so `twist! -block` panics on `Continue`. The `-label` form also panics
+on signals without a label, as there's no innermost loop.
+
+If you're breaking or continuing one of several labeled loops, without values, and the label index
+is only known at runtime:
+
+```text
+twist! { -with ['a, 'b] | $e } // Same as `-label 'a, 'b`, but it doesn't need the break value type
+```
+
+If you're breaking the current loop with a boxed value, eg. from code shared with `-box -label`:
+
+```text
+twist! { -box -val i32, $e } // Unbox the value to an i32 and break with it
+```
+
 If you're breaking from multiple loops:
 
 ```text
@@ -260,6 +1926,15 @@ twist! { -label 'a: i32, 'b, 'c: i32 | $e }
 twist! { -val i32, -label 'a:i32, 'b | $e }
 ```
 
+If the labeled loops break with a type that the break value converts into:
+
+```text
+twist! { -val -with 'a into | $e }            // Break 'a with `Into::into(v)`
+twist! { -label 'a: into i64, 'b: u8 | $e }   // Break 'a with `Into::<i64>::into(v)` and 'b with v
+```
+
+`into` comes before the type, as nothing but a separator can follow a type in a macro.
+
 If you're breaking from multiple loops with multiple types by using `Box<dyn Any>` as the value type:
 
 ```text
@@ -269,6 +1944,29 @@ twist! { -box -label 'a: i32, 'b: String | $e }
 twist! { -box -val i32, -label 'a, 'b: String | $e }
 ```
 
+If the label indices are always valid, and the checks are too slow in a hot loop:
+
+```text
+// Safety: $e only uses the label indices 0 and 1
+twist! { -unchecked -label 'a, 'b | $e }
+```
+
+The arms that panic on an invalid label index call `core::hint::unreachable_unchecked` instead,
+so an invalid index is **undefined behavior**. It works with `-val`, `-box`, `-with [labels]`,
+`-enum` and `-block -label`. A failed downcast with `-box`, or a missing break value, still
+panics. The default mode is unchanged, and `benches/unchecked.rs` compares both.
+
+In debug builds, `-label` and `-box` (with or without `-unchecked`) check the label index before
+matching, and panic with the expression that produced the signal. The check isn't in release
+builds, and `-try` returns the error instead.
+
+If you want to refer to the labels by name instead of by index:
+
+```text
+// `Label::A` is 0 and `Label::B` is 1
+twist! { -named -label 'a as A: i32, 'b as B | $e }
+```
+
 If you want to **extract a value** (eg. `Result` or `Option`) and break/continue otherwise:
 
 ```text
@@ -279,22 +1977,56 @@ twist! { $e => $f }
 with $e your value (that implements Judge) and $f the mapping function from the Bad type
 to a `Looping` value.
 
+If the loop body has cleanup to do before leaving the iteration, eg. rolling back a transaction:
+
+```text
+twist! { -finally { tx.rollback(); } $e => $f }
+// Or `-finally $block` in front of any of the previous ones
+```
+
+If you need to return from the function in the middle of the loops, eg. on an error:
+
+```text
+twist! { -ret io::Error, -label 'a | $e }
+// Or `-ret $type,` in front of any of the previous ones
+```
+
+If you need to know after the loop whether it was broken, like Python's `for … else`:
+
+```text
+let mut broke = false;
+for v in values {
+    twist! { -track broke | $e => $f }
+}
+if !broke { /* The loop ran to completion */ }
+```
+
+Continuing and resuming leave the variable untouched.
+
 # Description
 
 `twist!` takes an expression of `Looping` type, and `break`s, `continue`s or resume the loop
 execution based on the `Looping` variant. There are various flags that control which loop are
 concerned, and what value type to break with (for `loop` loops).
 
+Without `-label` or `-with [labels]`, `twist!` handles a single loop: the innermost one, or the
+`-with` one. The signals for it have no label index, and it panics on those that have one, as they
+are meant for another loop.
+
 Normally, you can only break with a single type because it is the `B` parameter for
 `Looping::<_ B>`. But if we use `Box<dyn Any>`, a trait object, and then we downcast to the
 correct concrete type, we can break with multiple types.
 
 The `-box` option tells `twist!` to expect a break type of `Box<dyn Any>` and to attempt to
-downcast to the type specified by `-val` or `-label` before breaking the loop.
+downcast to the type specified by `-val` or `-label` before breaking the loop. `Box<dyn Any + Send>`
+and `Box<dyn Any + Send + Sync>` from `anybox!(send $e)` and `anybox!(send_sync $e)` work too.
 
 The mapping syntax `$e => $f` is used to simplify "good value" handling in loops. `$e` implements
 Judge, and `$f` maps the bad type of `$e` to a `Looping` value.
 
+The signal can also be any type that implements [`IntoLooping`], eg. the actions of a state machine.
+`twist!` converts it with `into_looping` first, whether it's `$e` or what `$f` returns.
+
 For example, you generally want to skip the current loop iteration if you get an `Err(_)`
 from a function call. To do so, you would either use `if let` and
 have the happy path indented in the `if let` body, or you could add the following match
@@ -322,6 +2054,30 @@ let wanted_value = twist! { try_get_value() => |_| next!() };
 # }
 ```
 
+And since we don't use the error value, to this:
+
+```
+# use tear::extra::*;
+# fn try_get_value () -> Result<i32, ()> { Ok(1) }
+# loop {
+let wanted_value = twist! { try_get_value() =>> next!() };
+# break;
+# }
+```
+
+The right-hand side of `=>` is always a mapping function, even when it's a macro call, so
+`next!()` needs `=>>`. A struct literal like `Looping::Break { label: None }` can't be a function,
+so it works after `=>` too, and is only built if the value is Bad:
+
+```
+# use tear::extra::*;
+# fn try_get_value () -> Result<i32, ()> { Ok(1) }
+# loop {
+let wanted_value = twist! { try_get_value() => Looping::Break { label: None } };
+# break;
+# }
+```
+
 ## Errors
 
 ### Compile failure
@@ -330,10 +2086,55 @@ A common error (at least for me) is to forget that you need to specify if the in
 breaks with a value or not, even if you don't do anything with it.
 Similarly, you always need to specify the types of the loop labels.
 
+When the expression is a `breakval!` call or a `BreakVal { … }` literal that breaks the innermost
+loop, `twist!` tells you to add `-val` (or `-val $type,` with `-label`). Otherwise, the type
+mismatch mentions `Error0571__Tried_to_break_with_value_using_twist_without_val_flag…`,
+which is the same advice.
+
+`twist!` expands to `break` and `continue`, so it only works inside a loop, like `next_if!`
+and `last_if!`. Outside of one, rustc says ``"`break` outside of a loop"`` or
+``"`continue` outside of a loop"``, and points at the macro call. A macro can't tell whether it is
+in a loop, so we can't give a better message.
+
 ### Panics
 This **will panic if** you use the wrong loop label index; if you try to break a
-non-`loop` loop with a value; or if you try to break a `loop`-loop that expects a value,
-without a value
+non-`loop` loop with a value; if you try to break a `loop`-loop that expects a value,
+without a value; or on `Redo`, which needs [`tredo_loop!`] to run the loop body again
+
+With `-box`, you can replace the break value that failed to downcast with `-or $fallback`, instead of
+panicking. `$fallback` takes the `Box<dyn Any>` and returns a `Looping` that can't break with a value,
+which is then handled normally. If `$fallback` is a closure, its body ends at the next `|`.
+
+```
+# use tear::{twist, Looping, anybox, next};
+let mut skipped = 0;
+let x = 'a: loop {
+    for v in vec![anybox!("one"), anybox!(2)] {
+        twist! { -box -label 'a: i32 -or |_b| { skipped += 1; next!() } |
+            Looping::BreakVal::<(), _> { label: Some(0), value: v }
+        }
+    }
+};
+assert_eq![ x, 2 ];
+assert_eq![ skipped, 1 ];
+```
+
+With `-try`, the `-label` forms evaluate to `Ok(value)` on Resume, and to
+`Err(TwistError::InvalidLabel { index, max })` instead of panicking on a wrong label index, where
+`max` is the number of labels. They evaluate to `Err(TwistError::MissingValue)` when a `Break`
+without a value targets the innermost loop that breaks with a value. With `-box`, they also
+evaluate to `Err(TwistError::BadType { expected })` when the downcast fails.
+
+```
+# use tear::{twist, Looping, TwistError};
+'a: loop {
+    loop {
+        let r = twist! { -try -label 'a | Looping::Break::<i32, ()> { label: Some(3) } };
+        assert_eq![ r, Err(TwistError::InvalidLabel { index: 3, max: 1 }) ];
+        break 'a;
+    }
+}
+```
 
 # Examples
 
@@ -375,23 +2176,134 @@ refers to `'a: String`. The second loop also breaks with a value type of `i32`,
 
 ```
 # use tear::{twist, Looping};
-use tear::anybox;
-
 let x = 'a: loop {
     let _ = loop {
         twist! { -box -val i32, -label 'a: String |
-            Looping::BreakVal { label: Some(0), value: anybox!("a".to_string()) }
+            Looping::breakval_boxed(Some(0), "a".to_string())
         }
     };
 };
 assert_eq![ x, "a".to_string() ];
 ```
 
-See more barebones examples for breaking multiple loops in `test/label.rs`.
+Breaking multiple loops with different types with `-enum`, without allocating. It defines an enum
+with the given name in the scope of the expression, with an `Innermost` variant for the `-val` type
+and a variant for each typed label, named with `as`. The `Innermost` name is fixed, so a label
+variant can't use it with `-val`. The variant chooses the loop to break, so the label of `BreakVal`
+is ignored, even when it's `Some` of another label, and breaking with the wrong type fails to compile. As the enum is
+defined inside the macro, the break values must be created in the expression, and use the [`stop!`],
+[`skip!`] and [`go!`] shortcuts instead of [`last!`], [`next!`] and [`resume!`].
+
+```
+# use tear::{twist, Looping};
+let x = 'a: loop {
+    let _ = loop {
+        twist! { -enum Sig -val i32, -label 'a as A: String |
+            Looping::BreakVal::<(), _> { label: None, value: Sig::A("a".to_string()) }
+        }
+    };
+};
+assert_eq![ x, "a".to_string() ];
+```
+
+Naming the labels with `-named`. It defines a `Label` type in the scope of the expression, with
+an associated constant for each named label. Removing a label from the list then fails to compile
+instead of breaking the wrong loop.
+
+```
+# use tear::{twist, Looping};
+let x = 'a: loop {
+    'b: loop {
+        twist! { -named -label 'a as A: i32, 'b as B |
+            Looping::BreakVal { label: Some(Label::A), value: 3 }
+        }
+    }
+};
+assert_eq![ x, 3 ];
+```
+
+Checking typed labels with `-typed` (f=const-labels). The label indices of `Looping::break_at` and
+the other typed constructors are checked against the label list at compile time, even through
+aliases defined with `labels!`.
+
+```
+# #[cfg(feature = "const-labels")] {
+# use tear::{twist, labels, Looping};
+labels! { Outer, Inner }
+
+let x = 'a: loop {
+    'b: loop {
+        twist! { -typed -label 'a: i32, 'b | Looping::<(), _>::break_val_at::<Outer>(3) }
+    }
+};
+assert_eq![ x, 3 ];
+# }
+```
+
+See more barebones examples for breaking multiple loops in `test/label.rs` and `test/named.rs`.
+
+Cleaning up with `-finally`. The block runs once, after `$e` and `$f` are evaluated, when the
+`Looping` value is anything but `Resume`. Then `twist!` breaks or continues as usual. It also runs
+when `twist!` then fails because of an invalid label index or a failed downcast.
+
+```
+# use tear::{twist, next, last};
+let mut log = Vec::new();
+let mut sum = 0;
+'a: for v in vec![Ok(1), Err("skip"), Ok(2), Err("stop"), Ok(3)] {
+    log.push("open");
+    let v = twist! { -finally { log.push("close"); } -label 'a |
+        v => |e| if e == "skip" { next!(0) } else { last!(0) }
+    };
+    sum += v;
+    log.push("close");
+}
+assert_eq![ sum, 3 ];
+assert_eq![ log, ["open", "close", "open", "close", "open", "close", "open", "close"] ];
+```
+
+Returning from the function with `-ret`. The expression gives a `ValRet`: `Val` holds the
+`Looping` signal, and `Ret(v)` returns `From::from(v)`, converted to the return type with
+`Judge::from_bad` like `terror!` does. The `-finally` block runs before returning.
+
+```
+# use tear::{twist, go, breakval};
+# use tear::ValRet::{Val, Ret};
+fn find (grid :&[&[i32]]) -> Result<i32, String> {
+    let x = 'a: loop {
+        for row in grid {
+            for &v in row.iter() {
+                twist! { -ret &str, -label 'a :i32 | match v {
+                    0 => Val(go!(())),
+                    v if v < 0 => Ret("negative"),
+                    v => Val(breakval!(0, v)),
+                } }
+            }
+        }
+        break -1;
+    };
+    Ok(x)
+}
+assert_eq![ find(&[&[0, 0], &[0, 3]]), Ok(3) ];
+assert_eq![ find(&[&[0, -1], &[2]]), Err("negative".to_string()) ];
+```
+
+Knowing whether the loop was broken with `-track`. The variable is set right before `twist!` breaks
+any loop, after the `-finally` block.
+
+```
+# use tear::{twist, last, resume};
+let mut broke = false;
+for v in &[1, 2, 3] {
+    twist! { -track broke | if *v > 5 { last!() } else { resume!(()) } }
+}
+assert![ !broke ];
+```
 
 # See also
 
-- The [`last!`], [`next!`] and [`resume!`] utility macros.
+- The [`last!`], [`next!`], [`resume!`] and [`breakval!`] utility macros, and [`stop!`], [`skip!`]
+  and [`go!`] when breaking with a value
 - The [`anybox!`] macro when the expression is of type `Box<dyn Any>` and we unbox it
 
 # Developer docs
@@ -416,85 +2328,307 @@ because the types are different. It should then display the full name of `BreakV
 */
 #[macro_export]
 macro_rules! twist {
+	// Check the flags against the `-loop` hint, then drop it
+	( -loop $kind:tt $($tokens:tt)* ) => {
+		$crate::__impl_twist! { @loop-hint ($kind) [$($tokens)*] -> twist { $($tokens)* } }
+	};
+
+	/* Move the `-finally` block and the `-track` variable after the expression, where the
+	   right-hand side is parsed, in that order */
+	( -finally $fin:block $($tokens:tt)* ) => {
+		$crate::twist! { $($tokens)* ; -finally $fin }
+	};
+	( -track $tr:ident -finally $fin:block $($tokens:tt)* ) => {
+		$crate::twist! { -finally $fin -track $tr $($tokens)* }
+	};
+	( -track $tr:ident | $($tokens:tt)* ) => {
+		$crate::twist! { -track $tr $($tokens)* }
+	};
+	( -track $tr:ident $($tokens:tt)* ) => {
+		$crate::twist! { $($tokens)* ; -track $tr }
+	};
+	// `-ret` goes last, so `-finally` and `-track` are moved first when they follow it
+	( -ret $rt:ty, -track $tr:ident -finally $fin:block $($tokens:tt)* ) => {
+		$crate::twist! { -finally $fin -track $tr -ret $rt, $($tokens)* }
+	};
+	( -ret $rt:ty, -finally $fin:block $($tokens:tt)* ) => {
+		$crate::twist! { -finally $fin -ret $rt, $($tokens)* }
+	};
+	( -ret $rt:ty, -track $tr:ident $($tokens:tt)* ) => {
+		$crate::twist! { -track $tr -ret $rt, $($tokens)* }
+	};
+	( -ret $rt:ty, | $($tokens:tt)* ) => {
+		$crate::twist! { $($tokens)* ; -ret $rt }
+	};
+	( -ret $rt:ty, $($tokens:tt)* ) => {
+		$crate::twist! { $($tokens)* ; -ret $rt }
+	};
+
+	/* When we convert a ControlFlow first. Before `-val $type:ty`, which fails on `-cf` */
+
+	// Handle a ControlFlow object, by converting it to Looping
+	( -val -cf $e:expr $(; - finally $fin:block)? $(; - track $tr:ident)? ) => {
+		$crate::__with_control_flow! { "`twist! -cf`" $crate::__impl_twist! { @parse-map [] [("breakval") ()] (<$crate::Looping<_, _> as ::core::convert::From<_>>::from($e) $(; - finally $fin)? $(; - track $tr)?) } }
+	};
+	( -cf $e:expr $(; - finally $fin:block)? $(; - track $tr:ident)? ) => {
+		$crate::__with_control_flow! { "`twist! -cf`" $crate::__impl_twist! { @parse-map [("break") ()] [] ($crate::Looping::from_control_flow($e) $(; - finally $fin)? $(; - track $tr)?) } }
+	};
+
+	/* When we break labeled blocks */
+
+	// Handle a Looping object that breaks a labeled block, which can't be continued
+	( -block -with $l:lifetime | $($tokens:tt)* ) => {
+		$crate::__with_block_labels! { "`twist! -block`" $crate::__impl_twist! { @innermost-scan ("single") [$($tokens)*] ->
+			@parse-map [("block") ($l)] [] ($($tokens)*) } }
+	};
+	( -block -val $type:ty, -with $l:lifetime | $($tokens:tt)* ) => {
+		$crate::__with_block_labels! { "`twist! -block`" $crate::__impl_twist! {
+			@parse-map [] [("block") ($l) ($type)] ($($tokens)*) } }
+	};
+	// Same as `-label`, but every label is a block, and there's no innermost loop
+	( -block -label $($tokens:tt)* ) => {
+		$crate::__with_block_labels! { "`twist! -block`" $crate::__impl_twist! {
+			@label-parse (("panic") () ("pass") -> () () ()) [$($tokens)*] -> } }
+	};
+	( -try -block -label $($tokens:tt)* ) => {
+		$crate::__with_block_labels! { "`twist! -block`" $crate::__impl_twist! {
+			@label-parse (("try") () ("pass") -> () () ()) [$($tokens)*] -> } }
+	};
+	( -unchecked -block -label $($tokens:tt)* ) => {
+		$crate::__with_block_labels! { "`twist! -block`" $crate::__impl_twist! {
+			@label-parse (("unchecked") () ("pass") -> () () ()) [$($tokens)*] -> } }
+	};
+
 	/* When we break from multiple loops */
+
+	// Name the label indices. Strips the names then calls `twist!` again
+	( -named $($tokens:tt)* ) => {
+		$crate::__impl_twist! { @named-flags [] [$($tokens)*] }
+	};
+	// Check the typed labels of the expression at compile time, then calls `twist!` again
+	( -typed $($tokens:tt)* ) => {
+		$crate::__with_const_labels! { "`twist! -typed`" $crate::__impl_twist! { @typed-flags [] [$($tokens)*] } }
+	};
 	
 	// Handle a Looping object that can break with labels, and break with a value
 	( -label $($tokens:tt)* ) => {
-		$crate::__impl_twist! { @label-parse (("pass") -> ("break") () ()) [$($tokens)*] -> }
+		$crate::__impl_twist! { @label-parse (("panic") () ("pass") -> ("break") () ()) [$($tokens)*] -> }
 	};
 	// The innermost loop breaks with a value
 	( -val $type:ty, -label $($tokens:tt)* ) => {
-		$crate::__impl_twist! { @label-parse (("pass") -> () ($type) ()) [$($tokens)*] -> }
+		$crate::__impl_twist! { @label-parse (("panic") () ("pass") -> () ($type) ()) [$($tokens)*] -> }
 	};
 	// Same thing, but we unbox the breakval
 	( -box -label $($tokens:tt)* ) => {
-		$crate::__impl_twist! { @label-parse (("unbox") -> ("break") () ()) [$($tokens)*] -> }
+		$crate::__with_alloc! { "`twist! -box`" $crate::__impl_twist! { @label-parse (("panic") () ("unbox") -> ("break") () ()) [$($tokens)*] -> } }
 	};
 	( -box -val $type:ty, -label $($tokens:tt)* ) => {
-		$crate::__impl_twist! { @label-parse (("unbox") -> () () ($type)) [$($tokens)*] -> }
+		$crate::__with_alloc! { "`twist! -box`" $crate::__impl_twist! { @label-parse (("panic") () ("unbox") -> () () ($type)) [$($tokens)*] -> } }
+	};
+
+	// Same as the 4 previous ones, but evaluate to a Result instead of panicking
+	( -try -label $($tokens:tt)* ) => {
+		$crate::__impl_twist! { @label-parse (("try") () ("pass") -> ("break") () ()) [$($tokens)*] -> }
+	};
+	( -try -val $type:ty, -label $($tokens:tt)* ) => {
+		$crate::__impl_twist! { @label-parse (("try") () ("pass") -> () ($type) ()) [$($tokens)*] -> }
+	};
+	( -try -box -label $($tokens:tt)* ) => {
+		$crate::__with_alloc! { "`twist! -box`" $crate::__impl_twist! { @label-parse (("try") () ("unbox") -> ("break") () ()) [$($tokens)*] -> } }
+	};
+	( -try -box -val $type:ty, -label $($tokens:tt)* ) => {
+		$crate::__with_alloc! { "`twist! -box`" $crate::__impl_twist! { @label-parse (("try") () ("unbox") -> () () ($type)) [$($tokens)*] -> } }
+	};
+
+	// Same as the 4 first ones, but an invalid label index is undefined behavior instead of a panic
+	( -unchecked -label $($tokens:tt)* ) => {
+		$crate::__impl_twist! { @label-parse (("unchecked") () ("pass") -> ("break") () ()) [$($tokens)*] -> }
+	};
+	( -unchecked -val $type:ty, -label $($tokens:tt)* ) => {
+		$crate::__impl_twist! { @label-parse (("unchecked") () ("pass") -> () ($type) ()) [$($tokens)*] -> }
+	};
+	( -unchecked -box -label $($tokens:tt)* ) => {
+		$crate::__with_alloc! { "`twist! -box`" $crate::__impl_twist! { @label-parse (("unchecked") () ("unbox") -> ("break") () ()) [$($tokens)*] -> } }
+	};
+	( -unchecked -box -val $type:ty, -label $($tokens:tt)* ) => {
+		$crate::__with_alloc! { "`twist! -box`" $crate::__impl_twist! { @label-parse (("unchecked") () ("unbox") -> () () ($type)) [$($tokens)*] -> } }
+	};
+
+	// Break with the variants of a generated enum instead of a `Box<dyn Any>`
+	( -enum $name:ident -label $($tokens:tt)* ) => {
+		$crate::__impl_twist! { @enum-split (("panic") $name ("break") ()) [] [$($tokens)*] }
+	};
+	( -enum $name:ident -val $type:ty, -label $($tokens:tt)* ) => {
+		$crate::__impl_twist! { @enum-split (("panic") $name () ($type)) [] [$($tokens)*] }
+	};
+	( -try -enum $name:ident -label $($tokens:tt)* ) => {
+		$crate::__impl_twist! { @enum-split (("try") $name ("break") ()) [] [$($tokens)*] }
+	};
+	( -try -enum $name:ident -val $type:ty, -label $($tokens:tt)* ) => {
+		$crate::__impl_twist! { @enum-split (("try") $name () ($type)) [] [$($tokens)*] }
+	};
+	( -unchecked -enum $name:ident -label $($tokens:tt)* ) => {
+		$crate::__impl_twist! { @enum-split (("unchecked") $name ("break") ()) [] [$($tokens)*] }
+	};
+	( -unchecked -enum $name:ident -val $type:ty, -label $($tokens:tt)* ) => {
+		$crate::__impl_twist! { @enum-split (("unchecked") $name () ($type)) [] [$($tokens)*] }
 	};
 
 	// Generic implementation for when we handle loop labels
 	// We handle Break and BreakVal and boxed Breakval for the innermost loop (3 cases)
-	// Syntax: ($($flags:tt)*) ($($bk:tt)*) [( ) ( )] $e:expr
+	// Syntax: [<x>*] ($($flags:tt)*) ($($bk:tt)*) [( ) ( )] $e:expr
 	//             │               │          │   └ If we unbox, fill with $( ($count, $label, $type) )*
 	//             │               │          └ If we don't unbox, fill with $( ($count, $label, $type) )*
 	//             │               └ Breaks of ($count, $label)
 	//             └ "Flags": ($bk) ($bv) ($bx). Whether the innermost loop breaks, breakvals or breakval and unboxes
 	//               Specify the usable type for $bv and $bx
-	( @boxed ( ($($bk:tt)?) ($($bv:ty)?) ($($bx:ty)?) )         // Flags
-		( $( ($c:expr, $l:lifetime) )* )                        // Breaks
-		[ ($( ($count:expr,  $label:lifetime,  $type:ty)  )*)   // Normal breakvals
-		  ($( ($bcount:expr, $blabel:lifetime, $btype:ty) )*) ] // Boxed breakvals
+	( @boxed $mode:tt                                           // ("panic") or ("try")
+		$max:tt                                                 // The number of labels, as `[<x>*]`
+		$or:tt                                                  // `-or` fallback
+		$producer:tt                                            // The stringified expression
+		( ($($bk:tt)?) ($($bv:ty)?) ($($bx:ty)?) )              // Flags
+		( $( ($c:tt, $l:lifetime) )* )                        // Breaks
+		[ ($( ($count:tt,  $label:lifetime,  $type:ty $(, $conv:ident)?) )*) // Normal breakvals, maybe with `into`
+		  ($( ($bcount:tt, $blabel:lifetime, $btype:ty) )*) ]                 // Boxed breakvals
 		$e:expr
 	) => {
 		match $e {
-			$crate::Looping::Resume(v) => v,
-			$( $crate::Looping::Break { label: None } => { $crate::__unit!($bk); break; }, )?
-			$( $crate::Looping::Break { label: None } => { $crate::__unit!($bv); panic!("{}", $crate::BREAK_WITHOUT_VAL) }, )?
-			$( $crate::Looping::Break { label: None } => { $crate::__unit!($bx); panic!("{}", $crate::BREAK_WITHOUT_VAL) }, )?
-			$crate::Looping::Break { label: Some(l) } => {
-				match l {
-					$( x if x == $c => { break $l; }, )*
-					_ => panic!("Invalid label index in Looping::Break object."),
-				};
+			// In debug builds, check the label index before matching, to give the expression that produced it
+			#[cfg(debug_assertions)]
+			ref signal if $crate::__impl_twist! { @check $mode (signal)
+				($crate::__label_index!$max) $producer } => ::core::unreachable!(),
+			// The Resume arm is always there, so we check the labels in it
+			$crate::Looping::Resume(v) => {
+				$crate::__impl_twist! { @label-dups ($) $(($c, $l))* $(($count, $label))* $(($bcount, $blabel))* }
+				$crate::__impl_twist! { @ok $mode v }
 			},
-			$crate::Looping::Continue { label: None } => continue,
-			$crate::Looping::Continue { label: Some(l) } => {
+			$( $crate::Looping::Break { label: ::core::option::Option::None } => { $crate::__unit!($bk); $crate::__impl_twist! { @track $mode } break; }, )?
+			$( $crate::Looping::Break { label: ::core::option::Option::None } => { $crate::__unit!($bv); $crate::__impl_twist! { @fail $mode ($crate::TwistError::MissingValue) ($crate::twist_impl::__fail($crate::TearErrorKind::BreakWithoutVal)) } }, )?
+			$( $crate::Looping::Break { label: ::core::option::Option::None } => { $crate::__unit!($bx); $crate::__impl_twist! { @fail $mode ($crate::TwistError::MissingValue) ($crate::twist_impl::__fail($crate::TearErrorKind::BreakWithoutVal)) } }, )?
+			$crate::Looping::Break { label: ::core::option::Option::Some(l) } => {
 				match l {
-					$( x if x == $c => { continue $l; }, )*
-					$( x if x == $count => { continue $label; }, )*
-					$( x if x == $bcount => { continue $blabel; }, )*
-					_ => panic!("Invalid label index in Looping::Continue object."),
-				};
+					$( $crate::__label_index!$c => { $crate::__impl_twist! { @track $mode } break $l; }, )*
+					_ => $crate::__impl_twist! { @err $mode ($crate::TwistError::InvalidLabel { index: l, max: $crate::__label_index!$max }) ($crate::twist_impl::__invalid_label(l, $crate::__label_index!$max)) },
+				}
 			},
-			$( $crate::Looping::BreakVal { label: None, .. } => { $crate::__unit!($bk); panic!("{}", $crate::BREAKVAL_IN_NOT_LOOP); }, )?
-			$( $crate::Looping::BreakVal { label: None, value: v } => { $crate::__unit!($bv); break v; }, )?
-			$( $crate::Looping::BreakVal { label: None, value: v } => { // Unbox version
-				match v.downcast::<$bx>() {
-					Ok(v) => { break *v; },
-					_ => panic!("At label None with type {}: {}", stringify!($bx), $crate::BAD_BREAKVAL_TYPE),
-				};
+			$crate::Looping::Redo { .. } => $crate::twist_impl::__fail($crate::TearErrorKind::RedoWithoutTredo),
+			$crate::Looping::Continue { label } => $crate::__impl_twist! { @continue $mode label $max ( $(($c, $l))* $(($count, $label))* $(($bcount, $blabel))* ) },
+			$( $crate::Looping::BreakVal { label: ::core::option::Option::None, .. } => { $crate::__unit!($bk); $crate::twist_impl::__fail($crate::TearErrorKind::BreakValInNotLoop); }, )?
+			$( $crate::Looping::BreakVal { label: ::core::option::Option::None, value: v } => { $crate::__unit!($bv); $crate::__impl_twist! { @track $mode } break v; }, )?
+			$( $crate::Looping::BreakVal { label: ::core::option::Option::None, value: v } => { // Unbox version
+				match $crate::unbox!(@result v, $bx) {
+					::core::result::Result::Ok(v) => { $crate::__impl_twist! { @track $mode } break v; },
+					::core::result::Result::Err(b) => $crate::__impl_twist! { @or $mode $or (b) ($crate::TwistError::BadType { expected: stringify!($bx) })
+						($crate::twist_impl::__bad_type("None", stringify!($bx), ::core::any::Any::type_id(&*b))) },
+				}
 			}, )?
 			// Add explicit breakval type when it can't be infered by the labeled breaksvals
 			// (because there aren't any) but we do breakval the innermost loop
-			$crate::Looping::BreakVal $(::<_, $bv> )? { label: Some(l), value: v } => {
+			$crate::Looping::BreakVal $(::<_, $bv> )? { label: ::core::option::Option::Some(l), value: v } => {
 				match l {
-					$( x if x == $count => { break $label v; }, )*
-					$( x if x == $bcount => { // Unbox version
-						match v.downcast::<$btype>() {
-							Ok(v) => { break $blabel *v; }, // We got a ref so dereference it
-							_ => panic!("At label {} with type {}: {}", stringify!($blabel), stringify!($btype), $crate::BAD_BREAKVAL_TYPE),
+					$( $crate::__label_index!$count => { $crate::__impl_twist! { @track $mode } break $label $crate::__impl_twist! { @into ($($conv)?) ($type) v }; }, )*
+					$( $crate::__label_index!$bcount => { // Unbox version
+						match $crate::unbox!(@result v, $btype) {
+							::core::result::Result::Ok(v) => { $crate::__impl_twist! { @track $mode } break $blabel v; },
+							::core::result::Result::Err(b) => $crate::__impl_twist! { @or $mode $or (b) ($crate::TwistError::BadType { expected: stringify!($btype) })
+								($crate::twist_impl::__bad_type(stringify!($blabel), stringify!($btype), ::core::any::Any::type_id(&*b))) },
 						}
 					}, )*
-					_ => panic!("Invalid label index in Looping::BreakVal object."),
-				};
+					_ => $crate::__impl_twist! { @err $mode ($crate::TwistError::InvalidLabel { index: l, max: $crate::__label_index!$max }) ($crate::twist_impl::__invalid_label(l, $crate::__label_index!$max)) },
+				}
 			},
 		};
 	};
 	
+	// Same as `@boxed`, but the labels are blocks. They can't be continued, and there's no innermost loop
+	// Syntax: $mode [<x>*] ($($bk:tt)*) ($($bv:tt)*) $e:expr
+	( @block $mode:tt $max:tt
+		( $( ($c:tt, $l:lifetime) )* )                        // Breaks
+		( $( ($count:tt, $label:lifetime, $type:ty $(, $conv:ident)?) )* ) // Breakvals, maybe with `into`
+		$e:expr
+	) => {
+		match $e {
+			$crate::Looping::Resume(v) => {
+				$crate::__impl_twist! { @label-dups ($) $(($c, $l))* $(($count, $label))* }
+				$crate::__impl_twist! { @ok $mode v }
+			},
+			$crate::Looping::Break { label: ::core::option::Option::Some(l) } => {
+				match l {
+					$( $crate::__label_index!$c => { $crate::__impl_twist! { @track $mode } break $l; }, )*
+					_ => $crate::__impl_twist! { @err $mode ($crate::TwistError::InvalidLabel { index: l, max: $crate::__label_index!$max }) ($crate::twist_impl::__invalid_label(l, $crate::__label_index!$max)) },
+				}
+			},
+			$crate::Looping::BreakVal { label: ::core::option::Option::Some(l), value: v } => {
+				match l {
+					$( $crate::__label_index!$count => { $crate::__impl_twist! { @track $mode } break $label $crate::__impl_twist! { @into ($($conv)?) ($type) v }; }, )*
+					_ => $crate::__impl_twist! { @err $mode ($crate::TwistError::InvalidLabel { index: l, max: $crate::__label_index!$max }) ($crate::twist_impl::__invalid_label(l, $crate::__label_index!$max)) },
+				}
+			},
+			$crate::Looping::Break { label: ::core::option::Option::None } => $crate::twist_impl::__fail($crate::TearErrorKind::UnlabeledInBlock),
+			$crate::Looping::BreakVal { label: ::core::option::Option::None, .. } => $crate::twist_impl::__fail($crate::TearErrorKind::UnlabeledInBlock),
+			$crate::Looping::Redo { .. } => $crate::twist_impl::__fail($crate::TearErrorKind::RedoWithoutTredo),
+			$crate::Looping::Continue { .. } => $crate::twist_impl::__fail($crate::TearErrorKind::ContinueInBlock),
+		}
+	};
+
 	/* When we just break from a single loop */
 
+	// Break a labeled block, with or without a value. It can't be continued
+	// Syntax is [("block") ($label)] [] ($e) or [] [("block") ($label) ($type)] ($e)
+	( @single [("block") ($l:lifetime)] [] ($e:expr) $track:tt ) => {
+		match $e {
+			$crate::Looping::Resume::<_, $crate::BreakValError>(v) => v,
+			$crate::Looping::Break { label: ::core::option::Option::None } => { $crate::__impl_twist! { @track $track } break $l },
+			$crate::Looping::Break { label: ::core::option::Option::Some(l) } => $crate::__impl_twist! { @single-label ($l) l },
+			$crate::Looping::Redo { .. } => $crate::twist_impl::__fail($crate::TearErrorKind::RedoWithoutTredo),
+			$crate::Looping::Continue { .. } => $crate::twist_impl::__fail($crate::TearErrorKind::ContinueInBlock),
+			$crate::Looping::BreakVal { value, .. } => match value {},
+		}
+	};
+	( @single [] [("block") ($l:lifetime) ($type:ty)] ($e:expr) $track:tt ) => {
+		match $e {
+			$crate::Looping::Resume(v) => v,
+			$crate::Looping::Break { .. } => $crate::twist_impl::__fail($crate::TearErrorKind::BreakWithoutVal),
+			$crate::Looping::Redo { .. } => $crate::twist_impl::__fail($crate::TearErrorKind::RedoWithoutTredo),
+			$crate::Looping::Continue { .. } => $crate::twist_impl::__fail($crate::TearErrorKind::ContinueInBlock),
+			$crate::Looping::BreakVal::<_, $type> { label: ::core::option::Option::None, value: v } => { $crate::__impl_twist! { @track $track } break $l v },
+			$crate::Looping::BreakVal { label: ::core::option::Option::Some(l), .. } => $crate::__impl_twist! { @single-label ($l) l },
+		}
+	};
+
+	// Break a labeled loop with a value converted with `Into`
+	// Syntax is [] [("into") ($label)] ($e)
+	( @single [] [("into") ($l:lifetime)] ($e:expr) $track:tt ) => {
+		match $e {
+			$crate::Looping::Resume(v) => v,
+			$crate::Looping::Break { .. } => $crate::twist_impl::__fail($crate::TearErrorKind::BreakWithoutVal),
+			$crate::Looping::Redo { .. } => $crate::twist_impl::__fail($crate::TearErrorKind::RedoWithoutTredo),
+			$crate::Looping::Continue { label: ::core::option::Option::None } => continue $l,
+			$crate::Looping::Continue { label: ::core::option::Option::Some(l) } => $crate::__impl_twist! { @single-label ($l) l },
+			$crate::Looping::BreakVal { label: ::core::option::Option::None, value: v } => { $crate::__impl_twist! { @track $track } break $l ::core::convert::Into::into(v) },
+			$crate::Looping::BreakVal { label: ::core::option::Option::Some(l), .. } => $crate::__impl_twist! { @single-label ($l) l },
+		}
+	};
+
+	// Same as breaking with a value, but we unbox the break value first
+	// Syntax is [] [("unbox") ($type) ($fallback?)] ($e)
+	( @single [] [("unbox") ($type:ty) $or:tt] ($e:expr) $track:tt ) => {
+		match $e {
+			$crate::Looping::Resume(v) => v,
+			$crate::Looping::Break { .. } => $crate::twist_impl::__fail($crate::TearErrorKind::BreakWithoutVal),
+			$crate::Looping::Redo { .. } => $crate::twist_impl::__fail($crate::TearErrorKind::RedoWithoutTredo),
+			$crate::Looping::Continue { label: ::core::option::Option::None } => continue,
+			$crate::Looping::Continue { label: ::core::option::Option::Some(l) } => $crate::__impl_twist! { @single-label () l },
+			$crate::Looping::BreakVal { label: ::core::option::Option::Some(l), .. } => $crate::__impl_twist! { @single-label () l },
+			$crate::Looping::BreakVal { label: ::core::option::Option::None, value: v } => {
+				match $crate::unbox!(@result v, $type) {
+					::core::result::Result::Ok(v) => { $crate::__impl_twist! { @track $track } break v },
+					::core::result::Result::Err(b) => $crate::__impl_twist! { @single-or-apply $or (b)
+						($crate::twist_impl::__bad_single_type(stringify!($type), ::core::any::Any::type_id(&*b))) },
+				}
+			},
+		}
+	};
 	// Generic implementation for when we break from a single loop
 	// Syntax is [ ] [ ] ($e)
 	//            │   └ If breaking with value, fill with ("breakval") ( $label? )
@@ -503,33 +2637,61 @@ macro_rules! twist {
 		[$( ($breaker:tt) ($($label:lifetime)?) )?]   // Break
 		[$( ($breakval:tt) ($($vlabel:lifetime)?) )?] // BreakVal
 		($e:expr)
+		$track:tt                                     // ("single" $tr?), with the `-track` variable
 	) => {
 		match $e {
-			$( _ if $crate::__bool!($breaker)  => unreachable!(), $crate::Looping::Resume::<_, $crate::BreakValError>(v) => v, )?
-			$( _ if $crate::__bool!($breakval) => unreachable!(), $crate::Looping::Resume(v) => v, )?
-			$( _ if $crate::__bool!($breaker)  => unreachable!(), $crate::Looping::Break { .. } => break $($label)?, )?
-			$( _ if $crate::__bool!($breakval) => unreachable!(), $crate::Looping::Break { .. } => panic!("{}", $crate::BREAK_WITHOUT_VAL), )?
-			$crate::Looping::Continue { .. } => continue $($($label)?)? $($($vlabel)?)?,
-			$( _ if $crate::__bool!($breaker)  => unreachable!(), $crate::Looping::BreakVal { .. } => panic!("{}", $crate::BREAKVAL_IN_NOT_LOOP), )?
-			$( _ if $crate::__bool!($breakval) => unreachable!(), $crate::Looping::BreakVal { value: v, .. } => break $($vlabel)? v, )?
+			$( _ if $crate::__bool!($breaker)  => ::core::unreachable!(), $crate::Looping::Resume::<_, $crate::BreakValError>(v) => v, )?
+			$( _ if $crate::__bool!($breakval) => ::core::unreachable!(), $crate::Looping::Resume(v) => v, )?
+			$( _ if $crate::__bool!($breaker)  => ::core::unreachable!(), $crate::Looping::Break { label: ::core::option::Option::None } => { $crate::__impl_twist! { @track $track } break $($label)? }, )?
+			$( _ if $crate::__bool!($breakval) => ::core::unreachable!(), $crate::Looping::Break { .. } => $crate::twist_impl::__fail($crate::TearErrorKind::BreakWithoutVal), )?
+			$crate::Looping::Redo { .. } => $crate::twist_impl::__fail($crate::TearErrorKind::RedoWithoutTredo),
+			$crate::Looping::Continue { label: ::core::option::Option::None } => continue $($($label)?)? $($($vlabel)?)?,
+			$( _ if $crate::__bool!($breaker)  => ::core::unreachable!(), $crate::Looping::BreakVal { .. } => $crate::twist_impl::__fail($crate::TearErrorKind::BreakValInNotLoop), )?
+			$( _ if $crate::__bool!($breakval) => ::core::unreachable!(), $crate::Looping::BreakVal { label: ::core::option::Option::None, value: v } => { $crate::__impl_twist! { @track $track } break $($vlabel)? v }, )?
+			// A label index means that the signal is for another loop
+			$crate::Looping::Break { label: ::core::option::Option::Some(l) }
+			| $crate::Looping::Continue { label: ::core::option::Option::Some(l) }
+			| $crate::Looping::BreakVal { label: ::core::option::Option::Some(l), .. } => $crate::__impl_twist! { @single-label ($($($label)?)? $($($vlabel)?)?) l },
 		}
 	};
 
+	// Handle a Looping object that breaks one of the labels, selected by its index at runtime
+	( -with [ $($l:lifetime),* $(,)? ] | $($tokens:tt)* ) => {
+		$crate::__impl_twist! { @with-expr ("panic") [$($l)*] ($($tokens)*) }
+	};
+	( -try -with [ $($l:lifetime),* $(,)? ] | $($tokens:tt)* ) => {
+		$crate::__impl_twist! { @with-expr ("try") [$($l)*] ($($tokens)*) }
+	};
+	( -unchecked -with [ $($l:lifetime),* $(,)? ] | $($tokens:tt)* ) => {
+		$crate::__impl_twist! { @with-expr ("unchecked") [$($l)*] ($($tokens)*) }
+	};
 	// Handle a Looping object that breaks a specific label
 	( -with $l:lifetime | $($tokens:tt)* ) => {
-		$crate::__impl_twist! { @parse-map [("break") ($l)] [] ($($tokens)*) }
+		$crate::__impl_twist! { @innermost-scan ("single") [$($tokens)*] ->
+			@parse-map [("break") ($l)] [] ($($tokens)*) }
 	};
 	// Handle a Looping object that can break with a value for a specific label
+	( -val -with $l:lifetime into | $($tokens:tt)* ) => {
+		$crate::__impl_twist! { @parse-map [] [("into") ($l)] ($($tokens)*) }
+	};
 	( -val -with $l:lifetime | $($tokens:tt)* ) => {
 		$crate::__impl_twist! { @parse-map [] [("breakval") ($l)] ($($tokens)*) }
 	};
+	// Handle a Looping object that can break with a boxed value, and unbox it
+	( -box -val $type:ty, -or $($tokens:tt)* ) => {
+		$crate::__with_alloc! { "`twist! -box`" $crate::__impl_twist! { @single-or ($type) [$($tokens)*] } }
+	};
+	( -box -val $type:ty, $($tokens:tt)* ) => {
+		$crate::__with_alloc! { "`twist! -box`" $crate::__impl_twist! { @parse-map [] [("unbox") ($type) ()] ($($tokens)*) } }
+	};
 	// Handle a Looping object that can break with a value
 	( -val $($tokens:tt)* ) => {
 		$crate::__impl_twist! { @parse-map [] [("breakval") ()] ($($tokens)*) }
 	};
 	// Handle a Looping object
 	( $($tokens:tt)* ) => {
-		$crate::__impl_twist! { @parse-map [("break") ()] [] ($($tokens)*) }
+		$crate::__impl_twist! { @innermost-scan ("single") [$($tokens)*] ->
+			@parse-map [("break") ()] [] ($($tokens)*) }
 	};
 }
 
@@ -550,6 +2712,13 @@ next_if! { let $pat = $expr,
 }
 ```
 
+To continue a labeled loop instead of the innermost one, add `-with $label,` in front:
+```text
+next_if! { -with $label, $cond,
+    $body
+}
+```
+
 # Example
 
 ```
@@ -564,10 +2733,31 @@ assert_eq![ sum, 9 ];
 
 # See also
 - [`tear_if!`] with examples
-- [`last_if!`]
+- [`last_if!`] and [`resume_if!`]
 */
 #[macro_export]
 macro_rules! next_if {
+	// Labeled next_if! { -with $label, … }
+	( -with $l:lifetime , let $p:pat = $e:expr $( , $($b:tt)* )? ) => {
+		$crate::twist! { -with $l |
+			if let $p = $e {
+				{ $($($b)*)? };
+				$crate::next!()
+			} else {
+				$crate::resume!(())
+			}
+		}
+	};
+	( -with $l:lifetime , $c:expr $( , $($b:tt)* )? ) => {
+		$crate::twist! { -with $l |
+			if $c {
+				{ $($($b)*)? };
+				$crate::next!()
+			} else {
+				$crate::resume!(())
+			}
+		}
+	};
 	// Normal next_if! { $cond, $block }
 	( $c:expr $( , $($b:tt)* )? ) => {
 		$crate::twist! {
@@ -590,6 +2780,25 @@ macro_rules! next_if {
 			}
 		}
 	};
+	// Malformed calls, instead of the error cascade from the arms above
+	() => {
+		compile_error!("next_if! expects a condition: next_if! { cond, body }")
+	};
+	( , $($rest:tt)* ) => {
+		compile_error!(concat!(
+			"next_if! expects a condition before the comma: next_if! { cond, body }. Got: , ",
+			stringify!($($rest)*)))
+	};
+	( let $($rest:tt)* ) => {
+		compile_error!(concat!(
+			"next_if! expects `=` after the `let` pattern: next_if! { let pattern = expr, body }. Got: let ",
+			stringify!($($rest)*)))
+	};
+	( $($rest:tt)* ) => {
+		compile_error!(concat!(
+			"next_if! expects a comma between the condition and the body: next_if! { cond, body }. Got: ",
+			stringify!($($rest)*)))
+	};
 }
 
 /** Explicit loop break
@@ -609,6 +2818,25 @@ last_if! { let $pat = $expr,
 }
 ```
 
+To break a labeled loop instead of the innermost one, add `-with $label,` in front:
+```text
+last_if! { -with $label, $cond,
+    $body
+}
+```
+
+To break a `loop` with a value, use `-val` and give the value instead of the body. The bindings of
+the pattern can be used in the value:
+```text
+last_if! { -val $cond, $value }
+last_if! { -val let $pat = $expr, $value }
+```
+
+Like `twist!`, `-loop $kind,` in front fails to compile when `-val` breaks a `for` or `while` loop:
+```text
+last_if! { -loop for, $cond }
+```
+
 # Example
 
 ```
@@ -621,12 +2849,68 @@ for v in 0..=10 {
 assert_eq![ sum, 15 ];
 ```
 
+Breaking with a value:
+
+```
+# use tear::prelude::*;
+let mut i = 0;
+let x = loop {
+    i += 1;
+    last_if! { -val let Some(v) = (i as u32).checked_sub(3), v * 10 }
+};
+assert_eq![ x, 0 ];
+```
+
 # See also
 - [`tear_if!`] with examples
-- [`next_if!`]
+- [`next_if!`] and [`resume_if!`]
 */
 #[macro_export]
 macro_rules! last_if {
+	// Check the flags against the `-loop` hint: last_if! { -loop $kind, … }
+	( -loop $kind:tt , $($tokens:tt)* ) => {
+		$crate::__impl_twist! { @loop-hint ($kind) [$($tokens)*] -> last_if { $($tokens)* } }
+	};
+	// Break with a value: last_if! { -val $cond, $value }
+	( -val let $p:pat = $e:expr , $v:expr ) => {
+		$crate::twist! { -val
+			if let $p = $e {
+				$crate::Looping::BreakVal { label: ::core::option::Option::None, value: $v }
+			} else {
+				$crate::Looping::Resume::<(), _>(())
+			}
+		}
+	};
+	( -val $c:expr , $v:expr ) => {
+		$crate::twist! { -val
+			if $c {
+				$crate::Looping::BreakVal { label: ::core::option::Option::None, value: $v }
+			} else {
+				$crate::Looping::Resume::<(), _>(())
+			}
+		}
+	};
+	// Labeled last_if! { -with $label, … }
+	( -with $l:lifetime , let $p:pat = $e:expr $( , $($b:tt)* )? ) => {
+		$crate::twist! { -with $l |
+			if let $p = $e {
+				{ $($($b)*)? };
+				$crate::last!()
+			} else {
+				$crate::resume!(())
+			}
+		}
+	};
+	( -with $l:lifetime , $c:expr $( , $($b:tt)* )? ) => {
+		$crate::twist! { -with $l |
+			if $c {
+				{ $($($b)*)? };
+				$crate::last!()
+			} else {
+				$crate::resume!(())
+			}
+		}
+	};
 	// Normal last_if! { $cond, $block }
 	( $c:expr $( , $($b:tt)* )? ) => {
 		$crate::twist! {
@@ -649,4 +2933,403 @@ macro_rules! last_if {
 			}
 		}
 	};
+	// Malformed calls, instead of the error cascade from the arms above
+	() => {
+		compile_error!("last_if! expects a condition: last_if! { cond, body }")
+	};
+	( , $($rest:tt)* ) => {
+		compile_error!(concat!(
+			"last_if! expects a condition before the comma: last_if! { cond, body }. Got: , ",
+			stringify!($($rest)*)))
+	};
+	( let $($rest:tt)* ) => {
+		compile_error!(concat!(
+			"last_if! expects `=` after the `let` pattern: last_if! { let pattern = expr, body }. Got: let ",
+			stringify!($($rest)*)))
+	};
+	( $($rest:tt)* ) => {
+		compile_error!(concat!(
+			"last_if! expects a comma between the condition and the body: last_if! { cond, body }. Got: ",
+			stringify!($($rest)*)))
+	};
+}
+
+/** Evaluate to a value if the condition holds, or to the `else` value
+
+# Description
+
+```text
+let x = resume_if! { $cond, $value; else $other };
+```
+
+With a pattern, whose bindings can be used in the value:
+```text
+let x = resume_if! { let $pat = $expr, $value; else $other };
+```
+
+Without the `else` clause, it skips the current loop iteration like [`next_if!`] when
+the condition doesn't hold:
+```text
+let x = resume_if! { $cond, $value };
+let x = resume_if! { let $pat = $expr, $value };
+```
+
+# Example
+
+```
+# use tear::prelude::*;
+let mut v = Vec::new();
+for i in 0..6u32 {
+    let x = resume_if! { i % 2 == 0, i * 10; else i };
+    let y = resume_if! { let Some(y) = x.checked_sub(3), y };
+    v.push(y);
+}
+assert_eq![ v, vec![17, 0, 37, 2] ];
+```
+
+# See also
+- [`next_if!`] and [`last_if!`]
+*/
+#[macro_export]
+macro_rules! resume_if {
+	// With an `else` value
+	( let $p:pat = $e:expr , $v:expr ; else $o:expr ) => {
+		$crate::twist! {
+			if let $p = $e { $crate::resume!($v) } else { $crate::resume!($o) }
+		}
+	};
+	( $c:expr , $v:expr ; else $o:expr ) => {
+		$crate::twist! {
+			if $c { $crate::resume!($v) } else { $crate::resume!($o) }
+		}
+	};
+	// Skip the iteration otherwise
+	( let $p:pat = $e:expr , $v:expr ) => {
+		$crate::twist! {
+			if let $p = $e { $crate::resume!($v) } else { $crate::next!() }
+		}
+	};
+	( $c:expr , $v:expr ) => {
+		$crate::twist! {
+			if $c { $crate::resume!($v) } else { $crate::next!() }
+		}
+	};
+}
+
+/** Processes a `Looping` expression with `twist!` only if the condition holds
+
+# Description
+
+```text
+twist_if! { $cond, <twist! arguments> }
+```
+
+With a pattern, whose bindings can be used in the `twist!` arguments:
+```text
+twist_if! { let $pat = $expr, <twist! arguments> }
+```
+
+The arguments after the condition are passed to [`twist!`] as is, so it can use the flags and
+the label list to break with a value or to act on labeled loops. Nothing happens when the
+condition doesn't hold. The resumed value is discarded.
+
+# Example
+
+```
+# use tear::prelude::*;
+#[derive(Debug, PartialEq)]
+struct Timeout;
+
+let mut attempts = 0;
+let result :Result<(), Timeout> = 'a: loop {
+    loop {
+        attempts += 1;
+        twist_if! { attempts > 3, -label 'a: Result<(), Timeout> |
+            Looping::BreakVal { label: Some(0), value: Err(Timeout) }
+        }
+    }
+};
+assert_eq![ result, Err(Timeout) ];
+```
+
+# See also
+- [`tear_if!`], the equivalent for `tear!`
+- [`next_if!`], [`last_if!`] and [`resume_if!`] for the usual cases
+*/
+#[macro_export]
+macro_rules! twist_if {
+	( let $p:pat = $e:expr , $($t:tt)* ) => {
+		if let $p = $e {
+			let _ = $crate::twist! { $($t)* };
+		}
+	};
+	( $c:expr , $($t:tt)* ) => {
+		if $c {
+			let _ = $crate::twist! { $($t)* };
+		}
+	};
+}
+
+/** Get the value from an `Option`, or skip the current loop iteration
+
+# Description
+
+```text
+let x = some_or_next! { $opt };
+```
+
+Shortcut for `twist! { $opt =>> next!() }`. It continues the innermost loop if `$opt` is None.
+Otherwise, it evaluates to the value inside `Some`.
+
+# Example
+
+```
+# use tear::prelude::*;
+let mut sum = 0;
+for v in &[Some(1), None, Some(3)] {
+    let x = some_or_next! { *v };
+    sum += x;
+}
+assert_eq![ sum, 4 ];
+```
+
+# See also
+- [`some_or_last!`]
+- [`ok_or_next!`] and [`ok_or_last!`] for `Result`
+*/
+#[macro_export]
+macro_rules! some_or_next {
+	( $e:expr ) => {
+		$crate::twist! { $e =>> $crate::next!() }
+	};
+}
+
+/** Get the value from an `Option`, or break the current loop
+
+# Description
+
+```text
+let x = some_or_last! { $opt };
+```
+
+Shortcut for `twist! { $opt =>> last!() }`. It breaks the innermost loop if `$opt` is None.
+Otherwise, it evaluates to the value inside `Some`.
+
+# Example
+
+```
+# use tear::prelude::*;
+let mut sum = 0;
+for v in &[Some(1), None, Some(3)] {
+    let x = some_or_last! { *v };
+    sum += x;
+}
+assert_eq![ sum, 1 ];
+```
+
+# See also
+- [`some_or_next!`]
+- [`ok_or_next!`] and [`ok_or_last!`] for `Result`
+*/
+#[macro_export]
+macro_rules! some_or_last {
+	( $e:expr ) => {
+		$crate::twist! { $e =>> $crate::last!() }
+	};
+}
+
+/** Get the value from a `Result`, or skip the current loop iteration
+
+# Description
+
+```text
+let x = ok_or_next! { $res };
+let x = ok_or_next! { $res, $f };
+```
+
+Continues the innermost loop if `$res` is Err, discarding the error. Otherwise, it evaluates to
+the value inside `Ok`.
+
+With the second form, the function `$f` is called with the error before skipping the iteration.
+
+# Example
+
+```
+# use tear::prelude::*;
+let mut sum = 0;
+let mut errors = Vec::new();
+for s in &["1", "two", "3"] {
+    let x :i32 = ok_or_next! { s.parse(), |e| errors.push(e) };
+    sum += x;
+}
+assert_eq![ sum, 4 ];
+assert_eq![ errors.len(), 1 ];
+```
+
+# See also
+- [`ok_or_last!`]
+- [`some_or_next!`] and [`some_or_last!`] for `Option`
+*/
+#[macro_export]
+macro_rules! ok_or_next {
+	( $e:expr ) => {
+		$crate::twist! { $e =>> $crate::next!() }
+	};
+	( $e:expr , $f:expr ) => {
+		$crate::twist! { $e => |e| { $crate::__apply(e, $f); $crate::next!() } }
+	};
+}
+
+/** Get the value from a `Result`, or break the current loop
+
+# Description
+
+```text
+let x = ok_or_last! { $res };
+let x = ok_or_last! { $res, $f };
+```
+
+Breaks the innermost loop if `$res` is Err, discarding the error. Otherwise, it evaluates to
+the value inside `Ok`.
+
+With the second form, the function `$f` is called with the error before breaking the loop.
+
+# Example
+
+```
+# use tear::prelude::*;
+let mut sum = 0;
+let mut error = None;
+for s in &["1", "two", "3"] {
+    let x :i32 = ok_or_last! { s.parse(), |e| error = Some(e) };
+    sum += x;
+}
+assert_eq![ sum, 1 ];
+assert![ error.is_some() ];
+```
+
+# See also
+- [`ok_or_next!`]
+- [`some_or_next!`] and [`some_or_last!`] for `Option`
+*/
+#[macro_export]
+macro_rules! ok_or_last {
+	( $e:expr ) => {
+		$crate::twist! { $e =>> $crate::last!() }
+	};
+	( $e:expr , $f:expr ) => {
+		$crate::twist! { $e => |e| { $crate::__apply(e, $f); $crate::last!() } }
+	};
+}
+
+/** Loop whose body evaluates to a `Looping` signal, and that can run the body again
+
+# Description
+
+```text
+tredo_loop! { [$label:] for $pat in $iter => $body }
+tredo_loop! { [$label:] loop => $body }
+tredo_loop! { -val [$label:] loop => $body }
+```
+
+`$body` evaluates to a `Looping<(), B>`. `Redo` runs it again without advancing the loop, which
+`twist!` can't do. The other signals act on the loop as usual: `Resume` and `Continue` go on to the
+next iteration, `Break` breaks it, and with `-val`, `BreakVal` breaks it with the value.
+
+The body is wrapped in an inner `loop`, that `Redo` continues. So use the signals in the body
+instead of `break`, `continue` and the `twist!` forms without labels, that would act on the inner
+loop. Give the loop a label to break it from nested loops with `twist! -with $label`.
+
+The loop only has one label, so it panics if the signal has a label index. As the body runs
+again with the same item, it can't move out of it.
+
+# Example
+
+A parser that reads the token again in another mode.
+
+```
+use tear::extra::*;
+use tear::{tredo_loop, redo};
+
+enum Mode { Words, Numbers }
+
+let mut mode = Mode::Words;
+let (mut words, mut sum) = (Vec::new(), 0);
+tredo_loop! { for token in ["a", "b", "1", "2", "c"].iter() => {
+    match (&mode, token.parse::<i32>()) {
+        (Mode::Words, Err(_)) => { words.push(*token); next!() },
+        (Mode::Numbers, Ok(v)) => { sum += v; next!() },
+        (Mode::Words, Ok(_)) => { mode = Mode::Numbers; redo!() },
+        (Mode::Numbers, Err(_)) => { mode = Mode::Words; redo!() },
+    }
+} }
+assert_eq![ words, ["a", "b", "c"] ];
+assert_eq![ sum, 3 ];
+```
+
+# Panics
+
+When the signal has a label index, or is `Break` with `-val`.
+*/
+#[macro_export]
+macro_rules! tredo_loop {
+	( $l:lifetime : for $p:pat in $iter:expr => $body:expr ) => {
+		$l: for $p in $iter {
+			$crate::tredo_loop! { @redo ($l) () $body }
+		}
+	};
+	( for $p:pat in $iter:expr => $body:expr ) => {
+		$crate::tredo_loop! { 'tredo: for $p in $iter => $body }
+	};
+	( $l:lifetime : loop => $body:expr ) => {
+		$l: loop {
+			$crate::tredo_loop! { @redo ($l) () $body }
+		}
+	};
+	( loop => $body:expr ) => {
+		$crate::tredo_loop! { 'tredo: loop => $body }
+	};
+	( -val $l:lifetime : loop => $body:expr ) => {
+		$l: loop {
+			$crate::tredo_loop! { @redo ($l) ("val") $body }
+		}
+	};
+	( -val loop => $body:expr ) => {
+		$crate::tredo_loop! { -val 'tredo: loop => $body }
+	};
+
+	// Run the body until it says something else than Redo
+	( @redo ($l:lifetime) () $body:expr ) => {
+		loop {
+			match $body {
+				$crate::Looping::Resume(()) => break,
+				$crate::Looping::Continue { label: ::core::option::Option::None } => break,
+				$crate::Looping::Redo { label: ::core::option::Option::None } => continue,
+				$crate::Looping::Break { label: ::core::option::Option::None } => break $l,
+				$crate::Looping::BreakVal::<_, $crate::BreakValError> { label: ::core::option::Option::None, value } => match value {},
+				$crate::Looping::Break { label: ::core::option::Option::Some(l) }
+				| $crate::Looping::Continue { label: ::core::option::Option::Some(l) }
+				| $crate::Looping::BreakVal { label: ::core::option::Option::Some(l), .. }
+				| $crate::Looping::Redo { label: ::core::option::Option::Some(l) } => $crate::tredo_loop! { @label l },
+			}
+		}
+	};
+	( @redo ($l:lifetime) ("val") $body:expr ) => {
+		loop {
+			match $body {
+				$crate::Looping::Resume(()) => break,
+				$crate::Looping::Continue { label: ::core::option::Option::None } => break,
+				$crate::Looping::Redo { label: ::core::option::Option::None } => continue,
+				$crate::Looping::Break { label: ::core::option::Option::None } => $crate::twist_impl::__fail($crate::TearErrorKind::BreakWithoutVal),
+				$crate::Looping::BreakVal { label: ::core::option::Option::None, value } => break $l value,
+				$crate::Looping::Break { label: ::core::option::Option::Some(l) }
+				| $crate::Looping::Continue { label: ::core::option::Option::Some(l) }
+				| $crate::Looping::BreakVal { label: ::core::option::Option::Some(l), .. }
+				| $crate::Looping::Redo { label: ::core::option::Option::Some(l) } => $crate::tredo_loop! { @label l },
+			}
+		}
+	};
+	( @label $l:ident ) => {
+		$crate::twist_impl::__redo_label($l)
+	};
 }
@@ -0,0 +1,107 @@
+/*! Label indices checked by the type system (f=const-labels)
+
+# Description
+
+`Looping` stores its label as a plain `usize` index into the `twist! -label` list. [`LabelIndex<N>`]
+lifts that index into a zero-sized type, so that it can be named once with [`labels!`] and reused:
+```
+use tear::{labels, twist, Looping};
+
+labels! { Outer, Inner }
+
+let mut count = 0;
+'a: loop {
+    'b: loop {
+        count += 1;
+        twist! { -typed -label 'a, 'b | Looping::<(), ()>::break_at::<Outer>() }
+    }
+}
+assert_eq![ count, 1 ];
+```
+
+The runtime layout of `Looping` doesn't change: the constructors like [`Looping::break_at`] write
+`Some(L::INDEX)` in the label field. `twist! -typed` then checks at compile time that every typed
+label its expression mentions fits in its label list.
+
+It needs Rust 1.57 for the compile-time check.
+*/
+use crate::Looping;
+
+/** A label index as a zero-sized type (f=const-labels)
+
+Name it with a type alias, or define several at once with [`labels!`].
+
+```
+use tear::{LabelIndex, TypedLabel};
+
+type Outer = LabelIndex<0>;
+assert_eq![ Outer::INDEX, 0 ];
+assert_eq![ <LabelIndex<2> as TypedLabel>::INDEX, 2 ];
+```
+*/
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct LabelIndex<const N :usize>;
+
+impl<const N :usize> LabelIndex<N> {
+	/// The label index, that is the position of the loop in the `twist! -label` list
+	pub const INDEX :usize = N;
+}
+
+/** Types that stand for a label index, like [`LabelIndex<N>`] (f=const-labels)
+
+It lets the `Looping` constructors take the label as a type parameter.
+*/
+pub trait TypedLabel {
+	/// The label index
+	const INDEX :usize;
+}
+
+impl<const N :usize> TypedLabel for LabelIndex<N> {
+	const INDEX :usize = N;
+}
+
+/// Constructors from typed labels (f=const-labels)
+impl<T, B> Looping<T, B> {
+	/** Break the loop of label `L`
+
+	```
+	# use tear::{Looping, LabelIndex};
+	let l :Looping<(), ()> = Looping::break_at::<LabelIndex<1>>();
+	assert_eq![ l, Looping::Break { label: Some(1) } ];
+	```
+	*/
+	pub fn break_at<L :TypedLabel> () -> Self { Looping::Break { label: Some(L::INDEX) } }
+	/// Break the loop of label `L` with `value`
+	pub fn break_val_at<L :TypedLabel> (value :B) -> Self { Looping::BreakVal { label: Some(L::INDEX), value } }
+	/// Continue the loop of label `L`
+	pub fn continue_at<L :TypedLabel> () -> Self { Looping::Continue { label: Some(L::INDEX) } }
+	/// Restart the current iteration of the loop of label `L`
+	pub fn redo_at<L :TypedLabel> () -> Self { Looping::Redo { label: Some(L::INDEX) } }
+}
+
+/** Define type aliases for the label indices, in order (f=const-labels)
+
+The first name is `LabelIndex<0>`, the second `LabelIndex<1>`, and so on, up to 32 names. An optional
+visibility applies to all of them.
+
+```
+use tear::{labels, LabelIndex};
+
+labels! { pub Outer, Middle, Inner }
+
+assert_eq![ Outer::INDEX, 0 ];
+assert_eq![ Inner::INDEX, 2 ];
+let _ :Middle = LabelIndex::<1>;
+```
+*/
+#[macro_export]
+macro_rules! labels {
+	( @next ($($vis:tt)*) [$($c:tt)*] ) => {};
+	( @next ($($vis:tt)*) [$($c:tt)*] $name:ident $(, $rest:ident)* ) => {
+		$($vis)* type $name = $crate::LabelIndex<{ $crate::__label_index![$($c)*] }>;
+		$crate::labels! { @next ($($vis)*) [$($c)* x] $($rest),* }
+	};
+	( $vis:vis $($name:ident),+ $(,)? ) => {
+		$crate::labels! { @next ($vis) [] $($name),+ }
+	};
+}
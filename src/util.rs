@@ -1,10 +1,11 @@
 /*! Utility functions and macros
 
 Macros are accessible from the crate root:
-- `last!`, `next!`, `resume!` dirty macros
+- `last!`, `next!`, `resume!` and `breakval!` dirty macros
+- `stop!`, `skip!` and `go!`, their variants with an inferred break value type
 - `anybox!`
 - (dev) `__unit!` and `__bool!`
-- (not exported) `maybe_match!`
+- `maybe_match!` for enum accessors
 */
 use crate::Maru;
 
@@ -37,14 +38,14 @@ macro_rules! ret {
 
 # Description
 
-If called with no arguments, it breaks the current loop.
+If called with no arguments or with `None`, it breaks the current loop.
 
-If called with the label index, it breaks the corresponding loop (see [`twist!`]).
+If called with the label index (or `Some(index)`), it breaks the corresponding loop (see [`twist!`]).
 
 Used for writing short `twist!` statements that break from an enclosing loop. See examples.
 
 Note that this macro will fail to compile if `twist!` can break with a value or when
-using `twist -label`.
+using `twist -label`. Use [`stop!`] in that case.
 
 # Examples
 
@@ -73,22 +74,24 @@ the macro `break!` unless we use `r#break!`.
 - [`last_if!`]
 */
 #[macro_export] macro_rules! last {
-	() => { $crate::Looping::Break::<_, $crate::BreakValError> { label: None } };
-	( $id:expr ) => { $crate::Looping::Break::<_, $crate::BreakValError> { label: Some($id) } };
+	() => { $crate::Looping::Break::<_, $crate::BreakValError> { label: ::core::option::Option::None } };
+	( None ) => { $crate::Looping::Break::<_, $crate::BreakValError> { label: ::core::option::Option::None } };
+	( Some($id:expr) ) => { $crate::Looping::Break::<_, $crate::BreakValError> { label: ::core::option::Option::Some($id) } };
+	( $id:expr ) => { $crate::Looping::Break::<_, $crate::BreakValError> { label: ::core::option::Option::Some($id) } };
 }
 
 /** Dirty shortcut for creating a `Looping::Continue`
 
 # Description
 
-If called with no arguments, it skips the current loop.
+If called with no arguments or with `None`, it skips the current loop.
 
-If called with the label index, it skips the corresponding loop (see `twist!`).
+If called with the label index (or `Some(index)`), it skips the corresponding loop (see `twist!`).
 
 Used for writing short `twist!` statements that continue an enclosing loop. See examples.
 
 Note that this macro will fail to compile if `twist!` can break with a value or when
-using `twist -label`.
+using `twist -label`. Use [`skip!`] in that case.
 
 # Examples
 
@@ -128,20 +131,24 @@ the macro `continue!` unless we use `r#continue!`.
 - [`next_if!`]
 */
 #[macro_export] macro_rules! next {
-	() => { $crate::Looping::Continue::<_, $crate::BreakValError> { label: None } };
-	( $id:expr ) => { $crate::Looping::Continue::<_, $crate::BreakValError> { label: Some($id) } };
+	() => { $crate::Looping::Continue::<_, $crate::BreakValError> { label: ::core::option::Option::None } };
+	( None ) => { $crate::Looping::Continue::<_, $crate::BreakValError> { label: ::core::option::Option::None } };
+	( Some($id:expr) ) => { $crate::Looping::Continue::<_, $crate::BreakValError> { label: ::core::option::Option::Some($id) } };
+	( $id:expr ) => { $crate::Looping::Continue::<_, $crate::BreakValError> { label: ::core::option::Option::Some($id) } };
 }
 
 /** Dirty shortcut for creating a `Looping::Resume`
 
 # Description
 
-The only argument is the value to wrap in `Looping::Resume`.
+The only argument is the value to wrap in `Looping::Resume`. Without arguments,
+it resumes with `()`.
 
 Used for writing short `twist!` statements that evaluate to a value. See examples.
 
 Note that this macro will fail to compile if `twist!` can break with a value or when
-using `twist -label`.
+using `twist -label`. Use [`go!`] in that case, or specify the break value type
+with `resume!($value; $type)`.
 
 # Examples
 
@@ -163,32 +170,194 @@ let mut i = 0;
     }
 }
 # assert_eq![ i, 2 ];
+
+let s = 'a: loop {
+    loop {
+        i = twist! { -label 'a: String | resume!(3; String) };
+        break 'a "done".to_string();
+    }
+};
+# assert_eq![ i, 3 ];
+# assert_eq![ s, "done" ];
 ```
 */
 #[macro_export] macro_rules! resume {
-	( $($value:tt)* ) => { $crate::Looping::Resume::<_, $crate::BreakValError> ($($value)*) }
+	() => { $crate::Looping::Resume::<_, $crate::BreakValError> (()) };
+	( $value:expr ; $type:ty ) => { $crate::Looping::Resume::<_, $type> ($value) };
+	( $($value:tt)* ) => { $crate::Looping::Resume::<_, $crate::BreakValError> ($($value)*) };
+}
+
+/** Dirty shortcut for creating a `Looping::BreakVal`
+
+# Description
+
+If called with a single argument, it breaks the current loop with that value.
+
+If called with the label index and a value, it breaks the corresponding loop with that value
+(see `twist!`). Like [`last!`], the label index can also be `None` or `Some(index)`.
+
+The type parameters of `Looping` are left open, so they are inferred from the `twist!` invocation.
+Breaking the innermost loop with a value needs the `-val` flag of `twist!`, and breaking a labeled
+loop needs its value type in the `-label` list. Otherwise, `twist!` fails to compile or panics
+(see `twist!` documentation).
+
+# Examples
+
+```
+use tear::{twist, breakval};
+
+let x = loop {
+    twist! { -val breakval!(3) }
+};
+assert_eq![ x, 3 ];
+
+let x = 'a: loop {
+    loop {
+        twist! { -label 'a: i32 | breakval!(0, 5) }
+    }
+};
+assert_eq![ x, 5 ];
+```
+*/
+#[macro_export] macro_rules! breakval {
+	( $value:expr ) => { $crate::Looping::BreakVal { label: ::core::option::Option::None, value: $value } };
+	( None , $value:expr ) => { $crate::Looping::BreakVal { label: ::core::option::Option::None, value: $value } };
+	( Some($id:expr) , $value:expr ) => { $crate::Looping::BreakVal { label: ::core::option::Option::Some($id), value: $value } };
+	( $id:expr , $value:expr ) => { $crate::Looping::BreakVal { label: ::core::option::Option::Some($id), value: $value } };
 }
 
-/** Turn a value into a `Box<dyn Any>`
+/** Like [`last!`], but the break value type is inferred
 
 # Description
 
+`last!` sets the break value type of `Looping` to `BreakValError`, so that `twist!` gives a helpful
+error message if you break a non-`loop` loop with a value. But it fails to compile when `twist!`
+breaks with a value, ie. with `-val` or with labels that have a type.
+
+`stop!` leaves the break value type open, so it is inferred from the `twist!` invocation.
+It accepts the same arguments as `last!`.
+
+# Example
+
+```
+use tear::{twist, stop, breakval};
+
+let mut i = 0;
+let x = 'a: loop {
+    loop {
+        i += 1;
+        twist! { -label 'a: i32 | if i < 3 { stop!() } else { breakval!(0, i) } }
+    }
+};
+assert_eq![ x, 3 ];
+```
+
+# See also
+- [`skip!`] and [`go!`]
+*/
+#[macro_export] macro_rules! stop {
+	() => { $crate::Looping::Break { label: ::core::option::Option::None } };
+	( None ) => { $crate::Looping::Break { label: ::core::option::Option::None } };
+	( Some($id:expr) ) => { $crate::Looping::Break { label: ::core::option::Option::Some($id) } };
+	( $id:expr ) => { $crate::Looping::Break { label: ::core::option::Option::Some($id) } };
+}
+
+/** Like [`next!`], but the break value type is inferred
+
+See [`stop!`] for why you would use this.
+
+# Example
+
+```
+use tear::{twist, skip};
+
+let mut i = 0;
+let x = loop {
+    i += 1;
+    twist! { -val if i < 3 { skip!() } else { tear::Looping::BreakVal { label: None, value: i } } }
+};
+assert_eq![ x, 3 ];
+```
+*/
+#[macro_export] macro_rules! skip {
+	() => { $crate::Looping::Continue { label: ::core::option::Option::None } };
+	( None ) => { $crate::Looping::Continue { label: ::core::option::Option::None } };
+	( Some($id:expr) ) => { $crate::Looping::Continue { label: ::core::option::Option::Some($id) } };
+	( $id:expr ) => { $crate::Looping::Continue { label: ::core::option::Option::Some($id) } };
+}
+
+/** Like [`resume!`], but the break value type is inferred
+
+See [`stop!`] for why you would use this.
+
+# Example
+
+```
+use tear::{twist, go};
+
+let x = 'a: loop {
+    loop {
+        let v = twist! { -label 'a: i32 | go!(5) };
+        break 'a v;
+    }
+};
+assert_eq![ x, 5 ];
+```
+*/
+#[macro_export] macro_rules! go {
+	( $($value:tt)* ) => { $crate::Looping::Resume ($($value)*) }
+}
+
+/** Shortcut for creating a `Looping::Redo`, that runs the loop body again
+
+Only [`tredo_loop!`] handles it. The break value type is inferred, like [`skip!`].
+
+# Example
+
+```
+use tear::{tredo_loop, redo, next};
+
+let mut tries = 0;
+tredo_loop! { for _ in 0..2 => {
+    tries += 1;
+    if tries % 2 == 1 { redo!() } else { next!() }
+} }
+assert_eq![ tries, 4 ];
+```
+*/
+#[macro_export] macro_rules! redo {
+	() => { $crate::Looping::Redo { label: ::core::option::Option::None } };
+	( None ) => { $crate::Looping::Redo { label: ::core::option::Option::None } };
+	( Some($id:expr) ) => { $crate::Looping::Redo { label: ::core::option::Option::Some($id) } };
+	( $id:expr ) => { $crate::Looping::Redo { label: ::core::option::Option::Some($id) } };
+}
+
+/** Turn a value into a `Box<dyn Any>` (f=alloc)
+
+# Description
+
+```text
+anybox!($e)            // Box<dyn Any>
+anybox!(send $e)       // Box<dyn Any + Send>
+anybox!(send_sync $e)  // Box<dyn Any + Send + Sync>
+```
+
 Give it a value or an expression and it will turn it into a `Box<dyn Any>` value.
+It needs the "alloc" feature, as `Box` needs an allocator.
 
-Used for breaking multiple loops with different values types with `twist!`.
+Used for breaking multiple loops with different values types with `twist!`. The `send` and
+`send_sync` forms build signals that can cross threads, eg. through a channel, and `twist! -box`
+and [`unbox!`] downcast them the same way.
 
 # Examples
 
 Just wrapping the value and getting it back.
 
 ```
-use tear::anybox;
+use tear::{anybox, unbox};
 
 let boxed = anybox!(3);
-let x = match boxed.downcast::<i32>() {
-    Ok(v) => *v,
-    Err(_) => panic!("Failed to get the integer back."),
-};
+let x = unbox!(boxed, i32);
 
 assert_eq![ x, 3 ];
 ```
@@ -199,10 +368,9 @@ Using it as the breakval with `twist!`.
 use tear::{twist, anybox};
 use tear::Looping;
 
-let e = Looping::BreakVal { label: Some(0), value: anybox!("a".to_string()) };
-
 let x = 'a: loop {
     let _ = 'b: loop {
+        let e = Looping::BreakVal { label: Some(0), value: anybox!("a".to_string()) };
         twist! { -box -val i32, -label 'a: String | e }
         break 0;
     };
@@ -210,17 +378,257 @@ let x = 'a: loop {
 };
 assert_eq![ x, "a".to_string() ];
 ```
+
+Sending the signal to another thread.
+
+```
+use tear::{twist, anybox};
+use tear::Looping;
+
+let (tx, rx) = std::sync::mpsc::channel();
+std::thread::spawn(move || {
+    tx.send(Looping::<(), _>::BreakVal { label: Some(0), value: anybox!(send 5) }).unwrap();
+}).join().unwrap();
+
+let x = 'a: loop {
+    loop {
+        twist! { -box -label 'a :i32 | rx.recv().unwrap() }
+    }
+};
+assert_eq![ x, 5 ];
+```
 */
 #[macro_export]
 macro_rules! anybox {
+	// (dev) Box the value as the trait object $dyn
+	( @box ($e:expr) ($dyn:ty) ) => {
+		$crate::__with_alloc! { "`anybox!`"
+			{
+				let v = $e;
+				let b = $crate::__alloc::boxed::Box::new(v);
+				let x = b as $crate::__alloc::boxed::Box<$dyn>;
+				x
+			}
+		}
+	};
+	// Before `send`, so that an expression starting with a variable named `send` keeps its meaning
 	( $e:expr ) => {
-		{
-			let v = $e;
-			let b = Box::new(v);
-			let x = b as Box<dyn core::any::Any>;
-			x
+		$crate::anybox! { @box ($e) (dyn ::core::any::Any) }
+	};
+	( send $e:expr ) => {
+		$crate::anybox! { @box ($e) (dyn ::core::any::Any + ::core::marker::Send) }
+	};
+	( send_sync $e:expr ) => {
+		$crate::anybox! { @box ($e) (dyn ::core::any::Any + ::core::marker::Send + ::core::marker::Sync) }
+	};
+}
+
+/** Get the value back from a `Box<dyn Any>`
+
+# Description
+
+```text
+let v = unbox!($box, $type);          // Panics if the box doesn't contain a $type
+let v = unbox!(try $box, $type);      // Option<$type>
+```
+
+The inverse of [`anybox!`]. It downcasts the box to `$type` and moves the value out of the box.
+
+The panic message has the name of the requested type. As `dyn Any` doesn't know the name of its
+type, it also has the `TypeId` of the value in the box, which can be compared with
+`TypeId::of::<T>()`.
+
+# Examples
+
+```
+use tear::{anybox, unbox};
+
+let x = unbox!(anybox!(3), i32);
+assert_eq![ x, 3 ];
+
+assert_eq![ unbox!(try anybox!(3), i32), Some(3) ];
+assert_eq![ unbox!(try anybox!("three"), i32), None ];
+```
+
+# See also
+- [`anybox!`]
+*/
+#[macro_export]
+macro_rules! unbox {
+	// (dev) Keep the box on failure, eg. for the `-or` fallback of `twist!`
+	( @result $e:expr , $type:ty ) => {
+		$e.downcast::<$type>().map(|v| *v)
+	};
+	( try $e:expr , $type:ty ) => {
+		$crate::unbox!(@result $e, $type).ok()
+	};
+	( $e:expr , $type:ty ) => {
+		match $crate::unbox!(@result $e, $type) {
+			::core::result::Result::Ok(v) => v,
+			::core::result::Result::Err(b) => ::core::panic!("unbox!: expected a value of type {}, got a value with {:?}",
+				stringify!($type), ::core::any::Any::type_id(&*b)),
 		}
-	}
+	};
+}
+
+/** (dev) Expands to its arguments, or fails to compile without the "alloc" feature
+
+```text
+__with_alloc! { "`anybox!`" $tokens }
+```
+*/
+#[cfg(feature = "alloc")]
+#[macro_export] macro_rules! __with_alloc { ( $what:tt $($t:tt)* ) => { $($t)* } }
+
+/** (dev) Expands to its arguments, or fails to compile without the "alloc" feature
+
+```text
+__with_alloc! { "`anybox!`" $tokens }
+```
+*/
+#[cfg(not(feature = "alloc"))]
+#[macro_export] macro_rules! __with_alloc {
+	( $what:tt $($t:tt)* ) => { compile_error!(concat!($what, " needs the \"alloc\" feature of `tear`")) }
+}
+
+/** (dev) Expands to its arguments, or fails to compile without the "control-flow" feature
+
+```text
+__with_control_flow! { "`twist! -cf`" $tokens }
+```
+*/
+#[cfg(feature = "control-flow")]
+#[macro_export] macro_rules! __with_control_flow { ( $what:tt $($t:tt)* ) => { $($t)* } }
+
+/** (dev) Expands to its arguments, or fails to compile without the "control-flow" feature
+
+```text
+__with_control_flow! { "`twist! -cf`" $tokens }
+```
+*/
+#[cfg(not(feature = "control-flow"))]
+#[macro_export] macro_rules! __with_control_flow {
+	( $what:tt $($t:tt)* ) => { compile_error!(concat!($what, " needs the \"control-flow\" feature of `tear`")) }
+}
+
+/** (dev) Expands to its arguments, or fails to compile without the "block-labels" feature
+
+```text
+__with_block_labels! { "`twist! -block`" $tokens }
+```
+*/
+#[cfg(feature = "block-labels")]
+#[macro_export] macro_rules! __with_block_labels { ( $what:tt $($t:tt)* ) => { $($t)* } }
+
+/** (dev) Expands to its arguments, or fails to compile without the "block-labels" feature
+
+```text
+__with_block_labels! { "`twist! -block`" $tokens }
+```
+*/
+#[cfg(not(feature = "block-labels"))]
+#[macro_export] macro_rules! __with_block_labels {
+	( $what:tt $($t:tt)* ) => { compile_error!(concat!($what, " needs the \"block-labels\" feature of `tear`")) }
+}
+
+/** (dev) Expands to its arguments, or fails to compile without the "const-labels" feature
+
+```text
+__with_const_labels! { "`twist! -typed`" $tokens }
+```
+*/
+#[cfg(feature = "const-labels")]
+#[macro_export] macro_rules! __with_const_labels { ( $what:tt $($t:tt)* ) => { $($t)* } }
+
+/** (dev) Expands to its arguments, or fails to compile without the "const-labels" feature
+
+```text
+__with_const_labels! { "`twist! -typed`" $tokens }
+```
+*/
+#[cfg(not(feature = "const-labels"))]
+#[macro_export] macro_rules! __with_const_labels {
+	( $what:tt $($t:tt)* ) => { compile_error!(concat!($what, " needs the \"const-labels\" feature of `tear`")) }
+}
+
+/** (dev) Converts a unary count into an integer literal
+
+`twist!` counts the labels with one `x` token per label, so that the indices are literals
+and can be used as patterns. The number of labels is converted the same way.
+
+```text
+__label_index![x x x] // 3
+```
+*/
+#[macro_export]
+macro_rules! __label_index {
+	( ) => { 0 };
+	( x ) => { 1 };
+	( x x ) => { 2 };
+	( x x x ) => { 3 };
+	( x x x x ) => { 4 };
+	( x x x x x ) => { 5 };
+	( x x x x x x ) => { 6 };
+	( x x x x x x x ) => { 7 };
+	( x x x x x x x x ) => { 8 };
+	( x x x x x x x x x ) => { 9 };
+	( x x x x x x x x x x ) => { 10 };
+	( x x x x x x x x x x x ) => { 11 };
+	( x x x x x x x x x x x x ) => { 12 };
+	( x x x x x x x x x x x x x ) => { 13 };
+	( x x x x x x x x x x x x x x ) => { 14 };
+	( x x x x x x x x x x x x x x x ) => { 15 };
+	( x x x x x x x x x x x x x x x x ) => { 16 };
+	( x x x x x x x x x x x x x x x x x ) => { 17 };
+	( x x x x x x x x x x x x x x x x x x ) => { 18 };
+	( x x x x x x x x x x x x x x x x x x x ) => { 19 };
+	( x x x x x x x x x x x x x x x x x x x x ) => { 20 };
+	( x x x x x x x x x x x x x x x x x x x x x ) => { 21 };
+	( x x x x x x x x x x x x x x x x x x x x x x ) => { 22 };
+	( x x x x x x x x x x x x x x x x x x x x x x x ) => { 23 };
+	( x x x x x x x x x x x x x x x x x x x x x x x x ) => { 24 };
+	( x x x x x x x x x x x x x x x x x x x x x x x x x ) => { 25 };
+	( x x x x x x x x x x x x x x x x x x x x x x x x x x ) => { 26 };
+	( x x x x x x x x x x x x x x x x x x x x x x x x x x x ) => { 27 };
+	( x x x x x x x x x x x x x x x x x x x x x x x x x x x x ) => { 28 };
+	( x x x x x x x x x x x x x x x x x x x x x x x x x x x x x ) => { 29 };
+	( x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x ) => { 30 };
+	( x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x ) => { 31 };
+	( x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x ) => { 32 };
+	( x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x ) => { 33 };
+	( x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x ) => { 34 };
+	( x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x ) => { 35 };
+	( x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x ) => { 36 };
+	( x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x ) => { 37 };
+	( x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x ) => { 38 };
+	( x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x ) => { 39 };
+	( x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x ) => { 40 };
+	( x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x ) => { 41 };
+	( x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x ) => { 42 };
+	( x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x ) => { 43 };
+	( x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x ) => { 44 };
+	( x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x ) => { 45 };
+	( x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x ) => { 46 };
+	( x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x ) => { 47 };
+	( x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x ) => { 48 };
+	( x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x ) => { 49 };
+	( x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x ) => { 50 };
+	( x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x ) => { 51 };
+	( x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x ) => { 52 };
+	( x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x ) => { 53 };
+	( x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x ) => { 54 };
+	( x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x ) => { 55 };
+	( x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x ) => { 56 };
+	( x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x ) => { 57 };
+	( x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x ) => { 58 };
+	( x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x ) => { 59 };
+	( x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x ) => { 60 };
+	( x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x ) => { 61 };
+	( x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x ) => { 62 };
+	( x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x ) => { 63 };
+	// The number of labels, not an index
+	( x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x x ) => { 64 };
+	( $($x:tt)* ) => { compile_error!("Too many labels: `twist!` supports up to 64 labels") };
 }
 
 /** (dev) Always expands to `()`
@@ -250,22 +658,58 @@ match $something {
 
 /** Executes match arm, or returns None
 
+# Description
+
+```text
+maybe_match! { $e, $pattern => $value }
+maybe_match! { $e, $pattern | $pattern if $guard => $value }
+```
+
 Helper for writing enum accessors where you either match the correct pattern, or return None.
 
-The match arm expression is automatically wrapped into `Some`, so you don't need to.
+The match arm expression is automatically wrapped into `Some`, so you don't need to. The arm
+accepts several patterns separated by `|`, and a guard.
 
-# Example
+# Examples
 
 ```
-let x: Option<i32> = maybe_match! { "a", "a" => 3 };
+use tear::maybe_match;
+
+let x :Option<i32> = maybe_match! { "a", "a" => 3 };
 assert_eq![ x, Some(3) ];
+let x :Option<i32> = maybe_match! { "b", "a" => 3 };
+assert_eq![ x, None ];
+```
+
+Accessors on an enum, like [`ValRet::val`](crate::ValRet::val)
+
+```
+use tear::maybe_match;
+
+enum Shape { Circle(f64), Square(f64), Rectangle(f64, f64) }
+
+impl Shape {
+    fn side (&self) -> Option<f64> {
+        maybe_match! { *self, Shape::Square(s) | Shape::Rectangle(s, _) => s }
+    }
+
+    fn small_radius (&self) -> Option<f64> {
+        maybe_match! { *self, Shape::Circle(r) if r < 1. => r }
+    }
+}
+
+assert_eq![ Shape::Rectangle(2., 3.).side(), Some(2.) ];
+assert_eq![ Shape::Circle(2.).side(), None ];
+assert_eq![ Shape::Circle(0.5).small_radius(), Some(0.5) ];
+assert_eq![ Shape::Circle(2.).small_radius(), None ];
 ```
 */
+#[macro_export]
 macro_rules! maybe_match {
-	( $i:expr, $p:pat => $e:expr ) => {
+	( $i:expr, $($p:pat)|+ $(if $g:expr)? => $e:expr $(,)? ) => {
 		match $i {
-			$p => Some($e),
-			_ => None,
+			$($p)|+ $(if $g)? => ::core::option::Option::Some($e),
+			_ => ::core::option::Option::None,
 		}
 	}
 }
@@ -283,3 +727,21 @@ fn f () -> Option<i32> {
 ```
 */
 pub fn gut<T> (_ :T) -> Maru { Maru }
+
+/** (dev) Calls `f` with `v`
+
+Used by macros so that the argument type of a closure `f` is inferred from `v`,
+as `(|e| e.len())(v)` doesn't compile.
+*/
+#[doc(hidden)]
+pub fn __apply<T, R> (v :T, f :impl FnOnce(T) -> R) -> R { f(v) }
+
+/** (dev) Returns its argument. `tear!` and `terror!` return early through it
+
+With the "cold-path" feature, it's `#[cold]` and never inlined, so that the compiler lays out the
+early return as the unlikely branch. Otherwise, it's always inlined and changes nothing.
+*/
+#[doc(hidden)]
+#[cfg_attr(feature = "cold-path", cold, inline(never))]
+#[cfg_attr(not(feature = "cold-path"), inline(always))]
+pub fn __cold_ret<R> (r :R) -> R { r }
@@ -0,0 +1,165 @@
+/*! Implementation of `#[auto]`
+
+We walk the function body with `VisitMut`, and replace every `Expr::Try` with a `terror!` call.
+The children are rewritten first so that `a()?.b()?` works from the inside out.
+*/
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::visit_mut::{self, VisitMut};
+use syn::{parse_quote, Expr, Ident, Item, ItemFn};
+
+/// Parse the attribute arguments and the function, and rewrite it
+pub fn expand (attr :TokenStream, item :TokenStream) -> syn::Result<TokenStream> {
+	let nested = parse_nested(attr)?;
+	let mut func :ItemFn = syn::parse2(item)?;
+
+	Rewriter { nested }.visit_block_mut(&mut func.block);
+	Ok(quote! { #func })
+}
+
+/// The only accepted argument is `nested`
+fn parse_nested (attr :TokenStream) -> syn::Result<bool> {
+	if attr.is_empty() {
+		return Ok(false);
+	}
+
+	let ident :Ident = syn::parse2(attr)?;
+	if ident == "nested" {
+		Ok(true)
+	} else {
+		Err(syn::Error::new(ident.span(), "Unknown argument for `#[tear::auto]`. Expected `nested` or nothing."))
+	}
+}
+
+struct Rewriter {
+	/// Whether we also rewrite closures and async blocks
+	nested :bool,
+}
+
+impl VisitMut for Rewriter {
+	fn visit_expr_mut (&mut self, e :&mut Expr) {
+		// `?` in closures and async blocks don't return from the function
+		if let Expr::Closure(_) | Expr::Async(_) = e {
+			if !self.nested { return; }
+		}
+
+		visit_mut::visit_expr_mut(self, e);
+
+		if let Expr::Try(t) = e {
+			*e = rewrite_try(&t.expr);
+		}
+	}
+
+	// Nested items are different functions
+	fn visit_item_mut (&mut self, _ :&mut Item) {}
+}
+
+/// Rewrite the expression before `?`
+fn rewrite_try (e :&Expr) -> Expr {
+	if let Expr::MethodCall(call) = e {
+		if call.args.len() == 1 {
+			let receiver = &call.receiver;
+			let arg = &call.args[0];
+
+			if call.method == "map_err" {
+				return parse_quote! { ::tear::terror!(#receiver => #arg) };
+			} else if call.method == "ok_or" {
+				return parse_quote! { ::tear::terror!(#receiver =>> #arg) };
+			} else if call.method == "ok_or_else" {
+				return parse_quote! { ::tear::terror!(#receiver =>> (#arg)()) };
+			}
+		}
+	}
+
+	parse_quote! { ::tear::terror!(#e) }
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	// Compare without whitespace, as the spacing of joint punctuation (eg. `||`) is not stable
+	fn check (attr :TokenStream, input :TokenStream, expected :TokenStream) {
+		let strip = |s :String| s.split_whitespace().collect::<String>();
+		assert_eq![ strip(expand(attr, input).unwrap().to_string()), strip(expected.to_string()) ];
+	}
+
+	#[test] fn simple () {
+		check(quote!(), quote! {
+			fn f () -> Result<i32, E> { let v = g()?; Ok(v) }
+		}, quote! {
+			fn f () -> Result<i32, E> { let v = ::tear::terror!(g()); Ok(v) }
+		});
+	}
+
+	#[test] fn chained () {
+		check(quote!(), quote! {
+			fn f () -> Option<usize> { Some(a()?.b()?.len()) }
+		}, quote! {
+			fn f () -> Option<usize> { Some(::tear::terror!(::tear::terror!(a()).b()).len()) }
+		});
+	}
+
+	#[test] fn mapped () {
+		check(quote!(), quote! {
+			fn f () -> Result<(), E> {
+				a().map_err(E::A)?;
+				b().ok_or(E::B)?;
+				c().ok_or_else(|| E::C)?;
+				Ok(())
+			}
+		}, quote! {
+			fn f () -> Result<(), E> {
+				::tear::terror!(a() => E::A);
+				::tear::terror!(b() =>> E::B);
+				::tear::terror!(c() =>> (|| E::C)());
+				Ok(())
+			}
+		});
+	}
+
+	#[test] fn unknown_method () {
+		check(quote!(), quote! {
+			fn f () -> Result<(), E> { a().map(g)?; Ok(()) }
+		}, quote! {
+			fn f () -> Result<(), E> { ::tear::terror!(a().map(g)); Ok(()) }
+		});
+	}
+
+	#[test] fn closures_untouched () {
+		check(quote!(), quote! {
+			fn f () -> Result<(), E> { let c = || -> Result<(), E> { a()?; Ok(()) }; c()?; Ok(()) }
+		}, quote! {
+			fn f () -> Result<(), E> { let c = || -> Result<(), E> { a()?; Ok(()) }; ::tear::terror!(c()); Ok(()) }
+		});
+	}
+
+	#[test] fn async_untouched () {
+		check(quote!(), quote! {
+			fn f () -> impl Future { async { a()?; Ok(()) } }
+		}, quote! {
+			fn f () -> impl Future { async { a()?; Ok(()) } }
+		});
+	}
+
+	#[test] fn nested_rewrites_closures () {
+		check(quote!(nested), quote! {
+			fn f () -> Result<(), E> { let c = || -> Result<(), E> { a()?; Ok(()) }; c() }
+		}, quote! {
+			fn f () -> Result<(), E> { let c = || -> Result<(), E> { ::tear::terror!(a()); Ok(()) }; c() }
+		});
+	}
+
+	#[test] fn nested_items_untouched () {
+		check(quote!(nested), quote! {
+			fn f () -> Result<(), E> { fn g () -> Result<(), E> { a()?; Ok(()) } g() }
+		}, quote! {
+			fn f () -> Result<(), E> { fn g () -> Result<(), E> { a()?; Ok(()) } g() }
+		});
+	}
+
+	#[test] fn bad_argument () {
+		let e = expand(quote!(closures), quote! { fn f () {} }).unwrap_err();
+		assert![ e.to_string().contains("Expected `nested`") ];
+	}
+}
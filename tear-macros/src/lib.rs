@@ -0,0 +1,65 @@
+/*! Procedural macros for the `tear` crate
+
+Use them through `tear` with the corresponding crate feature, eg. `tear::auto` with the "auto" feature.
+*/
+extern crate proc_macro;
+
+use proc_macro::TokenStream;
+
+mod auto; // `#[auto]`
+mod tloop; // `tloop!`
+
+/** Rewrites `?` into `terror!` in the function body
+
+# Description
+
+```text
+#[tear::auto]
+fn f () -> Result<T, E> { … }
+```
+
+Every `$e?` in the function body becomes `tear::terror! { $e }`. The following method calls are
+also rewritten into the mapping forms of `terror!`:
+- `$e.map_err($f)?` becomes `terror! { $e => $f }`
+- `$e.ok_or($v)?` becomes `terror! { $e =>> $v }`
+- `$e.ok_or_else($f)?` becomes `terror! { $e =>> $f() }`
+
+`?` inside closures and async blocks are left alone, because they don't return from the function.
+Use `#[tear::auto(nested)]` to also rewrite them. Nested items like functions are never modified.
+
+Since `terror!` returns `from_bad(From::from(v))`, the types of the expressions need to implement `Judge`.
+
+The expansion refers to the `tear` crate as `::tear`, so it doesn't work if you renamed it.
+*/
+#[proc_macro_attribute]
+pub fn auto (attr :TokenStream, item :TokenStream) -> TokenStream {
+	match auto::expand(attr.into(), item.into()) {
+		Ok(tokens) => tokens.into(),
+		Err(e) => e.to_compile_error().into(),
+	}
+}
+
+/** Declares the `twist!` labels once for a loop nest
+
+# Description
+
+```text
+tear::tloop! { [$flags] <$label [: $type]>,* ; $body }
+```
+
+Every `twist_here! { … }` in `$body` becomes `tear::twist! { $flags -label $labels | … }`, so it
+accepts every right-hand side of `twist!`, eg. `twist_here! { $e => $f }`. The flags are the ones
+before `-label`, eg. `-val i32,` or `-box`, and `-or $fallback` goes after the labels.
+
+It evaluates to `$body`, so you can use it where the outermost loop goes. `twist_here!` calls inside
+a nested `tloop!` are left to it.
+
+The expansion refers to the `tear` crate as `::tear`, so it doesn't work if you renamed it.
+*/
+#[proc_macro]
+pub fn tloop (input :TokenStream) -> TokenStream {
+	match tloop::expand(input.into()) {
+		Ok(tokens) => tokens.into(),
+		Err(e) => e.to_compile_error().into(),
+	}
+}
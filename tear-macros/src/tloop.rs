@@ -0,0 +1,95 @@
+/*! Implementation of `tloop!`
+
+We split the input into the flags, the labels and the body, then walk the body token trees and
+replace every `twist_here! { … }` call with a `twist!` call that has the flags and the labels.
+
+It can't be a `macro_rules!` macro that defines a local `twist_here!`, because the labels would then
+come from the definition of `twist_here!`, and labels are hygienic: they wouldn't match the loops.
+*/
+use proc_macro2::{Group, Span, TokenStream, TokenTree};
+use quote::quote;
+
+/// Split the input, and rewrite the body
+pub fn expand (input :TokenStream) -> syn::Result<TokenStream> {
+	let mut tokens = input.into_iter();
+	let mut flags = TokenStream::new();
+	let mut labels = TokenStream::new();
+
+	// The flags are everything before the first label
+	for t in &mut tokens {
+		if is_punct(&t, '\'') {
+			labels.extend(Some(t));
+			break;
+		}
+		flags.extend(Some(t));
+	}
+	if labels.is_empty() {
+		return Err(syn::Error::new(Span::call_site(), "Missing labels in `tloop!` macro invocation. Use `twist!` if there are none."));
+	}
+
+	// The labels are everything before `;`
+	let mut separated = false;
+	for t in &mut tokens {
+		if is_punct(&t, ';') {
+			separated = true;
+			break;
+		}
+		labels.extend(Some(t));
+	}
+	if !separated {
+		return Err(syn::Error::new(Span::call_site(), "Missing `;` separator after labels in `tloop!` macro invocation."));
+	}
+
+	let body = rewrite(tokens.collect(), &flags, &labels);
+	Ok(quote! { { #body } })
+}
+
+fn is_punct (t :&TokenTree, c :char) -> bool {
+	match t {
+		TokenTree::Punct(p) => p.as_char() == c,
+		_ => false,
+	}
+}
+
+fn is_ident (t :&TokenTree, name :&str) -> bool {
+	match t {
+		TokenTree::Ident(i) => i == name,
+		_ => false,
+	}
+}
+
+/// Replace the `twist_here!` calls, except in nested `tloop!` calls which have their own labels
+fn rewrite (tokens :Vec<TokenTree>, flags :&TokenStream, labels :&TokenStream) -> TokenStream {
+	let mut out = TokenStream::new();
+	let mut i = 0;
+	while i < tokens.len() {
+		let call = match (tokens.get(i), tokens.get(i + 1), tokens.get(i + 2)) {
+			(Some(name), Some(bang), Some(TokenTree::Group(g))) if is_punct(bang, '!') => Some((name, g)),
+			_ => None,
+		};
+
+		match call {
+			Some((name, g)) if is_ident(name, "twist_here") => {
+				let args = g.stream();
+				out.extend(quote! { ::tear::twist! { #flags -label #labels | #args } });
+				i += 3;
+			},
+			Some((name, _)) if is_ident(name, "tloop") => {
+				out.extend(tokens[i..i + 3].iter().cloned());
+				i += 3;
+			},
+			_ => {
+				match &tokens[i] {
+					TokenTree::Group(g) => {
+						let mut group = Group::new(g.delimiter(), rewrite(g.stream().into_iter().collect(), flags, labels));
+						group.set_span(g.span());
+						out.extend(Some(TokenTree::Group(group)));
+					},
+					t => out.extend(Some(t.clone())),
+				}
+				i += 1;
+			},
+		}
+	}
+	out
+}
@@ -0,0 +1,80 @@
+// We test `anybox!(send …)` and `anybox!(send_sync …)` with signals sent between threads
+use tear::{twist, anybox, unbox, next};
+use tear::Looping;
+use std::any::Any;
+use std::sync::mpsc;
+use std::thread;
+
+type Signal = Looping<i32, Box<dyn Any + Send>>;
+
+fn is_send_sync<T :Send + Sync> (_ :&T) {}
+
+#[test] fn unbox_flavours () {
+	assert_eq![ unbox!(anybox!(send 3), i32), 3 ];
+	assert_eq![ unbox!(try anybox!(send_sync "a"), &str), Some("a") ];
+	assert_eq![ unbox!(try anybox!(send 3), u8), None ];
+	is_send_sync(&anybox!(send_sync 3));
+}
+
+#[test] fn send_is_still_a_variable () {
+	let send = 2;
+	assert_eq![ unbox!(anybox!(send), i32), 2 ];
+	assert_eq![ unbox!(anybox!(send + 1), i32), 3 ];
+}
+
+#[test] fn from_worker_thread () {
+	let (tx, rx) = mpsc::channel::<Signal>();
+	let worker = thread::spawn(move || {
+		for v in 1..=3 {
+			tx.send(Looping::Resume(v)).unwrap();
+		}
+		tx.send(Looping::BreakVal { label: Some(0), value: anybox!(send "done".to_string()) }).unwrap();
+	});
+
+	let mut sum = 0;
+	let s = 'a: loop {
+		'b: loop {
+			let v = twist! { -box -label 'a :String, 'b | rx.recv().unwrap() };
+			sum += v;
+		}
+	};
+	worker.join().unwrap();
+	assert_eq![ (s, sum), ("done".to_string(), 6) ];
+}
+
+#[test] fn innermost_send_sync () {
+	let (tx, rx) = mpsc::channel();
+	thread::spawn(move || {
+		tx.send(Looping::<(), _>::BreakVal { label: None, value: anybox!(send_sync 4u8) }).unwrap();
+	}).join().unwrap();
+
+	let x = 'a: loop {
+		let x = loop {
+			twist! { -box -val u8, -label 'a :u8 | rx.recv().unwrap() }
+		};
+		break x;
+	};
+	assert_eq![ x, 4 ];
+}
+
+#[test] fn fallback () {
+	let (tx, rx) = mpsc::channel();
+	thread::spawn(move || {
+		tx.send(Looping::<(), _>::BreakVal { label: Some(0), value: anybox!(send 5u8) }).unwrap();
+		tx.send(Looping::BreakVal { label: Some(0), value: anybox!(send 6i32) }).unwrap();
+	}).join().unwrap();
+
+	let mut skipped = 0;
+	let x = 'a: loop {
+		loop {
+			twist! { -box -label 'a :i32 -or |_| { skipped += 1; next!() } | rx.recv().unwrap() }
+		}
+	};
+	assert_eq![ (x, skipped), (6, 1) ];
+}
+
+#[test] fn panic_has_the_inner_type () {
+	let r = std::panic::catch_unwind(|| unbox!(anybox!(send 3u8), i32));
+	let msg = *r.unwrap_err().downcast::<String>().unwrap();
+	assert![ msg.ends_with(&format!("{:?}", std::any::TypeId::of::<u8>())), "{}", msg ];
+}
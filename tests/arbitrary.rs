@@ -0,0 +1,84 @@
+// Testing the "arbitrary" feature
+#![cfg(feature = "arbitrary")]
+
+use tear::{Looping, Judge, ARBITRARY_LABEL_COUNT};
+use tear::ValRet::{self, Val, Ret};
+use tear::Moral::{self, Good, Bad};
+use arbitrary::{Arbitrary, Unstructured};
+
+// Fixed inputs: every first byte, followed by a few others
+fn inputs () -> impl Iterator<Item = Vec<u8>> {
+	(0..=255u8).map(|b| vec![b, b.wrapping_mul(31), 7, b ^ 0x55, 200, 1])
+}
+
+#[test] fn looping_shape () {
+	let mut seen = [false; 5];
+	for data in inputs() {
+		let mut u = Unstructured::new(&data);
+		let l = Looping::<u8, u8>::arbitrary(&mut u).unwrap();
+		if let Some(i) = l.label() {
+			assert![ i < ARBITRARY_LABEL_COUNT, "Label index {} is too big", i ];
+		}
+		let variant = match l {
+			Looping::Resume(_) => 0,
+			Looping::Break { .. } => 1,
+			Looping::Continue { .. } => 2,
+			Looping::BreakVal { .. } => 3,
+			Looping::Redo { .. } => 4,
+		};
+		seen[variant] = true;
+
+		// Consumes at most the size hint
+		let (min, max) = Looping::<u8, u8>::size_hint(0);
+		let used = data.len() - u.len();
+		assert![ used >= min.min(data.len()) && used <= max.unwrap(), "Used {} bytes for {:?}", used, l ];
+	}
+	assert_eq![ seen, [true; 5] ];
+}
+
+#[test] fn labels () {
+	// Both labeled and unlabeled signals show up
+	let (mut labeled, mut unlabeled) = (0, 0);
+	for data in inputs() {
+		match Looping::<(), ()>::arbitrary(&mut Unstructured::new(&data)).unwrap() {
+			Looping::Resume(()) => {},
+			l => if l.label().is_some() { labeled += 1 } else { unlabeled += 1 },
+		}
+	}
+	assert![ labeled > 0 && unlabeled > 0 ];
+}
+
+#[test] fn size_hint () {
+	assert_eq![ Looping::<u8, u8>::size_hint(0), (2, Some(4)) ];
+	assert_eq![ Looping::<(), ()>::size_hint(0), (1, Some(3)) ];
+}
+
+// Arbitrary values survive the trip through Moral
+#[test] fn round_trip () {
+	let (mut vals, mut goods) = (0, 0);
+	for data in inputs() {
+		let v = ValRet::<u8, i16>::arbitrary(&mut Unstructured::new(&data)).unwrap();
+		let back = match v.clone().into_moral() {
+			Good(x) => ValRet::from_good(x),
+			Bad(x) => ValRet::from_bad(x),
+		};
+		assert_eq![ back, v ];
+		if let Val(_) = v { vals += 1 }
+
+		let m = Moral::<String, u8>::arbitrary(&mut Unstructured::new(&data)).unwrap();
+		let back :Moral<_, _> = match m.clone().into_valret() {
+			Val(x) => Good(x),
+			Ret(x) => Bad(x),
+		};
+		assert_eq![ back, m ];
+		if let Good(_) = m { goods += 1 }
+	}
+	assert![ vals > 0 && vals < 256 ];
+	assert![ goods > 0 && goods < 256 ];
+}
+
+#[test] fn maru () {
+	let mut u = Unstructured::new(&[]);
+	let m = Moral::<u8, tear::Maru>::arbitrary(&mut u).unwrap();
+	assert![ m.good().is_some() || u.is_empty() ];
+}
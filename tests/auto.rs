@@ -0,0 +1,93 @@
+// Testing the "auto" feature
+#![cfg(feature = "auto")]
+
+use std::num::ParseIntError;
+use std::path::PathBuf;
+
+#[derive(Debug, PartialEq)]
+enum Error {
+	Parse(ParseIntError),
+	Missing(PathBuf),
+	Empty,
+}
+
+impl From<ParseIntError> for Error {
+	fn from (e :ParseIntError) -> Self { Error::Parse(e) }
+}
+
+#[tear::auto]
+fn add (a :&str, b :&str) -> Result<i32, Error> {
+	let a :i32 = a.parse()?;
+	let b :i32 = b.parse()?;
+	Ok(a + b)
+}
+
+#[test] fn result () {
+	assert_eq![ add("1", "2"), Ok(3) ];
+	assert![ matches!(add("1", "b"), Err(Error::Parse(_))) ];
+}
+
+#[tear::auto]
+fn first_char_len (v :&[&str]) -> Option<usize> {
+	let first = v.first()?;
+	let c = first.chars().next()?;
+	Some(c.len_utf8())
+}
+
+#[test] fn option () {
+	assert_eq![ first_char_len(&["é"]), Some(2) ];
+	assert_eq![ first_char_len(&[""]), None ];
+	assert_eq![ first_char_len(&[]), None ];
+}
+
+// Moving into the error is allowed because `terror!` returns immediately
+#[tear::auto]
+fn moves (path :PathBuf, v :Option<i32>) -> Result<(PathBuf, i32), Error> {
+	let v = v.ok_or(Error::Missing(path))?;
+	Ok((path, v))
+}
+
+#[test] fn moves_into_error () {
+	let p = PathBuf::from("a");
+	assert_eq![ moves(p.clone(), Some(1)), Ok((p.clone(), 1)) ];
+	assert_eq![ moves(p.clone(), None), Err(Error::Missing(p)) ];
+}
+
+#[tear::auto]
+fn mapped (v :Result<i32, ()>) -> Result<i32, Error> {
+	let v = v.map_err(|_| Error::Empty)?;
+	Ok(v)
+}
+
+#[test] fn map_err () {
+	assert_eq![ mapped(Ok(1)), Ok(1) ];
+	assert_eq![ mapped(Err(())), Err(Error::Empty) ];
+}
+
+#[tear::auto(nested)]
+fn nested (v :Vec<&str>) -> Result<Vec<i32>, Error> {
+	let parse = |s :&str| -> Result<i32, Error> { Ok(s.parse::<i32>()?) };
+	let mut out = Vec::new();
+	for s in v {
+		out.push(parse(s)?);
+	}
+	Ok(out)
+}
+
+#[test] fn nested_closures () {
+	assert_eq![ nested(vec!["1", "2"]), Ok(vec![1, 2]) ];
+	assert![ nested(vec!["1", "x"]).is_err() ];
+}
+
+struct Parser;
+
+impl Parser {
+	#[tear::auto]
+	fn parse (&self, s :&str) -> Result<i32, Error> {
+		Ok(s.trim().parse::<i32>()?)
+	}
+}
+
+#[test] fn method () {
+	assert_eq![ Parser.parse(" 4 "), Ok(4) ];
+}
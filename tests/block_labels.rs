@@ -0,0 +1,144 @@
+// Testing the "block-labels" feature
+#![cfg(feature = "block-labels")]
+
+use tear::twist;
+use tear::{last, resume, breakval, stop, go};
+use tear::{Looping, TwistError};
+use std::num::ParseIntError;
+
+// Stops at the first negative value
+fn check (v :i32) -> Looping<i32, tear::BreakValError> {
+	if v < 0 { last!() } else { resume!(v) }
+}
+
+// Breaks with the first value above 10, or with -1 for negative values
+fn cap (v :i32) -> Looping<i32, i32> {
+	if v < 0 { breakval!(-1) } else if v > 10 { breakval!(v) } else { go!(v) }
+}
+
+// Breaks the block 0 with the error for negative values, and the block 1 for values above 10
+fn classify (v :i32) -> Looping<i32, String> {
+	if v < 0 {
+		breakval!(0, format!("negative: {}", v))
+	} else if v > 10 {
+		stop!(1)
+	} else {
+		go!(v)
+	}
+}
+
+fn checked_sum (values :&[i32]) -> i32 {
+	let mut sum = 0;
+	'b: {
+		for &v in values {
+			// `for` is the innermost loop, but `-with 'b` breaks the block
+			sum += twist! { -block -with 'b | check(v) };
+		}
+		sum *= 10;
+	}
+	sum
+}
+
+#[test] fn with () {
+	assert_eq![ checked_sum(&[1, 2, 3]), 60 ];
+	assert_eq![ checked_sum(&[1, 2, -1, 3]), 3 ];
+}
+
+#[test] fn with_val () {
+	let capped = |values :&[i32]| -> i32 {
+		'b: {
+			let mut last = 0;
+			for &v in values {
+				last = twist! { -block -val i32, -with 'b | cap(v) };
+			}
+			last
+		}
+	};
+	assert_eq![ capped(&[1, 2, 3]), 3 ];
+	assert_eq![ capped(&[1, 20, 3]), 20 ];
+	assert_eq![ capped(&[1, -5, 30]), -1 ];
+}
+
+#[test] fn with_mapping () {
+	let first_error = |values :&[&str]| -> String {
+		'b: {
+			let mut sum = 0;
+			for s in values {
+				sum += twist! { -block -val String, -with 'b | s.parse::<i32>() => |e :ParseIntError| breakval!(e.to_string()) };
+			}
+			format!("sum: {}", sum)
+		}
+	};
+	assert_eq![ first_error(&["1", "2"]), "sum: 3" ];
+	assert_eq![ first_error(&["1", "", "a"]), "cannot parse integer from empty string" ];
+}
+
+fn describe (values :&[i32]) -> (String, bool) {
+	let mut big = true;
+	let s :String = 'error: {
+		'big: {
+			let mut sum = 0;
+			for &v in values {
+				sum += twist! { -block -label 'error :String, 'big | classify(v) };
+			}
+			big = false;
+			break 'error format!("sum: {}", sum);
+		}
+		"too big".to_string()
+	};
+	(s, big)
+}
+
+#[test] fn label () {
+	assert_eq![ describe(&[1, 2]), ("sum: 3".to_string(), false) ];
+	assert_eq![ describe(&[1, -2, 30]), ("negative: -2".to_string(), true) ];
+	assert_eq![ describe(&[1, 30, -2]), ("too big".to_string(), true) ];
+}
+
+#[test] fn try_label () {
+	let mut r = Ok(());
+	'b: {
+		r = twist! { -try -block -label 'b | Looping::Break::<(), tear::BreakValError> { label: Some(2) } };
+	}
+	assert_eq![ r, Err(TwistError::InvalidLabel { index: 2, max: 1 }) ];
+}
+
+#[cfg(not(feature = "tiny-panic"))] // The message is a short code with "tiny-panic"
+#[test]
+#[should_panic(expected = "Labeled blocks can't be continued")]
+fn continue_panics () {
+	'b: {
+		twist! { -block -with 'b | Looping::Continue::<(), tear::BreakValError> { label: None } }
+	}
+}
+
+#[cfg(not(feature = "tiny-panic"))] // The message is a short code with "tiny-panic"
+#[test]
+#[should_panic(expected = "`twist! -block -label` only breaks the labeled blocks")]
+fn unlabeled_panics () {
+	'b: {
+		twist! { -block -label 'b | Looping::Break::<(), tear::BreakValError> { label: None } }
+	}
+}
+
+#[test] fn track () {
+	let mut broke = false;
+	let mut sum = 0;
+	'b: {
+		for &v in &[1, 2, -1, 3] {
+			sum += twist! { -track broke -block -with 'b | check(v) };
+		}
+	}
+	assert_eq![ sum, 3 ];
+	assert![ broke ];
+}
+
+#[test] fn label_into () {
+	let x :i64 = 'b: {
+		for v in 0..10u8 {
+			twist! { -block -label 'b: into i64 | if v == 4 { breakval!(0, v) } else { go!(()) } }
+		}
+		-1
+	};
+	assert_eq![ x, 4 ];
+}
@@ -0,0 +1,76 @@
+// The panics of the `-label` forms of `twist!` go through the shared cold functions of
+// `twist_impl`, instead of formatting the message in each expansion
+// With "tiny-panic", the messages are short codes, tested in tests/tiny_panic.rs
+#![cfg(not(feature = "tiny-panic"))]
+#![allow(clippy::never_loop)] // Loops that only run once are the point of these tests
+
+use std::panic;
+use std::sync::{Arc, Mutex};
+use tear::{twist, anybox, Looping};
+
+// Runs `f`, and returns the file where it panicked and the panic message
+fn panic_site (f :impl FnOnce() + panic::UnwindSafe) -> (String, String) {
+	let site = Arc::new(Mutex::new(None));
+	let hook_site = site.clone();
+	panic::set_hook(Box::new(move |info| {
+		let file = info.location().map(|l| l.file().to_string()).unwrap_or_default();
+		let msg = info.payload().downcast_ref::<String>().cloned()
+			.or_else(|| info.payload().downcast_ref::<&str>().map(|s| s.to_string()))
+			.unwrap_or_default();
+		*hook_site.lock().unwrap() = Some((file, msg));
+	}));
+	let r = panic::catch_unwind(f);
+	let _ = panic::take_hook();
+	assert![ r.is_err() ];
+	let site = site.lock().unwrap().take();
+	site.unwrap()
+}
+
+fn assert_shared (f :impl FnOnce() + panic::UnwindSafe, expected :&str) {
+	let (file, msg) = panic_site(f);
+	// With "track-caller", the location is the `twist!` call instead, see tests/track_caller.rs
+	#[cfg(not(feature = "track-caller"))]
+	assert![ file.ends_with("twist_impl.rs"), "panicked in {}", file ];
+	#[cfg(feature = "track-caller")]
+	assert_eq![ file, file!() ];
+	assert![ msg.starts_with(expected), "{:?} doesn't start with {:?}", msg, expected ];
+}
+
+// A single test, as the panic hook is global
+#[test] fn shared_helpers () {
+	assert_shared(|| 'a: loop {
+		twist! { -label 'a | Looping::Break::<(), ()> { label: Some(3) } }
+	}, "invalid label index 3 (twist! declared 1 labels)");
+
+	assert_shared(|| 'a: loop {
+		twist! { -label 'a | Looping::Continue::<(), ()> { label: Some(2) } }
+	}, "invalid label index 2 (twist! declared 1 labels)");
+
+	assert_shared(|| { let _ = 'a: loop {
+		loop {
+			twist! { -label 'a :i32 | Looping::BreakVal::<(), i32> { label: Some(1), value: 1 } }
+		}
+	}; }, "invalid label index 1 (twist! declared 1 labels)");
+
+	assert_shared(|| 'a: loop {
+		twist! { -label 'a | Looping::Redo::<(), ()> { label: None } }
+	}, tear::TearErrorKind::RedoWithoutTredo.message());
+
+	assert_shared(|| { let _ = 'a: loop {
+		let _ :i32 = loop {
+			twist! { -val i32, -label 'a :i32 | Looping::Break::<(), i32> { label: None } }
+		};
+	}; }, tear::TearErrorKind::BreakWithoutVal.message());
+
+	assert_shared(|| { let _ = 'a: loop {
+		loop {
+			twist! { -box -label 'a :i32 | Looping::BreakVal::<(), _> { label: Some(0), value: anybox!("5") } }
+		}
+	}; }, "At label 'a with type i32 (got TypeId");
+
+	assert_shared(|| 'a: loop {
+		loop {
+			twist! { -box -label 'a | Looping::Continue::<(), Box<dyn std::any::Any>> { label: Some(1) } }
+		}
+	}, "invalid label index 1 (twist! declared 1 labels)");
+}
@@ -0,0 +1,74 @@
+// We test that the early returns through the cold function keep their values
+#![cfg(feature = "cold-path")]
+
+use tear::prelude::*;
+
+#[derive(Debug, PartialEq, Clone)]
+struct Big([u64; 8]);
+
+#[derive(Debug, PartialEq)]
+struct Error(String);
+
+impl From<&str> for Error {
+	fn from (s :&str) -> Self { Error(s.to_string()) }
+}
+
+#[test] fn tear_forms () {
+	fn plain (v :ValRet<i32, Big>) -> Big {
+		let v = tear! { v };
+		Big([v as u64; 8])
+	}
+	fn ignore (v :Option<i32>) -> &'static str {
+		tear! { v =>> "none" };
+		"some"
+	}
+	fn mapped (v :Result<i32, String>) -> String {
+		tear! { v => |e :String| e + "!" };
+		String::new()
+	}
+
+	assert_eq![ plain(Ret(Big([7; 8]))), Big([7; 8]) ];
+	assert_eq![ plain(Val(2)), Big([2; 8]) ];
+	assert_eq![ ignore(None), "none" ];
+	assert_eq![ ignore(Some(1)), "some" ];
+	assert_eq![ mapped(Err("stop".to_string())), "stop!" ];
+}
+
+#[test] fn terror_forms () {
+	fn plain (v :Result<i32, &str>) -> Result<i32, Error> {
+		Ok(terror! { v } + 1)
+	}
+	fn ignore (v :Option<i32>) -> Result<i32, Error> {
+		Ok(terror! { v =>> "missing" })
+	}
+	fn mapped (v :Result<i32, Big>) -> Result<i32, Big> {
+		Ok(terror! { v => |mut b :Big| { b.0[0] = 1; b } })
+	}
+	fn annotated (v :Result<i32, u8>) -> Result<i32, u32> {
+		Ok(terror! { v => Into::into : u32 })
+	}
+
+	assert_eq![ plain(Err("bad")), Err(Error("bad".to_string())) ];
+	assert_eq![ plain(Ok(1)), Ok(2) ];
+	assert_eq![ ignore(None), Err(Error("missing".to_string())) ];
+	let mut expected = Big([5; 8]);
+	expected.0[0] = 1;
+	assert_eq![ mapped(Err(Big([5; 8]))), Err(expected) ];
+	assert_eq![ annotated(Err(3)), Err(3) ];
+}
+
+#[test] fn borrowed () {
+	fn first<'a> (v :&'a [&'a str]) -> Option<&'a str> {
+		let s = terror! { v.first() };
+		Some(*s)
+	}
+	fn longest<'a> (a :&'a str, b :&'a str) -> &'a str {
+		tear! { if a.len() >= b.len() { Ret(a) } else { Val(()) } };
+		b
+	}
+
+	assert_eq![ first(&["a", "b"]), Some("a") ];
+	assert_eq![ first(&[]), None ];
+	assert_eq![ longest("abc", "d"), "abc" ];
+	assert_eq![ longest("a", "de"), "de" ];
+}
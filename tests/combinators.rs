@@ -3,6 +3,7 @@
 
 use tear::prelude::*;
 use tear::Judge;
+use tear::Moral::{self, Good, Bad};
 use either::Either::*;
 
 #[test] fn side_works () {
@@ -11,3 +12,46 @@ use either::Either::*;
 	let v = v.side().map_left(|_| 46).map_right(|x| x * 2);
 	assert_eq![ v, Right(4) ];
 }
+
+#[test] fn moral_round_trip () {
+	let good = || Good::<i32, &str>(1);
+	let bad = || Bad::<i32, &str>("e");
+
+	assert_eq![ good().into_either(), Right(1) ];
+	assert_eq![ Moral::from_either(good().into_either()), good() ];
+	assert_eq![ Moral::from(bad().into_either()), bad() ];
+
+	// Good on the left
+	assert_eq![ good().side_left(), Left(1) ];
+	assert_eq![ bad().side_left(), Right("e") ];
+	assert_eq![ Moral::from(good().side_left().flip()), good() ];
+	assert_eq![ Moral::from(bad().side_left().flip()), bad() ];
+
+	// Good on the right
+	assert_eq![ good().side_right(), good().side() ];
+	assert_eq![ Moral::from(bad().side_right()), bad() ];
+}
+
+#[test] fn valret_round_trip () {
+	let val = || Val::<i32, &str>(1);
+	let ret = || Ret::<i32, &str>("e");
+
+	assert_eq![ val().into_either(), Right(1) ];
+	assert_eq![ ret().into_either(), Left("e") ];
+	assert_eq![ ValRet::from_either(val().into_either()), val() ];
+	assert_eq![ ValRet::from(ret().into_either()), ret() ];
+}
+
+#[test] fn pipeline () {
+	fn parse (s :&str) -> Result<i32, String> {
+		let e = s.parse::<i32>().side()
+			.map_left(|e| e.to_string())
+			.either(|e| if s.is_empty() { Right(0) } else { Left(e) }, Right);
+		let v = terror! { Moral::from(e) };
+		Ok(v * 2)
+	}
+
+	assert_eq![ parse("2"), Ok(4) ];
+	assert_eq![ parse(""), Ok(0) ];
+	assert_eq![ parse("x"), Err("invalid digit found in string".to_string()) ];
+}
@@ -0,0 +1,96 @@
+// Testing `compat::guard!` with the usage of the `guard` crate
+#![cfg(feature = "compat")]
+
+use tear::compat::guard;
+use tear::prelude::*;
+use tear::{next, last};
+use std::collections::HashMap;
+
+#[derive(Debug)]
+enum Shape {
+	Circle(f64),
+	Rect(f64, f64),
+	Point,
+}
+
+fn width (s :&Shape) -> Option<f64> {
+	guard!(let Shape::Circle(r) | Shape::Rect(r, _) = *s else { return None });
+	Some(r)
+}
+
+#[test] fn or_pattern () {
+	assert_eq![ width(&Shape::Circle(2.)), Some(2.) ];
+	assert_eq![ width(&Shape::Rect(3., 1.)), Some(3.) ];
+	assert_eq![ width(&Shape::Point), None ];
+}
+
+// Several bindings, that `terror!` can't give
+fn area (s :Shape) -> Result<f64, String> {
+	guard!(let Shape::Rect(w, h) = s else { return Err(format!("not a rectangle: {:?}", s)) });
+	Ok(w * h)
+}
+
+#[test] fn bindings () {
+	assert_eq![ area(Shape::Rect(2., 3.)), Ok(6.) ];
+	assert_eq![ area(Shape::Point), Err("not a rectangle: Point".to_string()) ];
+}
+
+#[test] fn loops () {
+	let ages :HashMap<_, _> = vec![("ann", 30), ("bob", 20)].into_iter().collect();
+	let mut total = 0;
+	let mut initials = String::new();
+	// Stops at the empty name, and skips the unknown ones
+	for name in &["ann", "eve", "bob", "", "ann"] {
+		guard!(let Some(initial) = name.chars().next() else { break });
+		guard!(let Some(age) = ages.get(name) else { continue });
+		initials.push(initial);
+		total += age;
+	}
+	assert_eq![ (total, initials), (50, "ab".to_string()) ];
+}
+
+#[test] fn old_syntax () {
+	let f = |v :Option<&str>| -> usize {
+		guard!({ return 0 } unless let Some(s) = v);
+		s.len()
+	};
+	assert_eq![ f(Some("abc")), 3 ];
+	assert_eq![ f(None), 0 ];
+}
+
+// Same thing with `guard!` and the native forms
+#[test] fn native () {
+	let parse = |s :&str| -> Result<i32, String> {
+		guard!(let Ok(a) = s.parse::<i32>() else { return Err("nope".to_string()) });
+		let b = terror! { s.parse::<i32>() =>> "nope".to_string() };
+		Ok(a + b)
+	};
+	assert_eq![ parse("2"), Ok(4) ];
+	assert_eq![ parse("x"), Err("nope".to_string()) ];
+
+	let sum = |values :&[Option<i32>]| -> (i32, i32) {
+		let (mut a, mut b) = (0, 0);
+		for v in values {
+			guard!(let Some(x) = *v else { continue });
+			a += x;
+		}
+		for v in values {
+			b += twist! { *v =>> next!() };
+		}
+		(a, b)
+	};
+	assert_eq![ sum(&[Some(1), None, Some(2)]), (3, 3) ];
+
+	let first = |values :&[Option<i32>]| -> (i32, i32) {
+		let (mut a, mut b) = (0, 0);
+		for v in values {
+			guard!(let Some(x) = *v else { break });
+			a += x;
+		}
+		for v in values {
+			b += twist! { *v =>> last!() };
+		}
+		(a, b)
+	};
+	assert_eq![ first(&[Some(1), None, Some(2)]), (1, 1) ];
+}
@@ -0,0 +1,84 @@
+// Testing the "anyhow" and "eyre" features
+#![cfg(any(feature = "anyhow", feature = "eyre"))]
+
+use tear::prelude::*;
+use std::io;
+
+fn open (path :&str) -> io::Result<()> {
+	Err(io::Error::new(io::ErrorKind::NotFound, format!("{} not found", path)))
+}
+
+fn check (v :i32) -> Result<i32, String> {
+	if v > 0 { Ok(v) } else { Err(format!("{} is not positive", v)) }
+}
+
+#[cfg(feature = "anyhow")]
+mod anyhow_ctx {
+	use super::*;
+	use tear::ctx;
+
+	fn read (path :&str) -> anyhow::Result<()> {
+		terror! { open(path) => ctx!("reading {}", path) };
+		Ok(())
+	}
+
+	#[test] fn std_error_chain () {
+		let e = read("a.txt").unwrap_err();
+		assert_eq![ e.to_string(), "reading a.txt" ];
+		assert_eq![ format!("{:#}", e), "reading a.txt: a.txt not found" ];
+		assert_eq![ e.chain().count(), 2 ];
+	}
+
+	#[test] fn string_error_chain () {
+		fn f (v :i32) -> anyhow::Result<i32> {
+			let v = terror! { check(v) => ctx!("checking {}", v) };
+			Ok(v)
+		}
+
+		assert_eq![ f(1).unwrap(), 1 ];
+		assert_eq![ format!("{:#}", f(-1).unwrap_err()), "checking -1: -1 is not positive" ];
+	}
+
+	#[test] fn context_is_lazy () {
+		use std::cell::Cell;
+		let formatted = Cell::new(0);
+		let count = || { formatted.set(formatted.get() + 1); "count" };
+
+		let f = |v :i32| -> anyhow::Result<i32> {
+			Ok(terror! { check(v) => ctx!("{}", count()) })
+		};
+
+		assert_eq![ f(1).unwrap(), 1 ];
+		assert_eq![ formatted.get(), 0 ];
+		assert![ f(0).is_err() ];
+		assert_eq![ formatted.get(), 1 ];
+	}
+}
+
+#[cfg(feature = "eyre")]
+mod eyre_wrap {
+	use super::*;
+	use tear::wrap_err;
+
+	fn read (path :&str) -> eyre::Result<()> {
+		terror! { open(path) => wrap_err!("reading {}", path) };
+		Ok(())
+	}
+
+	#[test] fn std_error_chain () {
+		let e = read("a.txt").unwrap_err();
+		let chain :Vec<String> = e.chain().map(|e| e.to_string()).collect();
+		assert_eq![ chain, vec!["reading a.txt".to_string(), "a.txt not found".to_string()] ];
+	}
+
+	#[test] fn string_error_chain () {
+		fn f (v :i32) -> eyre::Result<i32> {
+			let v = terror! { check(v) => wrap_err!("checking {}", v) };
+			Ok(v)
+		}
+
+		let e = f(-1).unwrap_err();
+		let chain :Vec<String> = e.chain().map(|e| e.to_string()).collect();
+		assert_eq![ chain, vec!["checking -1".to_string(), "-1 is not positive".to_string()] ];
+	}
+}
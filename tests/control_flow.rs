@@ -0,0 +1,121 @@
+// Testing the "control-flow" feature
+#![cfg(feature = "control-flow")]
+
+use std::ops::ControlFlow;
+use tear::twist;
+use tear::Looping;
+use tear::assert_looping;
+
+// Breaks on the first multiple of `n`
+fn searcher (v :i32, n :i32) -> ControlFlow<i32, i32> {
+	if v % n == 0 { ControlFlow::Break(v) } else { ControlFlow::Continue(v) }
+}
+
+#[test] fn from_control_flow () {
+	assert_looping! { Looping::from(ControlFlow::<i32, ()>::Break(3)), BreakVal(None, 3) };
+	assert_looping! { Looping::from(ControlFlow::<i32, ()>::Continue(())), Resume(()) };
+	assert_looping! { Looping::from_control_flow(ControlFlow::<(), i32>::Break(())), Break };
+	assert_looping! { Looping::from_control_flow(ControlFlow::<(), i32>::Continue(1)), Resume(1) };
+}
+
+#[test] fn into_control_flow () {
+	let signals :[Looping<i32, &str>; 7] = [
+		Looping::Resume(1),
+		Looping::BreakVal { label: None, value: "b" },
+		Looping::BreakVal { label: Some(0), value: "b" },
+		Looping::Break { label: None },
+		Looping::Break { label: Some(1) },
+		Looping::Continue { label: None },
+		Looping::Continue { label: Some(1) },
+	];
+	let cfs = signals.iter().cloned().map(Looping::into_control_flow).collect::<Vec<_>>();
+	assert_eq![ cfs, [
+		Some(ControlFlow::Continue(1)),
+		Some(ControlFlow::Break("b")),
+		None,
+		None,
+		None,
+		None,
+		None,
+	] ];
+	let cfs = signals.iter().cloned().map(Looping::to_control_flow).collect::<Vec<_>>();
+	assert_eq![ cfs, [
+		ControlFlow::Continue(Some(1)),
+		ControlFlow::Break(Some("b")),
+		ControlFlow::Break(Some("b")),
+		ControlFlow::Break(None),
+		ControlFlow::Break(None),
+		ControlFlow::Continue(None),
+		ControlFlow::Continue(None),
+	] ];
+}
+
+// The round trip keeps the representable signals
+#[test] fn round_trip () {
+	for cf in [ControlFlow::Break(3), ControlFlow::Continue("a")].iter() {
+		assert_eq![ Looping::from(*cf).into_control_flow(), Some(*cf) ];
+	}
+}
+
+// Reuses a `try_for_each` closure as a signal producer, and a signal producer in `try_for_each`
+#[test] fn reuse_closures () {
+	let first_even = |v :&i32| if v % 2 == 0 { ControlFlow::Break(*v) } else { ControlFlow::Continue(()) };
+	let mut values = [1, 3, 6, 7].iter();
+	let x = loop {
+		let signal = Looping::from(first_even(values.next().unwrap()));
+		twist! { -val signal }
+	};
+	assert_eq![ x, 6 ];
+
+	let over_5 = |v :&i32| -> Looping<(), i32> {
+		if *v > 5 { Looping::BreakVal { label: None, value: *v } } else { Looping::Resume(()) }
+	};
+	let r = [1, 7, 9].iter().try_for_each(|v| over_5(v).into_control_flow().unwrap());
+	assert_eq![ r, ControlFlow::Break(7) ];
+}
+
+#[test] fn break_with_value () {
+	let mut seen = Vec::new();
+	let mut values = 1..;
+	let x = loop {
+		let v = twist! { -val -cf searcher(values.next().unwrap(), 4) };
+		seen.push(v);
+		if v > 10 { break 0; }
+	};
+	assert_eq![ x, 4 ];
+	assert_eq![ seen, vec![1, 2, 3] ];
+}
+
+#[test] fn continue_and_break () {
+	let cf = |v :i32| -> ControlFlow<(), i32> {
+		if v > 4 { ControlFlow::Break(()) } else { ControlFlow::Continue(v) }
+	};
+	let mut seen = Vec::new();
+	for v in 0..10 {
+		let v = twist! { -cf cf(v) };
+		if v % 2 == 1 { continue; }
+		seen.push(v);
+	}
+	assert_eq![ seen, vec![0, 2, 4] ];
+}
+
+#[test] fn try_for_each () {
+	let rows = [vec![1, 3], vec![5, 8, 9], vec![10]];
+	let mut rows = rows.iter();
+	let x = loop {
+		let row = rows.next().unwrap();
+		twist! { -val -cf row.iter().try_for_each(|&v| {
+			if v % 2 == 0 { ControlFlow::Break(v) } else { ControlFlow::Continue(()) }
+		}) }
+	};
+	assert_eq![ x, 8 ];
+}
+
+#[test] fn finally () {
+	let mut closed = 0;
+	let x = loop {
+		let _ = twist! { -finally { closed += 1; } -val -cf searcher(4, 2) };
+	};
+	assert_eq![ x, 4 ];
+	assert_eq![ closed, 1 ];
+}
@@ -0,0 +1,49 @@
+// Testing the "defmt" feature, by logging into a mock logger that counts the bytes it receives
+#![cfg(feature = "defmt")]
+
+use tear::{Looping, Maru};
+use tear::ValRet::{self, Val, Ret};
+use tear::Moral::{self, Good, Bad};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+
+static LOCK :Mutex<()> = Mutex::new(());
+static BYTES :AtomicUsize = AtomicUsize::new(0);
+
+#[defmt::global_logger]
+struct MockLogger;
+
+unsafe impl defmt::Logger for MockLogger {
+	fn acquire () {}
+	unsafe fn flush () {}
+	unsafe fn release () {}
+	unsafe fn write (bytes :&[u8]) {
+		BYTES.fetch_add(bytes.len(), Ordering::SeqCst);
+	}
+}
+
+defmt::timestamp!("{=u8}", 0);
+
+// Bytes written when logging `value`
+fn logged<T :defmt::Format> (value :T) -> usize {
+	let _guard = LOCK.lock().unwrap();
+	let before = BYTES.load(Ordering::SeqCst);
+	defmt::println!("{}", value);
+	BYTES.load(Ordering::SeqCst) - before
+}
+
+// Only checks that the payloads are encoded, as the format strings are interned
+#[test] fn format () {
+	let v :ValRet<u32, u8> = Val(1);
+	let r :ValRet<u32, u8> = Ret(1);
+	assert![ logged(v) > logged(r) ];
+
+	let g :Moral<u32, Maru> = Good(1);
+	let b :Moral<u32, Maru> = Bad(Maru);
+	assert![ logged(g) > logged(b) ];
+
+	let resume :Looping<u64, ()> = Looping::Resume(1);
+	let brk :Looping<u64, ()> = Looping::Break { label: None };
+	assert![ logged(resume) > logged(brk) ];
+	assert![ logged(Looping::<(), u64>::BreakVal { label: Some(1), value: 2 }) > logged(Looping::<(), u64>::Continue { label: Some(1) }) ];
+}
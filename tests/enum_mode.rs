@@ -0,0 +1,134 @@
+// We test the twist! -enum syntax, porting some of the -box tests of tests/label.rs
+
+use tear::twist;
+use tear::{stop, skip, go};
+use tear::Looping;
+
+// All compile fail errors go here
+#[cfg(not(any(feature = "experimental", feature = "ignore-ui")))] // Feature flags to ignore test
+#[test] fn bad_input () {
+	use trybuild;
+	let t = trybuild::TestCases::new();
+	t.compile_fail("tests/enum_mode/*.rs");
+}
+
+#[test] fn just_break () {
+	let mut x = 0;
+	'a: loop {
+		loop {
+			twist! { -enum Sig -label 'a | stop!() }
+			panic!("Should break before this");
+		}
+		x = 1;
+		break;
+	}
+	assert_eq![ x, 1, "Only broke the innermost loop" ];
+}
+
+#[test] fn resume () {
+	let v = 'a: loop {
+		loop {
+			let x = twist! { -enum Sig -label 'a as A: i32 | go!(5) };
+			break 'a x;
+		}
+	};
+	assert_eq![ v, 5 ];
+}
+
+#[test] fn enum_breakval () {
+	let mut i = 0;
+	let mut step = || { i += 1; i - 1 };
+	
+	'a: loop {
+		let b = 'b: loop {
+			let c = 'c: loop {
+				loop {
+					twist! { -enum Sig -label 'a, 'b as B: i32, 'c as C: String |
+						match step() {
+							0 => Looping::BreakVal { label: None, value: Sig::B(2) },
+							1 => Looping::BreakVal { label: None, value: Sig::C("yeah".to_string()) },
+							_ => Looping::Break { label: Some(0) },
+						}
+					}
+					break;
+				}
+			};
+			assert_eq![ c, "yeah".to_string() ]; println!("1/2");
+		};
+		assert_eq![ b, 2 ]; println!("2/2");
+	}
+}
+
+#[test] fn enum_breakval_innermost () {
+	let mut i = 0;
+	'a: loop {
+		let v = loop {
+			i += 1;
+			twist! { -enum Sig -val i32, -label 'a |
+				match i {
+					1 => Looping::BreakVal { label: None, value: Sig::Innermost(0) },
+					_ => Looping::Break { label: Some(0) },
+				}
+			}
+		};
+		assert_eq![ v, 0 ]; println!("1/1");
+	}
+}
+
+#[test] fn continue_typed_label () {
+	let mut count = 0;
+	let x = 'a: loop {
+		count += 1;
+		loop {
+			twist! { -enum Sig -label 'a as A: i32 |
+				if count < 3 { skip!(0) } else { Looping::BreakVal { label: None, value: Sig::A(count) } }
+			}
+		}
+	};
+	assert_eq![ x, 3 ];
+}
+
+#[test] fn ignore_bad () {
+	let x = 'a: loop {
+		loop {
+			twist! { -enum Sig -label 'a as A: &'static str | None::<()> =>> Looping::BreakVal { label: None, value: Sig::A("none") } }
+		}
+	};
+	assert_eq![ x, "none" ];
+}
+
+#[test] fn try_invalid_label () {
+	use tear::TwistError;
+	let x = 'a: loop {
+		loop {
+			let r = twist! { -try -enum Sig -label 'a as A: i32 | stop!(3) };
+			assert_eq![ r, Err::<(), _>(TwistError::InvalidLabel { index: 3, max: 1 }) ];
+			break 'a 1;
+		}
+	};
+	assert_eq![ x, 1 ];
+}
+
+#[test] fn trailing_comma () {
+	let x = 'a: loop {
+		'b: loop {
+			twist! { -enum Sig -label 'a as A: i32, 'b, | Looping::BreakVal::<(), _> { label: None, value: Sig::A(4) } }
+		}
+	};
+	assert_eq![ x, 4 ];
+}
+
+// The variant chooses the loop, not the label of BreakVal
+#[test] fn breakval_label_ignored () {
+	let v = 'a: loop {
+		let _ = 'b: loop {
+			loop {
+				twist! { -enum Sig -label 'a as A: i32, 'b as B: &'static str |
+					Looping::BreakVal::<(), _> { label: Some(1), value: Sig::A(4) }
+				}
+			}
+		};
+		panic!("Should break 'a");
+	};
+	assert_eq![ v, 4 ];
+}
@@ -0,0 +1,5 @@
+use tear::twist;
+
+fn main () {
+	twist! { -enum Sig -label 'a: i32 | tear::stop!() }
+}
@@ -0,0 +1,12 @@
+use tear::twist;
+use tear::Looping;
+
+fn main () {
+	let _ = 'a: loop {
+		loop {
+			twist! { -enum Sig -label 'a as A: i32 |
+				Looping::BreakVal::<(), _> { label: None, value: Sig::A("not an i32") }
+			}
+		}
+	};
+}
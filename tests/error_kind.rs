@@ -0,0 +1,70 @@
+// Testing `TearErrorKind`, the kinds of panics of `twist!`, and the deprecated message constants
+// With "tiny-panic", the messages are short codes, tested in tests/tiny_panic.rs
+#![cfg(not(feature = "tiny-panic"))]
+
+use tear::{twist, TearErrorKind, TwistError, Looping};
+
+const KINDS :[TearErrorKind; 7] = [
+	TearErrorKind::BreakValInNotLoop,
+	TearErrorKind::BreakWithoutVal,
+	TearErrorKind::BadBreakValType,
+	TearErrorKind::ContinueInBlock,
+	TearErrorKind::UnlabeledInBlock,
+	TearErrorKind::RedoWithoutTredo,
+	TearErrorKind::InvalidLabel,
+];
+
+#[test] fn messages () {
+	assert![ TearErrorKind::BreakValInNotLoop.message().starts_with("error[E0571]") ];
+	assert![ TearErrorKind::BreakWithoutVal.message().contains("Breaking without a value when using `twist -val`") ];
+	assert![ TearErrorKind::BadBreakValType.message().contains("Looping::BreakVal has a value type different") ];
+	assert![ TearErrorKind::ContinueInBlock.message().starts_with("error[E0696]") ];
+	assert![ TearErrorKind::UnlabeledInBlock.message().starts_with("error[E0268]") ];
+	assert![ TearErrorKind::RedoWithoutTredo.message().starts_with("Looping::Redo runs the loop body again") ];
+	assert_eq![ TearErrorKind::InvalidLabel.message(), "invalid label index" ];
+}
+
+#[test] fn display_is_message () {
+	for kind in KINDS.iter() {
+		assert_eq![ kind.to_string(), kind.message() ];
+	}
+}
+
+#[test]
+#[allow(deprecated)]
+fn deprecated_constants () {
+	assert_eq![ tear::BREAKVAL_IN_NOT_LOOP, TearErrorKind::BreakValInNotLoop.message() ];
+	assert_eq![ tear::BREAK_WITHOUT_VAL, TearErrorKind::BreakWithoutVal.message() ];
+	assert_eq![ tear::BAD_BREAKVAL_TYPE, TearErrorKind::BadBreakValType.message() ];
+}
+
+#[test] fn twist_error_kind () {
+	assert_eq![ TwistError::InvalidLabel { index: 2, max: 1 }.kind(), TearErrorKind::InvalidLabel ];
+	assert_eq![ TwistError::MissingValue.kind(), TearErrorKind::BreakWithoutVal ];
+	assert_eq![ TwistError::BadType { expected: "i32" }.kind(), TearErrorKind::BadBreakValType ];
+
+	'a: loop {
+		let r = twist! { -try -label 'a | Looping::Break::<(), ()> { label: Some(1) } };
+		assert_eq![ r.map_err(|e| e.kind()), Err(TearErrorKind::InvalidLabel) ];
+		break;
+	}
+}
+
+// The panics of `twist!` have the message of their kind
+#[test]
+#[should_panic(expected = "Breaking without a value when using `twist -val`")]
+fn panics_with_message () {
+	let _ = loop {
+		twist! { -val Looping::Break::<(), i32> { label: None } }
+	};
+}
+
+// The invalid label panics add the label index and the number of labels to the message
+#[test] fn invalid_label_panic_has_message () {
+	let payload = std::panic::catch_unwind(|| 'a: loop {
+		twist! { -label 'a | Looping::Break::<(), ()> { label: Some(3) } }
+	}).unwrap_err();
+	let message = payload.downcast::<String>().unwrap();
+	assert![ message.starts_with(TearErrorKind::InvalidLabel.message()) ];
+	assert![ message.starts_with("invalid label index 3 (twist! declared 1 labels)") ];
+}
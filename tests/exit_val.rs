@@ -0,0 +1,48 @@
+// Testing `std_support::ExitVal`
+#![cfg(feature = "std")]
+
+use tear::prelude::*;
+use tear::Judge;
+use tear::std_support::ExitVal;
+use std::process::{ExitCode, Termination};
+
+fn run (fail :bool) -> Result<i32, std::num::ParseIntError> {
+	if fail { "x".parse() } else { "1".parse() }
+}
+
+fn main_with_code (fail :bool) -> ExitVal {
+	terror! { run(fail) => |e| ExitVal::fail(2, e) };
+	ExitVal::success()
+}
+
+fn main_from (fail :bool) -> ExitVal {
+	terror! { run(fail) };
+	ExitVal::success()
+}
+
+#[test] fn fail () {
+	let e = main_with_code(true);
+	assert_eq![ (e.code(), e.message()), (2, Some("invalid digit found in string")) ];
+	assert![ !e.is_success() ];
+	assert_eq![ main_with_code(false), ExitVal::success() ];
+}
+
+#[test] fn from_errors () {
+	let e = main_from(true);
+	assert_eq![ (e.code(), e.message()), (1, Some("invalid digit found in string")) ];
+	assert_eq![ main_from(false), ExitVal::success() ];
+
+	assert_eq![ ExitVal::from("bad").message(), Some("bad") ];
+	assert_eq![ ExitVal::from(String::from("bad")).code(), 1 ];
+}
+
+#[test] fn judge () {
+	assert![ ExitVal::success().into_moral().good().is_some() ];
+	assert_eq![ ExitVal::code_only(3).into_moral().bad(), Some(ExitVal::code_only(3)) ];
+}
+
+#[test] fn report () {
+	assert_eq![ ExitVal::success().report(), ExitCode::SUCCESS ];
+	assert_eq![ ExitVal::fail(2, "oops").report(), ExitCode::from(2) ];
+	assert_eq![ ExitVal::code_only(1).report(), ExitCode::FAILURE ];
+}
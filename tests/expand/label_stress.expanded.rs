@@ -0,0 +1,2430 @@
+#![feature(prelude_import)]
+// A stress fixture for the parsing of long label lists: each form of `twist!` gets 16 labels under
+// a low recursion limit, which fails to compile if the label lists are parsed one token per step
+// (the expansion is the same either way, only the number of steps changes). `many` has a lot of
+// shorter invocations. `expansion_snapshot` compares the expansion of this file with a snapshot
+#![recursion_limit = "80"]
+extern crate std;
+#[prelude_import]
+use std::prelude::rust_2018::*;
+
+use tear::twist;
+use tear::Looping;
+
+// Continues the innermost loop, then the middle one, resumes, and breaks the outermost one with
+// `value()`. `labels` are the indices of those three loops
+fn signal<B>(step: &mut i32, labels: [usize; 3], value: impl FnOnce() -> B)
+    -> Looping<i32, B> {
+    *step += 1;
+    match *step {
+        1 => Looping::Continue { label: Some(labels[0]) },
+        2 => Looping::Continue { label: Some(labels[1]) },
+        3 => Looping::Resume(*step),
+        _ => Looping::BreakVal { label: Some(labels[2]), value: value() },
+    }
+}
+
+extern crate test;
+#[rustc_test_marker = "label"]
+#[doc(hidden)]
+pub const label: test::TestDescAndFn =
+    test::TestDescAndFn {
+        // The loops are nested, but not indented
+        desc: test::TestDesc {
+            name: test::StaticTestName("label"),
+            ignore: false,
+            ignore_message: ::core::option::Option::None,
+            source_file: "tests/label_stress.rs",
+            start_line: 22usize,
+            start_col: 12usize,
+            end_line: 22usize,
+            end_col: 17usize,
+            compile_fail: false,
+            no_run: false,
+            should_panic: test::ShouldPanic::No,
+            test_type: test::TestType::IntegrationTest,
+        },
+        testfn: test::StaticTestFn(#[coverage(off)] ||
+                test::assert_test_result(label())),
+    };
+fn label() {
+    let mut step = 0;
+    let mut resumed = 0;
+    let x =
+        'l0: loop {
+            'l1: loop {
+                'l2: loop {
+                    'l3: loop {
+                        'l4: loop {
+                            'l5: loop {
+                                'l6: loop {
+                                    'l7: loop {
+                                        'l8: loop {
+                                            'l9: loop {
+                                                'l10: loop {
+                                                    'l11: loop {
+                                                        'l12: loop {
+                                                            'l13: loop {
+                                                                'l14: loop {
+                                                                    'l15: loop {
+                                                                        resumed =
+                                                                            // The loops are nested, but not indented
+
+                                                                            // The loops are nested, but not indented
+
+                                                                            // The loops are nested, but not indented
+
+                                                                            // The loops are nested, but not indented
+
+                                                                            // Many invocations of the other forms, that all resume
+
+                                                                            // The signal for the -enum invocations, whose enum can't be named outside of the macro
+
+                                                                            // Compare the expansion of this file with `tests/expand/label_stress.expanded.rs`, to check that
+                                                                            // a change in the parsing steps doesn't change the generated code. It needs a nightly toolchain
+                                                                            // for `-Zunpretty=expanded`, so it's ignored by default:
+                                                                            //     cargo test --test label_stress -- --ignored
+                                                                            // Set `TEAR_EXPAND=overwrite` to update the snapshot, eg. after changing what `twist!` generates
+                                                                            // Read at run time, so that the path isn't in the snapshot
+                                                                            match ::tear::IntoLooping::into_looping(signal(&mut step,
+                                                                                        [15, 7, 0], || 4))
+                                                                                {ref signal if
+                                                                                    ::tear::twist_impl::__check_label(signal, 16,
+                                                                                        "signal(&mut step, [15, 7, 0], || 4)") =>
+                                                                                    ::core::panicking::panic("internal error: entered unreachable code"),
+                                                                                ::tear::Looping::Resume(v) => {
+                                                                                    #[allow(unused_macros)]
+                                                                                    macro_rules! __tear_duplicate_label {
+                                                                                        ('l1 [x]) => {}; ('l1 $other : tt) =>
+                                                                                        {
+                                                                                            compile_error!
+                                                                                            (concat!
+                                                                                            ("duplicate label ", stringify! ('l1),
+                                                                                            " in twist! -label list"))
+                                                                                        }; ('l2 [x x]) => {}; ('l2 $other : tt) =>
+                                                                                        {
+                                                                                            compile_error!
+                                                                                            (concat!
+                                                                                            ("duplicate label ", stringify! ('l2),
+                                                                                            " in twist! -label list"))
+                                                                                        }; ('l3 [x x x]) => {}; ('l3 $other : tt) =>
+                                                                                        {
+                                                                                            compile_error!
+                                                                                            (concat!
+                                                                                            ("duplicate label ", stringify! ('l3),
+                                                                                            " in twist! -label list"))
+                                                                                        }; ('l4 [x x x x]) => {}; ('l4 $other : tt) =>
+                                                                                        {
+                                                                                            compile_error!
+                                                                                            (concat!
+                                                                                            ("duplicate label ", stringify! ('l4),
+                                                                                            " in twist! -label list"))
+                                                                                        }; ('l5 [x x x x x]) => {}; ('l5 $other : tt) =>
+                                                                                        {
+                                                                                            compile_error!
+                                                                                            (concat!
+                                                                                            ("duplicate label ", stringify! ('l5),
+                                                                                            " in twist! -label list"))
+                                                                                        }; ('l6 [x x x x x x]) => {}; ('l6 $other : tt) =>
+                                                                                        {
+                                                                                            compile_error!
+                                                                                            (concat!
+                                                                                            ("duplicate label ", stringify! ('l6),
+                                                                                            " in twist! -label list"))
+                                                                                        }; ('l7 [x x x x x x x]) => {}; ('l7 $other : tt) =>
+                                                                                        {
+                                                                                            compile_error!
+                                                                                            (concat!
+                                                                                            ("duplicate label ", stringify! ('l7),
+                                                                                            " in twist! -label list"))
+                                                                                        }; ('l8 [x x x x x x x x]) => {}; ('l8 $other : tt) =>
+                                                                                        {
+                                                                                            compile_error!
+                                                                                            (concat!
+                                                                                            ("duplicate label ", stringify! ('l8),
+                                                                                            " in twist! -label list"))
+                                                                                        }; ('l9 [x x x x x x x x x]) => {}; ('l9 $other : tt) =>
+                                                                                        {
+                                                                                            compile_error!
+                                                                                            (concat!
+                                                                                            ("duplicate label ", stringify! ('l9),
+                                                                                            " in twist! -label list"))
+                                                                                        }; ('l10 [x x x x x x x x x x]) => {}; ('l10 $other : tt) =>
+                                                                                        {
+                                                                                            compile_error!
+                                                                                            (concat!
+                                                                                            ("duplicate label ", stringify! ('l10),
+                                                                                            " in twist! -label list"))
+                                                                                        }; ('l11 [x x x x x x x x x x x]) => {}; ('l11 $other : tt)
+                                                                                        =>
+                                                                                        {
+                                                                                            compile_error!
+                                                                                            (concat!
+                                                                                            ("duplicate label ", stringify! ('l11),
+                                                                                            " in twist! -label list"))
+                                                                                        }; ('l12 [x x x x x x x x x x x x]) => {};
+                                                                                        ('l12 $other : tt) =>
+                                                                                        {
+                                                                                            compile_error!
+                                                                                            (concat!
+                                                                                            ("duplicate label ", stringify! ('l12),
+                                                                                            " in twist! -label list"))
+                                                                                        }; ('l13 [x x x x x x x x x x x x x]) => {};
+                                                                                        ('l13 $other : tt) =>
+                                                                                        {
+                                                                                            compile_error!
+                                                                                            (concat!
+                                                                                            ("duplicate label ", stringify! ('l13),
+                                                                                            " in twist! -label list"))
+                                                                                        }; ('l14 [x x x x x x x x x x x x x x]) => {};
+                                                                                        ('l14 $other : tt) =>
+                                                                                        {
+                                                                                            compile_error!
+                                                                                            (concat!
+                                                                                            ("duplicate label ", stringify! ('l14),
+                                                                                            " in twist! -label list"))
+                                                                                        }; ('l15 [x x x x x x x x x x x x x x x]) => {};
+                                                                                        ('l15 $other : tt) =>
+                                                                                        {
+                                                                                            compile_error!
+                                                                                            (concat!
+                                                                                            ("duplicate label ", stringify! ('l15),
+                                                                                            " in twist! -label list"))
+                                                                                        }; ('l0 []) => {}; ('l0 $other : tt) =>
+                                                                                        {
+                                                                                            compile_error!
+                                                                                            (concat!
+                                                                                            ("duplicate label ", stringify! ('l0),
+                                                                                            " in twist! -label list"))
+                                                                                        };
+                                                                                    }
+                                                                                    v
+                                                                                }
+                                                                                ::tear::Looping::Break { label: ::core::option::Option::None
+                                                                                    } => {
+                                                                                    ();
+                                                                                    break;
+                                                                                }
+                                                                                ::tear::Looping::Break {
+                                                                                    label: ::core::option::Option::Some(l) } => {
+                                                                                    match l {
+                                                                                        1 => { break 'l1; }
+                                                                                        2 => { break 'l2; }
+                                                                                        3 => { break 'l3; }
+                                                                                        4 => { break 'l4; }
+                                                                                        5 => { break 'l5; }
+                                                                                        6 => { break 'l6; }
+                                                                                        7 => { break 'l7; }
+                                                                                        8 => { break 'l8; }
+                                                                                        9 => { break 'l9; }
+                                                                                        10 => { break 'l10; }
+                                                                                        11 => { break 'l11; }
+                                                                                        12 => { break 'l12; }
+                                                                                        13 => { break 'l13; }
+                                                                                        14 => { break 'l14; }
+                                                                                        15 => { break 'l15; }
+                                                                                        _ => ::tear::twist_impl::__invalid_label(l, 16),
+                                                                                    }
+                                                                                }
+                                                                                ::tear::Looping::Redo { .. } =>
+                                                                                    ::tear::twist_impl::__fail(::tear::TearErrorKind::RedoWithoutTredo),
+                                                                                ::tear::Looping::Continue { label } =>
+                                                                                    match label {
+                                                                                        ::core::option::Option::None => continue,
+                                                                                        ::core::option::Option::Some(1) => continue 'l1,
+                                                                                        ::core::option::Option::Some(2) => continue 'l2,
+                                                                                        ::core::option::Option::Some(3) => continue 'l3,
+                                                                                        ::core::option::Option::Some(4) => continue 'l4,
+                                                                                        ::core::option::Option::Some(5) => continue 'l5,
+                                                                                        ::core::option::Option::Some(6) => continue 'l6,
+                                                                                        ::core::option::Option::Some(7) => continue 'l7,
+                                                                                        ::core::option::Option::Some(8) => continue 'l8,
+                                                                                        ::core::option::Option::Some(9) => continue 'l9,
+                                                                                        ::core::option::Option::Some(10) => continue 'l10,
+                                                                                        ::core::option::Option::Some(11) => continue 'l11,
+                                                                                        ::core::option::Option::Some(12) => continue 'l12,
+                                                                                        ::core::option::Option::Some(13) => continue 'l13,
+                                                                                        ::core::option::Option::Some(14) => continue 'l14,
+                                                                                        ::core::option::Option::Some(15) => continue 'l15,
+                                                                                        ::core::option::Option::Some(0) => continue 'l0,
+                                                                                        ::core::option::Option::Some(l) =>
+                                                                                            ::tear::twist_impl::__invalid_label(l, 16),
+                                                                                    },
+                                                                                ::tear::Looping::BreakVal {
+                                                                                    label: ::core::option::Option::None, .. } => {
+                                                                                    ();
+                                                                                    ::tear::twist_impl::__fail(::tear::TearErrorKind::BreakValInNotLoop);
+                                                                                }
+                                                                                ::tear::Looping::BreakVal {
+                                                                                    label: ::core::option::Option::Some(l), value: v } => {
+                                                                                    match l {
+                                                                                        0 => { break 'l0 v; }
+                                                                                        _ => ::tear::twist_impl::__invalid_label(l, 16),
+                                                                                    }
+                                                                                }
+                                                                            };
+                                                                    }
+                                                                }
+                                                            }
+                                                        }
+                                                    }
+                                                }
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        };
+    match (&(x, resumed), &(4, 3)) {
+        (left_val, right_val) => {
+            if !(*left_val == *right_val) {
+                let kind = ::core::panicking::AssertKind::Eq;
+                ::core::panicking::assert_failed(kind, &*left_val,
+                    &*right_val, ::core::option::Option::None);
+            }
+        }
+    };
+}
+extern crate test;
+#[rustc_test_marker = "label_box"]
+#[doc(hidden)]
+pub const label_box: test::TestDescAndFn =
+    test::TestDescAndFn {
+        desc: test::TestDesc {
+            name: test::StaticTestName("label_box"),
+            ignore: false,
+            ignore_message: ::core::option::Option::None,
+            source_file: "tests/label_stress.rs",
+            start_line: 62usize,
+            start_col: 12usize,
+            end_line: 62usize,
+            end_col: 21usize,
+            compile_fail: false,
+            no_run: false,
+            should_panic: test::ShouldPanic::No,
+            test_type: test::TestType::IntegrationTest,
+        },
+        testfn: test::StaticTestFn(#[coverage(off)] ||
+                test::assert_test_result(label_box())),
+    };
+fn label_box() {
+    let mut step = 0;
+    let mut resumed = 0;
+    let x =
+        'l0: loop {
+            'l1: loop {
+                'l2: loop {
+                    'l3: loop {
+                        'l4: loop {
+                            'l5: loop {
+                                'l6: loop {
+                                    'l7: loop {
+                                        'l8: loop {
+                                            'l9: loop {
+                                                'l10: loop {
+                                                    'l11: loop {
+                                                        'l12: loop {
+                                                            'l13: loop {
+                                                                'l14: loop {
+                                                                    'l15: loop {
+                                                                        resumed =
+                                                                            match ::tear::IntoLooping::into_looping(signal(&mut step,
+                                                                                        [15, 7, 0],
+                                                                                        ||
+                                                                                            {
+                                                                                                let v = 4;
+                                                                                                let b = ::tear::__alloc::boxed::Box::new(v);
+                                                                                                let x =
+                                                                                                    b as ::tear::__alloc::boxed::Box<dyn ::core::any::Any>;
+                                                                                                x
+                                                                                            }))
+                                                                                {ref signal if
+                                                                                    ::tear::twist_impl::__check_label(signal, 16,
+                                                                                        "signal(&mut step, [15, 7, 0], || tear::anybox!(4))") =>
+                                                                                    ::core::panicking::panic("internal error: entered unreachable code"),
+                                                                                ::tear::Looping::Resume(v) => {
+                                                                                    #[allow(unused_macros)]
+                                                                                    macro_rules! __tear_duplicate_label {
+                                                                                        ('l1 [x]) => {}; ('l1 $other : tt) =>
+                                                                                        {
+                                                                                            compile_error!
+                                                                                            (concat!
+                                                                                            ("duplicate label ", stringify! ('l1),
+                                                                                            " in twist! -label list"))
+                                                                                        }; ('l2 [x x]) => {}; ('l2 $other : tt) =>
+                                                                                        {
+                                                                                            compile_error!
+                                                                                            (concat!
+                                                                                            ("duplicate label ", stringify! ('l2),
+                                                                                            " in twist! -label list"))
+                                                                                        }; ('l3 [x x x]) => {}; ('l3 $other : tt) =>
+                                                                                        {
+                                                                                            compile_error!
+                                                                                            (concat!
+                                                                                            ("duplicate label ", stringify! ('l3),
+                                                                                            " in twist! -label list"))
+                                                                                        }; ('l4 [x x x x]) => {}; ('l4 $other : tt) =>
+                                                                                        {
+                                                                                            compile_error!
+                                                                                            (concat!
+                                                                                            ("duplicate label ", stringify! ('l4),
+                                                                                            " in twist! -label list"))
+                                                                                        }; ('l5 [x x x x x]) => {}; ('l5 $other : tt) =>
+                                                                                        {
+                                                                                            compile_error!
+                                                                                            (concat!
+                                                                                            ("duplicate label ", stringify! ('l5),
+                                                                                            " in twist! -label list"))
+                                                                                        }; ('l6 [x x x x x x]) => {}; ('l6 $other : tt) =>
+                                                                                        {
+                                                                                            compile_error!
+                                                                                            (concat!
+                                                                                            ("duplicate label ", stringify! ('l6),
+                                                                                            " in twist! -label list"))
+                                                                                        }; ('l7 [x x x x x x x]) => {}; ('l7 $other : tt) =>
+                                                                                        {
+                                                                                            compile_error!
+                                                                                            (concat!
+                                                                                            ("duplicate label ", stringify! ('l7),
+                                                                                            " in twist! -label list"))
+                                                                                        }; ('l8 [x x x x x x x x]) => {}; ('l8 $other : tt) =>
+                                                                                        {
+                                                                                            compile_error!
+                                                                                            (concat!
+                                                                                            ("duplicate label ", stringify! ('l8),
+                                                                                            " in twist! -label list"))
+                                                                                        }; ('l9 [x x x x x x x x x]) => {}; ('l9 $other : tt) =>
+                                                                                        {
+                                                                                            compile_error!
+                                                                                            (concat!
+                                                                                            ("duplicate label ", stringify! ('l9),
+                                                                                            " in twist! -label list"))
+                                                                                        }; ('l10 [x x x x x x x x x x]) => {}; ('l10 $other : tt) =>
+                                                                                        {
+                                                                                            compile_error!
+                                                                                            (concat!
+                                                                                            ("duplicate label ", stringify! ('l10),
+                                                                                            " in twist! -label list"))
+                                                                                        }; ('l11 [x x x x x x x x x x x]) => {}; ('l11 $other : tt)
+                                                                                        =>
+                                                                                        {
+                                                                                            compile_error!
+                                                                                            (concat!
+                                                                                            ("duplicate label ", stringify! ('l11),
+                                                                                            " in twist! -label list"))
+                                                                                        }; ('l12 [x x x x x x x x x x x x]) => {};
+                                                                                        ('l12 $other : tt) =>
+                                                                                        {
+                                                                                            compile_error!
+                                                                                            (concat!
+                                                                                            ("duplicate label ", stringify! ('l12),
+                                                                                            " in twist! -label list"))
+                                                                                        }; ('l13 [x x x x x x x x x x x x x]) => {};
+                                                                                        ('l13 $other : tt) =>
+                                                                                        {
+                                                                                            compile_error!
+                                                                                            (concat!
+                                                                                            ("duplicate label ", stringify! ('l13),
+                                                                                            " in twist! -label list"))
+                                                                                        }; ('l14 [x x x x x x x x x x x x x x]) => {};
+                                                                                        ('l14 $other : tt) =>
+                                                                                        {
+                                                                                            compile_error!
+                                                                                            (concat!
+                                                                                            ("duplicate label ", stringify! ('l14),
+                                                                                            " in twist! -label list"))
+                                                                                        }; ('l15 [x x x x x x x x x x x x x x x]) => {};
+                                                                                        ('l15 $other : tt) =>
+                                                                                        {
+                                                                                            compile_error!
+                                                                                            (concat!
+                                                                                            ("duplicate label ", stringify! ('l15),
+                                                                                            " in twist! -label list"))
+                                                                                        }; ('l0 []) => {}; ('l0 $other : tt) =>
+                                                                                        {
+                                                                                            compile_error!
+                                                                                            (concat!
+                                                                                            ("duplicate label ", stringify! ('l0),
+                                                                                            " in twist! -label list"))
+                                                                                        };
+                                                                                    }
+                                                                                    v
+                                                                                }
+                                                                                ::tear::Looping::Break { label: ::core::option::Option::None
+                                                                                    } => {
+                                                                                    ();
+                                                                                    break;
+                                                                                }
+                                                                                ::tear::Looping::Break {
+                                                                                    label: ::core::option::Option::Some(l) } => {
+                                                                                    match l {
+                                                                                        1 => { break 'l1; }
+                                                                                        2 => { break 'l2; }
+                                                                                        3 => { break 'l3; }
+                                                                                        4 => { break 'l4; }
+                                                                                        5 => { break 'l5; }
+                                                                                        6 => { break 'l6; }
+                                                                                        7 => { break 'l7; }
+                                                                                        8 => { break 'l8; }
+                                                                                        9 => { break 'l9; }
+                                                                                        10 => { break 'l10; }
+                                                                                        11 => { break 'l11; }
+                                                                                        12 => { break 'l12; }
+                                                                                        13 => { break 'l13; }
+                                                                                        14 => { break 'l14; }
+                                                                                        15 => { break 'l15; }
+                                                                                        _ => ::tear::twist_impl::__invalid_label(l, 16),
+                                                                                    }
+                                                                                }
+                                                                                ::tear::Looping::Redo { .. } =>
+                                                                                    ::tear::twist_impl::__fail(::tear::TearErrorKind::RedoWithoutTredo),
+                                                                                ::tear::Looping::Continue { label } =>
+                                                                                    match label {
+                                                                                        ::core::option::Option::None => continue,
+                                                                                        ::core::option::Option::Some(1) => continue 'l1,
+                                                                                        ::core::option::Option::Some(2) => continue 'l2,
+                                                                                        ::core::option::Option::Some(3) => continue 'l3,
+                                                                                        ::core::option::Option::Some(4) => continue 'l4,
+                                                                                        ::core::option::Option::Some(5) => continue 'l5,
+                                                                                        ::core::option::Option::Some(6) => continue 'l6,
+                                                                                        ::core::option::Option::Some(7) => continue 'l7,
+                                                                                        ::core::option::Option::Some(8) => continue 'l8,
+                                                                                        ::core::option::Option::Some(9) => continue 'l9,
+                                                                                        ::core::option::Option::Some(10) => continue 'l10,
+                                                                                        ::core::option::Option::Some(11) => continue 'l11,
+                                                                                        ::core::option::Option::Some(12) => continue 'l12,
+                                                                                        ::core::option::Option::Some(13) => continue 'l13,
+                                                                                        ::core::option::Option::Some(14) => continue 'l14,
+                                                                                        ::core::option::Option::Some(15) => continue 'l15,
+                                                                                        ::core::option::Option::Some(0) => continue 'l0,
+                                                                                        ::core::option::Option::Some(l) =>
+                                                                                            ::tear::twist_impl::__invalid_label(l, 16),
+                                                                                    },
+                                                                                ::tear::Looping::BreakVal {
+                                                                                    label: ::core::option::Option::None, .. } => {
+                                                                                    ();
+                                                                                    ::tear::twist_impl::__fail(::tear::TearErrorKind::BreakValInNotLoop);
+                                                                                }
+                                                                                ::tear::Looping::BreakVal {
+                                                                                    label: ::core::option::Option::Some(l), value: v } => {
+                                                                                    match l {
+                                                                                        0 => {
+                                                                                            match v.downcast::<i32>().map(|v| *v) {
+                                                                                                ::core::result::Result::Ok(v) => { break 'l0 v; }
+                                                                                                ::core::result::Result::Err(b) => {
+                                                                                                    let _ = b;
+                                                                                                    ::tear::twist_impl::__bad_type("\'l0", "i32",
+                                                                                                        ::core::any::Any::type_id(&*b))
+                                                                                                }
+                                                                                            }
+                                                                                        }
+                                                                                        _ => ::tear::twist_impl::__invalid_label(l, 16),
+                                                                                    }
+                                                                                }
+                                                                            };
+                                                                    }
+                                                                }
+                                                            }
+                                                        }
+                                                    }
+                                                }
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        };
+    match (&(x, resumed), &(4, 3)) {
+        (left_val, right_val) => {
+            if !(*left_val == *right_val) {
+                let kind = ::core::panicking::AssertKind::Eq;
+                ::core::panicking::assert_failed(kind, &*left_val,
+                    &*right_val, ::core::option::Option::None);
+            }
+        }
+    };
+}
+extern crate test;
+#[rustc_test_marker = "named"]
+#[doc(hidden)]
+pub const named: test::TestDescAndFn =
+    test::TestDescAndFn {
+        desc: test::TestDesc {
+            name: test::StaticTestName("named"),
+            ignore: false,
+            ignore_message: ::core::option::Option::None,
+            source_file: "tests/label_stress.rs",
+            start_line: 102usize,
+            start_col: 12usize,
+            end_line: 102usize,
+            end_col: 17usize,
+            compile_fail: false,
+            no_run: false,
+            should_panic: test::ShouldPanic::No,
+            test_type: test::TestType::IntegrationTest,
+        },
+        testfn: test::StaticTestFn(#[coverage(off)] ||
+                test::assert_test_result(named())),
+    };
+fn named() {
+    let mut step = 0;
+    let mut resumed = 0;
+    let x =
+        'l0: loop {
+            'l1: loop {
+                'l2: loop {
+                    'l3: loop {
+                        'l4: loop {
+                            'l5: loop {
+                                'l6: loop {
+                                    'l7: loop {
+                                        'l8: loop {
+                                            'l9: loop {
+                                                'l10: loop {
+                                                    'l11: loop {
+                                                        'l12: loop {
+                                                            'l13: loop {
+                                                                'l14: loop {
+                                                                    'l15: loop {
+                                                                        resumed =
+                                                                            {
+                                                                                #[allow(dead_code)]
+                                                                                struct Label;
+                                                                                #[allow(dead_code)]
+                                                                                impl Label {
+                                                                                    const L0: usize = 0;
+                                                                                    const L1: usize = 1;
+                                                                                    const L2: usize = 2;
+                                                                                    const L3: usize = 3;
+                                                                                    const L4: usize = 4;
+                                                                                    const L5: usize = 5;
+                                                                                    const L6: usize = 6;
+                                                                                    const L7: usize = 7;
+                                                                                    const L8: usize = 8;
+                                                                                    const L9: usize = 9;
+                                                                                    const L10: usize = 10;
+                                                                                    const L11: usize = 11;
+                                                                                    const L12: usize = 12;
+                                                                                    const L13: usize = 13;
+                                                                                    const L14: usize = 14;
+                                                                                    const L15: usize = 15;
+                                                                                }
+                                                                                match ::tear::IntoLooping::into_looping(signal(&mut step,
+                                                                                            [Label::L15, Label::L7, Label::L0], || 4))
+                                                                                    {ref signal if
+                                                                                        ::tear::twist_impl::__check_label(signal, 16,
+                                                                                            "signal(&mut step, [Label::L15, Label::L7, Label::L0], || 4)")
+                                                                                        =>
+                                                                                        ::core::panicking::panic("internal error: entered unreachable code"),
+                                                                                    ::tear::Looping::Resume(v) => {
+                                                                                        #[allow(unused_macros)]
+                                                                                        macro_rules! __tear_duplicate_label {
+                                                                                            ('l1 [x]) => {}; ('l1 $other : tt) =>
+                                                                                            {
+                                                                                                compile_error!
+                                                                                                (concat!
+                                                                                                ("duplicate label ", stringify! ('l1),
+                                                                                                " in twist! -label list"))
+                                                                                            }; ('l2 [x x]) => {}; ('l2 $other : tt) =>
+                                                                                            {
+                                                                                                compile_error!
+                                                                                                (concat!
+                                                                                                ("duplicate label ", stringify! ('l2),
+                                                                                                " in twist! -label list"))
+                                                                                            }; ('l3 [x x x]) => {}; ('l3 $other : tt) =>
+                                                                                            {
+                                                                                                compile_error!
+                                                                                                (concat!
+                                                                                                ("duplicate label ", stringify! ('l3),
+                                                                                                " in twist! -label list"))
+                                                                                            }; ('l4 [x x x x]) => {}; ('l4 $other : tt) =>
+                                                                                            {
+                                                                                                compile_error!
+                                                                                                (concat!
+                                                                                                ("duplicate label ", stringify! ('l4),
+                                                                                                " in twist! -label list"))
+                                                                                            }; ('l5 [x x x x x]) => {}; ('l5 $other : tt) =>
+                                                                                            {
+                                                                                                compile_error!
+                                                                                                (concat!
+                                                                                                ("duplicate label ", stringify! ('l5),
+                                                                                                " in twist! -label list"))
+                                                                                            }; ('l6 [x x x x x x]) => {}; ('l6 $other : tt) =>
+                                                                                            {
+                                                                                                compile_error!
+                                                                                                (concat!
+                                                                                                ("duplicate label ", stringify! ('l6),
+                                                                                                " in twist! -label list"))
+                                                                                            }; ('l7 [x x x x x x x]) => {}; ('l7 $other : tt) =>
+                                                                                            {
+                                                                                                compile_error!
+                                                                                                (concat!
+                                                                                                ("duplicate label ", stringify! ('l7),
+                                                                                                " in twist! -label list"))
+                                                                                            }; ('l8 [x x x x x x x x]) => {}; ('l8 $other : tt) =>
+                                                                                            {
+                                                                                                compile_error!
+                                                                                                (concat!
+                                                                                                ("duplicate label ", stringify! ('l8),
+                                                                                                " in twist! -label list"))
+                                                                                            }; ('l9 [x x x x x x x x x]) => {}; ('l9 $other : tt) =>
+                                                                                            {
+                                                                                                compile_error!
+                                                                                                (concat!
+                                                                                                ("duplicate label ", stringify! ('l9),
+                                                                                                " in twist! -label list"))
+                                                                                            }; ('l10 [x x x x x x x x x x]) => {}; ('l10 $other : tt) =>
+                                                                                            {
+                                                                                                compile_error!
+                                                                                                (concat!
+                                                                                                ("duplicate label ", stringify! ('l10),
+                                                                                                " in twist! -label list"))
+                                                                                            }; ('l11 [x x x x x x x x x x x]) => {}; ('l11 $other : tt)
+                                                                                            =>
+                                                                                            {
+                                                                                                compile_error!
+                                                                                                (concat!
+                                                                                                ("duplicate label ", stringify! ('l11),
+                                                                                                " in twist! -label list"))
+                                                                                            }; ('l12 [x x x x x x x x x x x x]) => {};
+                                                                                            ('l12 $other : tt) =>
+                                                                                            {
+                                                                                                compile_error!
+                                                                                                (concat!
+                                                                                                ("duplicate label ", stringify! ('l12),
+                                                                                                " in twist! -label list"))
+                                                                                            }; ('l13 [x x x x x x x x x x x x x]) => {};
+                                                                                            ('l13 $other : tt) =>
+                                                                                            {
+                                                                                                compile_error!
+                                                                                                (concat!
+                                                                                                ("duplicate label ", stringify! ('l13),
+                                                                                                " in twist! -label list"))
+                                                                                            }; ('l14 [x x x x x x x x x x x x x x]) => {};
+                                                                                            ('l14 $other : tt) =>
+                                                                                            {
+                                                                                                compile_error!
+                                                                                                (concat!
+                                                                                                ("duplicate label ", stringify! ('l14),
+                                                                                                " in twist! -label list"))
+                                                                                            }; ('l15 [x x x x x x x x x x x x x x x]) => {};
+                                                                                            ('l15 $other : tt) =>
+                                                                                            {
+                                                                                                compile_error!
+                                                                                                (concat!
+                                                                                                ("duplicate label ", stringify! ('l15),
+                                                                                                " in twist! -label list"))
+                                                                                            }; ('l0 []) => {}; ('l0 $other : tt) =>
+                                                                                            {
+                                                                                                compile_error!
+                                                                                                (concat!
+                                                                                                ("duplicate label ", stringify! ('l0),
+                                                                                                " in twist! -label list"))
+                                                                                            };
+                                                                                        }
+                                                                                        v
+                                                                                    }
+                                                                                    ::tear::Looping::Break { label: ::core::option::Option::None
+                                                                                        } => {
+                                                                                        ();
+                                                                                        break;
+                                                                                    }
+                                                                                    ::tear::Looping::Break {
+                                                                                        label: ::core::option::Option::Some(l) } => {
+                                                                                        match l {
+                                                                                            1 => { break 'l1; }
+                                                                                            2 => { break 'l2; }
+                                                                                            3 => { break 'l3; }
+                                                                                            4 => { break 'l4; }
+                                                                                            5 => { break 'l5; }
+                                                                                            6 => { break 'l6; }
+                                                                                            7 => { break 'l7; }
+                                                                                            8 => { break 'l8; }
+                                                                                            9 => { break 'l9; }
+                                                                                            10 => { break 'l10; }
+                                                                                            11 => { break 'l11; }
+                                                                                            12 => { break 'l12; }
+                                                                                            13 => { break 'l13; }
+                                                                                            14 => { break 'l14; }
+                                                                                            15 => { break 'l15; }
+                                                                                            _ => ::tear::twist_impl::__invalid_label(l, 16),
+                                                                                        }
+                                                                                    }
+                                                                                    ::tear::Looping::Redo { .. } =>
+                                                                                        ::tear::twist_impl::__fail(::tear::TearErrorKind::RedoWithoutTredo),
+                                                                                    ::tear::Looping::Continue { label } =>
+                                                                                        match label {
+                                                                                            ::core::option::Option::None => continue,
+                                                                                            ::core::option::Option::Some(1) => continue 'l1,
+                                                                                            ::core::option::Option::Some(2) => continue 'l2,
+                                                                                            ::core::option::Option::Some(3) => continue 'l3,
+                                                                                            ::core::option::Option::Some(4) => continue 'l4,
+                                                                                            ::core::option::Option::Some(5) => continue 'l5,
+                                                                                            ::core::option::Option::Some(6) => continue 'l6,
+                                                                                            ::core::option::Option::Some(7) => continue 'l7,
+                                                                                            ::core::option::Option::Some(8) => continue 'l8,
+                                                                                            ::core::option::Option::Some(9) => continue 'l9,
+                                                                                            ::core::option::Option::Some(10) => continue 'l10,
+                                                                                            ::core::option::Option::Some(11) => continue 'l11,
+                                                                                            ::core::option::Option::Some(12) => continue 'l12,
+                                                                                            ::core::option::Option::Some(13) => continue 'l13,
+                                                                                            ::core::option::Option::Some(14) => continue 'l14,
+                                                                                            ::core::option::Option::Some(15) => continue 'l15,
+                                                                                            ::core::option::Option::Some(0) => continue 'l0,
+                                                                                            ::core::option::Option::Some(l) =>
+                                                                                                ::tear::twist_impl::__invalid_label(l, 16),
+                                                                                        },
+                                                                                    ::tear::Looping::BreakVal {
+                                                                                        label: ::core::option::Option::None, .. } => {
+                                                                                        ();
+                                                                                        ::tear::twist_impl::__fail(::tear::TearErrorKind::BreakValInNotLoop);
+                                                                                    }
+                                                                                    ::tear::Looping::BreakVal {
+                                                                                        label: ::core::option::Option::Some(l), value: v } => {
+                                                                                        match l {
+                                                                                            0 => { break 'l0 v; }
+                                                                                            _ => ::tear::twist_impl::__invalid_label(l, 16),
+                                                                                        }
+                                                                                    }
+                                                                                }
+                                                                            };
+                                                                    }
+                                                                }
+                                                            }
+                                                        }
+                                                    }
+                                                }
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        };
+    match (&(x, resumed), &(4, 3)) {
+        (left_val, right_val) => {
+            if !(*left_val == *right_val) {
+                let kind = ::core::panicking::AssertKind::Eq;
+                ::core::panicking::assert_failed(kind, &*left_val,
+                    &*right_val, ::core::option::Option::None);
+            }
+        }
+    };
+}
+extern crate test;
+#[rustc_test_marker = "enum_mode"]
+#[doc(hidden)]
+pub const enum_mode: test::TestDescAndFn =
+    test::TestDescAndFn {
+        desc: test::TestDesc {
+            name: test::StaticTestName("enum_mode"),
+            ignore: false,
+            ignore_message: ::core::option::Option::None,
+            source_file: "tests/label_stress.rs",
+            start_line: 143usize,
+            start_col: 12usize,
+            end_line: 143usize,
+            end_col: 21usize,
+            compile_fail: false,
+            no_run: false,
+            should_panic: test::ShouldPanic::No,
+            test_type: test::TestType::IntegrationTest,
+        },
+        testfn: test::StaticTestFn(#[coverage(off)] ||
+                test::assert_test_result(enum_mode())),
+    };
+fn enum_mode() {
+    let mut step = 0;
+    let mut resumed = 0;
+    let x =
+        'l0: loop {
+            'l1: loop {
+                'l2: loop {
+                    'l3: loop {
+                        'l4: loop {
+                            'l5: loop {
+                                'l6: loop {
+                                    'l7: loop {
+                                        'l8: loop {
+                                            'l9: loop {
+                                                'l10: loop {
+                                                    'l11: loop {
+                                                        'l12: loop {
+                                                            'l13: loop {
+                                                                'l14: loop {
+                                                                    'l15: loop {
+                                                                        resumed =
+                                                                            {
+                                                                                #[allow(unused_macros)]
+                                                                                macro_rules! __tear_duplicate_label {
+                                                                                    ('l1 [x]) => {}; ('l1 $other : tt) =>
+                                                                                    {
+                                                                                        compile_error!
+                                                                                        (concat!
+                                                                                        ("duplicate label ", stringify! ('l1),
+                                                                                        " in twist! -label list"))
+                                                                                    }; ('l2 [x x]) => {}; ('l2 $other : tt) =>
+                                                                                    {
+                                                                                        compile_error!
+                                                                                        (concat!
+                                                                                        ("duplicate label ", stringify! ('l2),
+                                                                                        " in twist! -label list"))
+                                                                                    }; ('l3 [x x x]) => {}; ('l3 $other : tt) =>
+                                                                                    {
+                                                                                        compile_error!
+                                                                                        (concat!
+                                                                                        ("duplicate label ", stringify! ('l3),
+                                                                                        " in twist! -label list"))
+                                                                                    }; ('l4 [x x x x]) => {}; ('l4 $other : tt) =>
+                                                                                    {
+                                                                                        compile_error!
+                                                                                        (concat!
+                                                                                        ("duplicate label ", stringify! ('l4),
+                                                                                        " in twist! -label list"))
+                                                                                    }; ('l5 [x x x x x]) => {}; ('l5 $other : tt) =>
+                                                                                    {
+                                                                                        compile_error!
+                                                                                        (concat!
+                                                                                        ("duplicate label ", stringify! ('l5),
+                                                                                        " in twist! -label list"))
+                                                                                    }; ('l6 [x x x x x x]) => {}; ('l6 $other : tt) =>
+                                                                                    {
+                                                                                        compile_error!
+                                                                                        (concat!
+                                                                                        ("duplicate label ", stringify! ('l6),
+                                                                                        " in twist! -label list"))
+                                                                                    }; ('l7 [x x x x x x x]) => {}; ('l7 $other : tt) =>
+                                                                                    {
+                                                                                        compile_error!
+                                                                                        (concat!
+                                                                                        ("duplicate label ", stringify! ('l7),
+                                                                                        " in twist! -label list"))
+                                                                                    }; ('l8 [x x x x x x x x]) => {}; ('l8 $other : tt) =>
+                                                                                    {
+                                                                                        compile_error!
+                                                                                        (concat!
+                                                                                        ("duplicate label ", stringify! ('l8),
+                                                                                        " in twist! -label list"))
+                                                                                    }; ('l9 [x x x x x x x x x]) => {}; ('l9 $other : tt) =>
+                                                                                    {
+                                                                                        compile_error!
+                                                                                        (concat!
+                                                                                        ("duplicate label ", stringify! ('l9),
+                                                                                        " in twist! -label list"))
+                                                                                    }; ('l10 [x x x x x x x x x x]) => {}; ('l10 $other : tt) =>
+                                                                                    {
+                                                                                        compile_error!
+                                                                                        (concat!
+                                                                                        ("duplicate label ", stringify! ('l10),
+                                                                                        " in twist! -label list"))
+                                                                                    }; ('l11 [x x x x x x x x x x x]) => {}; ('l11 $other : tt)
+                                                                                    =>
+                                                                                    {
+                                                                                        compile_error!
+                                                                                        (concat!
+                                                                                        ("duplicate label ", stringify! ('l11),
+                                                                                        " in twist! -label list"))
+                                                                                    }; ('l12 [x x x x x x x x x x x x]) => {};
+                                                                                    ('l12 $other : tt) =>
+                                                                                    {
+                                                                                        compile_error!
+                                                                                        (concat!
+                                                                                        ("duplicate label ", stringify! ('l12),
+                                                                                        " in twist! -label list"))
+                                                                                    }; ('l13 [x x x x x x x x x x x x x]) => {};
+                                                                                    ('l13 $other : tt) =>
+                                                                                    {
+                                                                                        compile_error!
+                                                                                        (concat!
+                                                                                        ("duplicate label ", stringify! ('l13),
+                                                                                        " in twist! -label list"))
+                                                                                    }; ('l14 [x x x x x x x x x x x x x x]) => {};
+                                                                                    ('l14 $other : tt) =>
+                                                                                    {
+                                                                                        compile_error!
+                                                                                        (concat!
+                                                                                        ("duplicate label ", stringify! ('l14),
+                                                                                        " in twist! -label list"))
+                                                                                    }; ('l15 [x x x x x x x x x x x x x x x]) => {};
+                                                                                    ('l15 $other : tt) =>
+                                                                                    {
+                                                                                        compile_error!
+                                                                                        (concat!
+                                                                                        ("duplicate label ", stringify! ('l15),
+                                                                                        " in twist! -label list"))
+                                                                                    }; ('l0 []) => {}; ('l0 $other : tt) =>
+                                                                                    {
+                                                                                        compile_error!
+                                                                                        (concat!
+                                                                                        ("duplicate label ", stringify! ('l0),
+                                                                                        " in twist! -label list"))
+                                                                                    };
+                                                                                }
+                                                                                #[allow(dead_code)]
+                                                                                enum Sig { L0(i32), }
+                                                                                match ::tear::IntoLooping::into_looping(signal(&mut step,
+                                                                                            [15, 7, 0], || Sig::L0(4))) {
+                                                                                    ::tear::Looping::Resume(v) => v,
+                                                                                    ::tear::Looping::Break { label: ::core::option::Option::None
+                                                                                        } => {
+                                                                                        ();
+                                                                                        break;
+                                                                                    }
+                                                                                    ::tear::Looping::Break {
+                                                                                        label: ::core::option::Option::Some(l) } => {
+                                                                                        match l {
+                                                                                            1 => { break 'l1; }
+                                                                                            2 => { break 'l2; }
+                                                                                            3 => { break 'l3; }
+                                                                                            4 => { break 'l4; }
+                                                                                            5 => { break 'l5; }
+                                                                                            6 => { break 'l6; }
+                                                                                            7 => { break 'l7; }
+                                                                                            8 => { break 'l8; }
+                                                                                            9 => { break 'l9; }
+                                                                                            10 => { break 'l10; }
+                                                                                            11 => { break 'l11; }
+                                                                                            12 => { break 'l12; }
+                                                                                            13 => { break 'l13; }
+                                                                                            14 => { break 'l14; }
+                                                                                            15 => { break 'l15; }
+                                                                                            _ => ::tear::twist_impl::__invalid_label(l, 16),
+                                                                                        }
+                                                                                    }
+                                                                                    ::tear::Looping::Redo { .. } =>
+                                                                                        ::tear::twist_impl::__fail(::tear::TearErrorKind::RedoWithoutTredo),
+                                                                                    ::tear::Looping::Continue { label } =>
+                                                                                        match label {
+                                                                                            ::core::option::Option::None => continue,
+                                                                                            ::core::option::Option::Some(1) => continue 'l1,
+                                                                                            ::core::option::Option::Some(2) => continue 'l2,
+                                                                                            ::core::option::Option::Some(3) => continue 'l3,
+                                                                                            ::core::option::Option::Some(4) => continue 'l4,
+                                                                                            ::core::option::Option::Some(5) => continue 'l5,
+                                                                                            ::core::option::Option::Some(6) => continue 'l6,
+                                                                                            ::core::option::Option::Some(7) => continue 'l7,
+                                                                                            ::core::option::Option::Some(8) => continue 'l8,
+                                                                                            ::core::option::Option::Some(9) => continue 'l9,
+                                                                                            ::core::option::Option::Some(10) => continue 'l10,
+                                                                                            ::core::option::Option::Some(11) => continue 'l11,
+                                                                                            ::core::option::Option::Some(12) => continue 'l12,
+                                                                                            ::core::option::Option::Some(13) => continue 'l13,
+                                                                                            ::core::option::Option::Some(14) => continue 'l14,
+                                                                                            ::core::option::Option::Some(15) => continue 'l15,
+                                                                                            ::core::option::Option::Some(0) => continue 'l0,
+                                                                                            ::core::option::Option::Some(l) =>
+                                                                                                ::tear::twist_impl::__invalid_label(l, 16),
+                                                                                        },
+                                                                                    ::tear::Looping::BreakVal::<_, Sig> { value, .. } => {
+                                                                                        match value { Sig::L0(v) => { break 'l0 v; } }
+                                                                                    }
+                                                                                }
+                                                                            };
+                                                                    }
+                                                                }
+                                                            }
+                                                        }
+                                                    }
+                                                }
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        };
+    match (&(x, resumed), &(4, 3)) {
+        (left_val, right_val) => {
+            if !(*left_val == *right_val) {
+                let kind = ::core::panicking::AssertKind::Eq;
+                ::core::panicking::assert_failed(kind, &*left_val,
+                    &*right_val, ::core::option::Option::None);
+            }
+        }
+    };
+}
+extern crate test;
+#[rustc_test_marker = "many"]
+#[doc(hidden)]
+pub const many: test::TestDescAndFn =
+    test::TestDescAndFn {
+        desc: test::TestDesc {
+            name: test::StaticTestName("many"),
+            ignore: false,
+            ignore_message: ::core::option::Option::None,
+            source_file: "tests/label_stress.rs",
+            start_line: 227usize,
+            start_col: 12usize,
+            end_line: 227usize,
+            end_col: 16usize,
+            compile_fail: false,
+            no_run: false,
+            should_panic: test::ShouldPanic::No,
+            test_type: test::TestType::IntegrationTest,
+        },
+        testfn: test::StaticTestFn(#[coverage(off)] ||
+                test::assert_test_result(many())),
+    };
+fn many() {
+    let ok = |v: i32| Looping::<i32, i32>::Resume(v);
+    let mut sum = 0;
+    let x =
+        'a: loop {
+            'b: loop {
+                'c: loop {
+                    'd: loop {
+                        sum +=
+                            match ::tear::IntoLooping::into_looping(ok(1))
+                                {ref signal if
+                                    ::tear::twist_impl::__check_label(signal, 1, "ok(1)") =>
+                                    ::core::panicking::panic("internal error: entered unreachable code"),
+                                ::tear::Looping::Resume(v) => {
+                                    #[allow(unused_macros)]
+                                    macro_rules! __tear_duplicate_label {
+                                        ('a []) => {}; ('a $other : tt) =>
+                                        {
+                                            compile_error!
+                                            (concat!
+                                            ("duplicate label ", stringify! ('a),
+                                            " in twist! -label list"))
+                                        };
+                                    }
+                                    v
+                                }
+                                ::tear::Looping::Break { label: ::core::option::Option::None
+                                    } => {
+                                    ();
+                                    break;
+                                }
+                                ::tear::Looping::Break {
+                                    label: ::core::option::Option::Some(l) } => {
+                                    match l { _ => ::tear::twist_impl::__invalid_label(l, 1), }
+                                }
+                                ::tear::Looping::Redo { .. } =>
+                                    ::tear::twist_impl::__fail(::tear::TearErrorKind::RedoWithoutTredo),
+                                ::tear::Looping::Continue { label } =>
+                                    match label {
+                                        ::core::option::Option::None => continue,
+                                        ::core::option::Option::Some(0) => continue 'a,
+                                        ::core::option::Option::Some(l) =>
+                                            ::tear::twist_impl::__invalid_label(l, 1),
+                                    },
+                                ::tear::Looping::BreakVal {
+                                    label: ::core::option::Option::None, .. } => {
+                                    ();
+                                    ::tear::twist_impl::__fail(::tear::TearErrorKind::BreakValInNotLoop);
+                                }
+                                ::tear::Looping::BreakVal {
+                                    label: ::core::option::Option::Some(l), value: v } => {
+                                    match l {
+                                        0 => { break 'a v; }
+                                        _ => ::tear::twist_impl::__invalid_label(l, 1),
+                                    }
+                                }
+                            };
+                        sum +=
+                            match ::tear::IntoLooping::into_looping(ok(1))
+                                {ref signal if
+                                    ::tear::twist_impl::__check_label(signal, 2, "ok(1)") =>
+                                    ::core::panicking::panic("internal error: entered unreachable code"),
+                                ::tear::Looping::Resume(v) => {
+                                    #[allow(unused_macros)]
+                                    macro_rules! __tear_duplicate_label {
+                                        ('b [x]) => {}; ('b $other : tt) =>
+                                        {
+                                            compile_error!
+                                            (concat!
+                                            ("duplicate label ", stringify! ('b),
+                                            " in twist! -label list"))
+                                        }; ('a []) => {}; ('a $other : tt) =>
+                                        {
+                                            compile_error!
+                                            (concat!
+                                            ("duplicate label ", stringify! ('a),
+                                            " in twist! -label list"))
+                                        };
+                                    }
+                                    v
+                                }
+                                ::tear::Looping::Break { label: ::core::option::Option::None
+                                    } => {
+                                    ();
+                                    break;
+                                }
+                                ::tear::Looping::Break {
+                                    label: ::core::option::Option::Some(l) } => {
+                                    match l {
+                                        1 => { break 'b; }
+                                        _ => ::tear::twist_impl::__invalid_label(l, 2),
+                                    }
+                                }
+                                ::tear::Looping::Redo { .. } =>
+                                    ::tear::twist_impl::__fail(::tear::TearErrorKind::RedoWithoutTredo),
+                                ::tear::Looping::Continue { label } =>
+                                    match label {
+                                        ::core::option::Option::None => continue,
+                                        ::core::option::Option::Some(1) => continue 'b,
+                                        ::core::option::Option::Some(0) => continue 'a,
+                                        ::core::option::Option::Some(l) =>
+                                            ::tear::twist_impl::__invalid_label(l, 2),
+                                    },
+                                ::tear::Looping::BreakVal {
+                                    label: ::core::option::Option::None, .. } => {
+                                    ();
+                                    ::tear::twist_impl::__fail(::tear::TearErrorKind::BreakValInNotLoop);
+                                }
+                                ::tear::Looping::BreakVal {
+                                    label: ::core::option::Option::Some(l), value: v } => {
+                                    match l {
+                                        0 => { break 'a v; }
+                                        _ => ::tear::twist_impl::__invalid_label(l, 2),
+                                    }
+                                }
+                            };
+                        sum +=
+                            match ::tear::IntoLooping::into_looping(ok(1))
+                                {ref signal if
+                                    ::tear::twist_impl::__check_label(signal, 3, "ok(1)") =>
+                                    ::core::panicking::panic("internal error: entered unreachable code"),
+                                ::tear::Looping::Resume(v) => {
+                                    #[allow(unused_macros)]
+                                    macro_rules! __tear_duplicate_label {
+                                        ('b [x]) => {}; ('b $other : tt) =>
+                                        {
+                                            compile_error!
+                                            (concat!
+                                            ("duplicate label ", stringify! ('b),
+                                            " in twist! -label list"))
+                                        }; ('c [x x]) => {}; ('c $other : tt) =>
+                                        {
+                                            compile_error!
+                                            (concat!
+                                            ("duplicate label ", stringify! ('c),
+                                            " in twist! -label list"))
+                                        }; ('a []) => {}; ('a $other : tt) =>
+                                        {
+                                            compile_error!
+                                            (concat!
+                                            ("duplicate label ", stringify! ('a),
+                                            " in twist! -label list"))
+                                        };
+                                    }
+                                    v
+                                }
+                                ::tear::Looping::Break { label: ::core::option::Option::None
+                                    } => {
+                                    ();
+                                    break;
+                                }
+                                ::tear::Looping::Break {
+                                    label: ::core::option::Option::Some(l) } => {
+                                    match l {
+                                        1 => { break 'b; }
+                                        2 => { break 'c; }
+                                        _ => ::tear::twist_impl::__invalid_label(l, 3),
+                                    }
+                                }
+                                ::tear::Looping::Redo { .. } =>
+                                    ::tear::twist_impl::__fail(::tear::TearErrorKind::RedoWithoutTredo),
+                                ::tear::Looping::Continue { label } =>
+                                    match label {
+                                        ::core::option::Option::None => continue,
+                                        ::core::option::Option::Some(1) => continue 'b,
+                                        ::core::option::Option::Some(2) => continue 'c,
+                                        ::core::option::Option::Some(0) => continue 'a,
+                                        ::core::option::Option::Some(l) =>
+                                            ::tear::twist_impl::__invalid_label(l, 3),
+                                    },
+                                ::tear::Looping::BreakVal {
+                                    label: ::core::option::Option::None, .. } => {
+                                    ();
+                                    ::tear::twist_impl::__fail(::tear::TearErrorKind::BreakValInNotLoop);
+                                }
+                                ::tear::Looping::BreakVal {
+                                    label: ::core::option::Option::Some(l), value: v } => {
+                                    match l {
+                                        0 => { break 'a v; }
+                                        _ => ::tear::twist_impl::__invalid_label(l, 3),
+                                    }
+                                }
+                            };
+                        sum +=
+                            match ::tear::IntoLooping::into_looping(ok(1))
+                                {ref signal if
+                                    ::tear::twist_impl::__check_label(signal, 4, "ok(1)") =>
+                                    ::core::panicking::panic("internal error: entered unreachable code"),
+                                ::tear::Looping::Resume(v) => {
+                                    #[allow(unused_macros)]
+                                    macro_rules! __tear_duplicate_label {
+                                        ('b [x]) => {}; ('b $other : tt) =>
+                                        {
+                                            compile_error!
+                                            (concat!
+                                            ("duplicate label ", stringify! ('b),
+                                            " in twist! -label list"))
+                                        }; ('c [x x]) => {}; ('c $other : tt) =>
+                                        {
+                                            compile_error!
+                                            (concat!
+                                            ("duplicate label ", stringify! ('c),
+                                            " in twist! -label list"))
+                                        }; ('d [x x x]) => {}; ('d $other : tt) =>
+                                        {
+                                            compile_error!
+                                            (concat!
+                                            ("duplicate label ", stringify! ('d),
+                                            " in twist! -label list"))
+                                        }; ('a []) => {}; ('a $other : tt) =>
+                                        {
+                                            compile_error!
+                                            (concat!
+                                            ("duplicate label ", stringify! ('a),
+                                            " in twist! -label list"))
+                                        };
+                                    }
+                                    v
+                                }
+                                ::tear::Looping::Break { label: ::core::option::Option::None
+                                    } => {
+                                    ();
+                                    break;
+                                }
+                                ::tear::Looping::Break {
+                                    label: ::core::option::Option::Some(l) } => {
+                                    match l {
+                                        1 => { break 'b; }
+                                        2 => { break 'c; }
+                                        3 => { break 'd; }
+                                        _ => ::tear::twist_impl::__invalid_label(l, 4),
+                                    }
+                                }
+                                ::tear::Looping::Redo { .. } =>
+                                    ::tear::twist_impl::__fail(::tear::TearErrorKind::RedoWithoutTredo),
+                                ::tear::Looping::Continue { label } =>
+                                    match label {
+                                        ::core::option::Option::None => continue,
+                                        ::core::option::Option::Some(1) => continue 'b,
+                                        ::core::option::Option::Some(2) => continue 'c,
+                                        ::core::option::Option::Some(3) => continue 'd,
+                                        ::core::option::Option::Some(0) => continue 'a,
+                                        ::core::option::Option::Some(l) =>
+                                            ::tear::twist_impl::__invalid_label(l, 4),
+                                    },
+                                ::tear::Looping::BreakVal {
+                                    label: ::core::option::Option::None, .. } => {
+                                    ();
+                                    ::tear::twist_impl::__fail(::tear::TearErrorKind::BreakValInNotLoop);
+                                }
+                                ::tear::Looping::BreakVal {
+                                    label: ::core::option::Option::Some(l), value: v } => {
+                                    match l {
+                                        0 => { break 'a v; }
+                                        _ => ::tear::twist_impl::__invalid_label(l, 4),
+                                    }
+                                }
+                            };
+                        sum +=
+                            match ::tear::IntoLooping::into_looping(::tear::Looping::from_judge(Some(1),
+                                        |v|
+                                            ::tear::IntoLooping::into_looping(::tear::__apply(v,
+                                                    |_| Looping::<i32, i32>::Continue { label: None }))))
+                                {ref signal if
+                                    ::tear::twist_impl::__check_label(signal, 2, "Some(1)") =>
+                                    ::core::panicking::panic("internal error: entered unreachable code"),
+                                ::tear::Looping::Resume(v) => {
+                                    #[allow(unused_macros)]
+                                    macro_rules! __tear_duplicate_label {
+                                        ('b [x]) => {}; ('b $other : tt) =>
+                                        {
+                                            compile_error!
+                                            (concat!
+                                            ("duplicate label ", stringify! ('b),
+                                            " in twist! -label list"))
+                                        }; ('a []) => {}; ('a $other : tt) =>
+                                        {
+                                            compile_error!
+                                            (concat!
+                                            ("duplicate label ", stringify! ('a),
+                                            " in twist! -label list"))
+                                        };
+                                    }
+                                    v
+                                }
+                                ::tear::Looping::Break { label: ::core::option::Option::None
+                                    } => {
+                                    ();
+                                    break;
+                                }
+                                ::tear::Looping::Break {
+                                    label: ::core::option::Option::Some(l) } => {
+                                    match l {
+                                        1 => { break 'b; }
+                                        _ => ::tear::twist_impl::__invalid_label(l, 2),
+                                    }
+                                }
+                                ::tear::Looping::Redo { .. } =>
+                                    ::tear::twist_impl::__fail(::tear::TearErrorKind::RedoWithoutTredo),
+                                ::tear::Looping::Continue { label } =>
+                                    match label {
+                                        ::core::option::Option::None => continue,
+                                        ::core::option::Option::Some(1) => continue 'b,
+                                        ::core::option::Option::Some(0) => continue 'a,
+                                        ::core::option::Option::Some(l) =>
+                                            ::tear::twist_impl::__invalid_label(l, 2),
+                                    },
+                                ::tear::Looping::BreakVal {
+                                    label: ::core::option::Option::None, .. } => {
+                                    ();
+                                    ::tear::twist_impl::__fail(::tear::TearErrorKind::BreakValInNotLoop);
+                                }
+                                ::tear::Looping::BreakVal {
+                                    label: ::core::option::Option::Some(l), value: v } => {
+                                    match l {
+                                        0 => { break 'a v; }
+                                        _ => ::tear::twist_impl::__invalid_label(l, 2),
+                                    }
+                                }
+                            };
+                        sum +=
+                            match ::tear::IntoLooping::into_looping(::tear::Looping::from_judge(Err::<i32,
+                                                    i32>(2).or::<i32>(Ok(1)),
+                                        |v|
+                                            ::tear::IntoLooping::into_looping(::tear::__apply(v,
+                                                    |e| Looping::BreakVal { label: Some(0), value: e }))))
+                                {ref signal if
+                                    ::tear::twist_impl::__check_label(signal, 2,
+                                        "Err::<i32, i32>(2).or::<i32>(Ok(1))") =>
+                                    ::core::panicking::panic("internal error: entered unreachable code"),
+                                ::tear::Looping::Resume(v) => {
+                                    #[allow(unused_macros)]
+                                    macro_rules! __tear_duplicate_label {
+                                        ('b [x]) => {}; ('b $other : tt) =>
+                                        {
+                                            compile_error!
+                                            (concat!
+                                            ("duplicate label ", stringify! ('b),
+                                            " in twist! -label list"))
+                                        }; ('a []) => {}; ('a $other : tt) =>
+                                        {
+                                            compile_error!
+                                            (concat!
+                                            ("duplicate label ", stringify! ('a),
+                                            " in twist! -label list"))
+                                        };
+                                    }
+                                    v
+                                }
+                                ::tear::Looping::Break { label: ::core::option::Option::None
+                                    } => {
+                                    ();
+                                    break;
+                                }
+                                ::tear::Looping::Break {
+                                    label: ::core::option::Option::Some(l) } => {
+                                    match l {
+                                        1 => { break 'b; }
+                                        _ => ::tear::twist_impl::__invalid_label(l, 2),
+                                    }
+                                }
+                                ::tear::Looping::Redo { .. } =>
+                                    ::tear::twist_impl::__fail(::tear::TearErrorKind::RedoWithoutTredo),
+                                ::tear::Looping::Continue { label } =>
+                                    match label {
+                                        ::core::option::Option::None => continue,
+                                        ::core::option::Option::Some(1) => continue 'b,
+                                        ::core::option::Option::Some(0) => continue 'a,
+                                        ::core::option::Option::Some(l) =>
+                                            ::tear::twist_impl::__invalid_label(l, 2),
+                                    },
+                                ::tear::Looping::BreakVal {
+                                    label: ::core::option::Option::None, .. } => {
+                                    ();
+                                    ::tear::twist_impl::__fail(::tear::TearErrorKind::BreakValInNotLoop);
+                                }
+                                ::tear::Looping::BreakVal {
+                                    label: ::core::option::Option::Some(l), value: v } => {
+                                    match l {
+                                        0 => { break 'a v; }
+                                        _ => ::tear::twist_impl::__invalid_label(l, 2),
+                                    }
+                                }
+                            };
+                        sum +=
+                            match ::tear::IntoLooping::into_looping(ok(1))
+                                    {ref signal if false =>
+                                        ::core::panicking::panic("internal error: entered unreachable code"),
+                                    ::tear::Looping::Resume(v) => {
+                                        #[allow(unused_macros)]
+                                        macro_rules! __tear_duplicate_label {
+                                            ('b [x]) => {}; ('b $other : tt) =>
+                                            {
+                                                compile_error!
+                                                (concat!
+                                                ("duplicate label ", stringify! ('b),
+                                                " in twist! -label list"))
+                                            }; ('c [x x]) => {}; ('c $other : tt) =>
+                                            {
+                                                compile_error!
+                                                (concat!
+                                                ("duplicate label ", stringify! ('c),
+                                                " in twist! -label list"))
+                                            }; ('a []) => {}; ('a $other : tt) =>
+                                            {
+                                                compile_error!
+                                                (concat!
+                                                ("duplicate label ", stringify! ('a),
+                                                " in twist! -label list"))
+                                            };
+                                        }
+                                        ::core::result::Result::Ok(v)
+                                    }
+                                    ::tear::Looping::Break { label: ::core::option::Option::None
+                                        } => {
+                                        ();
+                                        break;
+                                    }
+                                    ::tear::Looping::Break {
+                                        label: ::core::option::Option::Some(l) } => {
+                                        match l {
+                                            1 => { break 'b; }
+                                            2 => { break 'c; }
+                                            _ =>
+                                                ::core::result::Result::Err(::tear::TwistError::InvalidLabel {
+                                                        index: l,
+                                                        max: 3,
+                                                    }),
+                                        }
+                                    }
+                                    ::tear::Looping::Redo { .. } =>
+                                        ::tear::twist_impl::__fail(::tear::TearErrorKind::RedoWithoutTredo),
+                                    ::tear::Looping::Continue { label } =>
+                                        match label {
+                                            ::core::option::Option::None => continue,
+                                            ::core::option::Option::Some(1) => continue 'b,
+                                            ::core::option::Option::Some(2) => continue 'c,
+                                            ::core::option::Option::Some(0) => continue 'a,
+                                            ::core::option::Option::Some(l) =>
+                                                ::core::result::Result::Err(::tear::TwistError::InvalidLabel {
+                                                        index: l,
+                                                        max: 3,
+                                                    }),
+                                        },
+                                    ::tear::Looping::BreakVal {
+                                        label: ::core::option::Option::None, .. } => {
+                                        ();
+                                        ::tear::twist_impl::__fail(::tear::TearErrorKind::BreakValInNotLoop);
+                                    }
+                                    ::tear::Looping::BreakVal {
+                                        label: ::core::option::Option::Some(l), value: v } => {
+                                        match l {
+                                            0 => { break 'a v; }
+                                            _ =>
+                                                ::core::result::Result::Err(::tear::TwistError::InvalidLabel {
+                                                        index: l,
+                                                        max: 3,
+                                                    }),
+                                        }
+                                    }
+                                }.unwrap();
+                        sum +=
+                            match ::tear::IntoLooping::into_looping(ok(1))
+                                {ref signal if
+                                    ::tear::twist_impl::__check_label(signal, 4, "ok(1)") =>
+                                    ::core::panicking::panic("internal error: entered unreachable code"),
+                                ::tear::Looping::Resume(v) => {
+                                    #[allow(unused_macros)]
+                                    macro_rules! __tear_duplicate_label {
+                                        ('b [x]) => {}; ('b $other : tt) =>
+                                        {
+                                            compile_error!
+                                            (concat!
+                                            ("duplicate label ", stringify! ('b),
+                                            " in twist! -label list"))
+                                        }; ('c [x x]) => {}; ('c $other : tt) =>
+                                        {
+                                            compile_error!
+                                            (concat!
+                                            ("duplicate label ", stringify! ('c),
+                                            " in twist! -label list"))
+                                        }; ('d [x x x]) => {}; ('d $other : tt) =>
+                                        {
+                                            compile_error!
+                                            (concat!
+                                            ("duplicate label ", stringify! ('d),
+                                            " in twist! -label list"))
+                                        }; ('a []) => {}; ('a $other : tt) =>
+                                        {
+                                            compile_error!
+                                            (concat!
+                                            ("duplicate label ", stringify! ('a),
+                                            " in twist! -label list"))
+                                        };
+                                    }
+                                    v
+                                }
+                                ::tear::Looping::Break { label: ::core::option::Option::None
+                                    } => {
+                                    ();
+                                    break;
+                                }
+                                ::tear::Looping::Break {
+                                    label: ::core::option::Option::Some(l) } => {
+                                    match l {
+                                        1 => { break 'b; }
+                                        2 => { break 'c; }
+                                        3 => { break 'd; }
+                                        _ => unsafe { ::core::hint::unreachable_unchecked() },
+                                    }
+                                }
+                                ::tear::Looping::Redo { .. } =>
+                                    ::tear::twist_impl::__fail(::tear::TearErrorKind::RedoWithoutTredo),
+                                ::tear::Looping::Continue { label } =>
+                                    match label {
+                                        ::core::option::Option::None => continue,
+                                        ::core::option::Option::Some(1) => continue 'b,
+                                        ::core::option::Option::Some(2) => continue 'c,
+                                        ::core::option::Option::Some(3) => continue 'd,
+                                        ::core::option::Option::Some(0) => continue 'a,
+                                        ::core::option::Option::Some(l) => unsafe {
+                                            ::core::hint::unreachable_unchecked()
+                                        },
+                                    },
+                                ::tear::Looping::BreakVal {
+                                    label: ::core::option::Option::None, .. } => {
+                                    ();
+                                    ::tear::twist_impl::__fail(::tear::TearErrorKind::BreakValInNotLoop);
+                                }
+                                ::tear::Looping::BreakVal {
+                                    label: ::core::option::Option::Some(l), value: v } => {
+                                    match l {
+                                        0 => { break 'a v; }
+                                        _ => unsafe { ::core::hint::unreachable_unchecked() },
+                                    }
+                                }
+                            };
+                        sum +=
+                            match ::tear::IntoLooping::into_looping(Looping::<i32,
+                                            Box<dyn std::any::Any>>::Resume(1))
+                                {ref signal if
+                                    ::tear::twist_impl::__check_label(signal, 2,
+                                        "Looping::<i32, Box<dyn std::any::Any>>::Resume(1)") =>
+                                    ::core::panicking::panic("internal error: entered unreachable code"),
+                                ::tear::Looping::Resume(v) => {
+                                    #[allow(unused_macros)]
+                                    macro_rules! __tear_duplicate_label {
+                                        ('b [x]) => {}; ('b $other : tt) =>
+                                        {
+                                            compile_error!
+                                            (concat!
+                                            ("duplicate label ", stringify! ('b),
+                                            " in twist! -label list"))
+                                        }; ('a []) => {}; ('a $other : tt) =>
+                                        {
+                                            compile_error!
+                                            (concat!
+                                            ("duplicate label ", stringify! ('a),
+                                            " in twist! -label list"))
+                                        };
+                                    }
+                                    v
+                                }
+                                ::tear::Looping::Break { label: ::core::option::Option::None
+                                    } => {
+                                    ();
+                                    break;
+                                }
+                                ::tear::Looping::Break {
+                                    label: ::core::option::Option::Some(l) } => {
+                                    match l {
+                                        1 => { break 'b; }
+                                        _ => ::tear::twist_impl::__invalid_label(l, 2),
+                                    }
+                                }
+                                ::tear::Looping::Redo { .. } =>
+                                    ::tear::twist_impl::__fail(::tear::TearErrorKind::RedoWithoutTredo),
+                                ::tear::Looping::Continue { label } =>
+                                    match label {
+                                        ::core::option::Option::None => continue,
+                                        ::core::option::Option::Some(1) => continue 'b,
+                                        ::core::option::Option::Some(0) => continue 'a,
+                                        ::core::option::Option::Some(l) =>
+                                            ::tear::twist_impl::__invalid_label(l, 2),
+                                    },
+                                ::tear::Looping::BreakVal {
+                                    label: ::core::option::Option::None, .. } => {
+                                    ();
+                                    ::tear::twist_impl::__fail(::tear::TearErrorKind::BreakValInNotLoop);
+                                }
+                                ::tear::Looping::BreakVal {
+                                    label: ::core::option::Option::Some(l), value: v } => {
+                                    match l {
+                                        0 => {
+                                            match v.downcast::<i32>().map(|v| *v) {
+                                                ::core::result::Result::Ok(v) => { break 'a v; }
+                                                ::core::result::Result::Err(b) => {
+                                                    let _ = b;
+                                                    ::tear::twist_impl::__bad_type("\'a", "i32",
+                                                        ::core::any::Any::type_id(&*b))
+                                                }
+                                            }
+                                        }
+                                        _ => ::tear::twist_impl::__invalid_label(l, 2),
+                                    }
+                                }
+                            };
+                        sum +=
+                            match ::tear::IntoLooping::into_looping(Looping::<i32,
+                                            Box<dyn std::any::Any>>::Resume(1))
+                                {ref signal if
+                                    ::tear::twist_impl::__check_label(signal, 4,
+                                        "Looping::<i32, Box<dyn std::any::Any>>::Resume(1)") =>
+                                    ::core::panicking::panic("internal error: entered unreachable code"),
+                                ::tear::Looping::Resume(v) => {
+                                    #[allow(unused_macros)]
+                                    macro_rules! __tear_duplicate_label {
+                                        ('b [x]) => {}; ('b $other : tt) =>
+                                        {
+                                            compile_error!
+                                            (concat!
+                                            ("duplicate label ", stringify! ('b),
+                                            " in twist! -label list"))
+                                        }; ('c [x x]) => {}; ('c $other : tt) =>
+                                        {
+                                            compile_error!
+                                            (concat!
+                                            ("duplicate label ", stringify! ('c),
+                                            " in twist! -label list"))
+                                        }; ('d [x x x]) => {}; ('d $other : tt) =>
+                                        {
+                                            compile_error!
+                                            (concat!
+                                            ("duplicate label ", stringify! ('d),
+                                            " in twist! -label list"))
+                                        }; ('a []) => {}; ('a $other : tt) =>
+                                        {
+                                            compile_error!
+                                            (concat!
+                                            ("duplicate label ", stringify! ('a),
+                                            " in twist! -label list"))
+                                        };
+                                    }
+                                    v
+                                }
+                                ::tear::Looping::Break { label: ::core::option::Option::None
+                                    } => {
+                                    ();
+                                    break;
+                                }
+                                ::tear::Looping::Break {
+                                    label: ::core::option::Option::Some(l) } => {
+                                    match l {
+                                        1 => { break 'b; }
+                                        2 => { break 'c; }
+                                        3 => { break 'd; }
+                                        _ => ::tear::twist_impl::__invalid_label(l, 4),
+                                    }
+                                }
+                                ::tear::Looping::Redo { .. } =>
+                                    ::tear::twist_impl::__fail(::tear::TearErrorKind::RedoWithoutTredo),
+                                ::tear::Looping::Continue { label } =>
+                                    match label {
+                                        ::core::option::Option::None => continue,
+                                        ::core::option::Option::Some(1) => continue 'b,
+                                        ::core::option::Option::Some(2) => continue 'c,
+                                        ::core::option::Option::Some(3) => continue 'd,
+                                        ::core::option::Option::Some(0) => continue 'a,
+                                        ::core::option::Option::Some(l) =>
+                                            ::tear::twist_impl::__invalid_label(l, 4),
+                                    },
+                                ::tear::Looping::BreakVal {
+                                    label: ::core::option::Option::None, .. } => {
+                                    ();
+                                    ::tear::twist_impl::__fail(::tear::TearErrorKind::BreakValInNotLoop);
+                                }
+                                ::tear::Looping::BreakVal {
+                                    label: ::core::option::Option::Some(l), value: v } => {
+                                    match l {
+                                        0 => {
+                                            match v.downcast::<i32>().map(|v| *v) {
+                                                ::core::result::Result::Ok(v) => { break 'a v; }
+                                                ::core::result::Result::Err(b) => {
+                                                    let _ = b;
+                                                    ::tear::twist_impl::__bad_type("\'a", "i32",
+                                                        ::core::any::Any::type_id(&*b))
+                                                }
+                                            }
+                                        }
+                                        _ => ::tear::twist_impl::__invalid_label(l, 4),
+                                    }
+                                }
+                            };
+                        sum +=
+                            match ::tear::IntoLooping::into_looping(Looping::<i32,
+                                            tear::BreakValError>::Resume(1)) {
+                                _ if false =>
+                                    ::core::panicking::panic("internal error: entered unreachable code"),
+                                ::tear::Looping::Resume::<_, ::tear::BreakValError>(v) => v,
+                                _ if false =>
+                                    ::core::panicking::panic("internal error: entered unreachable code"),
+                                ::tear::Looping::Break { label: ::core::option::Option::None
+                                    } => {
+                                    break 'b
+                                }
+                                ::tear::Looping::Redo { .. } =>
+                                    ::tear::twist_impl::__fail(::tear::TearErrorKind::RedoWithoutTredo),
+                                ::tear::Looping::Continue {
+                                    label: ::core::option::Option::None } => continue 'b,
+                                _ if false =>
+                                    ::core::panicking::panic("internal error: entered unreachable code"),
+                                ::tear::Looping::BreakVal { .. } =>
+                                    ::tear::twist_impl::__fail(::tear::TearErrorKind::BreakValInNotLoop),
+                                ::tear::Looping::Break {
+                                    label: ::core::option::Option::Some(l) } |
+                                    ::tear::Looping::Continue {
+                                    label: ::core::option::Option::Some(l) } |
+                                    ::tear::Looping::BreakVal {
+                                    label: ::core::option::Option::Some(l), .. } =>
+                                    ::tear::twist_impl::__single_label(::core::option::Option::Some("\'b"),
+                                        l),
+                            };
+                        sum +=
+                            match ::tear::IntoLooping::into_looping(ok(1)) {
+                                _ if false =>
+                                    ::core::panicking::panic("internal error: entered unreachable code"),
+                                ::tear::Looping::Resume(v) => v,
+                                _ if false =>
+                                    ::core::panicking::panic("internal error: entered unreachable code"),
+                                ::tear::Looping::Break { .. } =>
+                                    ::tear::twist_impl::__fail(::tear::TearErrorKind::BreakWithoutVal),
+                                ::tear::Looping::Redo { .. } =>
+                                    ::tear::twist_impl::__fail(::tear::TearErrorKind::RedoWithoutTredo),
+                                ::tear::Looping::Continue {
+                                    label: ::core::option::Option::None } => continue 'a,
+                                _ if false =>
+                                    ::core::panicking::panic("internal error: entered unreachable code"),
+                                ::tear::Looping::BreakVal {
+                                    label: ::core::option::Option::None, value: v } => {
+                                    break 'a v
+                                }
+                                ::tear::Looping::Break {
+                                    label: ::core::option::Option::Some(l) } |
+                                    ::tear::Looping::Continue {
+                                    label: ::core::option::Option::Some(l) } |
+                                    ::tear::Looping::BreakVal {
+                                    label: ::core::option::Option::Some(l), .. } =>
+                                    ::tear::twist_impl::__single_label(::core::option::Option::Some("\'a"),
+                                        l),
+                            };
+                        sum +=
+                            {
+                                #[allow(dead_code)]
+                                struct Label;
+                                #[allow(dead_code)]
+                                impl Label {
+                                    const A: usize = 0;
+                                    const B: usize = 1;
+                                }
+                                match ::tear::IntoLooping::into_looping(ok(1))
+                                    {ref signal if
+                                        ::tear::twist_impl::__check_label(signal, 2, "ok(1)") =>
+                                        ::core::panicking::panic("internal error: entered unreachable code"),
+                                    ::tear::Looping::Resume(v) => {
+                                        #[allow(unused_macros)]
+                                        macro_rules! __tear_duplicate_label {
+                                            ('b [x]) => {}; ('b $other : tt) =>
+                                            {
+                                                compile_error!
+                                                (concat!
+                                                ("duplicate label ", stringify! ('b),
+                                                " in twist! -label list"))
+                                            }; ('a []) => {}; ('a $other : tt) =>
+                                            {
+                                                compile_error!
+                                                (concat!
+                                                ("duplicate label ", stringify! ('a),
+                                                " in twist! -label list"))
+                                            };
+                                        }
+                                        v
+                                    }
+                                    ::tear::Looping::Break { label: ::core::option::Option::None
+                                        } => {
+                                        ();
+                                        break;
+                                    }
+                                    ::tear::Looping::Break {
+                                        label: ::core::option::Option::Some(l) } => {
+                                        match l {
+                                            1 => { break 'b; }
+                                            _ => ::tear::twist_impl::__invalid_label(l, 2),
+                                        }
+                                    }
+                                    ::tear::Looping::Redo { .. } =>
+                                        ::tear::twist_impl::__fail(::tear::TearErrorKind::RedoWithoutTredo),
+                                    ::tear::Looping::Continue { label } =>
+                                        match label {
+                                            ::core::option::Option::None => continue,
+                                            ::core::option::Option::Some(1) => continue 'b,
+                                            ::core::option::Option::Some(0) => continue 'a,
+                                            ::core::option::Option::Some(l) =>
+                                                ::tear::twist_impl::__invalid_label(l, 2),
+                                        },
+                                    ::tear::Looping::BreakVal {
+                                        label: ::core::option::Option::None, .. } => {
+                                        ();
+                                        ::tear::twist_impl::__fail(::tear::TearErrorKind::BreakValInNotLoop);
+                                    }
+                                    ::tear::Looping::BreakVal {
+                                        label: ::core::option::Option::Some(l), value: v } => {
+                                        match l {
+                                            0 => { break 'a v; }
+                                            _ => ::tear::twist_impl::__invalid_label(l, 2),
+                                        }
+                                    }
+                                }
+                            };
+                        sum +=
+                            {
+                                #[allow(dead_code)]
+                                struct Label;
+                                #[allow(dead_code)]
+                                impl Label {
+                                    const A: usize = 0;
+                                    const C: usize = 2;
+                                }
+                                match ::tear::IntoLooping::into_looping(ok(1))
+                                    {ref signal if
+                                        ::tear::twist_impl::__check_label(signal, 3, "ok(1)") =>
+                                        ::core::panicking::panic("internal error: entered unreachable code"),
+                                    ::tear::Looping::Resume(v) => {
+                                        #[allow(unused_macros)]
+                                        macro_rules! __tear_duplicate_label {
+                                            ('b [x]) => {}; ('b $other : tt) =>
+                                            {
+                                                compile_error!
+                                                (concat!
+                                                ("duplicate label ", stringify! ('b),
+                                                " in twist! -label list"))
+                                            }; ('c [x x]) => {}; ('c $other : tt) =>
+                                            {
+                                                compile_error!
+                                                (concat!
+                                                ("duplicate label ", stringify! ('c),
+                                                " in twist! -label list"))
+                                            }; ('a []) => {}; ('a $other : tt) =>
+                                            {
+                                                compile_error!
+                                                (concat!
+                                                ("duplicate label ", stringify! ('a),
+                                                " in twist! -label list"))
+                                            };
+                                        }
+                                        v
+                                    }
+                                    ::tear::Looping::Break { label: ::core::option::Option::None
+                                        } => {
+                                        ();
+                                        break;
+                                    }
+                                    ::tear::Looping::Break {
+                                        label: ::core::option::Option::Some(l) } => {
+                                        match l {
+                                            1 => { break 'b; }
+                                            2 => { break 'c; }
+                                            _ => ::tear::twist_impl::__invalid_label(l, 3),
+                                        }
+                                    }
+                                    ::tear::Looping::Redo { .. } =>
+                                        ::tear::twist_impl::__fail(::tear::TearErrorKind::RedoWithoutTredo),
+                                    ::tear::Looping::Continue { label } =>
+                                        match label {
+                                            ::core::option::Option::None => continue,
+                                            ::core::option::Option::Some(1) => continue 'b,
+                                            ::core::option::Option::Some(2) => continue 'c,
+                                            ::core::option::Option::Some(0) => continue 'a,
+                                            ::core::option::Option::Some(l) =>
+                                                ::tear::twist_impl::__invalid_label(l, 3),
+                                        },
+                                    ::tear::Looping::BreakVal {
+                                        label: ::core::option::Option::None, .. } => {
+                                        ();
+                                        ::tear::twist_impl::__fail(::tear::TearErrorKind::BreakValInNotLoop);
+                                    }
+                                    ::tear::Looping::BreakVal {
+                                        label: ::core::option::Option::Some(l), value: v } => {
+                                        match l {
+                                            0 => { break 'a v; }
+                                            _ => ::tear::twist_impl::__invalid_label(l, 3),
+                                        }
+                                    }
+                                }
+                            };
+                        sum +=
+                            {
+                                #[allow(unused_macros)]
+                                macro_rules! __tear_duplicate_label {
+                                    ('b [x]) => {}; ('b $other : tt) =>
+                                    {
+                                        compile_error!
+                                        (concat!
+                                        ("duplicate label ", stringify! ('b),
+                                        " in twist! -label list"))
+                                    }; ('a []) => {}; ('a $other : tt) =>
+                                    {
+                                        compile_error!
+                                        (concat!
+                                        ("duplicate label ", stringify! ('a),
+                                        " in twist! -label list"))
+                                    };
+                                }
+                                #[allow(dead_code)]
+                                enum Sig { A(i32), }
+                                match ::tear::IntoLooping::into_looping(go(1)) {
+                                    ::tear::Looping::Resume(v) => v,
+                                    ::tear::Looping::Break { label: ::core::option::Option::None
+                                        } => {
+                                        ();
+                                        break;
+                                    }
+                                    ::tear::Looping::Break {
+                                        label: ::core::option::Option::Some(l) } => {
+                                        match l {
+                                            1 => { break 'b; }
+                                            _ => ::tear::twist_impl::__invalid_label(l, 2),
+                                        }
+                                    }
+                                    ::tear::Looping::Redo { .. } =>
+                                        ::tear::twist_impl::__fail(::tear::TearErrorKind::RedoWithoutTredo),
+                                    ::tear::Looping::Continue { label } =>
+                                        match label {
+                                            ::core::option::Option::None => continue,
+                                            ::core::option::Option::Some(1) => continue 'b,
+                                            ::core::option::Option::Some(0) => continue 'a,
+                                            ::core::option::Option::Some(l) =>
+                                                ::tear::twist_impl::__invalid_label(l, 2),
+                                        },
+                                    ::tear::Looping::BreakVal::<_, Sig> { value, .. } => {
+                                        match value { Sig::A(v) => { break 'a v; } }
+                                    }
+                                }
+                            };
+                        sum +=
+                            {
+                                #[allow(unused_macros)]
+                                macro_rules! __tear_duplicate_label {
+                                    ('a []) => {}; ('a $other : tt) =>
+                                    {
+                                        compile_error!
+                                        (concat!
+                                        ("duplicate label ", stringify! ('a),
+                                        " in twist! -label list"))
+                                    }; ('b [x]) => {}; ('b $other : tt) =>
+                                    {
+                                        compile_error!
+                                        (concat!
+                                        ("duplicate label ", stringify! ('b),
+                                        " in twist! -label list"))
+                                    }; ('c [x x]) => {}; ('c $other : tt) =>
+                                    {
+                                        compile_error!
+                                        (concat!
+                                        ("duplicate label ", stringify! ('c),
+                                        " in twist! -label list"))
+                                    };
+                                }
+                                #[allow(dead_code)]
+                                enum Sig { A(i32), B(()), C(()), }
+                                match ::tear::IntoLooping::into_looping(go(1)) {
+                                    ::tear::Looping::Resume(v) => v,
+                                    ::tear::Looping::Break { label: ::core::option::Option::None
+                                        } => {
+                                        ();
+                                        break;
+                                    }
+                                    ::tear::Looping::Break {
+                                        label: ::core::option::Option::Some(l) } => {
+                                        match l { _ => ::tear::twist_impl::__invalid_label(l, 3), }
+                                    }
+                                    ::tear::Looping::Redo { .. } =>
+                                        ::tear::twist_impl::__fail(::tear::TearErrorKind::RedoWithoutTredo),
+                                    ::tear::Looping::Continue { label } =>
+                                        match label {
+                                            ::core::option::Option::None => continue,
+                                            ::core::option::Option::Some(0) => continue 'a,
+                                            ::core::option::Option::Some(1) => continue 'b,
+                                            ::core::option::Option::Some(2) => continue 'c,
+                                            ::core::option::Option::Some(l) =>
+                                                ::tear::twist_impl::__invalid_label(l, 3),
+                                        },
+                                    ::tear::Looping::BreakVal::<_, Sig> { value, .. } => {
+                                        match value {
+                                            Sig::A(v) => { break 'a v; }
+                                            Sig::B(v) => { break 'b v; }
+                                            Sig::C(v) => { break 'c v; }
+                                        }
+                                    }
+                                }
+                            };
+                        sum +=
+                            match match ::tear::IntoLooping::into_looping(ok(1)) {
+                                    v @ ::tear::Looping::Resume(_) => v,
+                                    v => { { sum += 0; }; v }
+                                }
+                                {ref signal if
+                                    ::tear::twist_impl::__check_label(signal, 2, "ok(1)") =>
+                                    ::core::panicking::panic("internal error: entered unreachable code"),
+                                ::tear::Looping::Resume(v) => {
+                                    #[allow(unused_macros)]
+                                    macro_rules! __tear_duplicate_label {
+                                        ('b [x]) => {}; ('b $other : tt) =>
+                                        {
+                                            compile_error!
+                                            (concat!
+                                            ("duplicate label ", stringify! ('b),
+                                            " in twist! -label list"))
+                                        }; ('a []) => {}; ('a $other : tt) =>
+                                        {
+                                            compile_error!
+                                            (concat!
+                                            ("duplicate label ", stringify! ('a),
+                                            " in twist! -label list"))
+                                        };
+                                    }
+                                    v
+                                }
+                                ::tear::Looping::Break { label: ::core::option::Option::None
+                                    } => {
+                                    ();
+                                    break;
+                                }
+                                ::tear::Looping::Break {
+                                    label: ::core::option::Option::Some(l) } => {
+                                    match l {
+                                        1 => { break 'b; }
+                                        _ => ::tear::twist_impl::__invalid_label(l, 2),
+                                    }
+                                }
+                                ::tear::Looping::Redo { .. } =>
+                                    ::tear::twist_impl::__fail(::tear::TearErrorKind::RedoWithoutTredo),
+                                ::tear::Looping::Continue { label } =>
+                                    match label {
+                                        ::core::option::Option::None => continue,
+                                        ::core::option::Option::Some(1) => continue 'b,
+                                        ::core::option::Option::Some(0) => continue 'a,
+                                        ::core::option::Option::Some(l) =>
+                                            ::tear::twist_impl::__invalid_label(l, 2),
+                                    },
+                                ::tear::Looping::BreakVal {
+                                    label: ::core::option::Option::None, .. } => {
+                                    ();
+                                    ::tear::twist_impl::__fail(::tear::TearErrorKind::BreakValInNotLoop);
+                                }
+                                ::tear::Looping::BreakVal {
+                                    label: ::core::option::Option::Some(l), value: v } => {
+                                    match l {
+                                        0 => { break 'a v; }
+                                        _ => ::tear::twist_impl::__invalid_label(l, 2),
+                                    }
+                                }
+                            };
+                        sum +=
+                            match ::tear::IntoLooping::into_looping(ok(1))
+                                {ref signal if
+                                    ::tear::twist_impl::__check_label(signal, 4, "ok(1)") =>
+                                    ::core::panicking::panic("internal error: entered unreachable code"),
+                                ::tear::Looping::Resume(v) => {
+                                    #[allow(unused_macros)]
+                                    macro_rules! __tear_duplicate_label {
+                                        ('b [x]) => {}; ('b $other : tt) =>
+                                        {
+                                            compile_error!
+                                            (concat!
+                                            ("duplicate label ", stringify! ('b),
+                                            " in twist! -label list"))
+                                        }; ('c [x x]) => {}; ('c $other : tt) =>
+                                        {
+                                            compile_error!
+                                            (concat!
+                                            ("duplicate label ", stringify! ('c),
+                                            " in twist! -label list"))
+                                        }; ('d [x x x]) => {}; ('d $other : tt) =>
+                                        {
+                                            compile_error!
+                                            (concat!
+                                            ("duplicate label ", stringify! ('d),
+                                            " in twist! -label list"))
+                                        }; ('a []) => {}; ('a $other : tt) =>
+                                        {
+                                            compile_error!
+                                            (concat!
+                                            ("duplicate label ", stringify! ('a),
+                                            " in twist! -label list"))
+                                        };
+                                    }
+                                    v
+                                }
+                                ::tear::Looping::Break { label: ::core::option::Option::None
+                                    } => {
+                                    ();
+                                    break;
+                                }
+                                ::tear::Looping::Break {
+                                    label: ::core::option::Option::Some(l) } => {
+                                    match l {
+                                        1 => { break 'b; }
+                                        2 => { break 'c; }
+                                        3 => { break 'd; }
+                                        _ => ::tear::twist_impl::__invalid_label(l, 4),
+                                    }
+                                }
+                                ::tear::Looping::Redo { .. } =>
+                                    ::tear::twist_impl::__fail(::tear::TearErrorKind::RedoWithoutTredo),
+                                ::tear::Looping::Continue { label } =>
+                                    match label {
+                                        ::core::option::Option::None => continue,
+                                        ::core::option::Option::Some(1) => continue 'b,
+                                        ::core::option::Option::Some(2) => continue 'c,
+                                        ::core::option::Option::Some(3) => continue 'd,
+                                        ::core::option::Option::Some(0) => continue 'a,
+                                        ::core::option::Option::Some(l) =>
+                                            ::tear::twist_impl::__invalid_label(l, 4),
+                                    },
+                                ::tear::Looping::BreakVal {
+                                    label: ::core::option::Option::None, .. } => {
+                                    ();
+                                    ::tear::twist_impl::__fail(::tear::TearErrorKind::BreakValInNotLoop);
+                                }
+                                ::tear::Looping::BreakVal {
+                                    label: ::core::option::Option::Some(l), value: v } => {
+                                    match l {
+                                        0 => { break 'a v; }
+                                        _ => ::tear::twist_impl::__invalid_label(l, 4),
+                                    }
+                                }
+                            };
+                        sum +=
+                            match ::tear::IntoLooping::into_looping(ok(1))
+                                {ref signal if
+                                    ::tear::twist_impl::__check_label(signal, 4, "ok(1)") =>
+                                    ::core::panicking::panic("internal error: entered unreachable code"),
+                                ::tear::Looping::Resume(v) => {
+                                    #[allow(unused_macros)]
+                                    macro_rules! __tear_duplicate_label {
+                                        ('d []) => {}; ('d $other : tt) =>
+                                        {
+                                            compile_error!
+                                            (concat!
+                                            ("duplicate label ", stringify! ('d),
+                                            " in twist! -label list"))
+                                        }; ('c [x]) => {}; ('c $other : tt) =>
+                                        {
+                                            compile_error!
+                                            (concat!
+                                            ("duplicate label ", stringify! ('c),
+                                            " in twist! -label list"))
+                                        }; ('b [x x]) => {}; ('b $other : tt) =>
+                                        {
+                                            compile_error!
+                                            (concat!
+                                            ("duplicate label ", stringify! ('b),
+                                            " in twist! -label list"))
+                                        }; ('a [x x x]) => {}; ('a $other : tt) =>
+                                        {
+                                            compile_error!
+                                            (concat!
+                                            ("duplicate label ", stringify! ('a),
+                                            " in twist! -label list"))
+                                        };
+                                    }
+                                    v
+                                }
+                                ::tear::Looping::Break { label: ::core::option::Option::None
+                                    } => {
+                                    ();
+                                    break;
+                                }
+                                ::tear::Looping::Break {
+                                    label: ::core::option::Option::Some(l) } => {
+                                    match l {
+                                        0 => { break 'd; }
+                                        1 => { break 'c; }
+                                        2 => { break 'b; }
+                                        _ => ::tear::twist_impl::__invalid_label(l, 4),
+                                    }
+                                }
+                                ::tear::Looping::Redo { .. } =>
+                                    ::tear::twist_impl::__fail(::tear::TearErrorKind::RedoWithoutTredo),
+                                ::tear::Looping::Continue { label } =>
+                                    match label {
+                                        ::core::option::Option::None => continue,
+                                        ::core::option::Option::Some(0) => continue 'd,
+                                        ::core::option::Option::Some(1) => continue 'c,
+                                        ::core::option::Option::Some(2) => continue 'b,
+                                        ::core::option::Option::Some(3) => continue 'a,
+                                        ::core::option::Option::Some(l) =>
+                                            ::tear::twist_impl::__invalid_label(l, 4),
+                                    },
+                                ::tear::Looping::BreakVal {
+                                    label: ::core::option::Option::None, .. } => {
+                                    ();
+                                    ::tear::twist_impl::__fail(::tear::TearErrorKind::BreakValInNotLoop);
+                                }
+                                ::tear::Looping::BreakVal {
+                                    label: ::core::option::Option::Some(l), value: v } => {
+                                    match l {
+                                        3 => { break 'a v; }
+                                        _ => ::tear::twist_impl::__invalid_label(l, 4),
+                                    }
+                                }
+                            };
+                        sum +=
+                            match ::tear::IntoLooping::into_looping(ok(1))
+                                {ref signal if
+                                    ::tear::twist_impl::__check_label(signal, 2, "ok(1)") =>
+                                    ::core::panicking::panic("internal error: entered unreachable code"),
+                                ::tear::Looping::Resume(v) => {
+                                    #[allow(unused_macros)]
+                                    macro_rules! __tear_duplicate_label {
+                                        ('b []) => {}; ('b $other : tt) =>
+                                        {
+                                            compile_error!
+                                            (concat!
+                                            ("duplicate label ", stringify! ('b),
+                                            " in twist! -label list"))
+                                        }; ('d [x]) => {}; ('d $other : tt) =>
+                                        {
+                                            compile_error!
+                                            (concat!
+                                            ("duplicate label ", stringify! ('d),
+                                            " in twist! -label list"))
+                                        };
+                                    }
+                                    v
+                                }
+                                ::tear::Looping::Break { label: ::core::option::Option::None
+                                    } => {
+                                    ();
+                                    break;
+                                }
+                                ::tear::Looping::Break {
+                                    label: ::core::option::Option::Some(l) } => {
+                                    match l {
+                                        0 => { break 'b; }
+                                        1 => { break 'd; }
+                                        _ => ::tear::twist_impl::__invalid_label(l, 2),
+                                    }
+                                }
+                                ::tear::Looping::Redo { .. } =>
+                                    ::tear::twist_impl::__fail(::tear::TearErrorKind::RedoWithoutTredo),
+                                ::tear::Looping::Continue { label } =>
+                                    match label {
+                                        ::core::option::Option::None => continue,
+                                        ::core::option::Option::Some(0) => continue 'b,
+                                        ::core::option::Option::Some(1) => continue 'd,
+                                        ::core::option::Option::Some(l) =>
+                                            ::tear::twist_impl::__invalid_label(l, 2),
+                                    },
+                                ::tear::Looping::BreakVal {
+                                    label: ::core::option::Option::None, .. } => {
+                                    ();
+                                    ::tear::twist_impl::__fail(::tear::TearErrorKind::BreakValInNotLoop);
+                                }
+                                ::tear::Looping::BreakVal {
+                                    label: ::core::option::Option::Some(l), value: v } => {
+                                    match l { _ => ::tear::twist_impl::__invalid_label(l, 2), }
+                                }
+                            };
+                        break 'a sum;
+                    };
+                }
+            }
+        };
+    match (&x, &20) {
+        (left_val, right_val) => {
+            if !(*left_val == *right_val) {
+                let kind = ::core::panicking::AssertKind::Eq;
+                ::core::panicking::assert_failed(kind, &*left_val,
+                    &*right_val, ::core::option::Option::None);
+            }
+        }
+    };
+}
+fn go<B>(v: i32) -> Looping<i32, B> { Looping::Resume(v) }
+extern crate test;
+#[rustc_test_marker = "expansion_snapshot"]
+#[doc(hidden)]
+pub const expansion_snapshot: test::TestDescAndFn =
+    test::TestDescAndFn {
+        desc: test::TestDesc {
+            name: test::StaticTestName("expansion_snapshot"),
+            ignore: true,
+            ignore_message: ::core::option::Option::None,
+            source_file: "tests/label_stress.rs",
+            start_line: 270usize,
+            start_col: 22usize,
+            end_line: 270usize,
+            end_col: 40usize,
+            compile_fail: false,
+            no_run: false,
+            should_panic: test::ShouldPanic::No,
+            test_type: test::TestType::IntegrationTest,
+        },
+        testfn: test::StaticTestFn(#[coverage(off)] ||
+                test::assert_test_result(expansion_snapshot())),
+    };
+#[ignore]
+fn expansion_snapshot() {
+    use std::process::Command;
+    let root = std::env::var("CARGO_MANIFEST_DIR").unwrap();
+    let out =
+        Command::new("cargo").args(["+nightly", "rustc", "--quiet", "--test",
+                                        "label_stress"]).args(["--manifest-path",
+                                    &::alloc::__export::must_use({
+                                                ::alloc::fmt::format(format_args!("{0}/Cargo.toml", root))
+                                            })]).args(["--target-dir",
+                                &::alloc::__export::must_use({
+                                            ::alloc::fmt::format(format_args!("{0}/target/expand",
+                                                    root))
+                                        })]).args(["--",
+                            "-Zunpretty=expanded"]).output().expect("cargo +nightly");
+    if !out.status.success() {
+        { ::std::rt::panic_display(&String::from_utf8_lossy(&out.stderr)); }
+    };
+    let path =
+        ::alloc::__export::must_use({
+                ::alloc::fmt::format(format_args!("{0}/tests/expand/label_stress.expanded.rs",
+                        root))
+            });
+    if std::env::var("TEAR_EXPAND").as_deref() == Ok("overwrite") {
+        std::fs::write(&path, &out.stdout).unwrap();
+    }
+    let expected = std::fs::read_to_string(&path).unwrap_or_default();
+    if !(String::from_utf8_lossy(&out.stdout) == expected) {
+        {
+            ::std::rt::panic_fmt(format_args!("The expansion differs from {0}",
+                    path));
+        }
+    };
+}
+#[rustc_main]
+#[coverage(off)]
+#[doc(hidden)]
+pub fn main() -> () {
+    extern crate test;
+    test::test_main_static(&[&enum_mode, &expansion_snapshot, &label,
+                    &label_box, &many, &named])
+}
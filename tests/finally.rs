@@ -0,0 +1,223 @@
+// Testing the `-finally` block of `twist!`
+use tear::twist;
+use tear::{next, last, resume, breakval};
+use tear::{stop, skip, go};
+use tear::anybox;
+use tear::{Looping, TwistError};
+
+// The block runs once for each signal that isn't Resume
+#[test] fn resume_skips_block () {
+	let mut closed = 0;
+	for i in 0..3 {
+		let v = twist! { -finally { closed += 1; } resume!(i) };
+		assert_eq![ v, i ];
+	}
+	assert_eq![ closed, 0 ];
+}
+
+#[test] fn break_runs_block () {
+	let mut closed = 0;
+	loop {
+		twist! { -finally { closed += 1; } last!() }
+		panic!("Should break before this");
+	}
+	assert_eq![ closed, 1 ];
+}
+
+#[test] fn continue_runs_block () {
+	let mut closed = 0;
+	let mut reached = 0;
+	for i in 0..4 {
+		twist! { -finally { closed += 1; } if i % 2 == 0 { next!() } else { resume!(()) } }
+		reached += 1;
+	}
+	assert_eq![ closed, 2 ];
+	assert_eq![ reached, 2 ];
+}
+
+#[test] fn breakval_runs_block () {
+	let mut closed = 0;
+	let x = loop {
+		twist! { -finally { closed += 1; } -val breakval!(5) }
+	};
+	assert_eq![ x, 5 ];
+	assert_eq![ closed, 1 ];
+}
+
+#[test] fn with_label_runs_block () {
+	let mut closed = 0;
+	'a: loop {
+		loop {
+			twist! { -finally { closed += 1; } -with 'a | last!() }
+		}
+	}
+	assert_eq![ closed, 1 ];
+}
+
+// `$e`, then `$f`, then the block, then the break
+#[test] fn evaluation_order () {
+	let mut log = Vec::new();
+	loop {
+		log.push("body");
+		let _ :i32 = twist! { -finally { log.push("finally"); }
+			{ log.push("e"); Err::<i32, _>("bad") } => |_| { log.push("f"); last!() }
+		};
+		unreachable!();
+	}
+	log.push("after");
+	assert_eq![ log, ["body", "e", "f", "finally", "after"] ];
+
+	let mut log = Vec::new();
+	for _ in 0..1 {
+		let _ :i32 = twist! { -finally { log.push("finally"); }
+			{ log.push("e"); None } =>> { log.push("l"); next!() }
+		};
+	}
+	assert_eq![ log, ["e", "l", "finally"] ];
+}
+
+#[test] fn good_value_skips_block () {
+	let mut closed = 0;
+	let mut sum = 0;
+	for v in [Ok(1), Err(()), Ok(2)] {
+		sum += twist! { -finally { closed += 1; } v => |_| next!() };
+	}
+	assert_eq![ sum, 3 ];
+	assert_eq![ closed, 1 ];
+}
+
+/* -label */
+
+#[test] fn label_signals () {
+	// The signal of the first pass, then Resume
+	for (i, expected) in [(0, -1), (1, 100), (2, 0), (3, 20), (4, 0), (5, 0)].iter() {
+		let mut closed = 0;
+		let mut pass = 0;
+		let mut x = 100;
+		'a: loop {
+			let y :i32 = 'b: loop {
+				loop {
+					pass += 1;
+					let signal = match if pass == 1 { *i } else { 5 } {
+						0 => stop!(),
+						1 => stop!(0),
+						2 => skip!(1),
+						3 => breakval!(1, 20),
+						4 => skip!(0),
+						_ => go!(()),
+					};
+					twist! { -finally { closed += 1; } -label 'a, 'b :i32 | signal }
+					break 'b 0;
+				}
+				break 'b -1;
+			};
+			x = y;
+			break;
+		}
+		assert_eq![ x, *expected, "Signal {}", i ];
+		assert_eq![ closed, if *i == 5 { 0 } else { 1 }, "Signal {}", i ];
+	}
+}
+
+#[test] fn label_continue () {
+	let mut closed = 0;
+	let mut outer = 0;
+	'a: for _ in 0..3 {
+		outer += 1;
+		loop {
+			twist! { -finally { closed += 1; } -label 'a | next!(0) }
+		}
+	}
+	assert_eq![ outer, 3 ];
+	assert_eq![ closed, 3 ];
+}
+
+#[test] fn val_label () {
+	let mut closed = 0;
+	let x = 'a: loop {
+		let y :i32 = loop {
+			twist! { -finally { closed += 1; } -val i32, -label 'a :i32 | breakval!(7) }
+		};
+		break 'a y + 1;
+	};
+	assert_eq![ x, 8 ];
+	assert_eq![ closed, 1 ];
+}
+
+#[test] fn box_label () {
+	let mut closed = 0;
+	let x = 'a: loop {
+		let _ :String = 'b: loop {
+			loop {
+				twist! { -finally { closed += 1; } -box -label 'a :i32, 'b :String |
+					Looping::BreakVal::<(), _> { label: Some(0), value: anybox!(9) }
+				}
+			}
+		};
+	};
+	assert_eq![ x, 9 ];
+	assert_eq![ closed, 1 ];
+}
+
+// The block runs before the fallback, and not again for its signal
+#[test] fn box_label_or () {
+	let mut log = Vec::new();
+	'a: loop {
+		let _ :i32 = 'b: loop {
+			loop {
+				twist! { -finally { log.push("finally"); } -box -label 'a, 'b :i32 -or |_| { log.push("or"); last!(0) } |
+					Looping::BreakVal::<(), _> { label: Some(1), value: anybox!("not an i32") }
+				}
+			}
+		};
+	}
+	assert_eq![ log, ["finally", "or"] ];
+}
+
+// The block runs before `-try` evaluates to an error
+#[test] fn try_invalid_label () {
+	let mut closed = 0;
+	'a: loop {
+		let r :Result<(), _> = twist! { -finally { closed += 1; } -try -label 'a | last!(3) };
+		assert_eq![ r, Err(TwistError::InvalidLabel { index: 3, max: 1 }) ];
+		break;
+	}
+	assert_eq![ closed, 1 ];
+}
+
+#[test] fn named () {
+	let mut closed = 0;
+	let x = 'a: loop {
+		loop {
+			twist! { -finally { closed += 1; } -named -label 'a as A :i32 | breakval!(Label::A, 4) }
+		}
+	};
+	assert_eq![ x, 4 ];
+	assert_eq![ closed, 1 ];
+}
+
+#[test] fn enum_mode () {
+	let mut closed = 0;
+	let x = 'a: loop {
+		loop {
+			twist! { -finally { closed += 1; } -enum Sig -label 'a as A :i32 |
+				Looping::BreakVal::<(), _> { label: None, value: Sig::A(6) }
+			}
+		}
+	};
+	assert_eq![ x, 6 ];
+	assert_eq![ closed, 1 ];
+}
+
+#[test] fn with_labels () {
+	let mut closed = 0;
+	let mut i = 0;
+	'a: loop {
+		i += 1;
+		loop {
+			twist! { -finally { closed += 1; } -with ['a] | if i < 3 { next!(0) } else { last!(0) } }
+		}
+	}
+	assert_eq![ i, 3 ];
+	assert_eq![ closed, 3 ];
+}
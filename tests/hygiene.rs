@@ -0,0 +1,355 @@
+// The macros should only use `$crate::` and `::core::` paths, so that they work when the crate is
+// renamed, without the prelude, and next to local items that have the names of the prelude items
+#![no_implicit_prelude]
+#![allow(dead_code, unused_macros, non_camel_case_types)]
+#![allow(clippy::never_loop, clippy::diverging_sub_expression, clippy::unit_arg)]
+
+extern crate tear as facade;
+
+// Catch the expansions that use the names of the prelude
+struct From;
+struct Into;
+struct Box;
+struct Some;
+struct None;
+struct Ok;
+struct Err;
+struct Option;
+struct Result;
+struct Vec;
+struct String;
+
+macro_rules! panic {
+	( $($t:tt)* ) => { compile_error!("The local `panic!` was called instead of `::core::panic!`") };
+}
+macro_rules! unreachable {
+	( $($t:tt)* ) => { compile_error!("The local `unreachable!` was called instead of `::core::unreachable!`") };
+}
+macro_rules! vec {
+	( $($t:tt)* ) => { compile_error!("The local `vec!` was called") };
+}
+macro_rules! format {
+	( $($t:tt)* ) => { compile_error!("The local `format!` was called") };
+}
+
+type Opt<T> = ::core::option::Option<T>;
+type Res<T, E> = ::core::result::Result<T, E>;
+
+fn some<T> (v :T) -> Opt<T> { ::core::option::Option::Some(v) }
+fn none<T> () -> Opt<T> { ::core::option::Option::None }
+fn ok<T, E> (v :T) -> Res<T, E> { ::core::result::Result::Ok(v) }
+fn err<T, E> (e :E) -> Res<T, E> { ::core::result::Result::Err(e) }
+
+mod returns {
+	use super::*;
+	use ::facade::{tear, tear_if, terror, terror_retry, terror_all, ret, maybe_match};
+	use ::facade::ValRet::{self, Val, Ret};
+
+	fn tear_forms (v :Opt<i32>) -> i32 {
+		let a = tear! { ValRet::<i32, i32>::Val(1) };
+		let b = tear! { v => |_| -1 };
+		let c = tear! { v =>> -2 };
+		a + b + c
+	}
+
+	fn tear_if_forms (v :i32, o :Opt<i32>) -> i32 {
+		tear_if! { v < 0, -1 };
+		tear_if! { let ::core::option::Option::Some(x) = o, x > 10, x; else if v == 0, 0 };
+		v
+	}
+
+	fn terror_forms (a :Res<i32, u8>, b :Opt<i32>) -> Res<i32, u16> {
+		let x = terror! { a };
+		let y = terror! { a => |e :u8| e };
+		let z = terror! { b =>> 7u16 };
+		let w = terror! { a => ::core::convert::Into::into : u16 };
+		ok(x + y + z + w)
+	}
+
+	fn terror_retry_forms (mut attempts :Opt<i32>) -> Res<i32, ()> {
+		let v = terror_retry! { 2, attempts.take() => |_| () };
+		ok(v)
+	}
+
+	fn terror_all_forms (a :Res<i32, u8>, b :Res<i32, u8>) -> Res<(i32, i32), u8> {
+		let (x, y) = terror_all! { a, b };
+		let (z, w) = terror_all! { a, b => |e| e };
+		ok((x + z, y + w))
+	}
+
+	fn ret_forms (v :i32) -> ValRet<i32, i32> {
+		if v < 0 { ret!(v) } else { Val(v) }
+	}
+
+	fn maybe_match_forms (v :ValRet<i32, i32>) -> Opt<i32> {
+		maybe_match! { v, Val(x) | Ret(x) if x > 0 => x }
+	}
+
+	#[test] fn hygiene () {
+		::std::assert_eq![ tear_forms(some(1)), 3 ];
+		::std::assert_eq![ tear_forms(none()), -1 ];
+		::std::assert_eq![ tear_if_forms(-1, none()), -1 ];
+		::std::assert_eq![ tear_if_forms(1, some(11)), 11 ];
+		::std::assert_eq![ tear_if_forms(0, some(1)), 0 ];
+		::std::assert_eq![ terror_forms(ok(1), some(2)), ok(5) ];
+		::std::assert_eq![ terror_forms(err(3), some(2)), err(3) ];
+		::std::assert_eq![ terror_forms(ok(1), none()), err(7) ];
+		::std::assert_eq![ terror_retry_forms(some(1)), ok(1) ];
+		::std::assert_eq![ terror_retry_forms(none()), err(()) ];
+		::std::assert_eq![ terror_all_forms(ok(1), ok(2)), ok((2, 4)) ];
+		::std::assert_eq![ terror_all_forms(ok(1), err(2)), err(2) ];
+		::std::assert_eq![ ret_forms(-1), Ret(-1) ];
+		::std::assert_eq![ ret_forms(1), Val(1) ];
+		::std::assert_eq![ maybe_match_forms(Ret(1)), some(1) ];
+		::std::assert_eq![ maybe_match_forms(Val(-1)), none() ];
+	}
+}
+
+mod loops {
+	use super::*;
+	use ::facade::{twist, next_if, last_if, resume_if, twist_if};
+	use ::facade::{some_or_next, some_or_last, ok_or_next, ok_or_last, tredo_loop};
+	use ::facade::{last, next, resume, breakval, redo, stop, skip, go};
+	use ::facade::{Looping, BreakValError};
+
+	#[test] fn twist_forms () {
+		let mut sum = 0;
+		for v in &[some(1), none(), some(2)] {
+			sum += twist! { *v => |_| next!() };
+			sum += twist! { *v =>> next!() };
+		}
+		::std::assert_eq![ sum, 6 ];
+
+		let x = loop {
+			twist! { -val breakval!(1) }
+		};
+		::std::assert_eq![ x, 1 ];
+
+		let mut broke = false;
+		let mut closed = 0;
+		let x = 'a: loop {
+			for v in 0..3 {
+				twist! { -finally { closed += 1; } -track broke -label 'a :i32 |
+					if v == 1 { breakval!(0, 2) } else { go!(()) } }
+			}
+			break -1;
+		};
+		::std::assert_eq![ (x, broke, closed), (2, true, 1) ];
+
+		let x = 'a: loop {
+			let y = loop {
+				twist! { -val i32, -label 'a :i32 | if true { breakval!(1) } else { breakval!(0, 2) } }
+			};
+			break 'a y;
+		};
+		::std::assert_eq![ x, 1 ];
+
+		let x = 'a: loop {
+			'b: loop {
+				twist! { -named -label 'a as A :i32, 'b as B | breakval!(Label::A, 3) }
+			}
+		};
+		::std::assert_eq![ x, 3 ];
+
+		'a: loop {
+			loop {
+				let r :Res<(), _> = twist! { -try -label 'a | Looping::<(), BreakValError>::Break { label: some(3) } };
+				::std::assert_eq![ r, err(::facade::TwistError::InvalidLabel { index: 3, max: 1 }) ];
+				break 'a;
+			}
+		}
+
+		let x :Opt<i32> = 'a: loop {
+			loop {
+				twist! { -unchecked -label 'a :Opt<i32> | Looping::<(), _>::BreakVal { label: some(0), value: some(4) } }
+			}
+		};
+		::std::assert_eq![ x, some(4) ];
+	}
+
+	fn twist_ret (v :i32) -> Res<i32, u8> {
+		let x = loop {
+			twist! { -ret u8, -val if v < 0 { ::facade::ValRet::Ret(1) } else { ::facade::ValRet::Val(breakval!(v)) } }
+		};
+		ok(x)
+	}
+
+	#[test] fn twist_ret_forms () {
+		::std::assert_eq![ twist_ret(2), ok(2) ];
+		::std::assert_eq![ twist_ret(-2), err(1) ];
+	}
+
+	#[test] fn twist_loop_hint () {
+		let mut n = 0;
+		for _ in 0..3 {
+			twist! { -loop for if n == 1 { last!() } else { n += 1; resume!(()) } }
+		}
+		::std::assert_eq![ n, 1 ];
+	}
+
+	#[test] fn conditionals () {
+		let mut seen = 0;
+		'a: for v in 0..10 {
+			next_if! { v % 2 == 0, () };
+			next_if! { -with 'a, v == 3 };
+			last_if! { let 7 = v, () };
+			let w = resume_if! { v > 1, v; else 0 };
+			twist_if! { v > 5, go!(()) };
+			seen += w;
+		}
+		::std::assert_eq![ seen, 5 ];
+
+		let x = loop {
+			last_if! { -val true, 8 }
+		};
+		::std::assert_eq![ x, 8 ];
+	}
+
+	#[test] fn shortcuts () {
+		let mut sum = 0;
+		for v in &[some(1), none(), some(2)] {
+			sum += some_or_next! { *v };
+		}
+		for v in &[some(1), none(), some(2)] {
+			sum += some_or_last! { *v };
+		}
+		for v in &[ok(1), err(()), ok(2)] {
+			sum += ok_or_next! { *v };
+			sum += ok_or_next! { *v, |_| () };
+		}
+		for v in &[ok(1), err(()), ok(2)] {
+			sum += ok_or_last! { *v };
+		}
+		::std::assert_eq![ sum, 11 ];
+	}
+
+	#[test] fn signals () {
+		::std::assert![ ::std::matches!(last!(None), Looping::<(), BreakValError>::Break { label: ::core::option::Option::None }) ];
+		::std::assert![ ::std::matches!(next!(Some(1)), Looping::<(), BreakValError>::Continue { label: ::core::option::Option::Some(1) }) ];
+		let l :Looping<(), i32> = breakval!(Some(1), 2);
+		::std::assert_eq![ l, Looping::BreakVal { label: some(1), value: 2 } ];
+		let l :Looping<(), i32> = breakval!(None, 2);
+		::std::assert_eq![ l, Looping::BreakVal { label: none(), value: 2 } ];
+		let l :Looping<(), i32> = stop!(Some(2));
+		::std::assert_eq![ l, Looping::Break { label: some(2) } ];
+		let l :Looping<(), i32> = skip!(None);
+		::std::assert_eq![ l, Looping::Continue { label: none() } ];
+		let l :Looping<(), i32> = redo!(Some(0));
+		::std::assert_eq![ l, Looping::Redo { label: some(0) } ];
+		::std::assert_eq![ resume!(1; i32), Looping::Resume(1) ];
+	}
+
+	#[test] fn redo_loop () {
+		let mut tries = 0;
+		let mut sum = 0;
+		tredo_loop! { for v in 0..3 => {
+			tries += 1;
+			if v == 1 && tries == 2 { redo!() } else { sum += v; next!() }
+		} }
+		::std::assert_eq![ (sum, tries), (3, 4) ];
+	}
+}
+
+#[cfg(feature = "alloc")]
+mod boxed {
+	use super::*;
+	use ::facade::{tear_all, twist, anybox, unbox, Looping};
+
+	fn check (a :Res<i32, u8>, b :Res<i32, u8>) -> Res<(i32, i32), ::facade::__alloc::vec::Vec<u8>> {
+		let v = tear_all! { a, b };
+		ok(v)
+	}
+
+	#[test] fn all () {
+		::std::assert_eq![ check(ok(1), ok(2)), ok((1, 2)) ];
+		::std::assert_eq![ check(err(1), err(2)).unwrap_err().len(), 2 ];
+	}
+
+	#[test] fn boxes () {
+		::std::assert_eq![ unbox!(anybox!(3), i32), 3 ];
+		::std::assert_eq![ unbox!(anybox!(send_sync 3), i32), 3 ];
+		::std::assert![ unbox!(try anybox!(3), u8).is_none() ];
+
+		let x = 'a: loop {
+			let y :u8 = loop {
+				twist! { -box -val u8, -label 'a :i32 | Looping::BreakVal::<(), _> { label: some(0), value: anybox!(5i32) } }
+			};
+			break 'a y as i32;
+		};
+		::std::assert_eq![ x, 5 ];
+	}
+}
+
+#[cfg(feature = "testing")]
+mod testing {
+	use super::*;
+	use ::facade::{assert_good, assert_bad, assert_looping, resume, Looping};
+
+	#[test] fn assertions () {
+		::std::assert_eq![ assert_good! { some(3) }, 3 ];
+		assert_good! { ok::<_, ()>(3), 3 };
+		assert_bad! { err::<(), _>(4), 4 };
+		assert_looping! { resume!(1; ()), Resume(1) };
+		assert_looping! { Looping::<(), ()>::Break { label: some(1) }, Break(some(1)) };
+	}
+}
+
+#[cfg(feature = "control-flow")]
+mod control_flow {
+	use ::facade::twist;
+
+	#[test] fn cf () {
+		let x = loop {
+			twist! { -val -cf ::core::ops::ControlFlow::<i32, ()>::Break(1) }
+		};
+		::std::assert_eq![ x, 1 ];
+	}
+}
+
+#[cfg(feature = "block-labels")]
+mod block_labels {
+	use ::facade::{twist, stop, Looping};
+
+	#[test] fn block () {
+		let mut reached = false;
+		'b: {
+			twist! { -block -with 'b | stop!() };
+			reached = true;
+		}
+		::std::assert![ !reached ];
+		let _ :Looping<(), ()> = stop!();
+	}
+}
+
+#[cfg(feature = "const-labels")]
+mod typed {
+	use ::facade::{labels, twist, Looping};
+
+	labels! { Outer, Inner }
+
+	#[test] fn typed () {
+		let x = 'a: loop {
+			'b: loop {
+				twist! { -typed -label 'a :i32, 'b | Looping::<(), i32>::break_val_at::<Outer>(6) }
+			}
+		};
+		::std::assert_eq![ x, 6 ];
+	}
+}
+
+#[cfg(feature = "compat")]
+mod compat {
+	use ::facade::compat::guard;
+
+	fn first (v :&[i32]) -> i32 {
+		guard!(let [x, ..] = v else { return -1 });
+		guard!({ return 0 } unless let 1..=9 = *x);
+		*x
+	}
+
+	#[test] fn guards () {
+		::std::assert_eq![ first(&[]), -1 ];
+		::std::assert_eq![ first(&[10]), 0 ];
+		::std::assert_eq![ first(&[3]), 3 ];
+	}
+}
@@ -0,0 +1,111 @@
+// Testing the `into` modifier, that converts the break value with `Into`
+use tear::twist;
+use tear::breakval;
+use tear::{skip, go};
+use tear::Looping;
+
+// Breaks with the index of the first odd value, as a u8
+fn first_odd (v :i32, i :u8) -> Looping<(), u8> {
+	if v % 2 == 1 { breakval!(0, i) } else { go!(()) }
+}
+
+#[test] fn with () {
+	let x :i64 = 'a: loop {
+		for v in 0..10u8 {
+			twist! { -val -with 'a into | if v == 3 { breakval!(v) } else { Looping::Resume(()) } }
+		}
+		break -1;
+	};
+	assert_eq![ x, 3 ];
+}
+
+#[test] fn with_continue () {
+	let mut count = 0;
+	let x :i64 = 'a: loop {
+		count += 1;
+		loop {
+			twist! { -val -with 'a into | if count < 3 { skip!() } else { breakval!(count as u8) } }
+		}
+	};
+	assert_eq![ x, 3 ];
+}
+
+#[test] fn with_mapping () {
+	let x :i64 = 'a: loop {
+		loop {
+			let _ :i32 = twist! { -val -with 'a into | "a".parse::<i32>() => |_| breakval!(7u8) };
+		}
+	};
+	assert_eq![ x, 7 ];
+}
+
+#[test] fn label () {
+	let index = |values :&[i32]| -> i64 {
+		'a: loop {
+			for (i, &v) in values.iter().enumerate() {
+				twist! { -label 'a: into i64 | first_odd(v, i as u8) }
+			}
+			break -1;
+		}
+	};
+	assert_eq![ index(&[2, 4, 5]), 2 ];
+	assert_eq![ index(&[2, 4]), -1 ];
+}
+
+// The break value type is `u8`, from `'b`, and `'a` converts it
+#[test] fn mixed_labels () {
+	for (i, expected) in [(0, (10, 0)), (1, (-1, 20))].iter() {
+		let mut inner = 0;
+		let x :i64 = 'a: loop {
+			inner = 'b: loop {
+				loop {
+					twist! { -label 'a: into i64, 'b: u8 | breakval!(*i, (*i as u8 + 1) * 10) }
+				}
+			};
+			break -1;
+		};
+		assert_eq![ (x, inner), *expected ];
+	}
+}
+
+#[test] fn val_label () {
+	let x :i64 = 'a: loop {
+		let y :u8 = loop {
+			twist! { -val u8, -label 'a: into i64 | breakval!(0, 5) }
+		};
+		break 'a y as i64 * 10;
+	};
+	assert_eq![ x, 5 ];
+}
+
+#[derive(Debug, PartialEq)]
+struct Meters(f64);
+
+impl From<u16> for Meters {
+	fn from (cm :u16) -> Self { Meters(cm as f64 / 100.) }
+}
+
+#[test] fn custom_from () {
+	let m :Meters = 'a: loop {
+		loop {
+			twist! { -val -with 'a into | breakval!(250u16) }
+		}
+	};
+	assert_eq![ m, Meters(2.5) ];
+
+	let m = 'a: loop {
+		loop {
+			twist! { -label 'a: into Meters | breakval!(0, 125u16) }
+		}
+	};
+	assert_eq![ m, Meters(1.25) ];
+}
+
+#[test] fn named () {
+	let x = 'a: loop {
+		'b: loop {
+			twist! { -named -label 'a as A: into i64, 'b as B | breakval!(Label::A, 4u8) }
+		}
+	};
+	assert_eq![ x, 4i64 ];
+}
@@ -0,0 +1,150 @@
+// Testing `twist!` with user-defined signal types, that implement `IntoLooping`
+use tear::twist;
+use tear::{Looping, IntoLooping};
+use tear::ValRet::{Val, Ret};
+
+#[derive(Debug, PartialEq)]
+enum Action {
+	Keep(i32),
+	SkipOne,
+	Abort(String),
+}
+
+// Aborting breaks the outer loop `'a`, skipping continues the inner one
+impl IntoLooping<i32, String> for Action {
+	fn into_looping (self) -> Looping<i32, String> {
+		match self {
+			Action::Keep(v) => Looping::Resume(v),
+			Action::SkipOne => Looping::Continue { label: Some(1) },
+			Action::Abort(e) => Looping::BreakVal { label: Some(0), value: e },
+		}
+	}
+}
+
+// A state machine that aborts after seeing three negative values
+struct Machine {
+	negatives :u32,
+}
+
+impl Machine {
+	fn step (&mut self, v :i32) -> Action {
+		if v >= 0 {
+			Action::Keep(v)
+		} else if self.negatives < 2 {
+			self.negatives += 1;
+			Action::SkipOne
+		} else {
+			Action::Abort(format!("too many negatives at {}", v))
+		}
+	}
+}
+
+fn run (rows :&[&[i32]]) -> (i32, String) {
+	let mut machine = Machine { negatives: 0 };
+	let mut sum = 0;
+	let e = 'a: loop {
+		for row in rows {
+			'b: for &v in row.iter() {
+				sum += twist! { -label 'a :String, 'b | machine.step(v) };
+			}
+		}
+		break "done".to_string();
+	};
+	(sum, e)
+}
+
+#[test] fn label () {
+	assert_eq![ run(&[&[1, 2], &[3]]), (6, "done".to_string()) ];
+	assert_eq![ run(&[&[1, -1], &[-2, 3]]), (4, "done".to_string()) ];
+	assert_eq![ run(&[&[1, -1], &[-2, 3], &[-3, 4]]), (4, "too many negatives at -3".to_string()) ];
+}
+
+// Only breaks the innermost loop
+struct Stop;
+
+impl IntoLooping<(), tear::BreakValError> for Stop {
+	fn into_looping (self) -> Looping<(), tear::BreakValError> {
+		Looping::Break { label: None }
+	}
+}
+
+#[test] fn single () {
+	let mut count = 0;
+	loop {
+		count += 1;
+		twist! { Stop }
+	}
+	assert_eq![ count, 1 ];
+}
+
+#[test] fn mapping () {
+	let parse = |values :&[&str]| -> (i32, String) {
+		let mut sum = 0;
+		let e = 'a: loop {
+			'b: for s in values {
+				sum += twist! { -label 'a :String, 'b | s.parse::<i32>() => |_| {
+					if s.is_empty() { Action::SkipOne } else { Action::Abort(s.to_string()) }
+				} };
+			}
+			break "done".to_string();
+		};
+		(sum, e)
+	};
+	assert_eq![ parse(&["1", "", "2"]), (3, "done".to_string()) ];
+	assert_eq![ parse(&["1", "a", "2"]), (1, "a".to_string()) ];
+}
+
+#[test] fn finally () {
+	let mut closed = 0;
+	let mut sum = 0;
+	let e = 'a: loop {
+		'b: for &v in &[1, -1, 2] {
+			sum += twist! { -finally { closed += 1; } -label 'a :String, 'b |
+				if v < 0 { Action::Abort("negative".to_string()) } else { Action::Keep(v) }
+			};
+		}
+		break "done".to_string();
+	};
+	assert_eq![ (sum, e, closed), (1, "negative".to_string(), 1) ];
+}
+
+// Returns the error instead of breaking
+#[test] fn ret () {
+	let first_abort = |values :&[i32]| -> Result<i32, String> {
+		let mut machine = Machine { negatives: 0 };
+		let mut sum = 0;
+		let _ :String = 'a: loop {
+			'b: for &v in values {
+				sum += twist! { -ret String, -label 'a :String, 'b |
+					match machine.step(v) { Action::Abort(e) => Ret(e), action => Val(action) }
+				};
+			}
+			break "done".to_string();
+		};
+		Ok(sum)
+	};
+	assert_eq![ first_abort(&[1, -1, 2]), Ok(3) ];
+	assert_eq![ first_abort(&[-1, -2, -3, 1]), Err("too many negatives at -3".to_string()) ];
+}
+
+#[test] fn with_label () {
+	let mut count = 0;
+	'a: loop {
+		loop {
+			count += 1;
+			twist! { -with 'a | Stop }
+		}
+	}
+	assert_eq![ count, 1 ];
+}
+
+// The conversion goes with the other flags
+#[test] fn with_flags () {
+	let mut closed = 0;
+	let mut count = 0;
+	loop {
+		count += 1;
+		twist! { -finally { closed += 1; } -loop loop Stop }
+	}
+	assert_eq![ (count, closed), (1, 1) ];
+}
@@ -0,0 +1,208 @@
+// Testing LoopingIteratorExt and JudgeIteratorExt
+
+use tear::extra::*;
+
+#[test] fn early_exit () {
+	let mut seen = Vec::new();
+	let r = (1..10).tear_for_each(|v| {
+		if v > 3 { return stop!(); }
+		seen.push(v);
+		go!(())
+	});
+	assert_eq![ r, None::<()> ];
+	assert_eq![ seen, vec![1, 2, 3] ];
+}
+
+#[test] fn exhausted () {
+	let mut seen = Vec::new();
+	let r = (1..5).tear_for_each(|v| {
+		if v % 2 == 0 { return skip!(); }
+		seen.push(v);
+		go!(())
+	});
+	assert_eq![ r, None::<()> ];
+	assert_eq![ seen, vec![1, 3] ];
+}
+
+#[test] fn breakval () {
+	let words = ["a", "bb", "ccc", "dd"];
+	let r = words.iter().tear_for_each(|w| {
+		if w.len() > 2 { breakval!(*w) } else { go!(()) }
+	});
+	assert_eq![ r, Some("ccc") ];
+}
+
+#[cfg(not(feature = "tiny-panic"))] // The message is a short code with "tiny-panic"
+#[test]
+#[should_panic(expected = "invalid label index 0 (twist! declared 0 labels)")]
+fn labeled_break () {
+	let _ = (1..5).tear_for_each(|_| -> Looping<(), ()> { stop!(0) });
+}
+
+#[test] fn fold_resume () {
+	assert_eq![ (1..5).looping_fold(0, |acc, v| go!(acc + v)), Folded::Done::<_, ()>(10) ];
+}
+
+#[test] fn fold_continue () {
+	let r = (1..5).looping_fold(0, |acc, v| if v % 2 == 0 { skip!() } else { go!(acc + v) });
+	assert_eq![ r, Folded::Done::<_, ()>(4) ];
+}
+
+#[test] fn fold_break () {
+	let r = (1..5).looping_fold(0, |acc, v| if v > 2 { stop!() } else { go!(acc + v) });
+	assert_eq![ r, Folded::Done::<_, ()>(3) ];
+}
+
+#[test] fn fold_breakval () {
+	let r = (1..5).looping_fold(0, |acc, v| if acc > 2 { breakval!(v * 10) } else { go!(acc + v) });
+	assert_eq![ r, Folded::Broke(30) ];
+}
+
+#[cfg(not(feature = "tiny-panic"))] // The message is a short code with "tiny-panic"
+#[test]
+#[should_panic(expected = "invalid label index 1 (twist! declared 0 labels)")]
+fn fold_labeled_continue () {
+	let _ = (1..5).looping_fold(0, |_, _| -> Looping<i32, ()> { skip!(1) });
+}
+
+/* map_looping */
+
+#[test] fn map_looping_skip () {
+	let v :Vec<i32> = vec!["1", "a", "2", "", "3"].into_iter()
+		.map_looping(|s| s.parse::<i32>(), |_| next!())
+		.collect();
+	assert_eq![ v, vec![1, 2, 3] ];
+}
+
+#[test] fn map_looping_resume () {
+	let v :Vec<i32> = vec![Some(1), None, Some(3)].into_iter()
+		.map_looping(|x| x, |_| resume!(0))
+		.collect();
+	assert_eq![ v, vec![1, 0, 3] ];
+}
+
+#[test] fn map_looping_stop () {
+	let mut calls = 0;
+	let mut it = (1..10).map_looping(|v| { calls += 1; if v < 3 { Good(v) } else { Bad(v) } }, |_| stop!());
+	assert_eq![ it.by_ref().collect::<Vec<_>>(), vec![1, 2] ];
+	assert![ it.is_stopped() ];
+	assert_eq![ it.broke(), None::<&()> ];
+	// Fused, even though the inner iterator has more items
+	assert_eq![ it.next(), None ];
+	drop(it);
+	assert_eq![ calls, 3 ];
+}
+
+#[test] fn map_looping_breakval () {
+	let mut it = vec!["1", "2", "x", "3"].into_iter()
+		.map_looping(|s| s.parse::<i32>(), |e| breakval!(e.to_string()));
+	assert_eq![ it.size_hint(), (0, Some(4)) ];
+	assert_eq![ it.by_ref().sum::<i32>(), 3 ];
+	assert_eq![ it.broke().map(String::as_str), Some("invalid digit found in string") ];
+	assert_eq![ it.size_hint(), (0, Some(0)) ];
+}
+
+#[test] fn map_looping_exhausted () {
+	let mut it = (1..4).map_looping(Good::<_, ()>, |_| -> Looping<i32, ()> { stop!() });
+	assert_eq![ it.by_ref().count(), 3 ];
+	assert![ it.is_stopped() ];
+	assert_eq![ it.broke(), None ];
+}
+
+#[cfg(not(feature = "tiny-panic"))] // The message is a short code with "tiny-panic"
+#[test]
+#[should_panic(expected = "invalid label index 0 (twist! declared 0 labels)")]
+fn map_looping_labeled_continue () {
+	let _ = (1..5).map_looping(|_| None::<i32>, |_| -> Looping<i32, ()> { skip!(0) }).count();
+}
+
+/* JudgeIteratorExt */
+
+fn mixed () -> Vec<Result<i32, String>> {
+	vec![Ok(1), Err("a".to_string()), Ok(2), Err("b".to_string()), Ok(3)]
+}
+
+#[test] fn goods () {
+	assert_eq![ mixed().into_iter().goods().collect::<Vec<_>>(), vec![1, 2, 3] ];
+	assert_eq![ mixed().into_iter().goods().sum::<i32>(), 6 ];
+
+	let options = vec![Some(1), None, Some(2)];
+	assert_eq![ options.into_iter().goods().collect::<Vec<_>>(), vec![1, 2] ];
+
+	let morals = vec![Bad::<i32, i32>(1), Good(2)];
+	assert_eq![ morals.into_iter().goods().collect::<Vec<_>>(), vec![2] ];
+}
+
+#[test] fn bads () {
+	assert_eq![ mixed().into_iter().bads().collect::<Vec<_>>(), vec!["a", "b"] ];
+
+	let options = vec![Some(1), None, Some(2)];
+	assert_eq![ options.into_iter().bads().count(), 1 ];
+}
+
+#[test] fn goods_size_hint () {
+	let mut it = mixed().into_iter().goods();
+	assert_eq![ it.size_hint(), (0, Some(5)) ];
+	assert_eq![ it.next(), Some(1) ];
+	assert_eq![ it.size_hint(), (0, Some(4)) ];
+	assert_eq![ it.by_ref().count(), 2 ];
+	assert_eq![ it.size_hint(), (0, Some(0)) ];
+	assert_eq![ it.next(), None ];
+
+	assert_eq![ (0..).map(Some).bads().size_hint(), (0, None) ];
+}
+
+#[test] fn until_bad () {
+	let mut it = mixed().into_iter().until_bad();
+	assert_eq![ it.by_ref().collect::<Vec<_>>(), vec![1] ];
+	assert![ it.is_stopped() ];
+	assert_eq![ it.bad().map(String::as_str), Some("a") ];
+	assert_eq![ it.next(), None ];
+	assert_eq![ it.size_hint(), (0, Some(0)) ];
+	assert_eq![ it.into_bad(), Some("a".to_string()) ];
+
+	let mut it = vec![Some(1), Some(2)].into_iter().until_bad();
+	assert_eq![ it.size_hint(), (0, Some(2)) ];
+	assert_eq![ it.by_ref().sum::<i32>(), 3 ];
+	assert![ it.is_stopped() ];
+	assert![ it.into_bad().is_none() ];
+}
+
+// The inner iterator isn't advanced past the Bad value
+#[test] fn until_bad_stops () {
+	let mut values = mixed().into_iter();
+	let mut calls = 0;
+	let mut it = values.by_ref().inspect(|_| calls += 1).until_bad();
+	assert_eq![ it.by_ref().count(), 1 ];
+	assert_eq![ it.next(), None ];
+	assert_eq![ it.into_bad(), Some("a".to_string()) ];
+	assert_eq![ calls, 2 ];
+	assert_eq![ values.next(), Some(Ok(2)) ];
+}
+
+#[test] fn fold_moral () {
+	let (sum, errors) = mixed().into_iter()
+		.fold_moral(0, String::new(), |sum, v| sum + v, |s, e| s + &e);
+	assert_eq![ (sum, errors.as_str()), (6, "ab") ];
+
+	let (count, bads) = vec![Some(1), Some(2)].into_iter().fold_moral(0, 0, |c, _| c + 1, |b, _| b + 1);
+	assert_eq![ (count, bads), (2, 0) ];
+}
+
+#[test] fn fold_good () {
+	let sum = vec![Ok::<_, String>(1), Ok(2), Ok(3)].into_iter().fold_good(0, |sum, v| sum + v);
+	assert_eq![ sum, Good(6) ];
+
+	let empty = Vec::<Option<i32>>::new().into_iter().fold_good(10, |sum, v| sum + v);
+	assert_eq![ empty.good(), Some(10) ];
+}
+
+// Stops at the first Bad value, without consuming the next items
+#[test] fn fold_good_short_circuits () {
+	let mut values = mixed().into_iter();
+	let mut calls = 0;
+	let r = values.by_ref().fold_good(0, |sum, v| { calls += 1; sum + v });
+	assert_eq![ r, Bad("a".to_string()) ];
+	assert_eq![ calls, 1 ];
+	assert_eq![ values.next(), Some(Ok(2)) ];
+}
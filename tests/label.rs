@@ -2,6 +2,8 @@
 
 use tear::twist;
 use tear::{next, last, resume};
+use tear::{stop, skip, go};
+use tear::breakval;
 use tear::anybox;
 use tear::Looping;
 
@@ -128,8 +130,8 @@ const BREAK_0 :L = Looping::Break { label: Some(0) };
 			let z = 'b: loop {
 				'd: loop {
 					let v = twist! { -label 'a :i32, 'c, 'b :i32, 'd |
-						if y < 5 { Looping::Resume (6) }
-						else if a < 8 { Looping::Break { label: Some(3) } }
+						if y < 5 { go!(6) }
+						else if a < 8 { stop!(3) }
 						else if y == 5 { y += 1; Looping::BreakVal { label: Some(2), value: 3 } }
 						else { Looping::BreakVal { label: Some(0), value: 4 } }
 					};
@@ -153,7 +155,7 @@ const BREAK_0 :L = Looping::Break { label: Some(0) };
 			let x = loop {
 				twist! { -val i32, -label 'a, 'v :i32 |
 					if c < 3 { Looping::BreakVal { label: None, value: 0 } }
-					else if c == 3 { c += 1; Looping::Break { label: Some(0) } }
+					else if c == 3 { c += 1; stop!(0) }
 					else { Looping::BreakVal { label: Some(1), value: 7 } }
 				}
 			};
@@ -177,6 +179,33 @@ const BREAK_0 :L = Looping::Break { label: Some(0) };
 	assert_eq![ s.d, 5 ];
 }
 
+#[test] fn unbox () {
+	use tear::unbox;
+	struct S { d :i32 }
+	
+	let s = unbox!(anybox!(S { d: 5 }), S);
+	assert_eq![ s.d, 5 ];
+	assert_eq![ unbox!(try anybox!(5), i32), Some(5) ];
+	assert_eq![ unbox!(try anybox!("5"), i32), None ];
+}
+
+#[test]
+#[should_panic(expected = "unbox!: expected a value of type i32, got a value with TypeId")]
+fn unbox_wrong_type () {
+	let _ = tear::unbox!(anybox!("5"), i32);
+}
+
+#[cfg(not(feature = "tiny-panic"))] // The message is a short code with "tiny-panic"
+#[test]
+#[should_panic(expected = "At label 'a with type i32 (got TypeId")]
+fn box_breakval_wrong_type () {
+	let _ = 'a: loop {
+		loop {
+			twist! { -box -label 'a :i32 | Looping::BreakVal::<(), _> { label: Some(0), value: anybox!("5") } }
+		}
+	};
+}
+
 #[test] fn box_breakval () {
 	let mut i = 0;
 	let mut f = || {
@@ -201,7 +230,9 @@ const BREAK_0 :L = Looping::Break { label: Some(0) };
 	}
 }
 
-#[test] fn box_breakval_innermost () {
+#[test]
+#[allow(clippy::redundant_guards)] // The guards spell out the call count
+fn box_breakval_innermost () {
 	use std::any::Any;
 	fn create_closure () -> impl FnMut() -> Looping<(), Box<dyn Any>> {
 		let mut i = 0;
@@ -227,9 +258,40 @@ const BREAK_0 :L = Looping::Break { label: Some(0) };
 	}
 }
 
+// The same signals as `anybox!` in the struct literal
+#[test] fn box_breakval_constructors () {
+	let x = 'a: loop {
+		let _ :String = 'b: loop {
+			loop {
+				twist! { -box -label 'a :i32, 'b :String | Looping::breakval_boxed(Some(0), 3) }
+			}
+		};
+	};
+	assert_eq![ x, 3 ];
+
+	'a: loop {
+		let v = loop {
+			twist! { -box -val i32, -label 'a | Looping::breakval_boxed_innermost(4) }
+		};
+		assert_eq![ v, 4 ];
+		break;
+	}
+
+	// The downcast still fails for the wrong type
+	let _ = 'a: loop {
+		loop {
+			let r :Result<(), _> = twist! { -try -box -label 'a :i32 | Looping::breakval_boxed(Some(0), "5") };
+			assert_eq![ r, Err(tear::TwistError::BadType { expected: "i32" }) ];
+			break 'a 0;
+		}
+	};
+}
+
 /* Too lazy to test more than one example for map syntax */
 
-#[test] fn breakval_multiple_map () {
+#[test]
+#[allow(unused_parens)] // The parentheses show what the loop breaks with
+fn breakval_multiple_map () {
 	let v :i32 = 'a: loop {
 		'b: loop {
 			let x = twist! { -label 'a :i32, 'b | Some(4) => |_| Looping::BreakVal { label: Some(0), value: 0 } };
@@ -239,3 +301,441 @@ const BREAK_0 :L = Looping::Break { label: Some(0) };
 	};
 	assert_eq![ v, 8 ];
 }
+
+#[test] fn breakval_multiple_ignore_bad () {
+	let v :i32 = 'a: loop {
+		'b: loop {
+			let x = twist! { -label 'a :i32, 'b | None::<i32> =>> Looping::BreakVal { label: Some(0), value: 5 } };
+			break 'a x;
+		}
+	};
+	assert_eq![ v, 5 ];
+}
+
+#[test] fn breakval_multiple_arrow_literal () {
+	let v :i32 = 'a: loop {
+		'b: loop {
+			let x = twist! { -label 'a :i32, 'b | None::<i32> => Looping::BreakVal { label: Some(0), value: 5 } };
+			break 'a x;
+		}
+	};
+	assert_eq![ v, 5 ];
+}
+
+#[test] fn ignore_bad_label_last () {
+	let mut seen = Vec::new();
+	'a: loop {
+		'b: for v in [Some(1), None, Some(3)] {
+			let v = twist! { -label 'a, 'b | v =>> last!(1) };
+			seen.push(v);
+		}
+		break;
+	}
+	assert_eq![ seen, vec![1] ];
+}
+
+#[test] fn ignore_bad_label_next () {
+	let mut seen = Vec::new();
+	'a: for v in [Some(1), None, Some(3)] {
+		loop {
+			let v = twist! { -label 'a | v =>> next!(0) };
+			seen.push(v);
+			break;
+		}
+	}
+	assert_eq![ seen, vec![1, 3] ];
+}
+
+#[test] fn trailing_comma () {
+	let x = 'a: loop {
+		'b: loop {
+			loop {
+				twist! { -label 'a :i32, 'b, | stop!(1) }
+			}
+		}
+		loop {
+			twist! { -label 'a :i32, | breakval!(0, 3) }
+		}
+	};
+	assert_eq![ x, 3 ];
+}
+
+#[test] fn trailing_comma_multiline () {
+	let x = 'a: loop {
+		'b: loop {
+			loop {
+				twist! { -box -label
+					'a :String,
+					'b,
+					| Looping::BreakVal::<(), _> { label: Some(0), value: anybox!("end".to_string()) }
+				}
+			}
+		}
+	};
+	assert_eq![ x, "end" ];
+}
+
+/* The label indices are integer literals, so they can be used as match patterns */
+#[test] fn literal_indices () {
+	let name = |i :usize| match i {
+		tear::__label_index![] => "a",
+		tear::__label_index![x] => "b",
+		tear::__label_index![x x x x x x x x x x] => "k",
+		_ => "?",
+	};
+	assert_eq![ (name(0), name(1), name(10), name(2)), ("a", "b", "k", "?") ];
+}
+
+/* The shortcuts accept no label, `None`, an index or `Some(index)` */
+#[test] fn shortcut_label_arguments () {
+	use tear::{assert_looping, BreakValError};
+	fn l (v :Looping<(), BreakValError>) -> Looping<(), BreakValError> { v }
+	fn i (v :Looping<(), i32>) -> Looping<(), i32> { v }
+	
+	assert_looping! { l(last!()), Break(None) };
+	assert_looping! { l(last!(None)), Break(None) };
+	assert_looping! { l(last!(0)), Break(Some(0)) };
+	assert_looping! { l(last!(Some(1))), Break(Some(1)) };
+	assert_looping! { l(next!()), Continue(None) };
+	assert_looping! { l(next!(None)), Continue(None) };
+	assert_looping! { l(next!(0)), Continue(Some(0)) };
+	assert_looping! { l(next!(Some(1))), Continue(Some(1)) };
+	assert_looping! { i(stop!(None)), Break(None) };
+	assert_looping! { i(stop!(Some(1))), Break(Some(1)) };
+	assert_looping! { i(skip!(None)), Continue(None) };
+	assert_looping! { i(skip!(Some(1))), Continue(Some(1)) };
+	assert_looping! { i(breakval!(None, 2)), BreakVal(None, 2) };
+	assert_looping! { i(breakval!(Some(0), 2)), BreakVal(Some(0), 2) };
+	assert_looping! { i(breakval!(0, 2)), BreakVal(Some(0), 2) };
+}
+
+#[test] fn shortcut_none_is_innermost () {
+	let mut count = 0;
+	'a: loop {
+		loop {
+			count += 1;
+			twist! { -label 'a | if count < 3 { next!(None) } else { last!(None) } }
+		}
+		break;
+	}
+	assert_eq![ count, 3 ];
+}
+
+#[test] fn many_labels () {
+	let x = 'a: loop {
+		'b: loop {
+			'c: loop {
+				'd: loop {
+					'e: loop {
+						twist! { -label 'a :i32, 'b, 'c, 'd, 'e | breakval!(0, 5) }
+					}
+				}
+			}
+		}
+	};
+	assert_eq![ x, 5 ];
+}
+
+/* Enough labels for generated state machines, without raising the recursion limit */
+#[test] fn thirty_two_labels () {
+	// The even labels break with a value. `hit[k]` records how the loop `'lk` ended
+	let mut hit = [0; 32];
+	let mut step = 0;
+	// The loops are nested, but not indented
+	let x = 'l0: loop {
+	'l1: loop {
+	hit[2] = 'l2: loop {
+	'l3: loop {
+	hit[4] = 'l4: loop {
+	'l5: loop {
+	hit[6] = 'l6: loop {
+	'l7: loop {
+	hit[8] = 'l8: loop {
+	'l9: loop {
+	hit[10] = 'l10: loop {
+	'l11: loop {
+	hit[12] = 'l12: loop {
+	'l13: loop {
+	hit[14] = 'l14: loop {
+	'l15: loop {
+	hit[16] = 'l16: loop {
+	'l17: loop {
+	hit[18] = 'l18: loop {
+	'l19: loop {
+	hit[20] = 'l20: loop {
+	'l21: loop {
+	hit[22] = 'l22: loop {
+	'l23: loop {
+	hit[24] = 'l24: loop {
+	'l25: loop {
+	hit[26] = 'l26: loop {
+	'l27: loop {
+	hit[28] = 'l28: loop {
+	'l29: loop {
+	hit[30] = 'l30: loop {
+	'l31: loop {
+		step += 1;
+		twist! { -label
+			'l0 :i32, 'l1, 'l2 :i32, 'l3, 'l4 :i32, 'l5, 'l6 :i32, 'l7,
+			'l8 :i32, 'l9, 'l10 :i32, 'l11, 'l12 :i32, 'l13, 'l14 :i32, 'l15,
+			'l16 :i32, 'l17, 'l18 :i32, 'l19, 'l20 :i32, 'l21, 'l22 :i32, 'l23,
+			'l24 :i32, 'l25, 'l26 :i32, 'l27, 'l28 :i32, 'l29, 'l30 :i32, 'l31,
+		| match step {
+			1 => skip!(31),
+			2 => breakval!(30, 300),
+			3 => stop!(17),
+			4 => skip!(0),
+			5 => breakval!(28, 280),
+			6 => breakval!(0, 1000),
+			_ => unreachable!(),
+		} }
+	} hit[31] += 1;
+	};
+	} hit[29] += 1;
+	};
+	} hit[27] += 1;
+	};
+	} hit[25] += 1;
+	};
+	} hit[23] += 1;
+	};
+	} hit[21] += 1;
+	};
+	} hit[19] += 1;
+	};
+	} hit[17] += 1;
+	};
+	} hit[15] += 1;
+	};
+	} hit[13] += 1;
+	};
+	} hit[11] += 1;
+	};
+	} hit[9] += 1;
+	};
+	} hit[7] += 1;
+	};
+	} hit[5] += 1;
+	};
+	} hit[3] += 1;
+	};
+	} hit[1] += 1;
+	};
+	assert_eq![ x, 1000 ];
+	assert_eq![ step, 6 ];
+	assert_eq![ hit[30], 300 ];
+	assert_eq![ hit[28], 280 ];
+	assert_eq![ hit[17], 1 ];
+	assert_eq![ hit.iter().sum::<i32>(), 581 ];
+}
+
+/* The most labels that `twist!` supports */
+#[test] fn sixty_four_labels () {
+	let mut step = 0;
+	// The loops are nested, but not indented
+	let x = 'l0: loop {
+	'l1: loop {
+	'l2: loop {
+	'l3: loop {
+	'l4: loop {
+	'l5: loop {
+	'l6: loop {
+	'l7: loop {
+	'l8: loop {
+	'l9: loop {
+	'l10: loop {
+	'l11: loop {
+	'l12: loop {
+	'l13: loop {
+	'l14: loop {
+	'l15: loop {
+	'l16: loop {
+	'l17: loop {
+	'l18: loop {
+	'l19: loop {
+	'l20: loop {
+	'l21: loop {
+	'l22: loop {
+	'l23: loop {
+	'l24: loop {
+	'l25: loop {
+	'l26: loop {
+	'l27: loop {
+	'l28: loop {
+	'l29: loop {
+	'l30: loop {
+	'l31: loop {
+	'l32: loop {
+	'l33: loop {
+	'l34: loop {
+	'l35: loop {
+	'l36: loop {
+	'l37: loop {
+	'l38: loop {
+	'l39: loop {
+	'l40: loop {
+	'l41: loop {
+	'l42: loop {
+	'l43: loop {
+	'l44: loop {
+	'l45: loop {
+	'l46: loop {
+	'l47: loop {
+	'l48: loop {
+	'l49: loop {
+	'l50: loop {
+	'l51: loop {
+	'l52: loop {
+	'l53: loop {
+	'l54: loop {
+	'l55: loop {
+	'l56: loop {
+	'l57: loop {
+	'l58: loop {
+	'l59: loop {
+	'l60: loop {
+	'l61: loop {
+	'l62: loop {
+	'l63: loop {
+		step += 1;
+		twist! { -label
+			'l0 :i32, 'l1, 'l2, 'l3, 'l4, 'l5, 'l6, 'l7,
+			'l8, 'l9, 'l10, 'l11, 'l12, 'l13, 'l14, 'l15,
+			'l16, 'l17, 'l18, 'l19, 'l20, 'l21, 'l22, 'l23,
+			'l24, 'l25, 'l26, 'l27, 'l28, 'l29, 'l30, 'l31,
+			'l32, 'l33, 'l34, 'l35, 'l36, 'l37, 'l38, 'l39,
+			'l40, 'l41, 'l42, 'l43, 'l44, 'l45, 'l46, 'l47,
+			'l48, 'l49, 'l50, 'l51, 'l52, 'l53, 'l54, 'l55,
+			'l56, 'l57, 'l58, 'l59, 'l60, 'l61, 'l62, 'l63,
+		| match step {
+			1 => skip!(63),
+			2 => skip!(32),
+			3 => breakval!(0, 64),
+			_ => unreachable!(),
+		} }
+	}
+	}
+	}
+	}
+	}
+	}
+	}
+	}
+	}
+	}
+	}
+	}
+	}
+	}
+	}
+	}
+	}
+	}
+	}
+	}
+	}
+	}
+	}
+	}
+	}
+	}
+	}
+	}
+	}
+	}
+	}
+	}
+	}
+	}
+	}
+	}
+	}
+	}
+	}
+	}
+	}
+	}
+	}
+	}
+	}
+	}
+	}
+	}
+	}
+	}
+	}
+	}
+	}
+	}
+	}
+	}
+	}
+	}
+	}
+	}
+	}
+	}
+	}
+	};
+	assert_eq![ (x, step), (64, 3) ];
+}
+
+#[test] fn breakval_shortcut () {
+	let x = 'a: loop {
+		let y = 'b: loop {
+			loop {
+				twist! { -label 'a :i32, 'b :i32 | breakval!(1, 2) }
+			}
+		};
+		assert_eq![ y, 2 ];
+		loop {
+			twist! { -label 'a :i32 | breakval!(0, 7) }
+		}
+	};
+	assert_eq![ x, 7 ];
+}
+
+#[test] fn breakval_shortcut_innermost () {
+	'a: loop {
+		let x = loop {
+			twist! { -val i32, -label 'a | breakval!(3) }
+		};
+		assert_eq![ x, 3 ];
+		break;
+	}
+}
+
+#[test] fn inferred_shortcuts () {
+	let mut i = 0;
+	let x = 'a: loop {
+		'b: loop {
+			i += 1;
+			let v = twist! { -label 'a :i32, 'b |
+				match i {
+					1 => skip!(),
+					2 => skip!(1),
+					3 => stop!(1),
+					14 => stop!(),
+					_ => go!(i),
+				}
+			};
+			break 'a v;
+		}
+		i += 10;
+	};
+	assert_eq![ x, 25 ];
+}
+
+#[test] fn resume_annotated () {
+	let mut v = Vec::new();
+	let s = 'a: loop {
+		for i in 0..3 {
+			let x = twist! { -label 'a :String |
+				if i < 2 { resume!(i; String) } else { breakval!(0, "end".to_string()) }
+			};
+			v.push(x);
+		}
+	};
+	assert_eq![ v, [0, 1] ];
+	assert_eq![ s, "end" ];
+}
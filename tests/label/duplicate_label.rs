@@ -0,0 +1,10 @@
+use tear::twist;
+use tear::Looping;
+
+fn main () {
+	let _ :i32 = 'a: loop {
+		'b: loop {
+			twist! { -label 'a :i32, 'b, 'a :i32 | Looping::Break::<(), _> { label: Some(1) } }
+		}
+	};
+}
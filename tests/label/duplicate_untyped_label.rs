@@ -0,0 +1,8 @@
+use tear::twist;
+use tear::Looping;
+
+fn main () {
+	'a: loop {
+		twist! { -label 'a, 'a | Looping::Break::<(), ()> { label: Some(0) } }
+	}
+}
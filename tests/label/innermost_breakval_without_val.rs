@@ -0,0 +1,16 @@
+#![allow(unused, unreachable_code)]
+use tear::{twist, breakval, Looping};
+
+fn main () {
+	'a: loop {
+		let _x :i32 = loop {
+			twist! { -label 'a | breakval!(5) }
+		};
+		let _y :i32 = loop {
+			twist! { -label 'a | breakval!(None, 5) }
+		};
+		let _z :i32 = loop {
+			twist! { -label 'a | Looping::BreakVal::<(), i32> { value: 5, label: None } }
+		};
+	}
+}
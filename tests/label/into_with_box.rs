@@ -0,0 +1,11 @@
+use tear::twist;
+
+fn into_with_box() {
+	'a: loop {
+		loop {
+			twist! { -box -label 'a: into i64 | tear::Looping::Resume::<(), Box<dyn std::any::Any>>(()) }
+		}
+	}
+}
+
+fn main () {}
@@ -0,0 +1,11 @@
+use tear::{twist, last};
+
+fn last_with_typed_label() {
+	let _x :i32 = 'a: loop {
+		loop {
+			twist! { -label 'a: i32 | last!(0) }
+		}
+	};
+}
+
+fn main () {}
@@ -0,0 +1,147 @@
+use tear::twist;
+use tear::Looping;
+
+// One label more than `twist!` supports
+fn main () {
+	'l0: loop {
+	'l1: loop {
+	'l2: loop {
+	'l3: loop {
+	'l4: loop {
+	'l5: loop {
+	'l6: loop {
+	'l7: loop {
+	'l8: loop {
+	'l9: loop {
+	'l10: loop {
+	'l11: loop {
+	'l12: loop {
+	'l13: loop {
+	'l14: loop {
+	'l15: loop {
+	'l16: loop {
+	'l17: loop {
+	'l18: loop {
+	'l19: loop {
+	'l20: loop {
+	'l21: loop {
+	'l22: loop {
+	'l23: loop {
+	'l24: loop {
+	'l25: loop {
+	'l26: loop {
+	'l27: loop {
+	'l28: loop {
+	'l29: loop {
+	'l30: loop {
+	'l31: loop {
+	'l32: loop {
+	'l33: loop {
+	'l34: loop {
+	'l35: loop {
+	'l36: loop {
+	'l37: loop {
+	'l38: loop {
+	'l39: loop {
+	'l40: loop {
+	'l41: loop {
+	'l42: loop {
+	'l43: loop {
+	'l44: loop {
+	'l45: loop {
+	'l46: loop {
+	'l47: loop {
+	'l48: loop {
+	'l49: loop {
+	'l50: loop {
+	'l51: loop {
+	'l52: loop {
+	'l53: loop {
+	'l54: loop {
+	'l55: loop {
+	'l56: loop {
+	'l57: loop {
+	'l58: loop {
+	'l59: loop {
+	'l60: loop {
+	'l61: loop {
+	'l62: loop {
+	'l63: loop {
+	'l64: loop {
+		twist! { -label
+		'l0, 'l1, 'l2, 'l3, 'l4, 'l5, 'l6, 'l7,
+		'l8, 'l9, 'l10, 'l11, 'l12, 'l13, 'l14, 'l15,
+		'l16, 'l17, 'l18, 'l19, 'l20, 'l21, 'l22, 'l23,
+		'l24, 'l25, 'l26, 'l27, 'l28, 'l29, 'l30, 'l31,
+		'l32, 'l33, 'l34, 'l35, 'l36, 'l37, 'l38, 'l39,
+		'l40, 'l41, 'l42, 'l43, 'l44, 'l45, 'l46, 'l47,
+		'l48, 'l49, 'l50, 'l51, 'l52, 'l53, 'l54, 'l55,
+		'l56, 'l57, 'l58, 'l59, 'l60, 'l61, 'l62, 'l63,
+		'l64
+		| Looping::Break::<(), ()> { label: Some(64) } }
+	}
+	}
+	}
+	}
+	}
+	}
+	}
+	}
+	}
+	}
+	}
+	}
+	}
+	}
+	}
+	}
+	}
+	}
+	}
+	}
+	}
+	}
+	}
+	}
+	}
+	}
+	}
+	}
+	}
+	}
+	}
+	}
+	}
+	}
+	}
+	}
+	}
+	}
+	}
+	}
+	}
+	}
+	}
+	}
+	}
+	}
+	}
+	}
+	}
+	}
+	}
+	}
+	}
+	}
+	}
+	}
+	}
+	}
+	}
+	}
+	}
+	}
+	}
+	}
+	}
+}
@@ -0,0 +1,287 @@
+// A stress fixture for the parsing of long label lists: each form of `twist!` gets 16 labels under
+// a low recursion limit, which fails to compile if the label lists are parsed one token per step
+// (the expansion is the same either way, only the number of steps changes). `many` has a lot of
+// shorter invocations. `expansion_snapshot` compares the expansion of this file with a snapshot
+#![recursion_limit = "80"]
+
+use tear::twist;
+use tear::Looping;
+
+// Continues the innermost loop, then the middle one, resumes, and breaks the outermost one with
+// `value()`. `labels` are the indices of those three loops
+fn signal<B> (step :&mut i32, labels :[usize; 3], value :impl FnOnce() -> B) -> Looping<i32, B> {
+	*step += 1;
+	match *step {
+		1 => Looping::Continue { label: Some(labels[0]) },
+		2 => Looping::Continue { label: Some(labels[1]) },
+		3 => Looping::Resume(*step),
+		_ => Looping::BreakVal { label: Some(labels[2]), value: value() },
+	}
+}
+
+#[test] fn label () {
+	let mut step = 0;
+	let mut resumed = 0;
+	// The loops are nested, but not indented
+	let x = 'l0: loop {
+	'l1: loop {
+	'l2: loop {
+	'l3: loop {
+	'l4: loop {
+	'l5: loop {
+	'l6: loop {
+	'l7: loop {
+	'l8: loop {
+	'l9: loop {
+	'l10: loop {
+	'l11: loop {
+	'l12: loop {
+	'l13: loop {
+	'l14: loop {
+	'l15: loop {
+		resumed = twist! { -label 'l0 :i32, 'l1, 'l2, 'l3, 'l4, 'l5, 'l6, 'l7, 'l8, 'l9, 'l10, 'l11, 'l12, 'l13, 'l14, 'l15 | signal(&mut step, [15, 7, 0], || 4) };
+	}
+	}
+	}
+	}
+	}
+	}
+	}
+	}
+	}
+	}
+	}
+	}
+	}
+	}
+	}
+	};
+	assert_eq![ (x, resumed), (4, 3) ];
+}
+
+#[test] fn label_box () {
+	let mut step = 0;
+	let mut resumed = 0;
+	// The loops are nested, but not indented
+	let x = 'l0: loop {
+	'l1: loop {
+	'l2: loop {
+	'l3: loop {
+	'l4: loop {
+	'l5: loop {
+	'l6: loop {
+	'l7: loop {
+	'l8: loop {
+	'l9: loop {
+	'l10: loop {
+	'l11: loop {
+	'l12: loop {
+	'l13: loop {
+	'l14: loop {
+	'l15: loop {
+		resumed = twist! { -box -label 'l0 :i32, 'l1, 'l2, 'l3, 'l4, 'l5, 'l6, 'l7, 'l8, 'l9, 'l10, 'l11, 'l12, 'l13, 'l14, 'l15 | signal(&mut step, [15, 7, 0], || tear::anybox!(4)) };
+	}
+	}
+	}
+	}
+	}
+	}
+	}
+	}
+	}
+	}
+	}
+	}
+	}
+	}
+	}
+	};
+	assert_eq![ (x, resumed), (4, 3) ];
+}
+
+#[test] fn named () {
+	let mut step = 0;
+	let mut resumed = 0;
+	// The loops are nested, but not indented
+	let x = 'l0: loop {
+	'l1: loop {
+	'l2: loop {
+	'l3: loop {
+	'l4: loop {
+	'l5: loop {
+	'l6: loop {
+	'l7: loop {
+	'l8: loop {
+	'l9: loop {
+	'l10: loop {
+	'l11: loop {
+	'l12: loop {
+	'l13: loop {
+	'l14: loop {
+	'l15: loop {
+		resumed = twist! { -named -label 'l0 as L0: i32, 'l1 as L1, 'l2 as L2, 'l3 as L3, 'l4 as L4, 'l5 as L5, 'l6 as L6, 'l7 as L7, 'l8 as L8, 'l9 as L9, 'l10 as L10, 'l11 as L11, 'l12 as L12, 'l13 as L13, 'l14 as L14, 'l15 as L15 |
+			signal(&mut step, [Label::L15, Label::L7, Label::L0], || 4) };
+	}
+	}
+	}
+	}
+	}
+	}
+	}
+	}
+	}
+	}
+	}
+	}
+	}
+	}
+	}
+	};
+	assert_eq![ (x, resumed), (4, 3) ];
+}
+
+#[test] fn enum_mode () {
+	let mut step = 0;
+	let mut resumed = 0;
+	// The loops are nested, but not indented
+	let x = 'l0: loop {
+	'l1: loop {
+	'l2: loop {
+	'l3: loop {
+	'l4: loop {
+	'l5: loop {
+	'l6: loop {
+	'l7: loop {
+	'l8: loop {
+	'l9: loop {
+	'l10: loop {
+	'l11: loop {
+	'l12: loop {
+	'l13: loop {
+	'l14: loop {
+	'l15: loop {
+		resumed = twist! { -enum Sig -label 'l0 as L0: i32, 'l1, 'l2, 'l3, 'l4, 'l5, 'l6, 'l7, 'l8, 'l9, 'l10, 'l11, 'l12, 'l13, 'l14, 'l15 |
+			signal(&mut step, [15, 7, 0], || Sig::L0(4)) };
+	}
+	}
+	}
+	}
+	}
+	}
+	}
+	}
+	}
+	}
+	}
+	}
+	}
+	}
+	}
+	};
+	assert_eq![ (x, resumed), (4, 3) ];
+}
+
+#[cfg(feature = "const-labels")]
+#[test] fn typed () {
+	let mut step = 0;
+	let mut resumed = 0;
+	// The loops are nested, but not indented
+	let x = 'l0: loop {
+	'l1: loop {
+	'l2: loop {
+	'l3: loop {
+	'l4: loop {
+	'l5: loop {
+	'l6: loop {
+	'l7: loop {
+	'l8: loop {
+	'l9: loop {
+	'l10: loop {
+	'l11: loop {
+	'l12: loop {
+	'l13: loop {
+	'l14: loop {
+	'l15: loop {
+		resumed = twist! { -typed -label 'l0 :i32, 'l1, 'l2, 'l3, 'l4, 'l5, 'l6, 'l7, 'l8, 'l9, 'l10, 'l11, 'l12, 'l13, 'l14, 'l15 |
+			signal(&mut step, [15, 7, 0], || 4) };
+	}
+	}
+	}
+	}
+	}
+	}
+	}
+	}
+	}
+	}
+	}
+	}
+	}
+	}
+	}
+	};
+	assert_eq![ (x, resumed), (4, 3) ];
+}
+
+// Many invocations of the other forms, that all resume
+#[test] fn many () {
+	let ok = |v :i32| Looping::<i32, i32>::Resume(v);
+	let mut sum = 0;
+	let x = 'a: loop {
+		'b: loop {
+			'c: loop {
+				'd: loop {
+					sum += twist! { -label 'a :i32 | ok(1) };
+					sum += twist! { -label 'a :i32, 'b | ok(1) };
+					sum += twist! { -label 'a :i32, 'b, 'c | ok(1) };
+					sum += twist! { -label 'a :i32, 'b, 'c, 'd | ok(1) };
+					sum += twist! { -label 'a :i32, 'b | Some(1) =>> Looping::<i32, i32>::Continue { label: None } };
+					sum += twist! { -label 'a :i32, 'b | Err::<i32, i32>(2).or::<i32>(Ok(1)) => |e| Looping::BreakVal { label: Some(0), value: e } };
+					sum += twist! { -try -label 'a :i32, 'b, 'c | ok(1) }.unwrap();
+					sum += twist! { -unchecked -label 'a :i32, 'b, 'c, 'd | ok(1) };
+					sum += twist! { -box -label 'a :i32, 'b | Looping::<i32, Box<dyn std::any::Any>>::Resume(1) };
+					sum += twist! { -box -label 'a :i32, 'b, 'c, 'd | Looping::<i32, Box<dyn std::any::Any>>::Resume(1) };
+					sum += twist! { -with 'b | Looping::<i32, tear::BreakValError>::Resume(1) };
+					sum += twist! { -val -with 'a | ok(1) };
+					sum += twist! { -named -label 'a as A: i32, 'b as B | ok(1) };
+					sum += twist! { -named -label 'a as A: i32, 'b, 'c as C | ok(1) };
+					sum += twist! { -enum Sig -label 'a as A: i32, 'b | go(1) };
+					sum += twist! { -enum Sig -label 'a as A: i32, 'b as B: (), 'c as C: () | go(1) };
+					sum += twist! { -finally { sum += 0; } -label 'a :i32, 'b | ok(1) };
+					sum += twist! { -label 'a :i32, 'b, 'c, 'd | ok(1) };
+					sum += twist! { -label 'd, 'c, 'b, 'a :i32 | ok(1) };
+					sum += twist! { -label 'b, 'd | ok(1) };
+					break 'a sum;
+				};
+			}
+		}
+	};
+	assert_eq![ x, 20 ];
+}
+
+// The signal for the -enum invocations, whose enum can't be named outside of the macro
+fn go<B> (v :i32) -> Looping<i32, B> { Looping::Resume(v) }
+
+// Compare the expansion of this file with `tests/expand/label_stress.expanded.rs`, to check that
+// a change in the parsing steps doesn't change the generated code. It needs a nightly toolchain
+// for `-Zunpretty=expanded`, so it's ignored by default:
+//     cargo test --test label_stress -- --ignored
+// Set `TEAR_EXPAND=overwrite` to update the snapshot, eg. after changing what `twist!` generates
+#[test] #[ignore] fn expansion_snapshot () {
+	use std::process::Command;
+	// Read at run time, so that the path isn't in the snapshot
+	let root = std::env::var("CARGO_MANIFEST_DIR").unwrap();
+	let out = Command::new("cargo")
+		.args(["+nightly", "rustc", "--quiet", "--test", "label_stress"])
+		.args(["--manifest-path", &format!("{}/Cargo.toml", root)])
+		.args(["--target-dir", &format!("{}/target/expand", root)])
+		.args(["--", "-Zunpretty=expanded"])
+		.output().expect("cargo +nightly");
+	assert![ out.status.success(), "{}", String::from_utf8_lossy(&out.stderr) ];
+	let path = format!("{}/tests/expand/label_stress.expanded.rs", root);
+	if std::env::var("TEAR_EXPAND").as_deref() == Ok("overwrite") {
+		std::fs::write(&path, &out.stdout).unwrap();
+	}
+	let expected = std::fs::read_to_string(&path).unwrap_or_default();
+	assert![ String::from_utf8_lossy(&out.stdout) == expected, "The expansion differs from {}", path ];
+}
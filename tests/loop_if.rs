@@ -0,0 +1,155 @@
+// Testing next_if! and last_if!, and their -with forms
+use tear::prelude::*;
+
+// All compile fail errors go here
+#[cfg(not(any(feature = "experimental", feature = "ignore-ui")))] // Feature flags to ignore test
+#[test] fn bad_input () {
+	use trybuild;
+	let t = trybuild::TestCases::new();
+	t.compile_fail("tests/loop_if/*.rs");
+}
+
+#[test] fn next_if_body () {
+	let mut skipped = Vec::new();
+	let mut seen = Vec::new();
+	for v in 0..5 {
+		next_if! { v % 2 == 0, skipped.push(v) }
+		seen.push(v);
+	}
+	assert_eq![ (skipped, seen), (vec![0, 2, 4], vec![1, 3]) ];
+}
+
+#[test] fn last_if_pattern () {
+	let mut seen = Vec::new();
+	for v in [Some(1), Some(2), None, Some(4)] {
+		last_if! { let None = v }
+		seen.push(v.unwrap());
+	}
+	assert_eq![ seen, vec![1, 2] ];
+}
+
+#[test] fn next_if_with () {
+	let mut seen = Vec::new();
+	let mut body = 0;
+	'outer: for i in 0..3 {
+		for j in 0..3 {
+			next_if! { -with 'outer, j == 1, body += 1 }
+			seen.push((i, j));
+		}
+		panic!("Should have continued 'outer");
+	}
+	assert_eq![ seen, vec![(0, 0), (1, 0), (2, 0)] ];
+	assert_eq![ body, 3 ];
+}
+
+#[test] fn last_if_with () {
+	let mut seen = Vec::new();
+	let mut body = 0;
+	'outer: for i in 0..3 {
+		for j in 0..3 {
+			last_if! { -with 'outer, i == 1 && j == 2, body += 1 }
+			seen.push((i, j));
+		}
+	}
+	assert_eq![ seen, vec![(0, 0), (0, 1), (0, 2), (1, 0), (1, 1)] ];
+	assert_eq![ body, 1 ];
+}
+
+#[test] fn with_pattern () {
+	let mut seen = Vec::new();
+	let mut skipped = Vec::new();
+	'outer: for row in [vec![Some(1), Some(2)], vec![None, Some(4)], vec![Some(5), None]] {
+		for v in row {
+			next_if! { -with 'outer, let None = v, skipped.push(seen.len()) }
+			seen.push(v.unwrap());
+		}
+	}
+	assert_eq![ seen, vec![1, 2, 5] ];
+	assert_eq![ skipped, vec![2, 3] ];
+
+	let mut last = 0;
+	'a: loop {
+		for v in [Some(1), Some(2), None] {
+			last_if! { -with 'a, let Some(2) = v, last = 2 }
+		}
+		panic!("Should have broken 'a");
+	}
+	assert_eq![ last, 2 ];
+}
+
+#[test] fn last_if_val () {
+	let mut i = 0;
+	let x = loop {
+		i += 1;
+		last_if! { -val i * i > 20, i }
+	};
+	assert_eq![ x, 5 ];
+}
+
+#[test] fn last_if_val_pattern () {
+	let mut it = [None, Some(3), Some(4)].iter();
+	let x = loop {
+		let v = it.next().unwrap();
+		last_if! { -val let Some(n) = v, n * 2 }
+	};
+	assert_eq![ x, 6 ];
+}
+
+#[test] fn resume_if_else () {
+	let mut v = Vec::new();
+	for i in 0..4 {
+		let x = resume_if! { i % 2 == 0, "even"; else "odd" };
+		let y = resume_if! { let Some(y) = [10, 20].get(i), *y; else 0 };
+		v.push((x, y));
+	}
+	assert_eq![ v, vec![("even", 10), ("odd", 20), ("even", 0), ("odd", 0)] ];
+}
+
+#[test] fn resume_if_skip () {
+	let mut v = Vec::new();
+	for i in 0..5u32 {
+		let x = resume_if! { i != 2, i * 10 };
+		let y = resume_if! { let Some(y) = x.checked_sub(10), y };
+		v.push(y);
+	}
+	assert_eq![ v, vec![0, 20, 30] ];
+}
+
+#[test] fn twist_if_breakval_label () {
+	let mut attempts = 0;
+	let x = 'a: loop {
+		'b: loop {
+			attempts += 1;
+			twist_if! { attempts == 2, -label 'a :i32, 'b | Looping::BreakVal { label: Some(0), value: attempts * 10 } }
+		}
+	};
+	assert_eq![ (x, attempts), (20, 2) ];
+}
+
+#[test] fn twist_if_continue_label () {
+	let mut seen = Vec::new();
+	'a: for i in 0..3 {
+		for j in 0..3 {
+			twist_if! { j > i, -with 'a | tear::next!() }
+			seen.push((i, j));
+		}
+	}
+	assert_eq![ seen, vec![(0, 0), (1, 0), (1, 1), (2, 0), (2, 1), (2, 2)] ];
+}
+
+#[test] fn twist_if_pattern () {
+	let mut it = [None, Some(1), Some(5)].iter();
+	let x = loop {
+		twist_if! { let Some(v) = it.next().unwrap(), -val if *v > 2 { Looping::BreakVal { label: None, value: *v } } else { Looping::Resume(()) } }
+	};
+	assert_eq![ x, 5 ];
+}
+
+#[test] fn twist_if_false () {
+	let mut count = 0;
+	for _ in 0..3 {
+		twist_if! { false, tear::last!() }
+		count += 1;
+	}
+	assert_eq![ count, 3 ];
+}
@@ -0,0 +1,8 @@
+use tear::{next_if, last_if};
+
+fn main () {
+	for x in &[Some(1), None] {
+		next_if! { let None x }
+		last_if! { let Some(1) x, println!("one") }
+	}
+}
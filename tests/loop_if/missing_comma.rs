@@ -0,0 +1,8 @@
+use tear::{next_if, last_if};
+
+fn main () {
+	for x in 0..3 {
+		next_if! { x == 0 println!("zero") }
+		last_if! { x == 2 println!("two") }
+	}
+}
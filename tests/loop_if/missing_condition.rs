@@ -0,0 +1,8 @@
+use tear::{next_if, last_if};
+
+fn main () {
+	for _ in 0..3 {
+		next_if! { , println!("skip") }
+		last_if! {}
+	}
+}
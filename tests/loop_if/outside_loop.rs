@@ -0,0 +1,14 @@
+use tear::{next_if, last_if};
+
+fn skip (x :bool) {
+	next_if! { x }
+}
+
+fn stop (x :bool) {
+	last_if! { x }
+}
+
+fn main () {
+	skip(true);
+	stop(true);
+}
@@ -0,0 +1,7 @@
+use tear::prelude::*;
+
+fn main () {
+	for i in 0..3 {
+		next_if! { -with 'nope, i == 1 }
+	}
+}
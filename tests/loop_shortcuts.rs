@@ -0,0 +1,88 @@
+// Testing the some_or_next!, some_or_last!, ok_or_next! and ok_or_last! shortcuts
+use tear::prelude::*;
+
+const OPTIONS :[Option<i32>; 4] = [Some(1), None, Some(3), Some(4)];
+const RESULTS :[Result<i32, &str>; 4] = [Ok(1), Err("two"), Ok(3), Ok(4)];
+
+#[test] fn some_next () {
+	let mut seen = Vec::new();
+	for v in OPTIONS.iter() {
+		let x = some_or_next! { *v };
+		seen.push(x);
+	}
+	assert_eq![ seen, [1, 3, 4] ];
+}
+
+#[test] fn some_last () {
+	let mut seen = Vec::new();
+	for v in OPTIONS.iter() {
+		let x = some_or_last! { *v };
+		seen.push(x);
+	}
+	assert_eq![ seen, [1] ];
+}
+
+#[test] fn ok_next () {
+	let mut seen = Vec::new();
+	for v in RESULTS.iter() {
+		let x = ok_or_next! { *v };
+		seen.push(x);
+	}
+	assert_eq![ seen, [1, 3, 4] ];
+}
+
+#[test] fn ok_last () {
+	let mut seen = Vec::new();
+	for v in RESULTS.iter() {
+		let x = ok_or_last! { *v };
+		seen.push(x);
+	}
+	assert_eq![ seen, [1] ];
+}
+
+#[test] fn ok_next_with_handler () {
+	let mut seen = Vec::new();
+	let mut errors = Vec::new();
+	for v in RESULTS.iter() {
+		let x = ok_or_next! { *v, |e| errors.push(e.len()) };
+		seen.push(x);
+	}
+	assert_eq![ seen, [1, 3, 4] ];
+	assert_eq![ errors, [3] ];
+}
+
+#[test] fn ok_last_with_handler () {
+	let mut seen = Vec::new();
+	let mut errors = Vec::new();
+	for v in RESULTS.iter() {
+		let x = ok_or_last! { *v, |e| errors.push(e.len()) };
+		seen.push(x);
+	}
+	assert_eq![ seen, [1] ];
+	assert_eq![ errors, [3] ];
+}
+
+#[test] fn handler_is_a_function () {
+	fn log (e :&str) { assert_eq![ e, "two" ]; }
+	let mut count = 0;
+	for v in RESULTS.iter() {
+		ok_or_next! { *v, log };
+		count += 1;
+	}
+	assert_eq![ count, 3 ];
+}
+
+#[test] fn innermost_loop () {
+	let mut seen = Vec::new();
+	for i in 0..2 {
+		let mut j = 0;
+		while j < 3 {
+			j += 1;
+			let x = some_or_last! { if j == 2 { None } else { Some(j) } };
+			seen.push((i, x));
+		}
+		let y = ok_or_next! { if i == 0 { Err(()) } else { Ok(i) } };
+		seen.push((y, y));
+	}
+	assert_eq![ seen, [(0, 1), (1, 1), (1, 1)] ];
+}
@@ -0,0 +1,340 @@
+// Testing the methods of Looping
+use tear::{Looping, TwistError};
+use tear::twist;
+
+type L = Looping<i32, &'static str>;
+
+const SIGNALS :[L; 6] = [
+	Looping::Resume(1),
+	Looping::Break { label: None },
+	Looping::Break { label: Some(2) },
+	Looping::Continue { label: None },
+	Looping::Continue { label: Some(1) },
+	Looping::BreakVal { label: Some(0), value: "b" },
+];
+
+#[test] fn map_resume () {
+	let mut calls = 0;
+	let mapped :Vec<_> = SIGNALS.iter().cloned().map(|l| l.map_resume(|v| { calls += 1; v * 10 })).collect();
+	assert_eq![ mapped, [
+		Looping::Resume(10),
+		Looping::Break { label: None },
+		Looping::Break { label: Some(2) },
+		Looping::Continue { label: None },
+		Looping::Continue { label: Some(1) },
+		Looping::BreakVal { label: Some(0), value: "b" },
+	] ];
+	assert_eq![ calls, 1 ];
+}
+
+#[test] fn map () {
+	let (mut resumes, mut breakvals) = (0, 0);
+	let mapped :Vec<Looping<String, usize>> = SIGNALS.iter().cloned()
+		.map(|l| l.map(|v| { resumes += 1; v.to_string() }, |b| { breakvals += 1; b.len() }))
+		.collect();
+	assert_eq![ mapped, [
+		Looping::Resume("1".to_string()),
+		Looping::Break { label: None },
+		Looping::Break { label: Some(2) },
+		Looping::Continue { label: None },
+		Looping::Continue { label: Some(1) },
+		Looping::BreakVal { label: Some(0), value: 1 },
+	] ];
+	assert_eq![ (resumes, breakvals), (1, 1) ];
+}
+
+#[test] fn map_break_value () {
+	let mut calls = 0;
+	let mapped :Vec<_> = SIGNALS.iter().cloned().map(|l| l.map_break_value(|b| { calls += 1; b.len() })).collect();
+	assert_eq![ mapped, [
+		Looping::Resume(1),
+		Looping::Break { label: None },
+		Looping::Break { label: Some(2) },
+		Looping::Continue { label: None },
+		Looping::Continue { label: Some(1) },
+		Looping::BreakVal { label: Some(0), value: 1 },
+	] ];
+	assert_eq![ calls, 1 ];
+}
+
+#[test] fn and_then () {
+	let mut calls = 0;
+	let chained :Vec<Looping<String, &str>> = SIGNALS.iter().cloned()
+		.map(|l| l.and_then(|v| { calls += 1; Looping::Resume(v.to_string()) }))
+		.collect();
+	assert_eq![ chained, [
+		Looping::Resume("1".to_string()),
+		Looping::Break { label: None },
+		Looping::Break { label: Some(2) },
+		Looping::Continue { label: None },
+		Looping::Continue { label: Some(1) },
+		Looping::BreakVal { label: Some(0), value: "b" },
+	] ];
+	assert_eq![ calls, 1 ];
+}
+
+// The first failing check short-circuits the next ones
+#[test] fn and_then_chain () {
+	let mut log = Vec::new();
+	let mut valid = Vec::new();
+	for line in ["1", "x", "-2", "3"].iter() {
+		let v = twist! { Looping::Resume(*line)
+			.and_then(|s| { log.push("parse"); Looping::from_judge(s.parse::<i32>(), |_| tear::next!()) })
+			.and_then(|v| { log.push("check"); if v > 0 { Looping::Resume(v) } else { tear::next!() } })
+		};
+		valid.push(v);
+	}
+	assert_eq![ valid, [1, 3] ];
+	assert_eq![ log, ["parse", "check", "parse", "parse", "check", "parse", "check"] ];
+}
+
+#[test] fn map_label () {
+	let mut calls = 0;
+	let mapped = SIGNALS.iter().cloned().map(|l| l.map_label(|l| { calls += 1; l + 10 })).collect::<Vec<_>>();
+	assert_eq![ mapped, [
+		Looping::Resume(1),
+		Looping::Break { label: None },
+		Looping::Break { label: Some(12) },
+		Looping::Continue { label: None },
+		Looping::Continue { label: Some(11) },
+		Looping::BreakVal { label: Some(10), value: "b" },
+	] ];
+	assert_eq![ calls, 3 ];
+}
+
+// Signals for the labels ['x, 'y, 'z] of a helper, in a nest with an extra outer loop
+fn helper (step :usize) -> Looping<(), i32> {
+	match step {
+		0 => Looping::Continue { label: Some(2) },
+		1 => Looping::Continue { label: Some(1) },
+		2 => Looping::BreakVal { label: Some(0), value: 7 },
+		_ => Looping::Break { label: None },
+	}
+}
+
+#[test] fn offset_labels () {
+	let (mut y_passes, mut z_passes) = (0, 0);
+	let mut step = 0;
+	let mut x = 0;
+	'outer: for _ in 0..1 {
+		x = 'x: loop {
+			'y: loop {
+				y_passes += 1;
+				'z: loop {
+					z_passes += 1;
+					loop {
+						step += 1;
+						twist! { -label 'outer, 'x :i32, 'y, 'z | helper(step - 1).offset_labels(1) }
+					}
+				}
+			}
+		};
+	}
+	// 'z is continued, then 'y, then 'x breaks with 7
+	assert_eq![ (y_passes, z_passes, step, x), (2, 3, 3, 7) ];
+}
+
+// A signal producer shared between loops with different item types
+fn positive (v :i32) -> Looping<i32, i32> {
+	if v < 0 { Looping::BreakVal { label: None, value: v } } else { Looping::Resume(v) }
+}
+
+#[test] fn shared_producer () {
+	let values = [1, 2, -3, 4];
+	let mut words = Vec::new();
+	let mut i = 0;
+	let x = loop {
+		let signal = positive(values[i]).map_resume(|v| v.to_string());
+		words.push(twist! { -val signal });
+		i += 1;
+	};
+	assert_eq![ x, -3 ];
+	assert_eq![ words, ["1", "2"] ];
+}
+
+/* Predicates and accessors */
+
+#[test] fn predicates () {
+	let table = SIGNALS.iter().map(|l| (l.is_resume(), l.is_break(), l.is_continue(), l.is_break_val())).collect::<Vec<_>>();
+	assert_eq![ table, [
+		(true, false, false, false),
+		(false, true, false, false),
+		(false, true, false, false),
+		(false, false, true, false),
+		(false, false, true, false),
+		(false, false, false, true),
+	] ];
+	assert![ Looping::BreakVal::<(), _> { label: None, value: "b" }.is_break_val() ];
+}
+
+#[test] fn label () {
+	let labels = SIGNALS.iter().map(Looping::label).collect::<Vec<_>>();
+	assert_eq![ labels, [None, None, Some(2), None, Some(1), Some(0)] ];
+	assert_eq![ Looping::BreakVal::<(), _> { label: None, value: "b" }.label(), None ];
+}
+
+#[test] fn resume_or () {
+	let values = SIGNALS.iter().cloned().map(|l| l.resume_or(-1)).collect::<Vec<_>>();
+	assert_eq![ values, [1, -1, -1, -1, -1, -1] ];
+
+	let mut calls = 0;
+	let values = SIGNALS.iter().cloned().map(|l| l.resume_or_else(|| { calls += 1; -1 })).collect::<Vec<_>>();
+	assert_eq![ values, [1, -1, -1, -1, -1, -1] ];
+	assert_eq![ calls, 5 ];
+}
+
+#[test] fn resume_and_break_value () {
+	let resumes = SIGNALS.iter().cloned().map(Looping::resume).collect::<Vec<_>>();
+	assert_eq![ resumes, [Some(1), None, None, None, None, None] ];
+
+	let values = SIGNALS.iter().cloned().map(Looping::break_value).collect::<Vec<_>>();
+	assert_eq![ values, [None, None, None, None, None, Some("b")] ];
+	assert_eq![ Looping::BreakVal::<(), _> { label: None, value: "b" }.break_value(), Some("b") ];
+}
+
+/* Default */
+
+// A state machine that reports a signal once
+struct Watcher {
+	pending :Looping<(), i32>,
+}
+
+impl Watcher {
+	fn poll (&mut self) -> Looping<(), i32> {
+		std::mem::take(&mut self.pending)
+	}
+}
+
+#[test] fn default () {
+	assert_eq![ Looping::<i32, ()>::default(), Looping::Resume(0) ];
+	assert_eq![ Looping::<String, ()>::default(), Looping::Resume(String::new()) ];
+}
+
+#[test] fn take_pending () {
+	let mut w = Watcher { pending: Looping::default() };
+	assert_eq![ w.poll(), Looping::Resume(()) ];
+
+	w.pending = Looping::BreakVal { label: None, value: 5 };
+	assert_eq![ w.poll(), Looping::BreakVal { label: None, value: 5 } ];
+	assert_eq![ w.pending, Looping::Resume(()) ];
+
+	let mut polls = 0;
+	let x = loop {
+		polls += 1;
+		if polls == 3 {
+			w.pending = Looping::BreakVal { label: None, value: 7 };
+		}
+		twist! { -val w.poll() }
+	};
+	assert_eq![ (x, polls), (7, 3) ];
+}
+
+/* from_judge */
+
+#[test] fn from_judge_option () {
+	let mut calls = 0;
+	let l :Looping<i32, ()> = Looping::from_judge(Some(2), |_| { calls += 1; Looping::Break { label: None } });
+	assert_eq![ l, Looping::Resume(2) ];
+	assert_eq![ calls, 0 ];
+
+	let l :Looping<i32, ()> = Looping::from_judge(None, |_| { calls += 1; Looping::Break { label: Some(1) } });
+	assert_eq![ l, Looping::Break { label: Some(1) } ];
+	assert_eq![ calls, 1 ];
+}
+
+#[test] fn from_judge_result () {
+	let parse = |s :&str| -> Looping<i32, String> {
+		Looping::from_judge(s.parse::<i32>(), |e| Looping::BreakVal { label: None, value: e.to_string() })
+	};
+	assert_eq![ parse("4"), Looping::Resume(4) ];
+	assert_eq![ parse(""), Looping::BreakVal { label: None, value: "cannot parse integer from empty string".to_string() } ];
+}
+
+// Same as the mapping syntax of `twist!`
+#[test] fn from_judge_twist () {
+	let inputs = ["1", "x", "3", "-"];
+	let (mut with_macro, mut with_fn) = (Vec::new(), Vec::new());
+	for s in inputs.iter() {
+		with_macro.push(twist! { s.parse::<i32>() => |_| tear::next!() });
+	}
+	for s in inputs.iter() {
+		with_fn.push(twist! { Looping::from_judge(s.parse::<i32>(), |_| tear::next!()) });
+	}
+	assert_eq![ with_macro, [1, 3] ];
+	assert_eq![ with_fn, with_macro ];
+}
+
+/* Conditions */
+
+#[test] fn conditions () {
+	type U = Looping<(), ()>;
+	assert_eq![ U::break_if(true), Looping::Break { label: None } ];
+	assert_eq![ U::continue_if(true), Looping::Continue { label: None } ];
+	assert_eq![ U::break_label_if(1, true), Looping::Break { label: Some(1) } ];
+	assert_eq![ U::continue_label_if(2, true), Looping::Continue { label: Some(2) } ];
+	for l in [U::break_if(false), U::continue_if(false), U::break_label_if(1, false), U::continue_label_if(2, false)].iter() {
+		assert_eq![ *l, Looping::Resume(()) ];
+	}
+}
+
+/* Display */
+
+#[test] fn display () {
+	let strings = SIGNALS.iter().map(|l| l.to_string()).collect::<Vec<_>>();
+	assert_eq![ strings, [
+		"resume",
+		"break innermost",
+		"break loop #2",
+		"continue innermost",
+		"continue loop #1",
+		"break loop #0 with value",
+	] ];
+	assert_eq![ Looping::BreakVal::<(), _> { label: None, value: "b" }.to_string(), "break innermost with value" ];
+}
+
+/* Unwrapping */
+
+#[test] fn unwrap () {
+	assert_eq![ Looping::<_, ()>::Resume(3).unwrap_resume(), 3 ];
+	assert_eq![ Looping::<(), _>::BreakVal { label: Some(1), value: 4 }.expect_break_val("breaks"), 4 ];
+}
+
+#[test]
+#[should_panic(expected = "called `Looping::unwrap_resume()` on BreakVal { label: Some(0), value: \"b\" }")]
+fn unwrap_resume_panics () {
+	SIGNALS[5].clone().unwrap_resume();
+}
+
+#[test]
+#[should_panic(expected = "should resume on Continue { label: None }")]
+fn expect_resume_panics () {
+	SIGNALS[3].clone().expect_resume("should resume");
+}
+
+#[test]
+#[should_panic(expected = "called `Looping::unwrap_break_val()` on Resume(1)")]
+fn unwrap_break_val_panics () {
+	SIGNALS[0].clone().unwrap_break_val();
+}
+
+#[test]
+#[should_panic(expected = "should break on Break { label: Some(2) }")]
+fn expect_break_val_panics () {
+	SIGNALS[2].clone().expect_break_val("should break");
+}
+
+/* validate */
+
+#[test] fn validate () {
+	let results = SIGNALS.iter().map(|l| l.validate(2)).collect::<Vec<_>>();
+	assert_eq![ results, [
+		Ok(()),
+		Ok(()),
+		Err(TwistError::InvalidLabel { index: 2, max: 2 }),
+		Ok(()),
+		Ok(()),
+		Ok(()),
+	] ];
+	assert![ SIGNALS.iter().all(|l| l.validate(3).is_ok()) ];
+	assert_eq![ SIGNALS[5].validate(0), Err(TwistError::InvalidLabel { index: 0, max: 0 }) ];
+}
@@ -0,0 +1,61 @@
+// Testing `maybe_match!` for enum accessors
+use tear::maybe_match;
+
+#[derive(Debug, PartialEq)]
+enum Token {
+	Number(i64),
+	Float(f64),
+	Word(String),
+	Symbol(char),
+}
+
+// Accessors like `ValRet::val` and `Moral::good`
+impl Token {
+	fn number (self) -> Option<i64> { maybe_match! { self, Token::Number(v) => v } }
+	fn word (self) -> Option<String> { maybe_match! { self, Token::Word(w) => w } }
+	fn as_word (&self) -> Option<&str> { maybe_match! { self, Token::Word(w) => w.as_str() } }
+	fn numeric (&self) -> Option<f64> {
+		maybe_match! { *self, Token::Number(v) => v as f64, }
+	}
+	fn digit (&self) -> Option<u32> {
+		maybe_match! { *self, Token::Symbol(c) if c.is_ascii_digit() => c as u32 - '0' as u32 }
+	}
+	fn text (&self) -> Option<String> {
+		maybe_match! { self, Token::Word(_) | Token::Symbol(_) => format!("{:?}", self) }
+	}
+}
+
+#[test] fn accessors () {
+	assert_eq![ Token::Number(3).number(), Some(3) ];
+	assert_eq![ Token::Float(3.).number(), None ];
+	assert_eq![ Token::Word("a".to_string()).word(), Some("a".to_string()) ];
+	assert_eq![ Token::Symbol('a').word(), None ];
+	assert_eq![ Token::Word("a".to_string()).as_word(), Some("a") ];
+	assert_eq![ Token::Number(2).numeric(), Some(2.) ];
+	assert_eq![ Token::Float(2.).numeric(), None ];
+}
+
+#[test] fn guard () {
+	assert_eq![ Token::Symbol('7').digit(), Some(7) ];
+	assert_eq![ Token::Symbol('x').digit(), None ];
+	assert_eq![ Token::Number(7).digit(), None ];
+}
+
+#[test] fn several_patterns () {
+	assert_eq![ Token::Symbol('+').text(), Some("Symbol('+')".to_string()) ];
+	assert_eq![ Token::Word("a".to_string()).text(), Some("Word(\"a\")".to_string()) ];
+	assert_eq![ Token::Float(1.).text(), None ];
+}
+
+#[test] fn several_patterns_and_guard () {
+	let small = |r :Result<i32, i32>| maybe_match! { r, Ok(v) | Err(v) if v < 10 => v };
+	assert_eq![ small(Ok(3)), Some(3) ];
+	assert_eq![ small(Err(4)), Some(4) ];
+	assert_eq![ small(Ok(30)), None ];
+}
+
+#[test] fn values () {
+	assert_eq![ maybe_match! { 5, 0..=9 => "digit" }, Some("digit") ];
+	assert_eq![ maybe_match! { Ok::<_, ()>(1), Ok(v) if v > 0 => v }, Some(1) ];
+	assert_eq![ maybe_match! { Err::<i32, _>(2), Ok(v) | Err(v) => v * 2 }, Some(4) ];
+}
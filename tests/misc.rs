@@ -9,7 +9,6 @@ use tear::prelude::*;
 	assert_eq![ f(), None ];
 }
 
-#[cfg(not(feature = "experimental"))]
 #[test] fn option_from_unit () {
 	fn f () -> Option<i32> {
 		terror! { None => |_| () };
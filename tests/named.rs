@@ -0,0 +1,182 @@
+// We test the twist! -named -label syntax, mirroring tests/label.rs
+
+use tear::twist;
+use tear::{next, last, resume};
+use tear::anybox;
+use tear::Looping;
+
+// All compile fail errors go here
+#[cfg(not(any(feature = "experimental", feature = "ignore-ui")))] // Feature flags to ignore test
+#[test] fn bad_input () {
+	use trybuild;
+	let t = trybuild::TestCases::new();
+	t.compile_fail("tests/named/*.rs");
+}
+
+#[test] fn just_break () {
+	let mut x = 0;
+	'a: loop {
+		loop {
+			twist! { -named -label 'a as A | last!() }
+			panic!("Should break before this");
+		}
+		x = 1;
+		break;
+	}
+	assert_eq![ x, 1, "Only broke the innermost loop" ];
+}
+
+#[test] fn break_label () {
+	'a: loop {
+		loop {
+			twist! { -named -label 'a as A | last!(Label::A) }
+			panic!("Should break before this");
+		}
+		panic!("Didn't break the label")
+	}
+}
+
+#[test] fn resume () {
+	let mut x :i32 = 5;
+	'a: loop {
+		x = twist! { -named -label 'a as A | resume!(1) };
+		break;
+	}
+	assert_eq![ x, 1 ];
+}
+
+#[test] fn continue_label () {
+	let mut x :i32 = 0;
+	'a: loop {
+		x += 1;
+		twist! { -named -label 'a as A |
+			if x < 4 { next!(Label::A) }
+			else { last!() }
+		}
+		x -= 1;
+	}
+	assert_eq![ x, 4 ];
+}
+
+#[test] fn break_label_two () {
+	'a: loop {
+		'b: loop {
+			twist! { -named -label 'a as A, 'b as B | last!(Label::A) }
+			panic!("Should break before this");
+		}
+		panic!("Didn't break the label")
+	}
+}
+
+#[test] fn reordered_labels () {
+	// Same as above, but the order of the labels doesn't matter
+	'a: loop {
+		'b: loop {
+			twist! { -named -label 'b as B, 'a as A | last!(Label::A) }
+			panic!("Should break before this");
+		}
+		panic!("Didn't break the label")
+	}
+}
+
+#[test] fn breakval_multiple () {
+	let mut y = 0;
+	let x = 'a: loop {
+		let z :i32 = 'b: loop {
+			loop {
+				y += 1;
+				twist! { -named -label 'a as A :i32, 'b as B :i32 |
+					if y > 5 { Looping::BreakVal { label: Some(Label::A), value: 8 } }
+					else { Looping::BreakVal { label: Some(Label::B), value: 3 } }
+				}
+				y -= 1;
+			}
+		};
+		assert_eq![ z, 3 ];
+	};
+	assert_eq![ y, 6 ];
+	assert_eq![ x, 8 ];
+}
+
+#[test] fn mixed_named_and_unnamed () {
+	let mut a = 0;
+	let x = 'a: loop {
+		'c: loop {
+			let z = 'b: loop {
+				'd: loop {
+					twist! { -named -label 'a as A :i32, 'c, 'b as B :i32, 'd as D |
+						if a == 0 { a += 1; Looping::Break { label: Some(Label::D) } }
+						else if a == 11 { a += 1; Looping::BreakVal { label: Some(Label::B), value: 3 } }
+						else { Looping::BreakVal { label: Some(Label::A), value: 4 } }
+					};
+				}
+				a += 10;
+			};
+			assert_eq![ z, 3 ];
+		}
+	};
+	assert_eq![ x, 4 ];
+	assert_eq![ a, 12 ];
+}
+
+#[test] fn innermost_breakval () {
+	let mut c = 0;
+	let v = 'v: loop {
+		'a: loop {
+			let x = loop {
+				twist! { -named -val i32, -label 'a as A, 'v as V :i32 |
+					if c < 3 { Looping::BreakVal { label: None, value: 0 } }
+					else if c == 3 { c += 1; Looping::Break { label: Some(Label::A) } }
+					else { Looping::BreakVal { label: Some(Label::V), value: 7 } }
+				}
+			};
+			assert_eq![ x, 0 ];
+			c += 1;
+		}
+	};
+	assert_eq![ v, 7 ];
+	assert_eq![ c, 4 ];
+}
+
+#[test] fn box_breakval () {
+	let mut i = 0;
+	'a: loop {
+		let b = 'b: loop {
+			let c = 'c: loop {
+				loop {
+					twist! { -named -box -label 'a as A, 'b as B :i32, 'c as C :String | {
+						i += 1;
+						match i {
+							1 => Looping::BreakVal { label: Some(Label::B), value: anybox!(2) },
+							2 => Looping::BreakVal { label: Some(Label::C), value: anybox!("yeah".to_string()) },
+							_ => Looping::Break { label: Some(Label::A) },
+						}
+					} }
+					break;
+				}
+			};
+			assert_eq![ c, "yeah".to_string() ];
+		};
+		assert_eq![ b, 2 ];
+	}
+	assert_eq![ i, 3 ];
+}
+
+#[test] fn breakval_multiple_map () {
+	let v :i32 = 'a: loop {
+		'b: loop {
+			let x = twist! { -named -label 'a as A :i32, 'b | None::<i32> => |_| Looping::BreakVal { label: Some(Label::A), value: 4 } };
+			break 'a x * 2;
+		}
+	};
+	assert_eq![ v, 4 ];
+}
+
+#[test] fn trailing_comma () {
+	let x = 'a: loop {
+		'b: loop {
+			twist! { -named -label 'a as A: i32, 'b as B, | Looping::BreakVal { label: Some(Label::A), value: 4 } }
+		}
+	};
+	assert_eq![ x, 4 ];
+}
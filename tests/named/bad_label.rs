@@ -0,0 +1,7 @@
+use tear::twist;
+
+fn bad_label_name() {
+	twist! { -named -label 'a as 0 | 1 }
+}
+
+fn main () {}
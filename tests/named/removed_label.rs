@@ -0,0 +1,10 @@
+use tear::{twist, last};
+
+fn main () {
+	'a: loop {
+		'b: loop {
+			// 'a was removed from the list, but is still used
+			twist! { -named -label 'b as B | last!(Label::A) }
+		}
+	}
+}
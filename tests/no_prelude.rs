@@ -0,0 +1,27 @@
+// The macros shouldn't rely on the std prelude, eg. for `Box`, `Ok` or `Some`
+#![no_implicit_prelude]
+
+mod boxed {
+	use ::tear::{twist, anybox, unbox, Looping};
+	
+	#[test] fn anybox_unbox () {
+		let b = anybox!(3);
+		::std::assert_eq![ unbox!(b, i32), 3 ];
+	}
+	
+	#[test] fn box_label () {
+		let x = 'a: loop {
+			loop {
+				twist! { -box -label 'a :i32 | Looping::BreakVal::<(), _> { label: ::core::option::Option::Some(0), value: anybox!(3) } }
+			}
+		};
+		::std::assert_eq![ x, 3 ];
+	}
+	
+	#[test] fn box_single () {
+		let x = loop {
+			twist! { -box -val i32, Looping::BreakVal::<(), _> { label: ::core::option::Option::None, value: anybox!(4) } }
+		};
+		::std::assert_eq![ x, 4 ];
+	}
+}
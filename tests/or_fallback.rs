@@ -0,0 +1,123 @@
+// Testing the `-or` fallback of `twist! -box` when downcasting fails
+use tear::twist;
+use tear::{anybox, last, next, stop};
+use tear::{Looping, TwistError};
+use std::any::Any;
+
+fn values () -> Vec<Box<dyn Any>> {
+	vec![anybox!("a"), anybox!(1u8), anybox!(5)]
+}
+
+#[test] fn fallback_to_continue () {
+	let mut rejected = Vec::new();
+	let x = 'a: loop {
+		for v in values() {
+			twist! { -box -label 'a :i32 -or |b| { rejected.push(b.is::<u8>()); next!() } |
+				Looping::BreakVal::<(), _> { label: Some(0), value: v }
+			}
+		}
+		panic!("Should have broken with the i32");
+	};
+	assert_eq![ x, 5 ];
+	assert_eq![ rejected, [false, true] ];
+}
+
+#[test] fn fallback_to_plain_break () {
+	let mut count = 0;
+	'a: loop {
+		let _s :String = 'b: loop {
+			for v in values() {
+				count += 1;
+				twist! { -box -label 'a, 'b :String -or |_| last!(0) |
+					Looping::BreakVal::<(), _> { label: Some(1), value: v }
+				}
+			}
+			panic!("Should have broken 'a");
+		};
+	}
+	assert_eq![ count, 1 ];
+}
+
+#[test] fn fallback_innermost () {
+	'a: loop {
+		let x :i32 = loop {
+			twist! { -box -val i32, -label 'a -or |_| stop!(0) |
+				Looping::BreakVal::<(), _> { label: None, value: anybox!("no") }
+			}
+		};
+		panic!("Should have broken 'a instead of {}", x);
+	}
+}
+
+#[test] fn fallback_resume () {
+	let x = 'a: loop {
+		loop {
+			let v :u8 = twist! { -box -label 'a :u8 -or |b :Box<dyn Any>| tear::resume!(*b.downcast::<u8>().unwrap_or(Box::new(0)) + 1) |
+				Looping::BreakVal::<u8, _> { label: Some(0), value: anybox!("no") }
+			};
+			break 'a v;
+		}
+	};
+	assert_eq![ x, 1 ];
+}
+
+#[test] fn fallback_function () {
+	fn skip_it (_ :Box<dyn Any>) -> Looping<(), tear::BreakValError> { next!(0) }
+
+	let mut i = 0;
+	let x = 'a: loop {
+		i += 1;
+		loop {
+			twist! { -box -label 'a :i32 -or skip_it |
+				Looping::BreakVal::<(), _> { label: Some(0), value: if i < 3 { anybox!("s") } else { anybox!(i) } }
+			}
+		}
+	};
+	assert_eq![ x, 3 ];
+}
+
+#[test] fn fallback_with_try () {
+	let x = 'a: loop {
+		loop {
+			let r = twist! { -try -box -label 'a :i32 -or |_| last!(4) |
+				Looping::BreakVal::<(), _> { label: Some(0), value: anybox!("no") }
+			};
+			assert_eq![ r, Err(TwistError::InvalidLabel { index: 4, max: 1 }) ];
+			break 'a 0;
+		}
+	};
+	assert_eq![ x, 0 ];
+}
+
+#[test] fn fallback_named () {
+	let x = 'a: loop {
+		'b: loop {
+			twist! { -named -box -label 'a as A :i32, 'b as B -or |_| last!(Label::B) |
+				Looping::BreakVal::<(), _> { label: Some(Label::A), value: anybox!("no") }
+			}
+		}
+		break 7;
+	};
+	assert_eq![ x, 7 ];
+}
+
+#[cfg(not(feature = "tiny-panic"))] // The message is a short code with "tiny-panic"
+#[test]
+#[should_panic(expected = "At label 'a with type i32")]
+fn default_still_panics () {
+	let _x :i32 = 'a: loop {
+		loop {
+			twist! { -box -label 'a :i32 |
+				Looping::BreakVal::<(), _> { label: Some(0), value: anybox!("no") }
+			}
+		}
+	};
+}
+
+// All compile fail errors go here
+#[cfg(not(any(feature = "experimental", feature = "ignore-ui")))] // Feature flags to ignore test
+#[test] fn bad_input () {
+	use trybuild;
+	let t = trybuild::TestCases::new();
+	t.compile_fail("tests/or_fallback/*.rs");
+}
@@ -0,0 +1,7 @@
+use tear::twist;
+
+fn or_without_box() {
+	twist! { -label 'a: i32 -or |_| 0 | 1 }
+}
+
+fn main () {}
@@ -0,0 +1,189 @@
+// We test the panic messages of twist!
+// With "tiny-panic", they are short codes that tests/tiny_panic.rs checks instead
+
+use tear::twist;
+use tear::Looping;
+
+#[cfg(not(feature = "tiny-panic"))]
+#[test]
+#[should_panic(expected = "Breaking without a value when using `twist -val`")]
+fn break_without_val () {
+	let _ = loop {
+		twist! { -val Looping::Break::<(), i32> { label: None } }
+	};
+}
+
+#[cfg(not(feature = "tiny-panic"))]
+#[test]
+#[should_panic(expected = "`break` with value is invalid in a `for` or `while` loop")]
+fn breakval_in_not_loop () {
+	// A `BreakVal` literal would fail to compile
+	let l :Looping<(), i32> = Looping::BreakVal { label: None, value: 1 };
+	let _ = 'a: loop {
+		for _ in 0..1 {
+			twist! { -label 'a :i32 | l.clone() }
+		}
+	};
+}
+
+#[cfg(not(feature = "tiny-panic"))]
+#[test]
+#[should_panic(expected = "invalid label index 3 (twist! declared 1 labels)")]
+fn invalid_break_label () {
+	'a: loop {
+		twist! { -label 'a | Looping::Break::<(), ()> { label: Some(3) } }
+	}
+}
+
+#[cfg(not(feature = "tiny-panic"))]
+#[test]
+#[should_panic(expected = "invalid label index 3 (twist! declared 1 labels)")]
+fn invalid_continue_label () {
+	'a: loop {
+		twist! { -label 'a | Looping::Continue::<(), ()> { label: Some(3) } }
+	}
+}
+
+#[cfg(not(feature = "tiny-panic"))]
+#[test]
+#[should_panic(expected = "invalid label index 3 (twist! declared 1 labels)")]
+fn invalid_breakval_label () {
+	let _ = 'a: loop {
+		for _ in 0..1 {
+			twist! { -label 'a :i32 | Looping::BreakVal::<(), i32> { label: Some(3), value: 1 } }
+		}
+	};
+}
+
+// The number of labels counts the value labels and the boxed labels too
+#[cfg(not(feature = "tiny-panic"))]
+#[test]
+#[should_panic(expected = "invalid label index 5 (twist! declared 3 labels)")]
+fn invalid_label_counts_all_labels () {
+	let _ = 'a: loop {
+		'b: loop {
+			'c: loop {
+				twist! { -label 'a :i32, 'b, 'c | Looping::Continue::<(), i32> { label: Some(5) } }
+			}
+		}
+	};
+}
+
+#[cfg(not(feature = "tiny-panic"))]
+#[test]
+#[should_panic(expected = "invalid label index 2 (twist! declared 2 labels)")]
+fn invalid_box_label () {
+	'a: loop {
+		let _ = 'b: loop {
+			loop {
+				twist! { -box -label 'a, 'b :i32 | Looping::BreakVal::<(), _> { label: Some(2), value: tear::anybox!(1) } }
+			}
+		};
+	}
+}
+
+#[cfg(not(feature = "tiny-panic"))]
+#[test]
+#[should_panic(expected = "At label None with type i32 (got TypeId")]
+fn box_innermost_bad_type () {
+	'a: loop {
+		let _ :i32 = loop {
+			twist! { -box -val i32, -label 'a | Looping::BreakVal::<(), _> { label: None, value: tear::anybox!("5") } }
+		};
+	}
+}
+
+#[cfg(not(feature = "tiny-panic"))]
+#[test]
+#[should_panic(expected = "With type i32 (got TypeId")]
+fn box_single_bad_type () {
+	let _ :i32 = loop {
+		twist! { -box -val i32, Looping::BreakVal::<(), _> { label: None, value: tear::anybox!("5") } }
+	};
+}
+
+/* Label indices in the single-loop forms */
+
+#[cfg(not(feature = "tiny-panic"))]
+#[test]
+#[should_panic(expected = "`twist!` only handles the innermost loop, got label index 1.")]
+fn single_break_label () {
+	loop {
+		twist! { Looping::Break::<(), tear::BreakValError> { label: Some(1) } }
+	}
+}
+
+#[cfg(not(feature = "tiny-panic"))]
+#[test]
+#[should_panic(expected = "`twist!` only handles the innermost loop, got label index 0.")]
+fn single_continue_label () {
+	for _ in 0..1 {
+		twist! { Looping::Continue::<(), tear::BreakValError> { label: Some(0) } }
+	}
+}
+
+#[cfg(not(feature = "tiny-panic"))]
+#[test]
+#[should_panic(expected = "`twist!` only handles the innermost loop, got label index 2.")]
+fn single_breakval_label () {
+	let _ :i32 = loop {
+		twist! { -val Looping::BreakVal::<(), i32> { label: Some(2), value: 1 } }
+	};
+}
+
+#[cfg(not(feature = "tiny-panic"))]
+#[test]
+#[should_panic(expected = "`twist!` only handles the 'a loop, got label index 1.")]
+fn with_break_label () {
+	'a: loop {
+		loop {
+			twist! { -with 'a | Looping::Break::<(), tear::BreakValError> { label: Some(1) } }
+		}
+	}
+}
+
+#[cfg(not(feature = "tiny-panic"))]
+#[test]
+#[should_panic(expected = "`twist!` only handles the 'a loop, got label index 0.")]
+fn val_with_breakval_label () {
+	let _ :i32 = 'a: loop {
+		loop {
+			twist! { -val -with 'a | Looping::BreakVal::<(), i32> { label: Some(0), value: 1 } }
+		}
+	};
+}
+
+#[cfg(not(feature = "tiny-panic"))]
+#[test]
+#[should_panic(expected = "`twist!` only handles the innermost loop, got label index 0.")]
+fn box_single_label () {
+	let _ :i32 = loop {
+		twist! { -box -val i32, Looping::BreakVal::<(), _> { label: Some(0), value: tear::anybox!(5) } }
+	};
+}
+
+// Without a label index, they behave as before
+#[test] fn single_no_label () {
+	let mut count = 0;
+	for i in 0..5 {
+		count += 1;
+		twist! { if i == 0 { Looping::Continue::<(), tear::BreakValError> { label: None } } else { Looping::Break { label: None } } }
+	}
+	assert_eq![ count, 2 ];
+
+	let x = 'a: loop {
+		loop {
+			twist! { -val -with 'a | Looping::BreakVal::<(), i32> { label: None, value: 3 } }
+		}
+	};
+	assert_eq![ x, 3 ];
+}
+
+// Lints don't look inside macros from other crates, so we check the source instead.
+// `panic!` with a non-literal message is an error in the 2021 edition.
+#[test] fn literal_panic_messages () {
+	for src in &[include_str!("../src/twist_impl.rs"), include_str!("../src/util.rs"), include_str!("../src/lib.rs")] {
+		assert![ !src.contains("panic!(format!") ];
+		assert![ !src.contains("panic!($crate::") ];
+	}
+}
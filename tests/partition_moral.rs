@@ -0,0 +1,28 @@
+// Testing `JudgeIteratorExt::partition_moral`
+#![cfg(feature = "alloc")]
+
+use tear::JudgeIteratorExt;
+
+#[test] fn order () {
+	let values = vec![Ok(1), Err("a".to_string()), Ok(2), Err("b".to_string()), Ok(3)];
+	let (goods, bads) = values.into_iter().partition_moral();
+	assert_eq![ goods, vec![1, 2, 3] ];
+	assert_eq![ bads, vec!["a".to_string(), "b".to_string()] ];
+}
+
+#[test] fn all_good () {
+	let (goods, bads) = vec![Some(1), Some(2)].into_iter().partition_moral();
+	assert_eq![ goods, vec![1, 2] ];
+	assert![ bads.is_empty() ];
+}
+
+#[test] fn all_bad () {
+	let (goods, bads) :(Vec<i32>, _) = vec![Err(1), Err(2)].into_iter().partition_moral();
+	assert![ goods.is_empty() ];
+	assert_eq![ bads, vec![1, 2] ];
+}
+
+#[test] fn empty () {
+	let (goods, bads) = Vec::<Result<i32, i32>>::new().into_iter().partition_moral();
+	assert![ goods.is_empty() && bads.is_empty() ];
+}
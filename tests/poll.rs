@@ -0,0 +1,73 @@
+// Testing the "poll" feature
+#![cfg(feature = "poll")]
+
+use tear::extra::*;
+use tear::StreamBad;
+use core::task::Poll;
+
+// A minimal `Stream`, without the `Context`
+trait Stream {
+	type Item;
+	fn poll_next (&mut self) -> Poll<Option<Self::Item>>;
+}
+
+// Scripted values
+struct Source(Vec<Poll<Option<Result<i32, String>>>>);
+
+impl Stream for Source {
+	type Item = Result<i32, String>;
+
+	fn poll_next (&mut self) -> Poll<Option<Self::Item>> {
+		if self.0.is_empty() { Poll::Ready(None) } else { self.0.remove(0) }
+	}
+}
+
+// Maps the values of the inner stream
+struct Map<S, F> {
+	inner :S,
+	f :F,
+}
+
+impl<S, F> Stream for Map<S, F> where S :Stream<Item = Result<i32, String>>, F :FnMut(i32) -> i32 {
+	type Item = Result<i32, String>;
+
+	fn poll_next (&mut self) -> Poll<Option<Self::Item>> {
+		let v = terror! { self.inner.poll_next() };
+		Poll::Ready(Some(Ok((self.f)(v))))
+	}
+}
+
+#[test] fn map_stream () {
+	let source = Source(vec![Poll::Ready(Some(Ok(1))), Poll::Pending, Poll::Ready(Some(Err("bad".to_string()))), Poll::Ready(Some(Ok(2)))]);
+	let mut s = Map { inner: source, f: |v| v * 10 };
+	assert_eq![ s.poll_next(), Poll::Ready(Some(Ok(10))) ];
+	assert_eq![ s.poll_next(), Poll::Pending ];
+	assert_eq![ s.poll_next(), Poll::Ready(Some(Err("bad".to_string()))) ];
+	assert_eq![ s.poll_next(), Poll::Ready(Some(Ok(20))) ];
+	assert_eq![ s.poll_next(), Poll::Ready(None) ];
+}
+
+#[test] fn judge () {
+	let p :Poll<Option<Result<i32, u8>>> = Poll::Ready(Some(Ok(1)));
+	assert_eq![ p.into_moral(), Good(1) ];
+	let p :Poll<Option<Result<i32, u8>>> = Poll::Ready(Some(Err(2)));
+	assert_eq![ p.into_moral(), Bad(StreamBad::Err(2)) ];
+	let p :Poll<Option<Result<i32, u8>>> = Poll::Ready(None);
+	assert_eq![ p.into_moral(), Bad(StreamBad::Done) ];
+	let p :Poll<Option<Result<i32, u8>>> = Poll::Pending;
+	assert_eq![ p.into_moral(), Bad(StreamBad::Pending) ];
+}
+
+#[test] fn from_stream_bad () {
+	let p :Poll<Option<Result<(), u16>>> = StreamBad::Err(3u8).into();
+	assert_eq![ p, Poll::Ready(Some(Err(3))) ];
+	let p :Poll<Option<Result<(), u16>>> = StreamBad::<u8>::Done.into();
+	assert_eq![ p, Poll::Ready(None) ];
+	let p :Poll<Option<Result<(), u16>>> = StreamBad::<u8>::Pending.into();
+	assert_eq![ p, Poll::Pending ];
+}
+
+#[test] fn map_err () {
+	assert_eq![ StreamBad::Err(3u8).map_err(u16::from), StreamBad::Err(3u16) ];
+	assert_eq![ StreamBad::<u8>::Done.map_err(u16::from), StreamBad::Done ];
+}
@@ -0,0 +1,68 @@
+// The prefixed macros work next to user macros with the short names
+#![allow(clippy::never_loop)] // Loops that only run once are the point of these tests
+#![allow(unused_macros)] // The user macros that fail to compile when called
+
+use tear::prefixed::*;
+
+// Conflicting user macros, that don't do what the macros of `tear` do
+macro_rules! next { ($v:expr) => { $v + 1 } }
+macro_rules! last { ($v:expr) => { $v.last().copied() } }
+macro_rules! resume { () => { "resumed" } }
+macro_rules! tear_if { ($($t:tt)*) => { compile_error!("The user `tear_if!` was called") } }
+macro_rules! twist { ($($t:tt)*) => { compile_error!("The user `twist!` was called") } }
+macro_rules! tear { ($($t:tt)*) => { compile_error!("The user `tear!` was called") } }
+macro_rules! terror { ($($t:tt)*) => { compile_error!("The user `terror!` was called") } }
+
+fn parse (s :&str) -> Result<i32, String> {
+	t_tear_if! { s.is_empty(), Err("empty".to_string()) };
+	let v = t_terror! { s.parse::<i32>() => |e :std::num::ParseIntError| e.to_string() };
+	let v = t_tear! { if v < 0 { Ret(Err("negative".to_string())) } else { Val(v) } };
+	Ok(v)
+}
+
+#[test] fn returns () {
+	assert_eq![ parse("3"), Ok(3) ];
+	assert_eq![ parse(""), Err("empty".to_string()) ];
+	assert_eq![ parse("-1"), Err("negative".to_string()) ];
+	assert![ parse("x").is_err() ];
+
+	let (a, b) = (|| -> Result<_, String> { Ok(t_terror_all! { parse("1"), parse("2") }) })().unwrap();
+	assert_eq![ (a, b), (1, 2) ];
+}
+
+#[test] fn loops () {
+	let mut sum = 0;
+	for s in &["1", "a", "2", "stop", "3"] {
+		t_last_if! { *s == "stop" };
+		sum += t_twist! { s.parse::<i32>() => |_| t_next!() };
+	}
+	assert_eq![ sum, 3 ];
+	assert_eq![ next!(1), 2 ];
+	assert_eq![ last!([1, 2]), Some(2) ];
+	assert_eq![ resume!(), "resumed" ];
+
+	let x = 'a: loop {
+		loop {
+			t_twist! { -label 'a :i32 | t_breakval!(0, 4) }
+		}
+	};
+	assert_eq![ x, 4 ];
+}
+
+#[test] fn shortcuts () {
+	let mut sum = 0;
+	for v in &[Some(1), None, Some(2)] {
+		t_next_if! { v.is_none() };
+		sum += t_some_or_next! { *v };
+		sum += t_ok_or_next! { v.ok_or(()) };
+	}
+	assert_eq![ sum, 6 ];
+	let r :Looping<i32, ()> = t_skip!();
+	assert_eq![ r, Looping::Continue { label: None } ];
+	assert_eq![ t_resume!(1; ()), Looping::Resume(1) ];
+	assert_eq![ t_maybe_match! { Good::<_, ()>(1), Good(v) => v }, Some(1) ];
+}
+
+#[test] fn boxed () {
+	assert_eq![ t_unbox!(t_anybox!(5), i32), 5 ];
+}
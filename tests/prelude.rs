@@ -0,0 +1,55 @@
+// `prelude` brings every extension trait into scope, without importing the names
+mod only_prelude {
+	use tear::prelude::*;
+	use tear::{Moral, Folded};
+
+	#[test] fn judge_and_return () {
+		assert_eq![ Ok::<_, ()>(1).into_moral(), Moral::Good(1) ];
+		assert_eq![ Err::<(), _>(2).result(), Err(2) ];
+		assert_eq![ Option::from_good(3), Some(3) ];
+		assert_eq![ Result::<(), _>::from_bad(4), Err(4) ];
+		assert_eq![ Val::<_, ()>(5).into_valret(), Val(5) ];
+	}
+
+	#[test] fn looping () {
+		assert_eq![ Looping::<_, ()>::Resume(1).into_looping(), Looping::Resume(1) ];
+		assert_eq![ (1..5).tear_for_each(|v| if v == 3 { Looping::BreakVal { label: None, value: v } } else { Looping::Resume(()) }), Some(3) ];
+		assert_eq![ (1..5).looping_fold(0, |acc, v| Looping::Resume::<_, ()>(acc + v)), Folded::Done(10) ];
+		let v :Vec<i32> = vec!["1", "a", "2"].into_iter()
+			.map_looping(|s| s.parse::<i32>(), |_| Looping::<i32, ()>::Continue { label: None })
+			.collect();
+		assert_eq![ v, [1, 2] ];
+	}
+
+	#[test] fn judge_iterator () {
+		let values = || vec![Ok(1), Err("a"), Ok(2)].into_iter();
+		assert_eq![ values().goods().collect::<Vec<_>>(), [1, 2] ];
+		assert_eq![ values().bads().collect::<Vec<_>>(), ["a"] ];
+		assert_eq![ values().until_bad().collect::<Vec<_>>(), [1] ];
+		assert_eq![ values().fold_good(0, |acc, v| acc + v), Moral::Bad("a") ];
+		assert_eq![ values().fold_moral(0, 0, |g, v| g + v, |b, _| b + 1), (3, 1) ];
+		assert_eq![ values().partition_moral(), (vec![1, 2], vec!["a"]) ];
+	}
+
+	#[test] fn valret_iterator () {
+		let mut it = (1..5).tear_map(|v| if v < 3 { Val(v) } else { Ret(v) });
+		assert_eq![ it.by_ref().collect::<Vec<_>>(), [1, 2] ];
+		assert_eq![ it.finish::<Vec<_>>(), Ret(3) ];
+		assert_eq![ (1..5).val_ret_fold(0, |acc, v| Val::<_, ()>(acc + v)), Val(10) ];
+	}
+}
+
+// The names aren't imported, so they can be defined again
+mod no_conflict {
+	#![allow(dead_code)]
+	use tear::prelude::*;
+
+	trait Judge {}
+	trait Return {}
+	trait JudgeIteratorExt {}
+	struct LoopingIteratorExt;
+
+	#[test] fn still_in_scope () {
+		assert_eq![ vec![Some(1), None].into_iter().goods().count(), 1 ];
+	}
+}
@@ -0,0 +1,110 @@
+// Testing `Looping::Redo` with `tredo_loop!`, and that the other loops reject it
+use tear::{tredo_loop, twist};
+use tear::{redo, next, last, skip, breakval, go};
+use tear::Looping;
+use tear::assert_looping;
+
+#[derive(Debug, PartialEq)]
+enum Mode { Words, Numbers }
+
+// Switching modes reads the same token again
+#[test] fn parser () {
+	let tokens = ["a", "b", "1", "2", "c", "3"];
+	let mut mode = Mode::Words;
+	let mut runs = Vec::new();
+	let mut parsed = Vec::new();
+	let mut iter = tokens.iter().enumerate();
+	tredo_loop! { for (i, token) in iter.by_ref() => {
+		runs.push(i);
+		match (&mode, token.parse::<i32>()) {
+			(Mode::Words, Err(_)) | (Mode::Numbers, Ok(_)) => { parsed.push((*token, i)); next!() },
+			(Mode::Words, Ok(_)) => { mode = Mode::Numbers; redo!() },
+			(Mode::Numbers, Err(_)) => { mode = Mode::Words; redo!() },
+		}
+	} }
+	assert_eq![ runs, [0, 1, 2, 2, 3, 4, 4, 5, 5] ];
+	assert_eq![ parsed, [("a", 0), ("b", 1), ("1", 2), ("2", 3), ("c", 4), ("3", 5)] ];
+	assert_eq![ mode, Mode::Numbers ];
+}
+
+#[test] fn break_and_continue () {
+	let mut seen = Vec::new();
+	let mut redone = false;
+	tredo_loop! { for v in 0..10 => {
+		if v == 2 && !redone { redone = true; return_redo() }
+		else if v % 2 == 1 { next!() }
+		else if v > 5 { last!() }
+		else { seen.push(v); next!() }
+	} }
+	assert_eq![ seen, [0, 2, 4] ];
+	assert![ redone ];
+}
+
+fn return_redo () -> Looping<(), tear::BreakValError> { redo!() }
+
+#[test] fn loop_val () {
+	let mut i = 0;
+	let mut redos = 0;
+	let x = tredo_loop! { -val loop => {
+		i += 1;
+		if i == 2 && redos == 0 { redos += 1; i -= 1; redo!() }
+		else if i < 4 { skip!() }
+		else { breakval!(i * 10) }
+	} };
+	assert_eq![ (x, redos), (40, 1) ];
+}
+
+#[test] fn labeled () {
+	let mut count = 0;
+	tredo_loop! { 'a: loop => {
+		count += 1;
+		for _ in 0..3 {
+			twist! { -with 'a | if count == 3 { last!() } else { go!(()) } }
+		}
+		if count < 3 { redo!() } else { next!() }
+	} }
+	assert_eq![ count, 3 ];
+}
+
+#[cfg(not(feature = "tiny-panic"))] // The message is a short code with "tiny-panic"
+#[test]
+#[should_panic(expected = "`tredo_loop!` only handles its own loop, got label index 1.")]
+fn labeled_panics () {
+	tredo_loop! { for _ in 0..3 => redo!(1) }
+}
+
+#[cfg(not(feature = "tiny-panic"))] // The message is a short code with "tiny-panic"
+#[test]
+#[should_panic(expected = "Looping::Redo runs the loop body again, which only `tredo_loop!` can do.")]
+fn twist_panics () {
+	loop {
+		twist! { Looping::Redo::<(), tear::BreakValError> { label: None } }
+	}
+}
+
+#[cfg(not(feature = "tiny-panic"))] // The message is a short code with "tiny-panic"
+#[test]
+#[should_panic(expected = "Looping::Redo runs the loop body again")]
+fn twist_label_panics () {
+	'a: loop {
+		twist! { -label 'a | Looping::Redo::<(), tear::BreakValError> { label: Some(0) } }
+	}
+}
+
+#[cfg(not(feature = "tiny-panic"))] // The message is a short code with "tiny-panic"
+#[test]
+#[should_panic(expected = "Looping::Redo runs the loop body again")]
+fn iterator_panics () {
+	use tear::LoopingIteratorExt;
+	(0..3).tear_for_each(|_| -> Looping<(), ()> { redo!() });
+}
+
+#[test] fn methods () {
+	let l :Looping<i32, ()> = redo!(2);
+	assert_looping! { l.clone(), Redo(Some(2)) };
+	assert![ l.is_redo() ];
+	assert_eq![ l.label(), Some(2) ];
+	assert_eq![ l.clone().offset_labels(1), Looping::Redo { label: Some(3) } ];
+	assert_eq![ l.to_string(), "redo loop #2" ];
+	assert![ !Looping::<(), ()>::Continue { label: None }.is_redo() ];
+}
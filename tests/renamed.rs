@@ -0,0 +1,181 @@
+// The macros should only call each other through `$crate`, so that they work when the crate is
+// renamed, and when another `twist!` is in scope
+#![allow(clippy::never_loop)] // Loops that only run once are the point of these tests
+
+extern crate tear as renamed;
+
+use renamed::Looping;
+
+// Catch the expansions that call `twist!` instead of `$crate::twist!`
+#[allow(unused_macros)]
+macro_rules! twist {
+	( $($t:tt)* ) => { compile_error!("The local `twist!` was called instead of `tear::twist!`") };
+}
+
+type L = Looping<(), i32>;
+type B = Looping<(), Box<dyn std::any::Any>>;
+
+#[test] fn label () {
+	let x = 'a: loop {
+		loop {
+			renamed::twist! { -label 'a :i32 | L::BreakVal { label: Some(0), value: 1 } }
+		}
+	};
+	assert_eq![ x, 1 ];
+}
+
+#[test] fn val_label () {
+	let x = 'a: loop {
+		let y = loop {
+			renamed::twist! { -val i32, -label 'a :i32 | L::BreakVal { label: None, value: 2 } }
+		};
+		break 'a y;
+	};
+	assert_eq![ x, 2 ];
+}
+
+#[test] fn box_label () {
+	let x = 'a: loop {
+		loop {
+			renamed::twist! { -box -label 'a :i32 | B::BreakVal { label: Some(0), value: renamed::anybox!(3) } }
+		}
+	};
+	assert_eq![ x, 3 ];
+}
+
+#[test] fn box_val_label () {
+	let x = 'a: loop {
+		let y :i32 = loop {
+			renamed::twist! { -box -val i32, -label 'a :i32 | B::BreakVal { label: None, value: renamed::anybox!(4) } }
+		};
+		break 'a y;
+	};
+	assert_eq![ x, 4 ];
+}
+
+#[test] fn box_label_or () {
+	let x = 'a: loop {
+		loop {
+			renamed::twist! { -box -label 'a :i32 -or |_| renamed::last!() |
+				B::BreakVal { label: Some(0), value: renamed::anybox!("five") }
+			}
+		}
+		break 5;
+	};
+	assert_eq![ x, 5 ];
+}
+
+#[test] fn try_label () {
+	let x = 'a: loop {
+		loop {
+			let r :Result<(), _> = renamed::twist! { -try -label 'a :i32 | L::Break { label: Some(3) } };
+			assert_eq![ r, Err(renamed::TwistError::InvalidLabel { index: 3, max: 1 }) ];
+			renamed::twist! { -try -label 'a :i32 | L::BreakVal { label: Some(0), value: 6 } }.unwrap();
+		}
+	};
+	assert_eq![ x, 6 ];
+}
+
+#[test] fn try_box_label () {
+	let x = 'a: loop {
+		loop {
+			renamed::twist! { -try -box -label 'a :i32 | B::BreakVal { label: Some(0), value: renamed::anybox!(7) } }.unwrap();
+		}
+	};
+	assert_eq![ x, 7 ];
+	let y = 'a: loop {
+		let z :i32 = loop {
+			renamed::twist! { -try -box -val i32, -label 'a :i32 | B::BreakVal { label: None, value: renamed::anybox!(8) } }.unwrap();
+		};
+		break 'a z;
+	};
+	assert_eq![ y, 8 ];
+}
+
+#[test] fn named () {
+	let x = 'a: loop {
+		loop {
+			renamed::twist! { -named -label 'a as A :i32 | L::BreakVal { label: Some(Label::A), value: 9 } }
+		}
+	};
+	assert_eq![ x, 9 ];
+	let y = 'a: loop {
+		loop {
+			renamed::twist! { -named -box -label 'a as A :i32 | B::BreakVal { label: Some(Label::A), value: renamed::anybox!(10) } }
+		}
+	};
+	assert_eq![ y, 10 ];
+}
+
+#[test] fn enum_mode () {
+	let x = 'a: loop {
+		loop {
+			renamed::twist! { -enum Sig -label 'a as A :i32 | Looping::BreakVal { label: None, value: Sig::A(11) } }
+		}
+	};
+	assert_eq![ x, 11 ];
+}
+
+#[test] fn box_single () {
+	let x = loop {
+		renamed::twist! { -box -val i32, B::BreakVal { label: None, value: renamed::anybox!(12) } }
+	};
+	assert_eq![ x, 12 ];
+}
+
+#[test] fn with_labels () {
+	let mut i = 0;
+	'a: loop {
+		i += 1;
+		loop {
+			renamed::twist! { -with ['a] | if i < 3 { renamed::next!(0) } else { renamed::last!(0) } }
+		}
+	}
+	assert_eq![ i, 3 ];
+}
+
+/* terror! and tear! */
+
+fn parse (s :&str) -> Result<i32, String> {
+	let v = renamed::terror! { s.parse::<i32>() => |e :std::num::ParseIntError| e.to_string() };
+	let w = renamed::terror! { (v > 0).then_some(v) =>> "not positive".to_string() };
+	Ok(w)
+}
+
+#[test] fn terror_mapped () {
+	assert_eq![ parse("3"), Ok(3) ];
+	assert_eq![ parse("-3"), Err("not positive".to_string()) ];
+	assert![ parse("a").is_err() ];
+}
+
+// A local `From` shouldn't replace `core::convert::From` in the expansions
+mod local_from {
+	#[allow(dead_code)]
+	trait From<T> {
+		fn from (v :T) -> Self;
+	}
+
+	#[derive(Debug, PartialEq)]
+	struct E(i32);
+	impl core::convert::From<i32> for E {
+		fn from (v :i32) -> Self { E(v) }
+	}
+
+	fn f (v :Result<i32, i32>) -> Result<i32, E> {
+		let x = renamed::terror! { v };
+		let y = renamed::terror! { Err::<i32, i32>(x) => |v| v + 1 };
+		Ok(y)
+	}
+
+	fn g (v :renamed::ValRet<i32, i32>) -> E {
+		let x = renamed::tear! { v };
+		E(x * 10)
+	}
+
+	#[test] fn from () {
+		assert_eq![ f(Err(1)), Err(E(1)) ];
+		assert_eq![ f(Ok(1)), Err(E(2)) ];
+		assert_eq![ g(renamed::ValRet::Ret(3)), E(3) ];
+		assert_eq![ g(renamed::ValRet::Val(3)), E(30) ];
+	}
+}
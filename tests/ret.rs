@@ -0,0 +1,177 @@
+// Testing the `-ret` flag of `twist!`, that returns from the function on `ValRet::Ret`
+use tear::twist;
+use tear::{next, last, resume, breakval};
+use tear::{stop, skip, go};
+use tear::{Looping, ValRet};
+use tear::ValRet::{Val, Ret};
+use std::num::ParseIntError;
+
+#[derive(Debug, PartialEq)]
+struct Error(String);
+
+impl From<ParseIntError> for Error {
+	fn from (e :ParseIntError) -> Self { Error(e.to_string()) }
+}
+
+impl From<&str> for Error {
+	fn from (s :&str) -> Self { Error(s.to_string()) }
+}
+
+// Skips empty strings, stops at "end", and returns the error of the other values
+fn parse (s :&str) -> ValRet<Looping<i32, tear::BreakValError>, ParseIntError> {
+	match s {
+		"" => Val(next!()),
+		"end" => Val(last!()),
+		s => match s.parse() {
+			Ok(v) => Val(resume!(v)),
+			Err(e) => Ret(e),
+		},
+	}
+}
+
+fn sum (values :&[&str]) -> Result<i32, Error> {
+	let mut sum = 0;
+	for s in values {
+		sum += twist! { -ret ParseIntError, parse(s) };
+	}
+	Ok(sum)
+}
+
+#[test] fn simple () {
+	assert_eq![ sum(&["1", "", "2"]), Ok(3) ];
+	assert_eq![ sum(&["1", "end", "a"]), Ok(1) ];
+	assert_eq![ sum(&["1", "a", "end"]), Err(Error("invalid digit found in string".to_string())) ];
+}
+
+#[test] fn option () {
+	let first_even = |values :&[i32]| -> Option<i32> {
+		for &v in values {
+			twist! { -ret (), if v % 2 == 0 { Ret(()) } else { Val(resume!(())) } }
+		}
+		Some(0)
+	};
+	assert_eq![ first_even(&[1, 3]), Some(0) ];
+	assert_eq![ first_even(&[1, 2, 3]), None ];
+}
+
+#[test] fn val () {
+	let first_big = |values :&[i32]| -> Result<i32, Error> {
+		let mut values = values.iter();
+		let x = loop {
+			let v = match values.next() { Some(&v) => v, None => break 0 };
+			twist! { -ret &str, -val if v < 0 { Ret("negative") } else if v > 10 { Val(breakval!(v)) } else { Val(go!(())) } }
+		};
+		Ok(x)
+	};
+	assert_eq![ first_big(&[1, 2]), Ok(0) ];
+	assert_eq![ first_big(&[1, 20, -1]), Ok(20) ];
+	assert_eq![ first_big(&[1, -1, 20]), Err(Error("negative".to_string())) ];
+}
+
+// Returns from inside two loops, while the other signals break or continue either of them
+fn search (grid :&[&[&str]]) -> Result<(usize, i32), Error> {
+	let mut rows = 0;
+	let found = 'a: loop {
+		for row in grid {
+			rows += 1;
+			'b: for s in row.iter() {
+				let signal = match *s {
+					"stop" => Val(stop!(1)),
+					"next" => Val(skip!(1)),
+					s if s.starts_with('=') => Val(breakval!(0, s[1..].parse::<i32>().unwrap())),
+					s => Ret(s),
+				};
+				twist! { -ret &str, -label 'a :i32, 'b | signal }
+			}
+		}
+		break -1;
+	};
+	Ok((rows, found))
+}
+
+#[test] fn nested () {
+	assert_eq![ search(&[&["next", "=4"], &["oops"]]), Ok((1, 4)) ];
+	assert_eq![ search(&[&["stop", "oops"], &["=5"]]), Ok((2, 5)) ];
+	assert_eq![ search(&[&["next"], &["stop"]]), Ok((2, -1)) ];
+	assert_eq![ search(&[&["next"], &["oops", "=4"]]), Err(Error("oops".to_string())) ];
+}
+
+#[test] fn with () {
+	let first = |grid :&[&[&str]]| -> Result<i32, Error> {
+		let mut count = 0;
+		'a: for row in grid {
+			for s in row.iter() {
+				count += 1;
+				let signal = match *s {
+					"next" => Val(next!()),
+					"stop" => Val(last!()),
+					s => Ret(s),
+				};
+				twist! { -ret &str, -with 'a | signal }
+			}
+		}
+		Ok(count)
+	};
+	assert_eq![ first(&[&["next", "oops"], &["stop"]]), Ok(2) ];
+	assert_eq![ first(&[&["stop", "oops"]]), Ok(1) ];
+	assert_eq![ first(&[&["next", "next"], &["oops"]]), Err(Error("oops".to_string())) ];
+}
+
+#[test] fn mapping () {
+	let sum = |values :&[&str]| -> Result<i32, Error> {
+		let mut sum = 0;
+		for s in values {
+			sum += twist! { -ret ParseIntError, s.parse::<i32>() => |e| if s.is_empty() { Val(next!()) } else { Ret(e) } };
+		}
+		Ok(sum)
+	};
+	assert_eq![ sum(&["1", "", "2"]), Ok(3) ];
+	assert_eq![ sum(&["1", "a"]), Err(Error("invalid digit found in string".to_string())) ];
+
+	let count = |values :&[Option<i32>]| -> Result<i32, Error> {
+		let mut count = 0;
+		for v in values {
+			twist! { -ret &str, *v =>> Ret("missing") };
+			count += 1;
+		}
+		Ok(count)
+	};
+	assert_eq![ count(&[Some(1), Some(2)]), Ok(2) ];
+	assert_eq![ count(&[Some(1), None]), Err(Error("missing".to_string())) ];
+}
+
+// The `-finally` block runs before returning, and `-track` isn't set
+#[test] fn finally_and_track () {
+	let mut closed = 0;
+	let mut broke = false;
+	let r = (|| -> Result<(), Error> {
+		loop {
+			twist! { -ret &str, -track broke -finally { closed += 1; } Ret::<Looping<(), tear::BreakValError>, _>("oops") }
+		}
+		Ok(())
+	})();
+	assert_eq![ r, Err(Error("oops".to_string())) ];
+	assert_eq![ closed, 1 ];
+	assert![ !broke ];
+
+	let mut closed = 0;
+	let r = (|| -> Result<(), Error> {
+		for i in 0..3 {
+			twist! { -finally { closed += 1; } -ret &str, if i < 2 { Val(next!()) } else { Ret("oops") } }
+		}
+		Ok(())
+	})();
+	assert_eq![ r, Err(Error("oops".to_string())) ];
+	assert_eq![ closed, 3 ];
+}
+
+#[test] fn resume () {
+	let r = (|| -> Result<i32, Error> {
+		let mut sum = 0;
+		for i in 0..3 {
+			sum += twist! { -ret &str, Val(go!(i)) };
+		}
+		Ok(sum)
+	})();
+	assert_eq![ r, Ok(3) ];
+}
@@ -0,0 +1,139 @@
+// Testing `terror_retry!`
+use tear::prelude::*;
+use std::cell::Cell;
+
+// Fails `fails` times, then succeeds with the attempt count
+struct Flaky {
+	fails :usize,
+	calls :Cell<usize>,
+}
+
+impl Flaky {
+	fn new (fails :usize) -> Self { Flaky { fails, calls: Cell::new(0) } }
+
+	fn call (&self) -> Result<usize, String> {
+		self.calls.set(self.calls.get() + 1);
+		if self.calls.get() <= self.fails {
+			Err(format!("fail {}", self.calls.get()))
+		} else {
+			Ok(self.calls.get())
+		}
+	}
+}
+
+fn retry (n :usize, flaky :&Flaky) -> Result<usize, String> {
+	let v = terror_retry! { n, flaky.call() };
+	Ok(v)
+}
+
+#[test] fn success_first_try () {
+	let f = Flaky::new(0);
+	assert_eq![ retry(3, &f), Ok(1) ];
+	assert_eq![ f.calls.get(), 1 ];
+}
+
+#[test] fn success_second_try () {
+	let f = Flaky::new(1);
+	assert_eq![ retry(3, &f), Ok(2) ];
+	assert_eq![ f.calls.get(), 2 ];
+}
+
+#[test] fn exhaustion () {
+	let f = Flaky::new(10);
+	assert_eq![ retry(3, &f), Err("fail 3".to_string()) ];
+	assert_eq![ f.calls.get(), 3 ];
+}
+
+#[test] fn zero_attempts_evaluates_once () {
+	let f = Flaky::new(10);
+	assert_eq![ retry(0, &f), Err("fail 1".to_string()) ];
+	assert_eq![ f.calls.get(), 1 ];
+}
+
+#[test] fn mapped () {
+	fn g (f :&Flaky) -> Result<usize, usize> {
+		let v = terror_retry! { 2, f.call() => |e :String| e.len() };
+		Ok(v)
+	}
+
+	let f = Flaky::new(5);
+	assert_eq![ g(&f), Err(6) ];
+	assert_eq![ f.calls.get(), 2 ];
+}
+
+#[test] fn on_retry_hook () {
+	let mut seen = Vec::new();
+	let f = Flaky::new(2);
+	let r = (|| -> Result<usize, String> {
+		let v = terror_retry! { 4, -on_retry |i, e| seen.push((i, e)), f.call() };
+		Ok(v)
+	})();
+
+	assert_eq![ r, Ok(3) ];
+	assert_eq![ seen, vec![(0, "fail 1".to_string()), (1, "fail 2".to_string())] ];
+}
+
+#[test] fn on_retry_not_called_on_last_attempt () {
+	let mut seen = 0;
+	let f = Flaky::new(5);
+	let r = (|| -> Result<usize, usize> {
+		let v = terror_retry! { 3, -on_retry |_, _| seen += 1, f.call() => |_| 0usize };
+		Ok(v)
+	})();
+
+	assert_eq![ r, Err(0) ];
+	assert_eq![ seen, 2 ];
+	assert_eq![ f.calls.get(), 3 ];
+}
+
+// Same tests with the scripted `tear::testing::Flaky`
+#[cfg(feature = "testing")]
+mod scripted {
+	use tear::prelude::*;
+	use tear::testing::Flaky;
+	use tear::Moral::{self, *};
+
+	fn retry (n :usize, source :&Flaky<usize, &'static str>) -> Result<usize, &'static str> {
+		let v = terror_retry! { n, source.next() };
+		Ok(v)
+	}
+
+	#[test] fn success_after_failures () {
+		let source = Flaky::new([Bad("a"), Bad("b"), Good(7)]);
+		assert_eq![ retry(3, &source), Ok(7) ];
+		assert_eq![ source.polls(), 3 ];
+	}
+
+	#[test] fn exhaustion_returns_last_bad () {
+		let source = Flaky::new([Bad("a"), Bad("b"), Good(7)]);
+		assert_eq![ retry(2, &source), Err("b") ];
+		assert_eq![ source.polls(), 2 ];
+		assert_eq![ source.remaining(), 1 ];
+	}
+
+	#[test] fn hook_sees_every_retried_value () {
+		let source = Flaky::new([Bad("a"), Bad("b"), Bad("c")]);
+		let mut seen = Vec::new();
+		let r = (|| -> Result<usize, &'static str> {
+			let v = terror_retry! { 3, -on_retry |i, e| seen.push((i, e)), source.next() };
+			Ok(v)
+		})();
+		assert_eq![ r, Err("c") ];
+		assert_eq![ seen, [(0, "a"), (1, "b")] ];
+	}
+
+	#[test] fn values_are_cloned () {
+		let source :Flaky<String, ()> = Flaky::new(vec![Good("x".to_string())]);
+		assert_eq![ source.next(), Moral::Good("x".to_string()) ];
+		assert_eq![ source.len(), 1 ];
+		assert![ !source.is_empty() ];
+	}
+
+	#[test]
+	#[should_panic(expected = "Flaky was polled 2 times, but its script only has 1 values")]
+	fn exhausted () {
+		let source :Flaky<(), ()> = Flaky::new([Good(())]);
+		source.next();
+		source.next();
+	}
+}
@@ -0,0 +1,82 @@
+// Testing the debug check of the label index at the top of `twist! -label`, that gives the
+// expression that produced the signal
+// With "tiny-panic", the messages are short codes, tested in tests/tiny_panic.rs
+#![cfg(not(feature = "tiny-panic"))]
+
+use tear::{twist, Looping};
+
+// A signal from an outdated label list
+fn stale (index :usize) -> Looping<(), i32> {
+	Looping::Break { label: Some(index) }
+}
+
+#[cfg(debug_assertions)]
+#[test]
+#[should_panic(expected = "invalid label index 3 (twist! declared 2 labels) in the signal from `stale(3)`")]
+fn producer_in_message () {
+	'a: loop {
+		'b: loop {
+			twist! { -label 'a, 'b | stale(3) }
+		}
+	}
+}
+
+#[cfg(debug_assertions)]
+#[test]
+#[should_panic(expected = "in the signal from `tear::next!(1)`")]
+fn continue_producer () {
+	'a: loop {
+		twist! { -label 'a | tear::next!(1) }
+	}
+}
+
+#[cfg(debug_assertions)]
+#[test]
+#[should_panic(expected = "in the signal from `Looping::BreakVal::<(), _> { label: Some(2), value: tear::anybox!(1) }`")]
+fn box_producer () {
+	'a: loop {
+		let _ = 'b: loop {
+			loop {
+				twist! { -box -label 'a, 'b :i32 | Looping::BreakVal::<(), _> { label: Some(2), value: tear::anybox!(1) } }
+			}
+		};
+	}
+}
+
+// The promise of `-unchecked` is checked in debug builds
+#[cfg(debug_assertions)]
+#[test]
+#[should_panic(expected = "in the signal from `tear::last!(1)`")]
+fn unchecked_is_checked_in_debug () {
+	'a: loop {
+		twist! { -unchecked -label 'a | tear::last!(1) }
+	}
+}
+
+// Valid label indices go through, and `-try` still returns the error
+#[test] fn valid_and_try () {
+	let mut count = 0;
+	'a: loop {
+		'b: loop {
+			count += 1;
+			if count < 3 { twist! { -label 'a, 'b | Looping::<(), ()>::Continue { label: Some(1) } } }
+			let r = twist! { -try -label 'a, 'b | stale(2) };
+			assert_eq![ r, Err(tear::TwistError::InvalidLabel { index: 2, max: 2 }) ];
+			twist! { -label 'a, 'b | stale(0) }
+		}
+	}
+	assert_eq![ count, 3 ];
+}
+
+// Without debug assertions, the check compiles away and the match arms panic without the expression
+#[cfg(not(debug_assertions))]
+#[test] fn no_producer_in_release () {
+	let r = std::panic::catch_unwind(|| 'a: loop {
+		'b: loop {
+			twist! { -label 'a, 'b | stale(3) }
+		}
+	});
+	let payload = r.unwrap_err();
+	let msg = payload.downcast_ref::<String>().unwrap();
+	assert_eq![ msg, "invalid label index 3 (twist! declared 2 labels)" ];
+}
@@ -0,0 +1,40 @@
+// Testing the crate with and without the "std" feature
+use tear::prelude::*;
+use tear::TwistError;
+
+// The core API is the same either way
+#[test] fn core_api () {
+	fn f (v :Option<i32>) -> Option<i32> {
+		Some(terror! { v } + 1)
+	}
+	assert_eq![ f(Some(1)), Some(2) ];
+	assert_eq![ f(None), None ];
+
+	let mut sum = 0;
+	for v in &[Ok(1), Err(()), Ok(2)] {
+		sum += twist! { *v => |_| tear::next!() };
+	}
+	assert_eq![ sum, 3 ];
+}
+
+#[cfg(feature = "std")]
+mod with_std {
+	use super::*;
+
+	fn as_error<E :std::error::Error + 'static> (e :E) -> Box<dyn std::error::Error> { Box::new(e) }
+
+	#[test] fn twist_error () {
+		let e = as_error(TwistError::MissingValue);
+		assert_eq![ e.to_string(), "The Looping object breaks without a value a loop that expects one" ];
+	}
+}
+
+#[cfg(not(feature = "std"))]
+mod without_std {
+	use super::*;
+
+	// Still displays without `std::error::Error`
+	#[test] fn twist_error () {
+		assert_eq![ TwistError::InvalidLabel { index: 2, max: 1 }.to_string(), "Invalid label index 2 in Looping object, expected less than 1" ];
+	}
+}
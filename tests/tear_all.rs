@@ -0,0 +1,64 @@
+// Testing `tear_all!`
+#![cfg(feature = "alloc")]
+
+use tear::prelude::*;
+
+fn check (v :i32) -> Result<i32, String> {
+	if v >= 0 { Ok(v) } else { Err(format!("{} is negative", v)) }
+}
+
+fn three (a :i32, b :i32, c :i32) -> Result<(i32, i32, i32), Vec<String>> {
+	let t = tear_all! { check(a), check(b), check(c) };
+	Ok(t)
+}
+
+#[test] fn no_bads () {
+	assert_eq![ three(1, 2, 3), Ok((1, 2, 3)) ];
+}
+
+#[test] fn some_bads () {
+	assert_eq![ three(-1, 2, -3), Err(vec!["-1 is negative".to_string(), "-3 is negative".to_string()]) ];
+}
+
+#[test] fn all_bads () {
+	assert_eq![ three(-1, -2, -3), Err(vec![
+		"-1 is negative".to_string(), "-2 is negative".to_string(), "-3 is negative".to_string(),
+	]) ];
+}
+
+#[test] fn evaluates_everything () {
+	let mut calls = Vec::new();
+	let mut f = |v :i32| { calls.push(v); check(v) };
+	let r = (|| -> Result<(i32, i32), Vec<String>> {
+		let t = tear_all! { f(-1), f(2) };
+		Ok(t)
+	})();
+	assert_eq![ r, Err(vec!["-1 is negative".to_string()]) ];
+	assert_eq![ calls, vec![-1, 2] ];
+}
+
+#[test] fn different_good_types () {
+	fn f (a :Option<i32>, b :Option<&str>) -> Result<(i32, &str), Vec<tear::Maru>> {
+		let t = tear_all! { a, b, };
+		Ok(t)
+	}
+	assert_eq![ f(Some(1), Some("b")).ok(), Some((1, "b")) ];
+	assert_eq![ f(None, None).unwrap_err().len(), 2 ];
+}
+
+// Converting the Vec to the return type with `From`
+#[derive(Debug, PartialEq)]
+struct Report (usize);
+
+impl From<Vec<String>> for Report {
+	fn from (v :Vec<String>) -> Self { Report(v.len()) }
+}
+
+#[test] fn from_conversion () {
+	fn f (a :i32, b :i32) -> Result<(i32, i32), Report> {
+		let t = tear_all! { check(a), check(b) };
+		Ok(t)
+	}
+	assert_eq![ f(-1, -2), Err(Report(2)) ];
+	assert_eq![ f(1, 2), Ok((1, 2)) ];
+}
@@ -0,0 +1,187 @@
+// Testing `tear_if!` with patterns and guards
+use tear::tear_if;
+
+// All compile fail errors go here
+#[cfg(not(any(feature = "experimental", feature = "ignore-ui")))] // Feature flags to ignore test
+#[test] fn bad_input () {
+	use trybuild;
+	let t = trybuild::TestCases::new();
+	t.compile_fail("tests/tear_if/*.rs");
+}
+
+fn guarded (maybe :Option<i32>) -> i32 {
+	tear_if! { let Some(x) = maybe, x > 3, x * 10 }
+	0
+}
+
+#[test] fn pattern_and_guard () {
+	assert_eq![ guarded(Some(5)), 50 ];
+	assert_eq![ guarded(Some(2)), 0 ];
+	assert_eq![ guarded(None), 0 ];
+}
+
+fn many_guards (maybe :Option<(i32, i32)>, enabled :bool) -> &'static str {
+	tear_if! { let Some((a, b)) = maybe, enabled, a < b, b - a > 2,
+		assert![ enabled ];
+		"far apart"
+	}
+	"no"
+}
+
+#[test] fn multiple_guards () {
+	assert_eq![ many_guards(Some((1, 5)), true), "far apart" ];
+	assert_eq![ many_guards(Some((1, 5)), false), "no" ];
+	assert_eq![ many_guards(Some((5, 1)), true), "no" ];
+	assert_eq![ many_guards(Some((1, 2)), true), "no" ];
+	assert_eq![ many_guards(None, true), "no" ];
+}
+
+#[test] fn guard_with_tuple_body () {
+	fn f (v :Result<i32, i32>) -> (i32, i32) {
+		tear_if! { let Err(e) = v, e != 0, (e, e) }
+		(0, 0)
+	}
+	assert_eq![ f(Err(3)), (3, 3) ];
+	assert_eq![ f(Err(0)), (0, 0) ];
+	assert_eq![ f(Ok(1)), (0, 0) ];
+}
+
+// Commas inside the body don't separate guards
+#[test] fn body_with_commas () {
+	use std::collections::HashMap;
+	fn f (x :Option<i32>) -> HashMap<i32, i32> {
+		tear_if! { let None = x, HashMap::<i32, i32>::new() }
+		tear_if! { let Some(v) = x, v < 0, HashMap::<i32, i32>::new() }
+		let mut m = HashMap::new();
+		m.insert(x.unwrap(), 1);
+		m
+	}
+	assert![ f(None).is_empty() ];
+	assert![ f(Some(-1)).is_empty() ];
+	assert_eq![ f(Some(2)).len(), 1 ];
+}
+
+#[test] fn long_guarded_body () {
+	fn f (x :Option<i32>) -> i32 {
+		tear_if! { let None = x, 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 }
+		tear_if! { let Some(v) = x, v > 0, v < 10, 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 }
+		tear_if! { let Some(v) = x, v == 0,
+			let y = 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1;
+			y + 1
+		}
+		x.unwrap()
+	}
+	assert_eq![ f(None), 80 ];
+	assert_eq![ f(Some(5)), 80 ];
+	assert_eq![ f(Some(0)), 81 ];
+	assert_eq![ f(Some(-5)), -5 ];
+}
+
+#[test] fn pattern_without_guard () {
+	fn f (v :Option<i32>) -> i32 {
+		tear_if! { let None = v, -1 }
+		v.unwrap()
+	}
+	assert_eq![ f(None), -1 ];
+	assert_eq![ f(Some(2)), 2 ];
+}
+
+#[test] fn pattern_without_body () {
+	fn f (v :Option<i32>, reached :&mut bool) {
+		tear_if! { let None = v }
+		*reached = true;
+	}
+	let mut reached = false;
+	f(None, &mut reached);
+	assert![ !reached ];
+	f(Some(1), &mut reached);
+	assert![ reached ];
+}
+
+fn two_branches (x :i32) -> &'static str {
+	tear_if! { x < 0, "negative" ; else if x == 0, "zero" }
+	"positive"
+}
+
+#[test] fn chain_of_two () {
+	assert_eq![ two_branches(-2), "negative" ];
+	assert_eq![ two_branches(0), "zero" ];
+	assert_eq![ two_branches(2), "positive" ];
+}
+
+fn three_branches (x :i32, calls :&mut Vec<i32>) -> i32 {
+	tear_if! { { calls.push(1); x == 1 }, 10 ;
+		else if { calls.push(2); x == 2 },
+			calls.push(20);
+			20 ;
+		else if { calls.push(3); x == 3 }, 30
+	}
+	0
+}
+
+#[test] fn chain_of_three () {
+	let mut calls = Vec::new();
+	assert_eq![ three_branches(1, &mut calls), 10 ];
+	assert_eq![ calls, [1] ];
+
+	let mut calls = Vec::new();
+	assert_eq![ three_branches(2, &mut calls), 20 ];
+	assert_eq![ calls, [1, 2, 20] ];
+
+	let mut calls = Vec::new();
+	assert_eq![ three_branches(3, &mut calls), 30 ];
+	assert_eq![ calls, [1, 2, 3] ];
+
+	let mut calls = Vec::new();
+	assert_eq![ three_branches(4, &mut calls), 0 ];
+	assert_eq![ calls, [1, 2, 3] ];
+}
+
+fn pattern_branches (v :Result<i32, &'static str>) -> i32 {
+	tear_if! { let Err(e) = v, e.len() as i32 ;
+		else if let Ok(x) = v, x > 100, 100 ;
+		else if let Ok(0) = v, -1
+	}
+	v.unwrap()
+}
+
+#[test] fn chain_with_patterns () {
+	assert_eq![ pattern_branches(Err("four")), 4 ];
+	assert_eq![ pattern_branches(Ok(200)), 100 ];
+	assert_eq![ pattern_branches(Ok(0)), -1 ];
+	assert_eq![ pattern_branches(Ok(7)), 7 ];
+}
+
+#[test] fn chain_without_bodies () {
+	fn f (x :Option<i32>, reached :&mut bool) {
+		tear_if! { let None = x ; else if x == Some(0) }
+		*reached = true;
+	}
+	let mut reached = false;
+	f(None, &mut reached);
+	f(Some(0), &mut reached);
+	assert![ !reached ];
+	f(Some(1), &mut reached);
+	assert![ reached ];
+}
+
+// Long bodies don't go through a token-by-token muncher, which would hit the recursion limit
+#[test] fn long_body () {
+	fn f (x :i32) -> i32 {
+		tear_if! { x == 0, 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 }
+		x
+	}
+	fn g (x :i32) -> i32 {
+		tear_if! { x < 0, -1 ;
+			else if x == 0,
+				let y = 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1;
+				y
+		}
+		x
+	}
+	assert_eq![ f(0), 80 ];
+	assert_eq![ f(2), 2 ];
+	assert_eq![ g(-5), -1 ];
+	assert_eq![ g(0), 80 ];
+	assert_eq![ g(3), 3 ];
+}
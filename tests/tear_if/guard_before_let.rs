@@ -0,0 +1,8 @@
+use tear::tear_if;
+
+fn guard_before_let(maybe: Option<i32>) -> i32 {
+	tear_if! { maybe.is_some(), let Some(x) = maybe, x }
+	0
+}
+
+fn main () {}
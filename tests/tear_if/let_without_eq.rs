@@ -0,0 +1,10 @@
+use tear::tear_if;
+
+fn f (x :Option<i32>) -> i32 {
+	tear_if! { let None x, -1 }
+	2
+}
+
+fn main () {
+	f(Some(1));
+}
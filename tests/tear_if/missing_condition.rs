@@ -0,0 +1,10 @@
+use tear::tear_if;
+
+fn f (x :i32) -> Option<i32> {
+	tear_if! { , None }
+	Some(x)
+}
+
+fn main () {
+	f(1);
+}
@@ -0,0 +1,13 @@
+use tear::tear_if;
+
+fn f (x :i32) -> i32 {
+	tear_if! { x < 0, -1 ;
+		else if x == 0, 0 ;
+		else { 1 }
+	}
+	2
+}
+
+fn main () {
+	f(1);
+}
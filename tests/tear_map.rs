@@ -0,0 +1,109 @@
+// Testing `ValRetIteratorExt`: `tear_map` with `TearMap::finish`, and `val_ret_fold`
+use tear::prelude::*;
+use tear::ValRetIteratorExt;
+
+fn parse (s :&str) -> ValRet<i32, String> {
+	match s.parse() {
+		Ok(v) => Val(v),
+		Err(_) => Ret(format!("not a number: {}", s)),
+	}
+}
+
+fn parse_all (values :&[&str]) -> Result<Vec<i32>, String> {
+	let v :Vec<i32> = tear! { values.iter().tear_map(|s| parse(s)).finish() => Err };
+	Ok(v)
+}
+
+#[test] fn success () {
+	assert_eq![ parse_all(&["1", "2", "3"]), Ok(vec![1, 2, 3]) ];
+}
+
+#[test] fn empty () {
+	assert_eq![ parse_all(&[]), Ok(vec![]) ];
+
+	let mut it = Vec::<i32>::new().into_iter().tear_map(Val::<_, ()>);
+	assert_eq![ it.next(), None ];
+	assert![ it.is_stopped() ];
+	assert_eq![ it.ret(), None ];
+}
+
+// Stops at the first Ret, without calling the function on the next items
+#[test] fn mid_stream () {
+	assert_eq![ parse_all(&["1", "x", "y"]), Err("not a number: x".to_string()) ];
+
+	let mut calls = 0;
+	let mut it = vec!["1", "x", "2"].into_iter().tear_map(|s| { calls += 1; parse(s) });
+	assert_eq![ it.size_hint(), (0, Some(3)) ];
+	assert_eq![ it.by_ref().collect::<Vec<_>>(), vec![1] ];
+	assert![ it.is_stopped() ];
+	assert_eq![ it.size_hint(), (0, Some(0)) ];
+	assert_eq![ it.next(), None ];
+	assert_eq![ it.ret().map(String::as_str), Some("not a number: x") ];
+	assert_eq![ it.into_ret(), Some("not a number: x".to_string()) ];
+	assert_eq![ calls, 2 ];
+}
+
+// `finish` collects what's left
+#[test] fn finish_rest () {
+	let mut it = (1..5).tear_map(Val::<_, ()>);
+	assert_eq![ it.next(), Some(1) ];
+	assert_eq![ it.finish::<Vec<_>>(), Val(vec![2, 3, 4]) ];
+
+	let r :ValRet<Vec<i32>, i32> = (1..5).tear_map(|v| if v == 3 { Ret(v) } else { Val(v) }).finish();
+	assert_eq![ r, Ret(3) ];
+}
+
+/* val_ret_fold */
+
+// Counts the items it yields
+struct Counting<'a> {
+	next :i32,
+	end :i32,
+	count :&'a mut i32,
+}
+
+impl Iterator for Counting<'_> {
+	type Item = i32;
+
+	fn next (&mut self) -> Option<i32> {
+		if self.next == self.end {
+			return None;
+		}
+		*self.count += 1;
+		self.next += 1;
+		Some(self.next - 1)
+	}
+}
+
+#[test] fn val_ret_fold () {
+	let sum :ValRet<i32, ()> = (1..5).val_ret_fold(0, |sum, v| Val(sum + v));
+	assert_eq![ sum, Val(10) ];
+
+	let empty :ValRet<i32, ()> = (0..0).val_ret_fold(7, |sum, v| Val(sum + v));
+	assert_eq![ empty, Val(7) ];
+}
+
+// Stops at the first Ret, in either half of the chain
+#[test] fn val_ret_fold_chain () {
+	let (mut first, mut second) = (0, 0);
+	let a = Counting { next: 0, end: 5, count: &mut first };
+	let b = Counting { next: 10, end: 15, count: &mut second };
+	let r = a.chain(b).val_ret_fold(0, |sum, v| if v == 12 { Ret(sum) } else { Val(sum + v) });
+	assert_eq![ r, Ret(1 + 2 + 3 + 4 + 10 + 11) ];
+	assert_eq![ (first, second), (5, 3) ];
+
+	let (mut first, mut second) = (0, 0);
+	let a = Counting { next: 0, end: 5, count: &mut first };
+	let b = Counting { next: 10, end: 15, count: &mut second };
+	let r = a.chain(b).val_ret_fold(0, |sum, v| if v == 2 { Ret(sum) } else { Val(sum + v) });
+	assert_eq![ r, Ret(1) ];
+	assert_eq![ (first, second), (3, 0) ];
+}
+
+// The rest of the iterator is left untouched
+#[test] fn val_ret_fold_by_ref () {
+	let mut values = 1..10;
+	let r = values.by_ref().val_ret_fold(0, |sum, v| if v == 4 { Ret(v) } else { Val(sum + v) });
+	assert_eq![ r, Ret(4) ];
+	assert_eq![ values.next(), Some(5) ];
+}
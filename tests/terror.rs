@@ -1,20 +1,19 @@
 // Testing `terror!`, pretty short because we use a lot of doctests
-#![cfg_attr(feature = "experimental", feature(try_trait))]
 
 use tear::prelude::*;
 
-// Difference between the implementations of Judge for Option between standard and "experimental"
-
-#[cfg(not(feature = "experimental"))]
-fn f () -> Option<i32> {
-	terror! { Err(1) => |_| () };
-	Some(1)
+// All compile fail errors go here
+#[cfg(not(any(feature = "experimental", feature = "ignore-ui")))] // Feature flags to ignore test
+#[test] fn bad_input () {
+	use trybuild;
+	let t = trybuild::TestCases::new();
+	t.compile_fail("tests/terror/*.rs");
 }
 
-#[cfg(feature = "experimental")]
+// Returning None from a function returning Option
+
 fn f () -> Option<i32> {
-	use std::option::NoneError;
-	terror! { Err(1) => |_| NoneError };
+	terror! { Err(1) => |_| () };
 	Some(1)
 }
 
@@ -43,3 +42,65 @@ impl From<i32> for MyInt {
 	
 	assert_eq![ f(), Err(MyInt { v: 0 }) ];
 }
+
+// Test the `=>>` shorthand
+
+#[test] fn terror_ignore_bad () {
+	fn f (v :Option<i32>) -> Result<i32, String> {
+		let v = terror! { v =>> "missing".to_string() };
+		Ok(v)
+	}
+
+	assert_eq![ f(Some(1)), Ok(1) ];
+	assert_eq![ f(None), Err("missing".to_string()) ];
+}
+
+#[test] fn terror_ignore_bad_from () {
+	fn f () -> Result<(), MyInt> {
+		terror! { Err::<(), _>("bad") =>> 3 };
+		Ok(())
+	}
+
+	assert_eq![ f(), Err(MyInt { v: 3 }) ];
+}
+
+#[test] fn terror_ignore_bad_moves () {
+	fn f (v :Option<i32>, name :String) -> Result<String, String> {
+		let v = terror! { v =>> name };
+		Ok(format!("{}: {}", name, v))
+	}
+
+	assert_eq![ f(Some(2), "a".to_string()), Ok("a: 2".to_string()) ];
+	assert_eq![ f(None, "a".to_string()), Err("a".to_string()) ];
+}
+
+// Test the type-annotated mapping form
+
+#[test] fn terror_annotated_into () {
+	fn f (v :Result<(), u8>) -> Result<(), i64> {
+		terror! { v => Into::into : i32 };
+		Ok(())
+	}
+
+	assert_eq![ f(Ok(())), Ok(()) ];
+	assert_eq![ f(Err(7)), Err(7) ];
+}
+
+#[test] fn terror_annotated_closure () {
+	fn f (v :Result<i32, &str>) -> Result<i32, MyInt> {
+		let v = terror! { v => |e :&str| e.len() as i32 : i32 };
+		Ok(v)
+	}
+
+	assert_eq![ f(Ok(1)), Ok(1) ];
+	assert_eq![ f(Err("four")), Err(MyInt { v: 4 }) ];
+}
+
+#[test] fn terror_annotated_generic_type () {
+	fn f (v :Option<u8>) -> Result<u8, Option<u16>> {
+		let v = terror! { v => |_| Some(Into::into(2u8)) : Option<u16> };
+		Ok(v)
+	}
+
+	assert_eq![ f(None), Err(Some(2)) ];
+}
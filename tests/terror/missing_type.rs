@@ -0,0 +1,8 @@
+use tear::terror;
+
+fn missing_type(v: Result<(), u8>) -> Result<(), i64> {
+	terror! { v => Into::into : };
+	Ok(())
+}
+
+fn main () {}
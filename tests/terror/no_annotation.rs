@@ -0,0 +1,9 @@
+use tear::terror;
+
+// The target type of `Into::into` can't be inferred without the annotation
+fn widen(v: Result<(), u8>) -> Result<(), i64> {
+	terror! { v => Into::into };
+	Ok(())
+}
+
+fn main () {}
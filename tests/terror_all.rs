@@ -0,0 +1,80 @@
+// Testing `terror_all!`
+use tear::prelude::*;
+use std::cell::RefCell;
+
+struct Log (RefCell<Vec<&'static str>>);
+
+impl Log {
+	fn new () -> Self { Log(RefCell::new(Vec::new())) }
+
+	fn ok<T> (&self, name :&'static str, v :T) -> Result<T, String> {
+		self.0.borrow_mut().push(name);
+		Ok(v)
+	}
+
+	fn err<T> (&self, name :&'static str) -> Result<T, String> {
+		self.0.borrow_mut().push(name);
+		Err(name.to_string())
+	}
+
+	fn calls (&self) -> Vec<&'static str> { self.0.borrow().clone() }
+}
+
+#[test] fn two () {
+	let log = Log::new();
+	let f = || -> Result<(i32, String), String> {
+		let t :(i32, String) = terror_all! { log.ok("a", 1), log.ok("b", "b".to_string()) };
+		Ok(t)
+	};
+	assert_eq![ f(), Ok((1, "b".to_string())) ];
+	assert_eq![ log.calls(), vec!["a", "b"] ];
+}
+
+#[test] fn three () {
+	let log = Log::new();
+	let f = || -> Result<(i32, bool, char), String> {
+		let (a, b, c) = terror_all! { log.ok("a", 1), log.ok("b", true), log.ok("c", 'c'), };
+		Ok((a, b, c))
+	};
+	assert_eq![ f(), Ok((1, true, 'c')) ];
+	assert_eq![ log.calls(), vec!["a", "b", "c"] ];
+}
+
+#[test] fn four_short_circuits () {
+	let log = Log::new();
+	let f = || -> Result<(i32, i32, i32, i32), String> {
+		let t = terror_all! { log.ok("a", 1), log.ok("b", 2), log.err("c"), log.ok("d", 4) };
+		Ok(t)
+	};
+	assert_eq![ f(), Err("c".to_string()) ];
+	assert_eq![ log.calls(), vec!["a", "b", "c"] ];
+}
+
+#[test] fn first_bad_wins () {
+	let log = Log::new();
+	let f = || -> Result<(i32, i32), String> {
+		let t = terror_all! { log.err("a"), log.err("b") };
+		Ok(t)
+	};
+	assert_eq![ f(), Err("a".to_string()) ];
+	assert_eq![ log.calls(), vec!["a"] ];
+}
+
+#[test] fn mapped () {
+	let log = Log::new();
+	let f = || -> Result<(i32, i32, i32), usize> {
+		let t = terror_all! { log.ok("a", 1), log.err("long"), log.ok("c", 3) => |e :String| e.len() };
+		Ok(t)
+	};
+	assert_eq![ f(), Err(4) ];
+	assert_eq![ log.calls(), vec!["a", "long"] ];
+}
+
+#[test] fn mixed_judges () {
+	fn f (a :Option<i32>, b :Option<&str>) -> Option<(i32, &str)> {
+		let t = terror_all! { a, b };
+		Some(t)
+	}
+	assert_eq![ f(Some(1), Some("b")), Some((1, "b")) ];
+	assert_eq![ f(Some(1), None), None ];
+}
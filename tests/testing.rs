@@ -0,0 +1,87 @@
+// Testing the assertion macros, and their failure messages
+use tear::{assert_good, assert_bad, assert_looping};
+use tear::{last, next, resume, Looping, BreakValError};
+use tear::ValRet::*;
+
+// Annotates the resume type of `last!` and `next!`
+fn l (v :Looping<i32, BreakValError>) -> Looping<i32, BreakValError> { v }
+
+#[test] fn good () {
+	assert_eq![ assert_good! { Some(1) }, 1 ];
+	assert_eq![ assert_good! { Ok::<_, ()>("a") }, "a" ];
+	assert_good! { Some(1), 1 };
+	assert_good! { Val::<_, ()>(2), 2 };
+}
+
+#[test] fn bad () {
+	assert_bad! { None::<i32> };
+	assert_eq![ assert_bad! { Err::<(), _>(3) }, 3 ];
+	assert_bad! { Err::<(), _>(3), 3 };
+	assert_bad! { Ret::<(), _>("r"), "r" };
+}
+
+#[test] fn looping () {
+	assert_looping! { resume!(1), Resume(1) };
+	assert_looping! { l(last!()), Break };
+	assert_looping! { l(last!(2)), Break };
+	assert_looping! { l(last!()), Break(None) };
+	assert_looping! { l(last!(2)), Break(Some(2)) };
+	assert_looping! { l(next!()), Continue };
+	assert_looping! { l(next!()), Continue(None) };
+	assert_looping! { l(next!(0)), Continue(Some(0)) };
+	assert_looping! { Looping::BreakVal::<(), _> { label: Some(1), value: 'v' }, BreakVal(Some(1), 'v') };
+}
+
+#[test] fn evaluated_once () {
+	let mut count = 0;
+	let mut f = || { count += 1; Some(count) };
+	assert_good! { f(), 1 };
+	assert_good! { f() };
+	assert_eq![ count, 2 ];
+}
+
+#[test]
+#[should_panic(expected = "assertion failed: `None::<i32>` is Good, got: Bad(Maru)")]
+fn good_fails () { assert_good! { None::<i32> }; }
+
+#[test]
+#[should_panic(expected = "assertion failed: `Some(1)` is Good(2), got: Good(1)")]
+fn good_value_fails () { assert_good! { Some(1), 2 }; }
+
+#[test]
+#[should_panic(expected = "assertion failed: `Err::<i32, _>(\"e\")` is Good(2), got: Bad(\"e\")")]
+fn good_value_is_bad () { assert_good! { Err::<i32, _>("e"), 2 }; }
+
+#[test]
+#[should_panic(expected = "assertion failed: `Val::<i32, i32>(1)` is Bad, got: Good(1)")]
+fn bad_fails () { assert_bad! { Val::<i32, i32>(1) }; }
+
+#[test]
+#[should_panic(expected = "assertion failed: `Ret::<i32, i32>(1)` is Bad(2), got: Bad(1)")]
+fn bad_value_fails () { assert_bad! { Ret::<i32, i32>(1), 2 }; }
+
+#[test]
+#[should_panic(expected = "assertion failed: `resume!(1)` is Resume(2), got: Resume(1)")]
+fn resume_fails () { assert_looping! { resume!(1), Resume(2) }; }
+
+#[test]
+#[should_panic(expected = "assertion failed: `l(next!())` is Break, got: Continue { label: None }")]
+fn break_fails () { assert_looping! { l(next!()), Break }; }
+
+#[test]
+#[should_panic(expected = "assertion failed: `l(last!(1))` is Break(Some(0)), got: Break { label: Some(1) }")]
+fn break_label_fails () { assert_looping! { l(last!(1)), Break(Some(0)) }; }
+
+#[test]
+#[should_panic(expected = "assertion failed: `l(last!())` is Continue, got: Break { label: None }")]
+fn continue_fails () { assert_looping! { l(last!()), Continue }; }
+
+#[test]
+#[should_panic(expected = "assertion failed: `l(next!())` is Continue(Some(1)), got: Continue { label: None }")]
+fn continue_label_fails () { assert_looping! { l(next!()), Continue(Some(1)) }; }
+
+#[test]
+#[should_panic(expected = "is BreakVal(None, 2), got: BreakVal { label: None, value: 1 }")]
+fn breakval_fails () {
+	assert_looping! { Looping::BreakVal::<(), i32> { label: None, value: 1 }, BreakVal(None, 2) };
+}
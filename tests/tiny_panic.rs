@@ -0,0 +1,87 @@
+// We test the short panic codes of the "tiny-panic" feature. tests/panic_messages.rs has the
+// messages without it
+#![cfg(feature = "tiny-panic")]
+
+use std::panic;
+use tear::{twist, tredo_loop, anybox};
+use tear::Looping;
+
+// The panic message of `f`, that must be a code without formatting
+fn message (f :impl FnOnce() + panic::UnwindSafe) -> String {
+	let payload = panic::catch_unwind(f).unwrap_err();
+	match payload.downcast::<&str>() {
+		Ok(s) => s.to_string(),
+		Err(payload) => *payload.downcast::<String>().unwrap(),
+	}
+}
+
+#[test] fn kinds () {
+	use tear::TearErrorKind::*;
+	assert_eq![ BreakValInNotLoop.message(), "tear:E1" ];
+	assert_eq![ BreakWithoutVal.message(), "tear:E2" ];
+	assert_eq![ BadBreakValType.message(), "tear:E3" ];
+	assert_eq![ ContinueInBlock.message(), "tear:E4" ];
+	assert_eq![ UnlabeledInBlock.message(), "tear:E5" ];
+	assert_eq![ RedoWithoutTredo.message(), "tear:E6" ];
+	assert_eq![ InvalidLabel.message(), "tear:E7" ];
+}
+
+#[test]
+#[allow(deprecated)]
+fn constants () {
+	assert_eq![ tear::BREAKVAL_IN_NOT_LOOP, "tear:E1" ];
+}
+
+#[test] fn codes () {
+	assert_eq![ message(|| { let l :Looping<(), i32> = Looping::BreakVal { label: None, value: 1 }; let _ = 'a: loop {
+		for _ in 0..1 { twist! { -label 'a :i32 | l.clone() } }
+	}; }), "tear:E1" ];
+
+	assert_eq![ message(|| { let _ = loop {
+		twist! { -val Looping::Break::<(), i32> { label: None } }
+	}; }), "tear:E2" ];
+
+	assert_eq![ message(|| { let _ = 'a: loop {
+		loop { twist! { -box -label 'a :i32 | Looping::BreakVal::<(), _> { label: Some(0), value: anybox!("5") } } }
+	}; }), "tear:E3" ];
+
+	assert_eq![ message(|| { let _ :i32 = loop {
+		twist! { -box -val i32, Looping::BreakVal::<(), _> { label: None, value: anybox!("5") } }
+	}; }), "tear:E3" ];
+
+	assert_eq![ message(|| 'a: loop {
+		twist! { -label 'a | Looping::Redo::<(), ()> { label: None } }
+	}), "tear:E6" ];
+
+	assert_eq![ message(|| {
+		use tear::LoopingIteratorExt;
+		(0..3).tear_for_each(|_| -> Looping<(), ()> { Looping::Redo { label: None } });
+	}), "tear:E6" ];
+
+	assert_eq![ message(|| 'a: loop {
+		twist! { -label 'a | Looping::Break::<(), ()> { label: Some(3) } }
+	}), "tear:E7" ];
+
+	assert_eq![ message(|| loop {
+		twist! { Looping::Break::<(), tear::BreakValError> { label: Some(1) } }
+	}), "tear:E8" ];
+
+	assert_eq![ message(|| 'a: loop {
+		loop { twist! { -with 'a | Looping::Continue::<(), tear::BreakValError> { label: Some(1) } } }
+	}), "tear:E8" ];
+
+	assert_eq![ message(|| tredo_loop! { for _ in 0..1 => {
+		Looping::<(), tear::BreakValError>::Continue { label: Some(1) }
+	} }), "tear:E9" ];
+}
+
+#[cfg(feature = "block-labels")]
+#[test] fn block_codes () {
+	assert_eq![ message(|| 'b: {
+		twist! { -block -with 'b | Looping::Continue::<(), tear::BreakValError> { label: None } }
+	}), "tear:E4" ];
+
+	assert_eq![ message(|| 'b: {
+		twist! { -block -label 'b | Looping::Break::<(), tear::BreakValError> { label: None } }
+	}), "tear:E5" ];
+}
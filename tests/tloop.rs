@@ -0,0 +1,88 @@
+// Testing the "tloop" feature
+#![cfg(feature = "tloop")]
+
+use tear::tloop;
+use tear::{stop, go};
+use tear::Looping;
+
+// Same as `breakval_multiple` in label.rs
+#[test] fn breakval_multiple () {
+	let mut y = 0;
+	let x = tloop! { 'a :i32, 'b :i32;
+		'a: loop {
+			let z :i32 = 'b: loop {
+				loop {
+					y += 1;
+					twist_here! {
+						if y > 5 { Looping::BreakVal { label: Some(0), value: 8 } }
+						else { Looping::BreakVal { label: Some(1), value: 3 } }
+					}
+					y -= 1;
+				}
+			};
+			assert_eq![ z, 3 ];
+		}
+	};
+	assert_eq![ y, 6 ];
+	assert_eq![ x, 8 ];
+}
+
+// Same as `break_and_breakval` in label.rs, with two calls
+#[test] fn break_and_breakval () {
+	let mut y = 0;
+	let mut a = 0;
+	let x = tloop! { 'a :i32, 'c, 'b :i32, 'd;
+		'a: loop {
+			'c: loop {
+				let z = 'b: loop {
+					'd: loop {
+						let v = twist_here! { if y < 5 { go!(6) } else if a < 8 { stop!(3) } else { go!(6) } };
+						twist_here! {
+							if y == 5 { y += 1; Looping::BreakVal { label: Some(2), value: 3 } }
+							else if y > 5 { Looping::BreakVal { label: Some(0), value: 4 } }
+							else { go!(()) }
+						}
+						assert_eq![ v, 6 ];
+						y += 1;
+					}
+					a += 1;
+				};
+				assert_eq![ z, 3 ];
+			}
+		}
+	};
+	assert_eq![ y, 6 ];
+	assert_eq![ x, 4 ];
+	assert_eq![ a, 8 ];
+}
+
+#[test] fn flags_and_mapping () {
+	let x = tloop! { -val i32, 'a :i32;
+		'a: loop {
+			let v = loop {
+				let v = twist_here! { Some(1) => |_| go!(0) };
+				twist_here! { None::<i32> => |_| Looping::BreakVal { label: None, value: v + 1 } };
+			};
+			break 'a v * 10;
+		}
+	};
+	assert_eq![ x, 20 ];
+}
+
+#[test] fn nested_tloop () {
+	let x = tloop! { 'a :i32;
+		'a: loop {
+			let y = tloop! { 'b :i32;
+				'b: loop {
+					loop {
+						twist_here! { Looping::BreakVal::<(), i32> { label: Some(0), value: 3 } }
+					}
+				}
+			};
+			loop {
+				twist_here! { Looping::BreakVal::<(), i32> { label: Some(0), value: y } }
+			}
+		}
+	};
+	assert_eq![ x, 3 ];
+}
@@ -0,0 +1,85 @@
+// Testing Traced and the location it captures
+#![cfg(feature = "track-caller")]
+use tear::prelude::*;
+use tear::{traced, retraced, Traced};
+
+#[derive(Debug, PartialEq)]
+struct Low(i32);
+
+#[derive(Debug, PartialEq)]
+struct High(i32);
+
+impl From<Low> for High {
+	fn from (v :Low) -> Self { High(v.0 * 10) }
+}
+
+fn low (ok :bool) -> Result<i32, Low> {
+	if ok { Ok(1) } else { Err(Low(2)) }
+}
+
+#[test] fn location_of_terror () {
+	let line = line!() + 2;
+	fn f () -> Result<i32, Traced<Low>> {
+		let v = terror! { low(false) => traced };
+		Ok(v)
+	}
+
+	let e = f().unwrap_err();
+	assert_eq![ *e, Low(2) ];
+	assert_eq![ e.location().file(), file!() ];
+	assert_eq![ e.location().line(), line ];
+}
+
+#[test] fn location_through_layers () {
+	let line = line!() + 2;
+	fn inner () -> Result<i32, Traced<Low>> {
+		Ok(terror! { low(false) => traced })
+	}
+	fn middle () -> Result<i32, Traced<High>> {
+		Ok(terror! { inner() => retraced })
+	}
+	fn outer () -> Result<i32, Traced<High>> {
+		Ok(terror! { middle() })
+	}
+
+	let e = outer().unwrap_err();
+	assert_eq![ e.location().line(), line ];
+	assert_eq![ e.into_inner(), High(20) ];
+}
+
+#[test] fn no_error () {
+	fn f () -> Result<i32, Traced<Low>> {
+		Ok(terror! { low(true) => traced })
+	}
+	assert_eq![ f(), Ok(1) ];
+}
+
+#[test] fn display () {
+	fn f () -> Result<(), Traced<&'static str>> {
+		terror! { Err("oops") => traced };
+		Ok(())
+	}
+	let e = f().unwrap_err();
+	assert_eq![ e.to_string(), format!("oops (at {}:{}:{})", file!(), e.location().line(), e.location().column()) ];
+}
+
+#[cfg(feature = "std")]
+#[test] fn error_trait () {
+	use std::error::Error;
+	fn f () -> Result<i32, Box<dyn Error>> {
+		let v = terror! { "x".parse::<i32>() => traced };
+		Ok(v)
+	}
+	let e = f().unwrap_err();
+	assert![ e.to_string().starts_with("invalid digit found in string (at ") ];
+	assert![ e.source().is_none() ];
+}
+
+#[test] fn deref_mut_and_map () {
+	let mut t = Traced::new(Low(1));
+	t.0 = 5;
+	let line = t.location().line();
+	let h = t.map(High::from);
+	assert_eq![ *h, High(50) ];
+	assert_eq![ h.location().line(), line ];
+}
@@ -0,0 +1,194 @@
+// Testing the `-track` variable of `twist!`, set when it breaks a loop
+#![allow(unused_assignments)] // The loops that always break never read the initial `false`
+use tear::twist;
+use tear::{next, last, resume, breakval};
+use tear::{stop, skip, go};
+use tear::anybox;
+use tear::{Looping, TwistError};
+
+// Like Python's for/else
+fn find (values :&[i32], target :i32) -> Option<usize> {
+	let mut broke = false;
+	let mut index = 0;
+	for (i, &v) in values.iter().enumerate() {
+		index = i;
+		twist! { -track broke | if v == target { last!() } else { resume!(()) } }
+	}
+	if broke { Some(index) } else { None }
+}
+
+#[test] fn break_and_natural_end () {
+	assert_eq![ find(&[1, 2, 3], 2), Some(1) ];
+	assert_eq![ find(&[1, 2, 3], 4), None ];
+	assert_eq![ find(&[], 4), None ];
+}
+
+#[test] fn continue_and_resume_leave_it () {
+	let mut broke = false;
+	let mut sum = 0;
+	for v in 0..6 {
+		sum += twist! { -track broke | if v % 2 == 0 { next!() } else { resume!(v) } };
+	}
+	assert_eq![ sum, 9 ];
+	assert![ !broke ];
+}
+
+#[test] fn breakval () {
+	let mut broke = false;
+	let x = loop {
+		twist! { -track broke -val breakval!(3) }
+	};
+	assert_eq![ x, 3 ];
+	assert![ broke ];
+
+	let mut broke = false;
+	let mut i = 0;
+	let x = loop {
+		i += 1;
+		twist! { -track broke -val if i < 3 { skip!() } else { breakval!(i) } }
+	};
+	assert_eq![ x, 3 ];
+	assert![ broke ];
+}
+
+#[test] fn mapping () {
+	let mut broke = false;
+	let mut sum = 0;
+	for v in &[Ok(1), Err("stop"), Ok(2)] {
+		sum += twist! { -track broke | *v => |_| last!() };
+	}
+	assert_eq![ sum, 1 ];
+	assert![ broke ];
+}
+
+#[test] fn with_label () {
+	let mut broke = false;
+	'a: for _ in 0..3 {
+		for _ in 0..3 {
+			twist! { -track broke -with 'a | last!() }
+		}
+	}
+	assert![ broke ];
+}
+
+/* -label */
+
+#[test] fn label () {
+	// The signal of the first pass, then Resume
+	for (i, expected) in [(0, true), (1, true), (2, false), (3, true), (4, false)].iter() {
+		let mut broke = false;
+		let mut pass = 0;
+		'a: loop {
+			let _ :i32 = 'b: loop {
+				loop {
+					pass += 1;
+					let signal = match if pass == 1 { *i } else { 4 } {
+						0 => stop!(),
+						1 => stop!(0),
+						2 => skip!(1),
+						3 => breakval!(1, 20),
+						_ => go!(()),
+					};
+					twist! { -track broke -label 'a, 'b :i32 | signal }
+					break 'a;
+				}
+				break 'a;
+			};
+			break;
+		}
+		assert_eq![ broke, *expected, "Signal {}", i ];
+	}
+}
+
+#[test] fn box_label () {
+	let mut broke = false;
+	let x = 'a: loop {
+		loop {
+			twist! { -track broke -box -label 'a :i32 | Looping::BreakVal::<(), _> { label: Some(0), value: anybox!(5) } }
+		}
+	};
+	assert_eq![ x, 5 ];
+	assert![ broke ];
+}
+
+// The fallback continues, so nothing breaks
+#[test] fn box_label_or () {
+	let mut broke = false;
+	let mut count = 0;
+	'a: for _ in 0..2 {
+		let _ :i32 = 'b: loop {
+			loop {
+				count += 1;
+				twist! { -track broke -box -label 'a, 'b :i32 -or |_| next!(0) |
+					Looping::BreakVal::<(), _> { label: Some(1), value: anybox!("not an i32") }
+				}
+			}
+		};
+	}
+	assert_eq![ count, 2 ];
+	assert![ !broke ];
+}
+
+#[test] fn try_invalid_label () {
+	let mut broke = false;
+	'a: loop {
+		let r :Result<(), _> = twist! { -track broke -try -label 'a | last!(3) };
+		assert_eq![ r, Err(TwistError::InvalidLabel { index: 3, max: 1 }) ];
+		break;
+	}
+	assert![ !broke ];
+}
+
+#[test] fn named_and_enum () {
+	let mut broke = false;
+	let x = 'a: loop {
+		loop {
+			twist! { -track broke -named -label 'a as A :i32 | breakval!(Label::A, 4) }
+		}
+	};
+	assert_eq![ x, 4 ];
+	assert![ broke ];
+
+	let mut broke = false;
+	let x = 'a: loop {
+		loop {
+			twist! { -track broke -enum Sig -label 'a as A :i32 |
+				Looping::BreakVal::<(), _> { label: None, value: Sig::A(6) }
+			}
+		}
+	};
+	assert_eq![ x, 6 ];
+	assert![ broke ];
+}
+
+#[test] fn with_labels () {
+	let mut broke = false;
+	let mut i = 0;
+	'a: loop {
+		i += 1;
+		loop {
+			twist! { -track broke -with ['a] | if i < 3 { next!(0) } else { last!(0) } }
+		}
+	}
+	assert_eq![ i, 3 ];
+	assert![ broke ];
+}
+
+// The block runs before the variable is set, in either order
+#[test] fn with_finally () {
+	let mut broke = false;
+	let mut seen = None;
+	loop {
+		twist! { -track broke -finally { seen = Some(broke); } last!() }
+	}
+	assert_eq![ seen, Some(false) ];
+	assert![ broke ];
+
+	let mut broke = false;
+	let mut closed = 0;
+	loop {
+		twist! { -finally { closed += 1; } -track broke last!() }
+	}
+	assert_eq![ closed, 1 ];
+	assert![ broke ];
+}
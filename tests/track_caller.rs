@@ -0,0 +1,59 @@
+// Testing the "track-caller" feature, where the panics of `twist!` and of the `Looping` accessors
+// have the location of the caller
+#![cfg(feature = "track-caller")]
+#![allow(clippy::never_loop)] // Loops that only run once are the point of these tests
+
+use std::panic;
+use std::sync::{Arc, Mutex};
+use tear::{twist, tredo_loop, anybox, Looping};
+
+// Runs `f`, and returns the file and line where it panicked
+fn panic_location (f :impl FnOnce() + panic::UnwindSafe) -> (String, u32) {
+	let site = Arc::new(Mutex::new(None));
+	let hook_site = site.clone();
+	panic::set_hook(Box::new(move |info| {
+		let location = info.location().map(|l| (l.file().to_string(), l.line()));
+		*hook_site.lock().unwrap() = location;
+	}));
+	let r = panic::catch_unwind(f);
+	let _ = panic::take_hook();
+	assert![ r.is_err() ];
+	let site = site.lock().unwrap().take();
+	site.unwrap()
+}
+
+// Asserts that `f` panicked at `line` of this file
+fn assert_here (f :impl FnOnce() + panic::UnwindSafe, line :u32) {
+	assert_eq![ panic_location(f), (file!().to_string(), line) ];
+}
+
+// A single test, as the panic hook is global
+#[test] fn points_at_caller () {
+	assert_here(|| 'a: loop {
+		twist! { -label 'a | Looping::Break::<(), ()> { label: Some(3) } }
+	}, line!() - 1);
+
+	assert_here(|| 'a: loop {
+		twist! { -label 'a | Looping::Redo::<(), ()> { label: None } }
+	}, line!() - 1);
+
+	assert_here(|| { let _ = 'a: loop {
+		loop {
+			twist! { -box -label 'a :i32 | Looping::BreakVal::<(), _> { label: Some(0), value: anybox!("5") } }
+		}
+	}; }, line!() - 2);
+
+	assert_here(|| loop {
+		twist! { Looping::Continue::<(), tear::BreakValError> { label: Some(1) } }
+	}, line!() - 1);
+
+	assert_here(|| tredo_loop! { for _ in 0..1 => tear::redo!(1) }, line!());
+
+	assert_here(|| {
+		Looping::<i32, &str>::BreakVal { label: None, value: "stop" }.unwrap_resume();
+	}, line!() - 1);
+
+	assert_here(|| {
+		Looping::<i32, &str>::Continue { label: Some(1) }.unwrap_break_val();
+	}, line!() - 1);
+}
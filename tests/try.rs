@@ -1,29 +1,85 @@
 // Testing the "experimental" features
 #![cfg(feature = "experimental")]
 
-#![feature(try_trait)]
+#![feature(try_trait_v2, try_trait_v2_residual)]
 
 use tear::prelude::*;
-use tear::Maru;
-use std::ops::Try;
+use tear::Moral::{self, Good, Bad};
+use std::ops::{Try, FromResidual, Residual, ControlFlow};
 
-fn try_val () -> Option<i32> {
-	let v = Val::<_, Maru>(3)?;
-	Some(v)
+/* `?` on ValRet */
+
+fn try_val () -> ValRet<i32, String> {
+	let v = Val::<_, &str>(3)?;
+	Val(v + 1)
+}
+
+fn try_ret () -> ValRet<i32, String> {
+	let v :i32 = Ret("stop")?;
+	Val(v + 1)
+}
+
+#[test] fn valret () {
+	assert_eq![ try_val(), Val(4) ];
+	assert_eq![ try_ret(), Ret("stop".to_string()) ];
+}
+
+/* `?` on Moral */
+
+fn try_good () -> Moral<i32, String> {
+	let v = Good::<_, &str>(3)?;
+	Good(v + 1)
+}
+
+fn try_bad () -> Moral<i32, String> {
+	let v :i32 = Bad("stop")?;
+	Good(v + 1)
 }
 
-fn try_ret () -> Option<i32> {
-	let v = Ret(5).val()?;
-	Some(v)
+#[test] fn moral () {
+	assert_eq![ try_good(), Good(4) ];
+	assert_eq![ try_bad(), Bad("stop".to_string()) ];
 }
 
-#[test]
-fn test_all () {
-	assert_eq![ try_val(), Some(3) ];
-	assert_eq![ try_ret(), None ];
+/* `?` and `terror!` on Option, whose Bad value converts from `()` */
+
+fn first_even (v :&[i32]) -> Option<i32> {
+	let first = *v.first()?;
+	terror! { if first % 2 == 0 { Some(first) } else { None } => |_| () };
+	Some(first)
 }
 
-/* Test if implementing Try does automatically implement Judge */
+#[test] fn option () {
+	assert_eq![ first_even(&[2, 3]), Some(2) ];
+	assert_eq![ first_even(&[1, 2]), None ];
+	assert_eq![ first_even(&[]), None ];
+}
+
+/* `?` on Result in functions returning ValRet or Moral */
+
+#[derive(Debug, PartialEq)]
+struct Error(String);
+
+impl From<std::num::ParseIntError> for Error {
+	fn from (e :std::num::ParseIntError) -> Self { Error(e.to_string()) }
+}
+
+fn parse_valret (s :&str) -> ValRet<i32, Error> {
+	Val(s.parse::<i32>()? * 2)
+}
+
+fn parse_moral (s :&str) -> Moral<i32, Error> {
+	Good(s.parse::<i32>()? * 2)
+}
+
+#[test] fn from_result () {
+	assert_eq![ parse_valret("2"), Val(4) ];
+	assert_eq![ parse_valret("x"), Ret(Error("invalid digit found in string".to_string())) ];
+	assert_eq![ parse_moral("2"), Good(4) ];
+	assert_eq![ parse_moral("x"), Bad(Error("invalid digit found in string".to_string())) ];
+}
+
+/* Test if implementing Try does implement Judge with `impl_judge_from_try!` */
 
 #[derive(Debug, PartialEq)]
 struct PendingMessage {
@@ -31,25 +87,37 @@ struct PendingMessage {
 	data: Option<String>,
 }
 
+// The residual of PendingMessage
+struct Failed;
+
+impl Residual<String> for Failed {
+	type TryType = PendingMessage;
+}
+
 impl Try for PendingMessage {
-	type Ok = String;
-	type Error = ();
+	type Output = String;
+	type Residual = Failed;
 
-	fn into_result (self) -> Result<String, ()> {
+	fn from_output (v :String) -> Self {
+		PendingMessage { status: true, data: Some(v) }
+	}
+
+	fn branch (self) -> ControlFlow<Failed, String> {
 		match self {
-			PendingMessage { status: false, .. } => Err(()),
-			PendingMessage { data: None, .. } => Err(()),
-			PendingMessage { status: true, data: Some(v)} => Ok(v),
+			PendingMessage { status: true, data: Some(v) } => ControlFlow::Continue(v),
+			_ => ControlFlow::Break(Failed),
 		}
 	}
+}
 
-	fn from_error(_: ()) -> Self {
+impl FromResidual<Failed> for PendingMessage {
+	fn from_residual (_ :Failed) -> Self {
 		PendingMessage { status: false, data: None }
 	}
+}
 
-	fn from_ok(v: String) -> Self {
-		PendingMessage { status: true, data: Some(v) }
-	}
+impl From<()> for Failed {
+	fn from (_ :()) -> Self { Failed }
 }
 
 impl_judge_from_try!(PendingMessage);
@@ -61,5 +129,51 @@ impl_judge_from_try!(PendingMessage);
 		PendingMessage { status: true, data: Some("sip".to_string())}
 	}
 
+	fn g() -> Option<String> {
+		let v = terror! { PendingMessage { status: true, data: Some("sip".to_string()) } => |_| () };
+		Some(v)
+	}
+
 	assert_eq![ f(), PendingMessage { status: false, data: None } ];
+	assert_eq![ g(), Some("sip".to_string()) ];
+}
+
+/* `?` in functions returning Looping */
+
+// Skips what isn't a number, breaks the outer loop at "stop", and skips the rest of the row at a negative value
+fn parse (s :&str) -> Looping<i32, String> {
+	if s == "stop" {
+		Err(Looping::BreakVal { label: Some(0), value: "stopped".to_string() })?;
+	}
+	let v = s.parse::<i32>().ok()?;
+	let v = if v < 0 { Err(Looping::Continue { label: Some(1) }) } else { Ok(v) }?;
+	Looping::Resume(v)
+}
+
+#[test] fn looping () {
+	let sum = |rows :&[&[&str]]| {
+		let mut sum = 0;
+		let r = 'a: loop {
+			'b: for row in rows {
+				for s in row.iter() {
+					sum += twist! { -label 'a :String, 'b | parse(s) };
+				}
+			}
+			break "done".to_string();
+		};
+		(sum, r)
+	};
+	assert_eq![ sum(&[&["1", "x", "2"], &["3"]]), (6, "done".to_string()) ];
+	assert_eq![ sum(&[&["1", "-1", "2"], &["3"]]), (4, "done".to_string()) ];
+	assert_eq![ sum(&[&["1"], &["stop", "2"]]), (1, "stopped".to_string()) ];
+}
+
+#[test] fn looping_residual () {
+	let l :Looping<i32, ()> = Looping::Continue { label: Some(2) };
+	let f = || -> Looping<u8, ()> { let _ = l?; Looping::Resume(0) };
+	assert_eq![ f(), Looping::Continue { label: Some(2) } ];
+
+	let g = |v :Option<i32>| -> Looping<i32, ()> { Looping::Resume(v? * 2) };
+	assert_eq![ g(Some(2)), Looping::Resume(4) ];
+	assert_eq![ g(None), Looping::Continue { label: None } ];
 }
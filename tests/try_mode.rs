@@ -0,0 +1,162 @@
+// Testing `twist! -try` that evaluates to a Result instead of panicking
+use tear::twist;
+use tear::{anybox, last, next, resume, skip};
+use tear::{Looping, TwistError};
+
+#[test] fn resume_is_ok () {
+	'a: loop {
+		loop {
+			let r = twist! { -try -label 'a | resume!(3) };
+			assert_eq![ r, Ok(3) ];
+			break 'a;
+		}
+	}
+}
+
+#[test] fn invalid_break_label () {
+	'a: loop {
+		loop {
+			let r = twist! { -try -label 'a | last!(1) };
+			let _ :Result<(), _> = r;
+			assert_eq![ r, Err(TwistError::InvalidLabel { index: 1, max: 1 }) ];
+			break 'a;
+		}
+	}
+}
+
+#[test] fn invalid_continue_label () {
+	'a: loop {
+		'b: loop {
+			let r = twist! { -try -label 'a, 'b | next!(5) };
+			let _ :Result<(), _> = r;
+			assert_eq![ r, Err(TwistError::InvalidLabel { index: 5, max: 2 }) ];
+			break 'a;
+		}
+	}
+}
+
+#[test] fn invalid_breakval_label () {
+	let x = 'a: loop {
+		loop {
+			let r = twist! { -try -label 'a :i32 | Looping::BreakVal::<(), _> { label: Some(2), value: 1 } };
+			assert_eq![ r, Err(TwistError::InvalidLabel { index: 2, max: 1 }) ];
+			break 'a 0;
+		}
+	};
+	assert_eq![ x, 0 ];
+}
+
+#[test] fn valid_labels_unchanged () {
+	let mut i = 0;
+	let x = 'a: loop {
+		'b: loop {
+			i += 1;
+			let r :Result<(), TwistError> = twist! { -try -label 'a :i32, 'b |
+				match i {
+					1 => skip!(),
+					2 => Looping::Continue { label: Some(1) },
+					3 => Looping::Break { label: Some(1) },
+					_ => Looping::BreakVal { label: Some(0), value: i },
+				}
+			};
+			r.unwrap();
+			panic!("Should break or continue before this");
+		}
+		i += 10;
+	};
+	assert_eq![ x, 14 ];
+}
+
+#[test] fn innermost_breakval () {
+	'a: loop {
+		let x = loop {
+			let r :Result<(), TwistError> = twist! { -try -val i32, -label 'a |
+				Looping::BreakVal { label: None, value: 4 }
+			};
+			r.unwrap();
+		};
+		assert_eq![ x, 4 ];
+		break;
+	}
+}
+
+#[test] fn box_bad_type () {
+	let x = 'a: loop {
+		loop {
+			let r = twist! { -try -box -label 'a :i32 |
+				Looping::BreakVal::<(), _> { label: Some(0), value: anybox!("not an i32") }
+			};
+			assert_eq![ r, Err(TwistError::BadType { expected: "i32" }) ];
+			break 'a 0;
+		}
+	};
+	assert_eq![ x, 0 ];
+}
+
+#[test] fn box_innermost_bad_type () {
+	'a: loop {
+		let x :i32 = loop {
+			let r = twist! { -try -box -val i32, -label 'a |
+				Looping::BreakVal::<(), _> { label: None, value: anybox!(1u8) }
+			};
+			assert_eq![ r, Err(TwistError::BadType { expected: "i32" }) ];
+			break 0;
+		};
+		assert_eq![ x, 0 ];
+		break;
+	}
+}
+
+#[test] fn box_good_type () {
+	let x = 'a: loop {
+		loop {
+			let r :Result<(), _> = twist! { -try -box -label 'a :String |
+				Looping::BreakVal { label: Some(0), value: anybox!("yes".to_string()) }
+			};
+			r.unwrap();
+		}
+	};
+	assert_eq![ x, "yes" ];
+}
+
+#[test] fn mapped () {
+	'a: loop {
+		loop {
+			let r = twist! { -try -label 'a | Err::<i32, usize>(4) => |e| last!(e) };
+			assert_eq![ r, Err(TwistError::InvalidLabel { index: 4, max: 1 }) ];
+			let r = twist! { -try -label 'a | Ok::<i32, usize>(2) => |e| last!(e) };
+			assert_eq![ r, Ok(2) ];
+			break 'a;
+		}
+	}
+}
+
+#[test] fn missing_value () {
+	'a: loop {
+		let x :i32 = loop {
+			let r = twist! { -try -val i32, -label 'a | Looping::Break::<(), _> { label: None } };
+			assert_eq![ r, Err(TwistError::MissingValue) ];
+			let r = twist! { -try -box -val i32, -label 'a | Looping::Break::<(), Box<dyn std::any::Any>> { label: None } };
+			assert_eq![ r, Err(TwistError::MissingValue) ];
+			break 0;
+		};
+		assert_eq![ x, 0 ];
+		break;
+	}
+}
+
+#[test] fn display () {
+	assert_eq![ TwistError::InvalidLabel { index: 2, max: 1 }.to_string(), "Invalid label index 2 in Looping object, expected less than 1" ];
+	assert_eq![ TwistError::MissingValue.to_string(), "The Looping object breaks without a value a loop that expects one" ];
+	assert_eq![ TwistError::BadType { expected: "i32" }.to_string(), "The boxed break value isn't a i32, the type of the loop it's breaking from" ];
+}
+
+#[test] fn named () {
+	'a: loop {
+		loop {
+			let r :Result<(), _> = twist! { -named -try -label 'a as A | last!(Label::A + 1) };
+			assert_eq![ r, Err(TwistError::InvalidLabel { index: 1, max: 1 }) ];
+			break 'a;
+		}
+	}
+}
@@ -1,8 +1,11 @@
 // We test the simple twist!
+#![allow(clippy::never_loop)] // Loops that only run once are the point of these tests
 
 use tear::twist;
 use tear::{next, last, resume};
 use tear::Looping;
+use tear::anybox;
+use std::any::Any;
 
 // All compile fail errors go here
 #[cfg(not(any(feature = "experimental", feature = "ignore-ui")))] // Feature flags to ignore test
@@ -96,6 +99,59 @@ use tear::Looping;
 	assert_eq![ x, 5 ];
 }
 
+#[test] fn with_runtime_labels () {
+	// For each value of i, continue 'c, continue 'b, then break 'a
+	let plan = [2, 1, 0];
+	let mut seen = Vec::new();
+	'a: for (i, &l) in plan.iter().enumerate() {
+		'b: for j in 0..2 {
+			'c: for k in 0..2 {
+				loop {
+					seen.push((i, j, k));
+					twist! { -with ['a, 'b, 'c,] | if l == 0 { last!(l) } else { next!(l) } }
+				}
+			}
+		}
+	}
+	assert_eq![ seen, vec![(0, 0, 0), (0, 0, 1), (0, 1, 0), (0, 1, 1), (1, 0, 0), (1, 1, 0), (2, 0, 0)] ];
+}
+
+#[test] fn with_runtime_labels_mapping () {
+	let mut count = 0;
+	'a: for i in 0..10 {
+		'b: loop {
+			let v = twist! { -with ['a, 'b] | if i > 3 { None } else { Some(i) } => |_| last!(0) };
+			count += v;
+			twist! { -with ['a, 'b] | next!(0) }
+		}
+	}
+	assert_eq![ count, 6 ];
+}
+
+#[test] fn with_runtime_labels_try () {
+	'a: loop {
+		loop {
+			let r :Result<(), _> = twist! { -try -with ['a] | next!(2) };
+			assert_eq![ r, Err(tear::TwistError::InvalidLabel { index: 2, max: 1 }) ];
+			let r = twist! { -try -with ['a] | resume!(3) };
+			assert_eq![ r, Ok(3) ];
+			twist! { -with ['a] | last!(0) }
+		}
+	}
+}
+
+#[cfg(not(feature = "tiny-panic"))] // The message is a short code with "tiny-panic"
+#[test]
+#[should_panic(expected = "invalid label index 1 (twist! declared 1 labels)")]
+fn with_runtime_labels_out_of_range () {
+	let index = 1;
+	'a: loop {
+		loop {
+			twist! { -with ['a] | last!(index) }
+		}
+	}
+}
+
 /* I'm too lazy to test all possibilities, so we test 2 of them with the mapping syntax */
 
 #[test] fn map_breakval () {
@@ -114,3 +170,206 @@ use tear::Looping;
 	}
 	assert_eq![ v, 3 ];
 }
+
+/* The `=>>` shorthand */
+
+#[test] fn ignore_bad_next () {
+	let mut sum = 0;
+	for v in &[Some(1), None, Some(3)] {
+		let v = twist! { *v =>> next!() };
+		sum += v;
+	}
+	assert_eq![ sum, 4 ];
+}
+
+#[test] fn ignore_bad_breakval () {
+	let x = loop {
+		let _ = twist! { -val Err::<i32, _>("failed") =>> Looping::BreakVal { label: None, value: 8 } };
+		break 3;
+	};
+	assert_eq![ x, 8 ];
+}
+
+#[test] fn ignore_bad_is_lazy () {
+	let mut evaluated = 0;
+	for v in &[Some(1), None, Some(3)] {
+		let _ = twist! { *v =>> { evaluated += 1; next!() } };
+	}
+	assert_eq![ evaluated, 1 ];
+}
+
+#[test] fn ignore_bad_last () {
+	let mut sum = 0;
+	for v in &[Some(1), None, Some(3)] {
+		let v = twist! { *v =>> last!() };
+		sum += v;
+	}
+	assert_eq![ sum, 1 ];
+}
+
+/* A struct literal after `=>` is the same as `=>>` */
+
+#[test] fn arrow_struct_literal () {
+	let x = loop {
+		let _ = twist! { -val Err::<i32, _>("failed") => Looping::BreakVal { label: None, value: 8 } };
+		break 3;
+	};
+	assert_eq![ x, 8 ];
+}
+
+#[test] fn arrow_struct_literal_labeled () {
+	let mut sum = 0;
+	'a: for v in &[Some(1), None, Some(3)] {
+		sum += twist! { -with 'a | *v => Looping::Continue { label: None } };
+	}
+	assert_eq![ sum, 4 ];
+}
+
+#[test] fn arrow_struct_literal_is_lazy () {
+	let mut evaluated = 0;
+	for v in &[Some(1), None, Some(3)] {
+		let _ = twist! { *v => Looping::Continue { label: { evaluated += 1; None } } };
+	}
+	assert_eq![ evaluated, 1 ];
+}
+
+/* `=>` takes a mapping function, even from a macro call */
+macro_rules! skip_fn { () => { |_| tear::next!() } }
+
+#[test] fn arrow_macro_is_mapping () {
+	let mut sum = 0;
+	for x in &[Some(1), None, Some(3)] {
+		let v = twist! { *x => skip_fn!() };
+		sum += v;
+	}
+	assert_eq![ sum, 4 ];
+}
+
+#[test] fn breakval_shortcut () {
+	use tear::breakval;
+	let mut i = 0;
+	let x = loop {
+		i += 1;
+		twist! { -val if i < 3 { Looping::Resume(()) } else { breakval!(i * 2) } }
+	};
+	assert_eq![ x, 6 ];
+}
+
+#[test] fn breakval_shortcut_mapped () {
+	use tear::breakval;
+	let results :[Result<i32, &str>; 2] = [Ok(1), Err("bad")];
+	let mut sum = 0;
+	let mut it = results.iter();
+	let x :&str = loop {
+		let r = it.next().unwrap();
+		sum += twist! { -val r.as_ref() => |e| breakval!(*e) };
+	};
+	assert_eq![ x, "bad" ];
+	assert_eq![ sum, 1 ];
+}
+
+#[test] fn resume_unit () {
+	let mut i = 0;
+	loop {
+		i += 1;
+		twist! { if i < 3 { resume!() } else { last!() } }
+	}
+	assert_eq![ i, 3 ];
+}
+
+/* -box -val without -label */
+
+fn create_closure () -> impl FnMut() -> Looping<(), Box<dyn Any>> {
+	let mut i = 0;
+	
+	move || {
+		let v = match i {
+			0 => Looping::Continue { label: None },
+			1 => Looping::BreakVal { label: None, value: anybox!(5) },
+			_ => unreachable!(),
+		};
+		i += 1;
+		v
+	}
+}
+
+#[test] fn box_breakval_innermost () {
+	let mut f = create_closure();
+	let v = loop {
+		twist! { -box -val i32, f() }
+	};
+	assert_eq![ v, 5 ];
+}
+
+#[test] fn box_breakval_map () {
+	let mut i = 0;
+	let v = loop {
+		i += 1;
+		let x = twist! { -box -val String, if i < 3 { Ok(i) } else { Err("done") } => |e :&str|
+			Looping::BreakVal { label: None, value: anybox!(e.to_string()) }
+		};
+		assert![ x < 3 ];
+	};
+	assert_eq![ v, "done" ];
+}
+
+#[test] fn box_breakval_or () {
+	let mut skipped = 0;
+	let mut values = vec![anybox!("one"), anybox!(2)].into_iter();
+	let v = loop {
+		let b = values.next().unwrap();
+		twist! { -box -val i32, -or |_b| { skipped += 1; Looping::Continue { label: None } } |
+			Looping::BreakVal::<(), _> { label: None, value: b }
+		}
+	};
+	assert_eq![ (v, skipped), (2, 1) ];
+}
+
+#[cfg(not(feature = "tiny-panic"))] // The message is a short code with "tiny-panic"
+#[test]
+#[should_panic(expected = "With type i32")]
+fn box_breakval_bad_type () {
+	let _ = loop {
+		twist! { -box -val i32, Looping::BreakVal::<(), _> { label: None, value: anybox!("one") } }
+	};
+}
+
+/* -loop hint */
+
+#[test] fn loop_hint () {
+	let mut count = 0;
+	for _ in 0..5 {
+		count += 1;
+		twist! { -loop for last!() }
+	}
+	assert_eq![ count, 1 ];
+
+	let mut i = 0;
+	while i < 5 {
+		i += 1;
+		twist! { -loop while -finally { i += 10; } if i % 2 == 0 { next!() } else { resume!(()) } }
+	}
+	assert_eq![ i, 12 ];
+
+	let x = loop {
+		twist! { -loop loop -val Looping::BreakVal::<(), _> { label: None, value: 3 } }
+	};
+	assert_eq![ x, 3 ];
+}
+
+#[test] fn last_if_loop_hint () {
+	use tear::last_if;
+	let mut count = 0;
+	for i in 0..5 {
+		last_if! { -loop for, i == 2 }
+		count += 1;
+	}
+	assert_eq![ count, 2 ];
+
+	let mut i = 0;
+	let x = loop {
+		i += 1;
+		last_if! { -loop loop, -val i == 3, i * 10 }
+	};
+	assert_eq![ x, 30 ];
+}
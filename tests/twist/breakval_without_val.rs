@@ -0,0 +1,11 @@
+#![allow(unused, unreachable_code)]
+use tear::{twist, breakval, Looping};
+
+fn main () {
+	let _x :i32 = loop {
+		twist! { breakval!(5) }
+	};
+	let _y :i32 = loop {
+		twist! { Looping::BreakVal::<(), i32> { label: None, value: 5 } }
+	};
+}
@@ -0,0 +1,9 @@
+use tear::{twist, last};
+
+fn last_in_val_loop() {
+	let _x :i32 = loop {
+		twist! { -val last!() }
+	};
+}
+
+fn main () {}
@@ -0,0 +1,20 @@
+#![allow(unused, unreachable_code, while_true)]
+use tear::{twist, last_if, breakval, last};
+
+fn main () {
+	for _ in 0..3 {
+		twist! { -loop for -val breakval!(5) }
+	}
+	while true {
+		twist! { -loop while -box -val i32, last!() }
+	}
+	for _ in 0..3 {
+		twist! { -track broke -loop for -val breakval!(5) }
+	}
+	for i in 0..3 {
+		last_if! { -loop for, -val i > 1, i }
+	}
+	loop {
+		twist! { -loop do last!() }
+	}
+}
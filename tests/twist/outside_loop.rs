@@ -0,0 +1,5 @@
+use tear::{twist, next};
+
+fn main () {
+	twist! { next!() }
+}
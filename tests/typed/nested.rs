@@ -0,0 +1,8 @@
+use tear::{twist, LabelIndex, Looping};
+
+fn main () {
+	'a: loop {
+		// Typed labels nested in blocks and calls are checked too
+		twist! { -typed -label 'a | if true { Looping::<(), ()>::Resume(()) } else { Some(Looping::continue_at::<LabelIndex<1>>()).unwrap() } }
+	}
+}
@@ -0,0 +1,12 @@
+use tear::{twist, labels, Looping};
+
+labels! { Outer, Middle, Inner }
+
+fn main () {
+	'a: loop {
+		'b: loop {
+			// There's no third label
+			twist! { -typed -label 'a, 'b | Looping::<(), ()>::break_at::<Inner>() }
+		}
+	}
+}
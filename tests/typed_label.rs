@@ -0,0 +1,98 @@
+// Testing typed labels and `twist! -typed`
+#![cfg(feature = "const-labels")]
+
+use tear::{twist, labels, LabelIndex, TypedLabel};
+use tear::{Looping, anybox};
+use tear::ValRet::{Val, Ret};
+
+labels! { Outer, Inner }
+
+// All compile fail errors go here
+#[cfg(not(any(feature = "experimental", feature = "ignore-ui")))] // Feature flags to ignore test
+#[test] fn bad_input () {
+	use trybuild;
+	let t = trybuild::TestCases::new();
+	t.compile_fail("tests/typed/*.rs");
+}
+
+#[test] fn indices () {
+	assert_eq![ Outer::INDEX, 0 ];
+	assert_eq![ Inner::INDEX, 1 ];
+	assert_eq![ <LabelIndex<5> as TypedLabel>::INDEX, 5 ];
+	assert_eq![ Looping::<(), ()>::break_at::<Inner>(), Looping::Break { label: Some(1) } ];
+	assert_eq![ Looping::<(), i32>::break_val_at::<Outer>(2), Looping::BreakVal { label: Some(0), value: 2 } ];
+	assert_eq![ Looping::<(), ()>::continue_at::<Outer>(), Looping::Continue { label: Some(0) } ];
+	assert_eq![ Looping::<(), ()>::redo_at::<LabelIndex<3>>(), Looping::Redo { label: Some(3) } ];
+}
+
+#[test] fn visibility () {
+	mod labels {
+		tear::labels! { pub First, Second, }
+	}
+	assert_eq![ labels::Second::INDEX, 1 ];
+}
+
+// Skips the rest of a row at a negative value, and stops at zero
+fn sum (rows :&[&[i32]]) -> (i32, &'static str) {
+	let mut sum = 0;
+	let e = 'a: loop {
+		'b: for row in rows {
+			for &v in row.iter() {
+				sum += twist! { -typed -label 'a: &str, 'b |
+					if v == 0 { Looping::break_val_at::<Outer>("zero") }
+					else if v < 0 { Looping::continue_at::<Inner>() }
+					else { Looping::Resume(v) }
+				};
+			}
+		}
+		break "done";
+	};
+	(sum, e)
+}
+
+#[test] fn label () {
+	assert_eq![ sum(&[&[1, 2], &[3]]), (6, "done") ];
+	assert_eq![ sum(&[&[1, -1, 2], &[3]]), (4, "done") ];
+	assert_eq![ sum(&[&[1], &[0, 3]]), (1, "zero") ];
+}
+
+#[test] fn with_flags () {
+	// With -named, in both orders
+	let x = 'a: loop {
+		'b: loop {
+			twist! { -typed -named -label 'a as A: i32, 'b | Looping::<(), _>::break_val_at::<LabelIndex<0>>(Label::A as i32 + 1) }
+		}
+	};
+	assert_eq![ x, 1 ];
+	let x = 'a: loop {
+		'b: loop {
+			twist! { -named -typed -label 'a: i32, 'b as B | Looping::<(), _>::break_val_at::<Outer>(Label::B as i32) }
+		}
+	};
+	assert_eq![ x, 1 ];
+
+	// With -box and a mapping function
+	let x = 'a: loop {
+		loop {
+			twist! { -typed -box -label 'a: i32 | Err::<(), _>(2) => |v| Looping::break_val_at::<Outer>(anybox!(v)) }
+		}
+	};
+	assert_eq![ x, 2 ];
+
+	// With -ret
+	let f = |values :&[i32]| -> Result<i32, String> {
+		let mut sum = 0;
+		'a: for _ in 0..2 {
+			'b: for &v in values {
+				sum += twist! { -typed -ret String, -label 'a, 'b |
+					if v < 0 { Ret("negative".to_string()) }
+					else if v == 0 { Val(Looping::<_, tear::BreakValError>::break_at::<Inner>()) }
+					else { Val(Looping::Resume(v)) }
+				};
+			}
+		}
+		Ok(sum)
+	};
+	assert_eq![ f(&[1, 0, 2]), Ok(2) ];
+	assert_eq![ f(&[1, -1]), Err("negative".to_string()) ];
+}
@@ -0,0 +1,114 @@
+// Testing `twist! -unchecked`, where invalid label indices are undefined behavior, and that the
+// default mode still panics on them
+use tear::twist;
+use tear::{next, last, breakval, anybox};
+use tear::{stop, skip, go};
+use tear::Looping;
+
+// Sums the values until the first negative one, skipping the multiples of 3 in the outer loop
+fn sum_until_negative (rows :&[&[i32]]) -> i32 {
+	let mut sum = 0;
+	'a: for row in rows {
+		'b: for &v in row.iter() {
+			let signal :Looping<_, ()> = if v < 0 { stop!(0) } else if v % 3 == 0 { skip!(1) } else { go!(v) };
+			sum += twist! { -unchecked -label 'a, 'b | signal };
+		}
+	}
+	sum
+}
+
+#[test] fn label () {
+	assert_eq![ sum_until_negative(&[&[1, 2], &[4, 3, 5], &[7]]), 19 ];
+	assert_eq![ sum_until_negative(&[&[1, -2], &[4]]), 1 ];
+}
+
+#[test] fn val_label () {
+	let x = 'a: loop {
+		let y :i32 = loop {
+			twist! { -unchecked -val i32, -label 'a :i32 | breakval!(5) }
+		};
+		break 'a y + 1;
+	};
+	assert_eq![ x, 6 ];
+}
+
+#[test] fn box_label () {
+	let x = 'a: loop {
+		loop {
+			twist! { -unchecked -box -label 'a :String | Looping::BreakVal::<(), _> { label: Some(0), value: anybox!("a".to_string()) } }
+		}
+	};
+	assert_eq![ x, "a" ];
+}
+
+// A failed downcast isn't an invalid label index, so it still panics
+#[cfg(not(feature = "tiny-panic"))] // The message is a short code with "tiny-panic"
+#[test]
+#[should_panic(expected = "At label 'a with type i32")]
+fn box_bad_type_panics () {
+	let _ :i32 = 'a: loop {
+		loop {
+			twist! { -unchecked -box -label 'a :i32 | Looping::BreakVal::<(), _> { label: Some(0), value: anybox!("a") } }
+		}
+	};
+}
+
+#[test] fn with_labels () {
+	let mut i = 0;
+	'a: loop {
+		i += 1;
+		loop {
+			twist! { -unchecked -with ['a] | if i < 3 { next!(0) } else { last!(0) } }
+		}
+	}
+	assert_eq![ i, 3 ];
+}
+
+#[test] fn enum_mode () {
+	let x = 'a: loop {
+		loop {
+			twist! { -unchecked -enum Sig -label 'a as A :i32 | Looping::BreakVal::<(), _> { label: None, value: Sig::A(7) } }
+		}
+	};
+	assert_eq![ x, 7 ];
+}
+
+#[test] fn named () {
+	let x = 'a: loop {
+		loop {
+			twist! { -named -unchecked -label 'a as A :i32 | breakval!(Label::A, 8) }
+		}
+	};
+	assert_eq![ x, 8 ];
+}
+
+/* The default mode */
+
+#[cfg(not(feature = "tiny-panic"))] // The message is a short code with "tiny-panic"
+#[test]
+#[should_panic(expected = "invalid label index 1 (twist! declared 1 labels)")]
+fn checked_break_panics () {
+	'a: loop {
+		twist! { -label 'a | last!(1) }
+	}
+}
+
+#[cfg(not(feature = "tiny-panic"))] // The message is a short code with "tiny-panic"
+#[test]
+#[should_panic(expected = "invalid label index 1 (twist! declared 1 labels)")]
+fn checked_continue_panics () {
+	'a: loop {
+		twist! { -with ['a] | next!(1) }
+	}
+}
+
+#[cfg(not(feature = "tiny-panic"))] // The message is a short code with "tiny-panic"
+#[test]
+#[should_panic(expected = "invalid label index 1 (twist! declared 1 labels)")]
+fn checked_breakval_panics () {
+	let _ :i32 = 'a: loop {
+		loop {
+			twist! { -label 'a :i32 | breakval!(1, 0) }
+		}
+	};
+}
@@ -34,3 +34,13 @@ impl<T, U> tear::Judge for AB<T, U> {
 	}
 	assert_eq![ f(), 6 ];
 }
+
+#[test] fn tear_ignore_bad () {
+	fn f (v :Result<i32, String>, fallback :String) -> String {
+		let v = tear! { v =>> fallback };
+		format!("{} {}", fallback, v)
+	}
+
+	assert_eq![ f(Ok(1), "a".to_string()), "a 1".to_string() ];
+	assert_eq![ f(Err("no".to_string()), "a".to_string()), "a".to_string() ];
+}